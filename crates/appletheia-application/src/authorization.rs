@@ -1,3 +1,5 @@
+pub mod aggregate_authorization_filter;
+pub mod aggregate_authorization_filter_error;
 pub mod aggregate_ref;
 pub mod aggregate_ref_error;
 pub mod authorization_model;
@@ -5,9 +7,11 @@ pub mod authorization_model_error;
 pub mod authorization_plan;
 pub mod authorizer;
 pub mod authorizer_error;
+pub mod cached_relationship_resolver;
 pub mod default_authorizer;
 pub mod default_relationship_resolver;
 pub mod in_memory_authorization_model;
+pub mod in_memory_relationship_decision_cache;
 pub mod principal_requirement;
 pub mod relation;
 pub mod relation_name;
@@ -17,16 +21,24 @@ pub mod relation_ref;
 pub mod relation_ref_owned;
 pub mod relationship;
 pub mod relationship_change;
+pub mod relationship_decision_cache;
+pub mod relationship_decision_cache_error;
+pub mod relationship_decision_cache_key;
+pub mod relationship_decision_cache_stats;
 pub mod relationship_eval_node_count;
 pub mod relationship_eval_scanned_relationship_count;
 mod relationship_eval_state;
 pub mod relationship_id;
 pub mod relationship_id_error;
 mod relationship_memo_key;
+mod relationship_prefetch_group;
+mod relationship_read_key;
 pub mod relationship_requirement;
 pub mod relationship_resolver;
 pub mod relationship_resolver_config;
 pub mod relationship_resolver_error;
+pub mod relationship_revision;
+pub mod relationship_revision_error;
 pub mod relationship_store;
 pub mod relationship_store_error;
 pub mod relationship_subject;
@@ -35,6 +47,8 @@ mod userset_expr_eval_context;
 pub mod userset_expr_eval_depth;
 pub mod userset_expr_owned;
 
+pub use aggregate_authorization_filter::AggregateAuthorizationFilter;
+pub use aggregate_authorization_filter_error::AggregateAuthorizationFilterError;
 pub use aggregate_ref::AggregateRef;
 pub use aggregate_ref_error::AggregateRefError;
 pub use authorization_model::AuthorizationModel;
@@ -42,9 +56,11 @@ pub use authorization_model_error::AuthorizationModelError;
 pub use authorization_plan::AuthorizationPlan;
 pub use authorizer::Authorizer;
 pub use authorizer_error::AuthorizerError;
+pub use cached_relationship_resolver::CachedRelationshipResolver;
 pub use default_authorizer::DefaultAuthorizer;
 pub use default_relationship_resolver::DefaultRelationshipResolver;
 pub use in_memory_authorization_model::InMemoryAuthorizationModel;
+pub use in_memory_relationship_decision_cache::InMemoryRelationshipDecisionCache;
 pub use principal_requirement::PrincipalRequirement;
 pub use relation::Relation;
 pub use relation_name::RelationName;
@@ -54,6 +70,10 @@ pub use relation_ref::RelationRef;
 pub use relation_ref_owned::RelationRefOwned;
 pub use relationship::Relationship;
 pub use relationship_change::RelationshipChange;
+pub use relationship_decision_cache::RelationshipDecisionCache;
+pub use relationship_decision_cache_error::RelationshipDecisionCacheError;
+pub use relationship_decision_cache_key::RelationshipDecisionCacheKey;
+pub use relationship_decision_cache_stats::RelationshipDecisionCacheStats;
 pub use relationship_eval_node_count::RelationshipEvalNodeCount;
 pub use relationship_eval_scanned_relationship_count::RelationshipEvalScannedRelationshipCount;
 pub use relationship_id::RelationshipId;
@@ -62,6 +82,8 @@ pub use relationship_requirement::RelationshipRequirement;
 pub use relationship_resolver::RelationshipResolver;
 pub use relationship_resolver_config::RelationshipResolverConfig;
 pub use relationship_resolver_error::RelationshipResolverError;
+pub use relationship_revision::RelationshipRevision;
+pub use relationship_revision_error::RelationshipRevisionError;
 pub use relationship_store::RelationshipStore;
 pub use relationship_store_error::RelationshipStoreError;
 pub use relationship_subject::RelationshipSubject;
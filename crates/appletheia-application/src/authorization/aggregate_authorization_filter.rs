@@ -0,0 +1,274 @@
+use appletheia_domain::Aggregate;
+
+use crate::request_context::Principal;
+use crate::unit_of_work::UnitOfWork;
+
+use super::{
+    AggregateAuthorizationFilterError, AggregateRef, RelationRef, RelationRefOwned,
+    RelationshipResolver,
+};
+
+/// Filters read-model rows down to the aggregates a principal may access.
+///
+/// Query handlers use this when `QueryHandler::authorization_plan` cannot express
+/// row-level access: candidates are evaluated in bulk through
+/// `RelationshipResolver::filter_satisfying`, which lets the resolver batch its
+/// relationship reads. Only authenticated principals can hold relationships, so
+/// anonymous and system principals never see any candidate.
+#[derive(Debug)]
+pub struct AggregateAuthorizationFilter<RR>
+where
+    RR: RelationshipResolver,
+    RR::Uow: UnitOfWork,
+{
+    relationship_resolver: RR,
+}
+
+impl<RR> AggregateAuthorizationFilter<RR>
+where
+    RR: RelationshipResolver,
+    RR::Uow: UnitOfWork,
+{
+    pub fn new(relationship_resolver: RR) -> Self {
+        Self {
+            relationship_resolver,
+        }
+    }
+
+    /// Returns the candidates on which the principal satisfies `relation`, in
+    /// their original order.
+    pub async fn filter(
+        &self,
+        uow: &mut RR::Uow,
+        principal: &Principal,
+        candidates: &[AggregateRef],
+        relation: &RelationRefOwned,
+    ) -> Result<Vec<AggregateRef>, AggregateAuthorizationFilterError> {
+        let subject = match principal {
+            Principal::Authenticated { subject } => subject,
+            Principal::Anonymous | Principal::System => return Ok(Vec::new()),
+            Principal::Unavailable => {
+                return Err(AggregateAuthorizationFilterError::PrincipalUnavailable);
+            }
+        };
+
+        if candidates.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .relationship_resolver
+            .filter_satisfying(uow, subject, candidates, relation)
+            .await?)
+    }
+
+    /// Typed variant of [`Self::filter`] for identifiers of a single aggregate type.
+    pub async fn filter_ids<A: Aggregate>(
+        &self,
+        uow: &mut RR::Uow,
+        principal: &Principal,
+        ids: &[A::Id],
+        relation: RelationRef,
+    ) -> Result<Vec<A::Id>, AggregateAuthorizationFilterError> {
+        let candidates: Vec<AggregateRef> = ids
+            .iter()
+            .map(|id| AggregateRef::from_id::<A>(*id))
+            .collect();
+
+        let allowed = self
+            .filter(
+                uow,
+                principal,
+                &candidates,
+                &RelationRefOwned::from(relation),
+            )
+            .await?;
+
+        let mut allowed = allowed.into_iter().peekable();
+        let mut allowed_ids = Vec::new();
+        for (id, candidate) in ids.iter().zip(&candidates) {
+            if allowed.peek() == Some(candidate) {
+                allowed.next();
+                allowed_ids.push(*id);
+            }
+        }
+        Ok(allowed_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use uuid::Uuid;
+
+    use super::AggregateAuthorizationFilter;
+    use crate::authorization::{
+        AggregateAuthorizationFilterError, AggregateRef, DefaultRelationshipResolver,
+        InMemoryAuthorizationModel, RelationName, RelationRefOwned, RelationshipChange,
+        RelationshipResolverConfig, RelationshipRevision, RelationshipStore,
+        RelationshipStoreError, RelationshipSubject, UsersetExprOwned,
+    };
+    use crate::event::{AggregateIdValue, AggregateTypeOwned};
+    use crate::request_context::Principal;
+    use crate::unit_of_work::{UnitOfWork, UnitOfWorkError};
+
+    #[derive(Default)]
+    struct TestUow;
+
+    impl UnitOfWork for TestUow {
+        async fn commit(self) -> Result<(), UnitOfWorkError> {
+            Ok(())
+        }
+
+        async fn rollback(self) -> Result<(), UnitOfWorkError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct TestStore {
+        map: HashMap<(AggregateRef, RelationRefOwned), Vec<RelationshipSubject>>,
+    }
+
+    impl RelationshipStore for TestStore {
+        type Uow = TestUow;
+
+        async fn apply_changes(
+            &self,
+            _uow: &mut Self::Uow,
+            _changes: &[RelationshipChange],
+        ) -> Result<(), RelationshipStoreError> {
+            Ok(())
+        }
+
+        async fn read_aggregates_by_subject(
+            &self,
+            _uow: &mut Self::Uow,
+            _subject: &RelationshipSubject,
+            _relation: &RelationRefOwned,
+        ) -> Result<Vec<AggregateRef>, RelationshipStoreError> {
+            Ok(Vec::new())
+        }
+
+        async fn read_subjects_by_aggregate(
+            &self,
+            _uow: &mut Self::Uow,
+            aggregate: &AggregateRef,
+            relation: &RelationRefOwned,
+            _subject_aggregate_type: Option<&AggregateTypeOwned>,
+        ) -> Result<Vec<RelationshipSubject>, RelationshipStoreError> {
+            Ok(self
+                .map
+                .get(&(aggregate.clone(), relation.clone()))
+                .cloned()
+                .unwrap_or_default())
+        }
+
+        async fn read_revision(
+            &self,
+            _uow: &mut Self::Uow,
+        ) -> Result<RelationshipRevision, RelationshipStoreError> {
+            Ok(RelationshipRevision::initial())
+        }
+    }
+
+    fn aggregate_ref(ty: &str, id: u128) -> AggregateRef {
+        AggregateRef::new(
+            ty.parse::<AggregateTypeOwned>()
+                .expect("aggregate type should be valid"),
+            AggregateIdValue::from(Uuid::from_u128(id)),
+        )
+    }
+
+    fn reader_relation() -> RelationRefOwned {
+        RelationRefOwned::new(
+            "document".parse().expect("aggregate type should be valid"),
+            RelationName::new("reader").into(),
+        )
+    }
+
+    fn filter(
+        user: &AggregateRef,
+        readable: &[AggregateRef],
+    ) -> AggregateAuthorizationFilter<
+        DefaultRelationshipResolver<TestStore, InMemoryAuthorizationModel>,
+    > {
+        let mut store = TestStore::default();
+        for document in readable {
+            store.map.insert(
+                (document.clone(), reader_relation()),
+                vec![RelationshipSubject::Aggregate(user.clone())],
+            );
+        }
+        let mut model = InMemoryAuthorizationModel::new();
+        model.define_expr(reader_relation(), UsersetExprOwned::This);
+
+        AggregateAuthorizationFilter::new(DefaultRelationshipResolver::new(
+            store,
+            model,
+            RelationshipResolverConfig::default(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn keeps_only_candidates_readable_by_authenticated_principal() {
+        let user = aggregate_ref("user", 1);
+        let documents: Vec<AggregateRef> =
+            (10..14).map(|id| aggregate_ref("document", id)).collect();
+        let filter = filter(&user, &[documents[3].clone(), documents[1].clone()]);
+
+        let allowed = filter
+            .filter(
+                &mut TestUow,
+                &Principal::Authenticated { subject: user },
+                &documents,
+                &reader_relation(),
+            )
+            .await
+            .expect("filtering should succeed");
+
+        assert_eq!(allowed, vec![documents[1].clone(), documents[3].clone()]);
+    }
+
+    #[tokio::test]
+    async fn returns_nothing_for_anonymous_principal() {
+        let user = aggregate_ref("user", 1);
+        let document = aggregate_ref("document", 10);
+        let filter = filter(&user, std::slice::from_ref(&document));
+
+        let allowed = filter
+            .filter(
+                &mut TestUow,
+                &Principal::Anonymous,
+                &[document],
+                &reader_relation(),
+            )
+            .await
+            .expect("filtering should succeed");
+
+        assert!(allowed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejects_unavailable_principal() {
+        let user = aggregate_ref("user", 1);
+        let document = aggregate_ref("document", 10);
+        let filter = filter(&user, std::slice::from_ref(&document));
+
+        let error = filter
+            .filter(
+                &mut TestUow,
+                &Principal::Unavailable,
+                &[document],
+                &reader_relation(),
+            )
+            .await
+            .expect_err("unavailable principal should be rejected");
+
+        assert!(matches!(
+            error,
+            AggregateAuthorizationFilterError::PrincipalUnavailable
+        ));
+    }
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+
+use super::RelationshipResolverError;
+
+#[derive(Debug, Error)]
+pub enum AggregateAuthorizationFilterError {
+    #[error("principal is unavailable in request context")]
+    PrincipalUnavailable,
+
+    #[error(transparent)]
+    RelationshipResolver(#[from] RelationshipResolverError),
+}
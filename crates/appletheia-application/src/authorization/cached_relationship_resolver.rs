@@ -0,0 +1,492 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+
+use crate::unit_of_work::UnitOfWork;
+
+use super::{
+    AggregateRef, RelationRefOwned, RelationshipDecisionCache, RelationshipDecisionCacheKey,
+    RelationshipDecisionCacheStats, RelationshipRequirement, RelationshipResolver,
+    RelationshipResolverError, RelationshipRevision, RelationshipStore, RelationshipStoreError,
+};
+
+/// Serves relationship decisions from a `RelationshipDecisionCache` before
+/// delegating to the wrapped resolver.
+///
+/// Decisions are keyed by subject, requirement and the revision reported by the
+/// relationship store. Because `RelationshipStore::apply_changes` moves the store
+/// to a new revision, cached decisions are never served after the relationship
+/// graph changes. A decision is only cached when the revision did not change
+/// while it was being evaluated. Stores that do not support revisions are
+/// resolved through the wrapped resolver without caching.
+#[derive(Debug)]
+pub struct CachedRelationshipResolver<RR, RS, C>
+where
+    RR: RelationshipResolver,
+    RR::Uow: UnitOfWork,
+    RS: RelationshipStore<Uow = RR::Uow>,
+    C: RelationshipDecisionCache,
+{
+    relationship_resolver: RR,
+    relationship_store: RS,
+    cache: C,
+    hit_count: AtomicU64,
+    miss_count: AtomicU64,
+}
+
+impl<RR, RS, C> CachedRelationshipResolver<RR, RS, C>
+where
+    RR: RelationshipResolver,
+    RR::Uow: UnitOfWork,
+    RS: RelationshipStore<Uow = RR::Uow>,
+    C: RelationshipDecisionCache,
+{
+    pub fn new(relationship_resolver: RR, relationship_store: RS, cache: C) -> Self {
+        Self {
+            relationship_resolver,
+            relationship_store,
+            cache,
+            hit_count: AtomicU64::new(0),
+            miss_count: AtomicU64::new(0),
+        }
+    }
+
+    pub fn cache(&self) -> &C {
+        &self.cache
+    }
+
+    pub fn stats(&self) -> RelationshipDecisionCacheStats {
+        RelationshipDecisionCacheStats {
+            hit_count: self.hit_count.load(AtomicOrdering::Relaxed),
+            miss_count: self.miss_count.load(AtomicOrdering::Relaxed),
+        }
+    }
+
+    async fn read_revision(
+        &self,
+        uow: &mut RR::Uow,
+    ) -> Result<Option<RelationshipRevision>, RelationshipResolverError> {
+        match self.relationship_store.read_revision(uow).await {
+            Ok(revision) => Ok(Some(revision)),
+            Err(RelationshipStoreError::RevisionsUnsupported) => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+impl<RR, RS, C> RelationshipResolver for CachedRelationshipResolver<RR, RS, C>
+where
+    RR: RelationshipResolver,
+    RR::Uow: UnitOfWork,
+    RS: RelationshipStore<Uow = RR::Uow>,
+    C: RelationshipDecisionCache,
+{
+    type Uow = RR::Uow;
+
+    async fn satisfies(
+        &self,
+        uow: &mut Self::Uow,
+        subject: &AggregateRef,
+        requirement: &RelationshipRequirement,
+    ) -> Result<bool, RelationshipResolverError> {
+        let Some(revision) = self.read_revision(uow).await? else {
+            return self
+                .relationship_resolver
+                .satisfies(uow, subject, requirement)
+                .await;
+        };
+        let key = RelationshipDecisionCacheKey::new(subject.clone(), requirement.clone(), revision);
+
+        if let Some(decision) = self.cache.get(&key).await? {
+            self.hit_count.fetch_add(1, AtomicOrdering::Relaxed);
+            return Ok(decision);
+        }
+        self.miss_count.fetch_add(1, AtomicOrdering::Relaxed);
+
+        let decision = self
+            .relationship_resolver
+            .satisfies(uow, subject, requirement)
+            .await?;
+
+        if self.read_revision(uow).await? == Some(revision) {
+            self.cache.insert(key, decision).await?;
+        }
+
+        Ok(decision)
+    }
+
+    async fn filter_satisfying(
+        &self,
+        uow: &mut Self::Uow,
+        subject: &AggregateRef,
+        candidates: &[AggregateRef],
+        relation: &RelationRefOwned,
+    ) -> Result<Vec<AggregateRef>, RelationshipResolverError> {
+        let Some(revision) = self.read_revision(uow).await? else {
+            return self
+                .relationship_resolver
+                .filter_satisfying(uow, subject, candidates, relation)
+                .await;
+        };
+
+        let mut decisions = Vec::with_capacity(candidates.len());
+        let mut misses = Vec::new();
+        for candidate in candidates {
+            let key = RelationshipDecisionCacheKey::new(
+                subject.clone(),
+                RelationshipRequirement::Check {
+                    aggregate: candidate.clone(),
+                    relation: relation.clone(),
+                },
+                revision,
+            );
+            let decision = self.cache.get(&key).await?;
+            match decision {
+                Some(_) => self.hit_count.fetch_add(1, AtomicOrdering::Relaxed),
+                None => {
+                    misses.push(candidate.clone());
+                    self.miss_count.fetch_add(1, AtomicOrdering::Relaxed)
+                }
+            };
+            decisions.push((key, decision));
+        }
+
+        if !misses.is_empty() {
+            let allowed: HashSet<AggregateRef> = self
+                .relationship_resolver
+                .filter_satisfying(uow, subject, &misses, relation)
+                .await?
+                .into_iter()
+                .collect();
+            let cacheable = self.read_revision(uow).await? == Some(revision);
+
+            for (key, decision) in decisions.iter_mut().filter(|(_, d)| d.is_none()) {
+                let RelationshipRequirement::Check { aggregate, .. } = &key.requirement else {
+                    continue;
+                };
+                let allowed = allowed.contains(aggregate);
+                if cacheable {
+                    self.cache.insert(key.clone(), allowed).await?;
+                }
+                *decision = Some(allowed);
+            }
+        }
+
+        Ok(candidates
+            .iter()
+            .zip(decisions)
+            .filter(|(_, (_, decision))| *decision == Some(true))
+            .map(|(candidate, _)| candidate.clone())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering as AtomicOrdering};
+    use std::sync::{Arc, Mutex};
+
+    use uuid::Uuid;
+
+    use super::CachedRelationshipResolver;
+    use crate::authorization::{
+        AggregateRef, DefaultRelationshipResolver, InMemoryAuthorizationModel,
+        InMemoryRelationshipDecisionCache, RelationName, RelationRefOwned, Relationship,
+        RelationshipChange, RelationshipDecisionCacheStats, RelationshipRequirement,
+        RelationshipResolver, RelationshipResolverConfig, RelationshipRevision, RelationshipStore,
+        RelationshipStoreError, RelationshipSubject, UsersetExprOwned,
+    };
+    use crate::event::{AggregateIdValue, AggregateTypeOwned};
+    use crate::unit_of_work::{UnitOfWork, UnitOfWorkError};
+
+    #[derive(Default)]
+    struct TestUow;
+
+    impl UnitOfWork for TestUow {
+        async fn commit(self) -> Result<(), UnitOfWorkError> {
+            Ok(())
+        }
+
+        async fn rollback(self) -> Result<(), UnitOfWorkError> {
+            Ok(())
+        }
+    }
+
+    type SubjectsByRelation = HashMap<(AggregateRef, RelationRefOwned), Vec<RelationshipSubject>>;
+
+    #[derive(Clone, Default)]
+    struct TestStore {
+        map: Arc<Mutex<SubjectsByRelation>>,
+        revision: Arc<AtomicI64>,
+        read_count: Arc<AtomicUsize>,
+        revisions_unsupported: bool,
+    }
+
+    impl RelationshipStore for TestStore {
+        type Uow = TestUow;
+
+        async fn apply_changes(
+            &self,
+            _uow: &mut Self::Uow,
+            changes: &[RelationshipChange],
+        ) -> Result<(), RelationshipStoreError> {
+            let mut map = self.map.lock().expect("lock should succeed");
+            for change in changes {
+                match change {
+                    RelationshipChange::Upsert(relationship) => map
+                        .entry((
+                            relationship.aggregate.clone(),
+                            relationship.relation.clone(),
+                        ))
+                        .or_default()
+                        .push(relationship.subject.clone()),
+                    RelationshipChange::Delete(relationship) => {
+                        if let Some(subjects) = map.get_mut(&(
+                            relationship.aggregate.clone(),
+                            relationship.relation.clone(),
+                        )) {
+                            subjects.retain(|subject| subject != &relationship.subject);
+                        }
+                    }
+                }
+            }
+            self.revision.fetch_add(1, AtomicOrdering::SeqCst);
+            Ok(())
+        }
+
+        async fn read_aggregates_by_subject(
+            &self,
+            _uow: &mut Self::Uow,
+            _subject: &RelationshipSubject,
+            _relation: &RelationRefOwned,
+        ) -> Result<Vec<AggregateRef>, RelationshipStoreError> {
+            Ok(Vec::new())
+        }
+
+        async fn read_subjects_by_aggregate(
+            &self,
+            _uow: &mut Self::Uow,
+            aggregate: &AggregateRef,
+            relation: &RelationRefOwned,
+            _subject_aggregate_type: Option<&AggregateTypeOwned>,
+        ) -> Result<Vec<RelationshipSubject>, RelationshipStoreError> {
+            self.read_count.fetch_add(1, AtomicOrdering::SeqCst);
+            Ok(self
+                .map
+                .lock()
+                .expect("lock should succeed")
+                .get(&(aggregate.clone(), relation.clone()))
+                .cloned()
+                .unwrap_or_default())
+        }
+
+        async fn read_revision(
+            &self,
+            _uow: &mut Self::Uow,
+        ) -> Result<RelationshipRevision, RelationshipStoreError> {
+            if self.revisions_unsupported {
+                return Err(RelationshipStoreError::RevisionsUnsupported);
+            }
+            RelationshipRevision::try_from(self.revision.load(AtomicOrdering::SeqCst))
+                .map_err(|e| RelationshipStoreError::MappingFailed(Box::new(e)))
+        }
+    }
+
+    fn aggregate_ref(ty: &str, id: u128) -> AggregateRef {
+        AggregateRef::new(
+            ty.parse::<AggregateTypeOwned>()
+                .expect("aggregate type should be valid"),
+            AggregateIdValue::from(Uuid::from_u128(id)),
+        )
+    }
+
+    fn relation_ref(aggregate_type_name: &str, relation_name: &'static str) -> RelationRefOwned {
+        RelationRefOwned::new(
+            aggregate_type_name
+                .parse()
+                .expect("aggregate type should be valid"),
+            RelationName::new(relation_name).into(),
+        )
+    }
+
+    fn cached_resolver(
+        store: TestStore,
+    ) -> CachedRelationshipResolver<
+        DefaultRelationshipResolver<TestStore, InMemoryAuthorizationModel>,
+        TestStore,
+        InMemoryRelationshipDecisionCache,
+    > {
+        let mut model = InMemoryAuthorizationModel::new();
+        model.define_expr(relation_ref("document", "editor"), UsersetExprOwned::This);
+
+        CachedRelationshipResolver::new(
+            DefaultRelationshipResolver::new(
+                store.clone(),
+                model,
+                RelationshipResolverConfig::default(),
+            ),
+            store,
+            InMemoryRelationshipDecisionCache::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn serves_repeated_checks_from_cache() {
+        let document = aggregate_ref("document", 1);
+        let user = aggregate_ref("user", 2);
+        let store = TestStore::default();
+        store
+            .apply_changes(
+                &mut TestUow,
+                &[RelationshipChange::Upsert(Relationship {
+                    aggregate: document.clone(),
+                    relation: relation_ref("document", "editor"),
+                    subject: RelationshipSubject::Aggregate(user.clone()),
+                })],
+            )
+            .await
+            .expect("changes should be applied");
+        let resolver = cached_resolver(store.clone());
+        let requirement = RelationshipRequirement::Check {
+            aggregate: document,
+            relation: relation_ref("document", "editor"),
+        };
+
+        for _ in 0..3 {
+            let allowed = resolver
+                .satisfies(&mut TestUow, &user, &requirement)
+                .await
+                .expect("resolution should succeed");
+            assert!(allowed);
+        }
+
+        assert_eq!(store.read_count.load(AtomicOrdering::SeqCst), 1);
+        assert_eq!(
+            resolver.stats(),
+            RelationshipDecisionCacheStats {
+                hit_count: 2,
+                miss_count: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn bypasses_cache_when_store_does_not_support_revisions() {
+        let document = aggregate_ref("document", 1);
+        let user = aggregate_ref("user", 2);
+        let store = TestStore {
+            revisions_unsupported: true,
+            ..TestStore::default()
+        };
+        let resolver = cached_resolver(store.clone());
+        let requirement = RelationshipRequirement::Check {
+            aggregate: document,
+            relation: relation_ref("document", "editor"),
+        };
+
+        for _ in 0..2 {
+            let allowed = resolver
+                .satisfies(&mut TestUow, &user, &requirement)
+                .await
+                .expect("resolution should succeed");
+            assert!(!allowed);
+        }
+
+        assert_eq!(store.read_count.load(AtomicOrdering::SeqCst), 2);
+        assert!(resolver.cache().is_empty());
+        assert_eq!(resolver.stats(), RelationshipDecisionCacheStats::default());
+    }
+
+    #[tokio::test]
+    async fn applied_changes_invalidate_cached_decisions() {
+        let document = aggregate_ref("document", 1);
+        let user = aggregate_ref("user", 2);
+        let store = TestStore::default();
+        let resolver = cached_resolver(store.clone());
+        let requirement = RelationshipRequirement::Check {
+            aggregate: document.clone(),
+            relation: relation_ref("document", "editor"),
+        };
+
+        let allowed = resolver
+            .satisfies(&mut TestUow, &user, &requirement)
+            .await
+            .expect("resolution should succeed");
+        assert!(!allowed);
+
+        store
+            .apply_changes(
+                &mut TestUow,
+                &[RelationshipChange::Upsert(Relationship {
+                    aggregate: document,
+                    relation: relation_ref("document", "editor"),
+                    subject: RelationshipSubject::Aggregate(user.clone()),
+                })],
+            )
+            .await
+            .expect("changes should be applied");
+
+        let allowed = resolver
+            .satisfies(&mut TestUow, &user, &requirement)
+            .await
+            .expect("resolution should succeed");
+        assert!(allowed);
+        assert_eq!(resolver.stats().miss_count, 2);
+        assert_eq!(resolver.stats().hit_count, 0);
+    }
+
+    #[tokio::test]
+    async fn filter_satisfying_only_evaluates_uncached_candidates() {
+        let first = aggregate_ref("document", 1);
+        let second = aggregate_ref("document", 2);
+        let user = aggregate_ref("user", 3);
+        let store = TestStore::default();
+        store
+            .apply_changes(
+                &mut TestUow,
+                &[RelationshipChange::Upsert(Relationship {
+                    aggregate: second.clone(),
+                    relation: relation_ref("document", "editor"),
+                    subject: RelationshipSubject::Aggregate(user.clone()),
+                })],
+            )
+            .await
+            .expect("changes should be applied");
+        let resolver = cached_resolver(store.clone());
+        let candidates = vec![first.clone(), second.clone()];
+
+        let allowed = resolver
+            .satisfies(
+                &mut TestUow,
+                &user,
+                &RelationshipRequirement::Check {
+                    aggregate: first,
+                    relation: relation_ref("document", "editor"),
+                },
+            )
+            .await
+            .expect("resolution should succeed");
+        assert!(!allowed);
+
+        for _ in 0..2 {
+            let allowed = resolver
+                .filter_satisfying(
+                    &mut TestUow,
+                    &user,
+                    &candidates,
+                    &relation_ref("document", "editor"),
+                )
+                .await
+                .expect("resolution should succeed");
+            assert_eq!(allowed, vec![second.clone()]);
+        }
+
+        assert_eq!(
+            resolver.stats(),
+            RelationshipDecisionCacheStats {
+                hit_count: 3,
+                miss_count: 2,
+            }
+        );
+    }
+}
@@ -141,6 +141,7 @@ mod tests {
     use crate::authorization::DefaultRelationshipResolver;
     use crate::authorization::InMemoryAuthorizationModel;
     use crate::authorization::RelationshipChange;
+    use crate::authorization::RelationshipRevision;
     use crate::authorization::RelationshipStoreError;
     use crate::event::{AggregateIdValue, AggregateTypeOwned};
     use crate::request_context::Principal;
@@ -217,6 +218,13 @@ mod tests {
                 .cloned()
                 .unwrap_or_default())
         }
+
+        async fn read_revision(
+            &self,
+            _uow: &mut TestUow,
+        ) -> Result<RelationshipRevision, RelationshipStoreError> {
+            Ok(RelationshipRevision::initial())
+        }
     }
 
     fn aggregate_type(value: &str) -> AggregateTypeOwned {
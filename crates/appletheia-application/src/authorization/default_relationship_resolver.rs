@@ -1,7 +1,16 @@
+use std::collections::{HashMap, HashSet};
+
+use metrics::histogram;
+
+use crate::event::AggregateTypeOwned;
+use crate::telemetry::MetricName;
 use crate::unit_of_work::UnitOfWork;
 
+use super::relationship_eval_scanned_relationship_count::RelationshipEvalScannedRelationshipCount;
 use super::relationship_eval_state::RelationshipEvalState;
 use super::relationship_memo_key::RelationshipMemoKey;
+use super::relationship_prefetch_group::RelationshipPrefetchGroup;
+use super::relationship_read_key::RelationshipReadKey;
 use super::userset_expr_eval_context::UsersetExprEvalContext;
 use super::userset_expr_eval_depth::UsersetExprEvalDepth;
 use super::{
@@ -91,6 +100,11 @@ where
         }
     }
 
+    fn record_node_count(state: &RelationshipEvalState) {
+        histogram!(MetricName::AuthorizationEvaluatedNodes.as_str())
+            .record(state.node_count.value() as f64);
+    }
+
     async fn check_relation(
         &self,
         uow: &mut RS::Uow,
@@ -153,6 +167,197 @@ where
         Ok(result)
     }
 
+    async fn read_subjects(
+        &self,
+        uow: &mut RS::Uow,
+        state: &RelationshipEvalState,
+        aggregate: &AggregateRef,
+        relation: &RelationRefOwned,
+        subject_aggregate_type: Option<&AggregateTypeOwned>,
+    ) -> Result<Vec<RelationshipSubject>, RelationshipResolverError> {
+        if !state.prefetched.is_empty() {
+            let key = RelationshipReadKey {
+                aggregate: aggregate.clone(),
+                relation: relation.clone(),
+                subject_aggregate_type: subject_aggregate_type.cloned(),
+            };
+            if let Some(subjects) = state.prefetched.get(&key) {
+                return Ok(subjects.clone());
+            }
+        }
+
+        self.relationship_store
+            .read_subjects_by_aggregate(uow, aggregate, relation, subject_aggregate_type)
+            .await
+            .map_err(RelationshipResolverError::from)
+    }
+
+    /// Reads, level by level, every relationship the evaluation of `relation` on the
+    /// given aggregates may need, issuing one batched read per relation and level.
+    ///
+    /// Prefetching stops once `max_depth` levels were read or more than
+    /// `max_scanned_relationship_count` relationships were loaded; anything not
+    /// prefetched is read lazily during evaluation.
+    async fn prefetch(
+        &self,
+        uow: &mut RS::Uow,
+        state: &mut RelationshipEvalState,
+        aggregates: &[AggregateRef],
+        relation: &RelationRefOwned,
+    ) -> Result<(), RelationshipResolverError> {
+        let mut visited: HashSet<(AggregateRef, RelationRefOwned)> = HashSet::new();
+        let mut frontier: Vec<(AggregateRef, RelationRefOwned)> = aggregates
+            .iter()
+            .map(|aggregate| (aggregate.clone(), relation.clone()))
+            .collect();
+        let mut depth = UsersetExprEvalDepth::default();
+        let mut prefetched_count = RelationshipEvalScannedRelationshipCount::default();
+
+        while !frontier.is_empty() && depth <= self.config.max_depth {
+            let mut groups: HashMap<
+                (RelationRefOwned, Option<AggregateTypeOwned>),
+                RelationshipPrefetchGroup,
+            > = HashMap::new();
+
+            while let Some((aggregate, relation)) = frontier.pop() {
+                if aggregate.aggregate_type != relation.aggregate_type
+                    || !visited.insert((aggregate.clone(), relation.clone()))
+                {
+                    continue;
+                }
+
+                let Some(expr) = self
+                    .authorization_model
+                    .expr_for(&relation)
+                    .await
+                    .map_err(RelationshipResolverError::backend)?
+                else {
+                    continue;
+                };
+
+                Self::collect_prefetch_reads(
+                    &expr,
+                    &aggregate,
+                    &relation,
+                    &mut groups,
+                    &mut frontier,
+                );
+            }
+
+            let mut next_frontier = Vec::new();
+            for ((relation, subject_aggregate_type), group) in groups {
+                let aggregates: Vec<AggregateRef> = group
+                    .aggregates
+                    .into_iter()
+                    .filter(|aggregate| {
+                        !state.prefetched.contains_key(&RelationshipReadKey {
+                            aggregate: aggregate.clone(),
+                            relation: relation.clone(),
+                            subject_aggregate_type: subject_aggregate_type.clone(),
+                        })
+                    })
+                    .collect();
+                if aggregates.is_empty() {
+                    continue;
+                }
+
+                let subjects_by_aggregate = self
+                    .relationship_store
+                    .read_subjects_by_aggregates(
+                        uow,
+                        &aggregates,
+                        &relation,
+                        subject_aggregate_type.as_ref(),
+                    )
+                    .await?;
+
+                for (aggregate, subjects) in subjects_by_aggregate {
+                    prefetched_count = prefetched_count.saturating_add(subjects.len());
+                    for subject in &subjects {
+                        match subject {
+                            RelationshipSubject::AggregateSet {
+                                aggregate: target,
+                                relation: target_relation,
+                            } if group.follows_aggregate_sets => {
+                                next_frontier.push((target.clone(), target_relation.clone()));
+                            }
+                            RelationshipSubject::Aggregate(target) => {
+                                for computed_userset in &group.computed_usersets {
+                                    if target.aggregate_type == computed_userset.aggregate_type {
+                                        next_frontier
+                                            .push((target.clone(), computed_userset.clone()));
+                                    }
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                    state.prefetched.insert(
+                        RelationshipReadKey {
+                            aggregate,
+                            relation: relation.clone(),
+                            subject_aggregate_type: subject_aggregate_type.clone(),
+                        },
+                        subjects,
+                    );
+                }
+
+                if prefetched_count > self.config.max_scanned_relationship_count {
+                    return Ok(());
+                }
+            }
+
+            frontier = next_frontier;
+            depth = depth.increment();
+        }
+
+        Ok(())
+    }
+
+    fn collect_prefetch_reads(
+        expr: &UsersetExprOwned,
+        aggregate: &AggregateRef,
+        relation: &RelationRefOwned,
+        groups: &mut HashMap<
+            (RelationRefOwned, Option<AggregateTypeOwned>),
+            RelationshipPrefetchGroup,
+        >,
+        frontier: &mut Vec<(AggregateRef, RelationRefOwned)>,
+    ) {
+        match expr {
+            UsersetExprOwned::This => {
+                let group = groups.entry((relation.clone(), None)).or_default();
+                group.aggregates.insert(aggregate.clone());
+                group.follows_aggregate_sets = true;
+            }
+            UsersetExprOwned::ComputedUserset { relation } => {
+                frontier.push((aggregate.clone(), relation.clone()));
+            }
+            UsersetExprOwned::TupleToUserset {
+                tupleset_relation,
+                computed_userset,
+            } => {
+                let group = groups
+                    .entry((
+                        tupleset_relation.clone(),
+                        Some(computed_userset.aggregate_type.clone()),
+                    ))
+                    .or_default();
+                group.aggregates.insert(aggregate.clone());
+                group.computed_usersets.insert(computed_userset.clone());
+            }
+            UsersetExprOwned::Union(items) | UsersetExprOwned::Intersection(items) => {
+                for item in items {
+                    Self::collect_prefetch_reads(item, aggregate, relation, groups, frontier);
+                }
+            }
+            UsersetExprOwned::Difference { base, subtract } => {
+                Self::collect_prefetch_reads(base, aggregate, relation, groups, frontier);
+                Self::collect_prefetch_reads(subtract, aggregate, relation, groups, frontier);
+            }
+        }
+    }
+
     async fn eval_expr(
         &self,
         uow: &mut RS::Uow,
@@ -163,10 +368,8 @@ where
         match expr {
             UsersetExprOwned::This => {
                 let subjects = self
-                    .relationship_store
-                    .read_subjects_by_aggregate(uow, context.aggregate, context.relation, None)
-                    .await
-                    .map_err(RelationshipResolverError::from)?;
+                    .read_subjects(uow, state, context.aggregate, context.relation, None)
+                    .await?;
 
                 state.scanned_relationship_count = state
                     .scanned_relationship_count
@@ -227,15 +430,14 @@ where
                 computed_userset,
             } => {
                 let subjects = self
-                    .relationship_store
-                    .read_subjects_by_aggregate(
+                    .read_subjects(
                         uow,
+                        state,
                         context.aggregate,
                         tupleset_relation,
                         Some(&computed_userset.aggregate_type),
                     )
-                    .await
-                    .map_err(RelationshipResolverError::from)?;
+                    .await?;
 
                 state.scanned_relationship_count = state
                     .scanned_relationship_count
@@ -308,14 +510,54 @@ where
         requirement: &RelationshipRequirement,
     ) -> Result<bool, RelationshipResolverError> {
         let mut state = RelationshipEvalState::default();
-        self.check_requirement(uow, subject, requirement, &mut state)
-            .await
+        let result = self
+            .check_requirement(uow, subject, requirement, &mut state)
+            .await;
+        Self::record_node_count(&state);
+        result
+    }
+
+    async fn filter_satisfying(
+        &self,
+        uow: &mut Self::Uow,
+        subject: &AggregateRef,
+        candidates: &[AggregateRef],
+        relation: &RelationRefOwned,
+    ) -> Result<Vec<AggregateRef>, RelationshipResolverError> {
+        let mut state = RelationshipEvalState::default();
+        self.prefetch(uow, &mut state, candidates, relation).await?;
+
+        let mut allowed = Vec::new();
+        for candidate in candidates {
+            state.node_count = Default::default();
+            state.scanned_relationship_count = Default::default();
+
+            let satisfied = self
+                .check_relation(
+                    uow,
+                    subject,
+                    candidate,
+                    relation,
+                    &mut state,
+                    UsersetExprEvalDepth::default(),
+                )
+                .await;
+            Self::record_node_count(&state);
+
+            if satisfied? {
+                allowed.push(candidate.clone());
+            }
+        }
+
+        Ok(allowed)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     use uuid::Uuid;
 
@@ -323,8 +565,8 @@ mod tests {
     use crate::authorization::{
         AggregateRef, InMemoryAuthorizationModel, RelationName, RelationRefOwned,
         RelationshipChange, RelationshipRequirement, RelationshipResolver,
-        RelationshipResolverConfig, RelationshipStore, RelationshipStoreError, RelationshipSubject,
-        UsersetExprOwned,
+        RelationshipResolverConfig, RelationshipRevision, RelationshipStore,
+        RelationshipStoreError, RelationshipSubject, UsersetExprOwned,
     };
     use crate::event::{AggregateIdValue, AggregateTypeOwned};
     use crate::unit_of_work::{UnitOfWork, UnitOfWorkError};
@@ -345,6 +587,41 @@ mod tests {
     #[derive(Clone, Default)]
     struct TestStore {
         map: HashMap<(AggregateRef, RelationRefOwned), Vec<RelationshipSubject>>,
+        single_reads: Arc<AtomicUsize>,
+        batched_reads: Arc<AtomicUsize>,
+    }
+
+    impl TestStore {
+        fn subjects(
+            &self,
+            aggregate: &AggregateRef,
+            relation: &RelationRefOwned,
+            subject_aggregate_type: Option<&AggregateTypeOwned>,
+        ) -> Vec<RelationshipSubject> {
+            let subjects = self
+                .map
+                .get(&(aggregate.clone(), relation.clone()))
+                .cloned()
+                .unwrap_or_default();
+
+            match subject_aggregate_type {
+                Some(subject_aggregate_type) => subjects
+                    .into_iter()
+                    .filter(|subject| match subject {
+                        RelationshipSubject::Aggregate(aggregate) => {
+                            &aggregate.aggregate_type == subject_aggregate_type
+                        }
+                        RelationshipSubject::Wildcard { aggregate_type } => {
+                            aggregate_type == subject_aggregate_type
+                        }
+                        RelationshipSubject::AggregateSet { aggregate, .. } => {
+                            &aggregate.aggregate_type == subject_aggregate_type
+                        }
+                    })
+                    .collect(),
+                None => subjects,
+            }
+        }
     }
 
     impl RelationshipStore for TestStore {
@@ -374,29 +651,35 @@ mod tests {
             relation: &RelationRefOwned,
             subject_aggregate_type: Option<&AggregateTypeOwned>,
         ) -> Result<Vec<RelationshipSubject>, RelationshipStoreError> {
-            let subjects = self
-                .map
-                .get(&(aggregate.clone(), relation.clone()))
-                .cloned()
-                .unwrap_or_default();
+            self.single_reads.fetch_add(1, Ordering::SeqCst);
+            Ok(self.subjects(aggregate, relation, subject_aggregate_type))
+        }
 
-            Ok(match subject_aggregate_type {
-                Some(subject_aggregate_type) => subjects
-                    .into_iter()
-                    .filter(|subject| match subject {
-                        RelationshipSubject::Aggregate(aggregate) => {
-                            &aggregate.aggregate_type == subject_aggregate_type
-                        }
-                        RelationshipSubject::Wildcard { aggregate_type } => {
-                            aggregate_type == subject_aggregate_type
-                        }
-                        RelationshipSubject::AggregateSet { aggregate, .. } => {
-                            &aggregate.aggregate_type == subject_aggregate_type
-                        }
-                    })
-                    .collect(),
-                None => subjects,
-            })
+        async fn read_subjects_by_aggregates(
+            &self,
+            _uow: &mut Self::Uow,
+            aggregates: &[AggregateRef],
+            relation: &RelationRefOwned,
+            subject_aggregate_type: Option<&AggregateTypeOwned>,
+        ) -> Result<HashMap<AggregateRef, Vec<RelationshipSubject>>, RelationshipStoreError>
+        {
+            self.batched_reads.fetch_add(1, Ordering::SeqCst);
+            Ok(aggregates
+                .iter()
+                .map(|aggregate| {
+                    (
+                        aggregate.clone(),
+                        self.subjects(aggregate, relation, subject_aggregate_type),
+                    )
+                })
+                .collect())
+        }
+
+        async fn read_revision(
+            &self,
+            _uow: &mut Self::Uow,
+        ) -> Result<RelationshipRevision, RelationshipStoreError> {
+            Ok(RelationshipRevision::initial())
         }
     }
 
@@ -468,4 +751,80 @@ mod tests {
 
         assert!(result);
     }
+
+    #[tokio::test]
+    async fn filter_satisfying_prefetches_relationships_in_batches() {
+        let user = aggregate_ref("user", Uuid::from_u128(1));
+        let other_user = aggregate_ref("user", Uuid::from_u128(2));
+        let organization = aggregate_ref("organization", Uuid::from_u128(3));
+        let documents: Vec<AggregateRef> = (10..20)
+            .map(|id| aggregate_ref("document", Uuid::from_u128(id)))
+            .collect();
+
+        let viewer_relation = relation_ref("document", "viewer");
+        let owner_relation = relation_ref("document", "owner");
+        let parent_relation = relation_ref("document", "parent");
+        let member_relation = relation_ref("organization", "member");
+
+        let mut store = TestStore::default();
+        store.map.insert(
+            (documents[0].clone(), owner_relation.clone()),
+            vec![RelationshipSubject::Aggregate(user.clone())],
+        );
+        store.map.insert(
+            (documents[1].clone(), owner_relation.clone()),
+            vec![RelationshipSubject::Aggregate(other_user.clone())],
+        );
+        for document in &documents[5..8] {
+            store.map.insert(
+                (document.clone(), parent_relation.clone()),
+                vec![RelationshipSubject::Aggregate(organization.clone())],
+            );
+        }
+        store.map.insert(
+            (organization.clone(), member_relation.clone()),
+            vec![RelationshipSubject::Aggregate(user.clone())],
+        );
+        let single_reads = Arc::clone(&store.single_reads);
+        let batched_reads = Arc::clone(&store.batched_reads);
+
+        let mut model = InMemoryAuthorizationModel::new();
+        model.define_expr(
+            viewer_relation.clone(),
+            UsersetExprOwned::Union(vec![
+                UsersetExprOwned::ComputedUserset {
+                    relation: owner_relation.clone(),
+                },
+                UsersetExprOwned::TupleToUserset {
+                    tupleset_relation: parent_relation,
+                    computed_userset: member_relation,
+                },
+            ]),
+        );
+        model.define_expr(owner_relation, UsersetExprOwned::This);
+        model.define_expr(
+            relation_ref("organization", "member"),
+            UsersetExprOwned::This,
+        );
+
+        let resolver =
+            DefaultRelationshipResolver::new(store, model, RelationshipResolverConfig::default());
+
+        let allowed = resolver
+            .filter_satisfying(&mut TestUow, &user, &documents, &viewer_relation)
+            .await
+            .expect("relationship resolution should succeed");
+
+        assert_eq!(
+            allowed,
+            vec![
+                documents[0].clone(),
+                documents[5].clone(),
+                documents[6].clone(),
+                documents[7].clone(),
+            ]
+        );
+        assert_eq!(single_reads.load(Ordering::SeqCst), 0);
+        assert_eq!(batched_reads.load(Ordering::SeqCst), 3);
+    }
 }
@@ -0,0 +1,172 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, PoisonError};
+
+use super::{
+    RelationshipDecisionCache, RelationshipDecisionCacheError, RelationshipDecisionCacheKey,
+};
+
+/// Keeps relationship decisions in process memory.
+///
+/// Decisions are stored under their full key, revision included, so decisions of different
+/// revisions coexist: a writer caching under its transaction's revision does not evict the
+/// decisions readers use at the committed one. When `max_entries` is reached the oldest
+/// entry is evicted, which also ages out decisions of revisions nobody reads any more.
+#[derive(Debug)]
+pub struct InMemoryRelationshipDecisionCache {
+    max_entries: usize,
+    state: Mutex<InMemoryRelationshipDecisionCacheState>,
+}
+
+#[derive(Debug, Default)]
+struct InMemoryRelationshipDecisionCacheState {
+    decisions: HashMap<RelationshipDecisionCacheKey, bool>,
+    insertion_order: VecDeque<RelationshipDecisionCacheKey>,
+}
+
+impl InMemoryRelationshipDecisionCache {
+    pub const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+    pub fn new() -> Self {
+        Self::with_max_entries(Self::DEFAULT_MAX_ENTRIES)
+    }
+
+    pub fn with_max_entries(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            state: Mutex::new(InMemoryRelationshipDecisionCacheState::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .decisions
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.decisions.clear();
+        state.insertion_order.clear();
+    }
+}
+
+impl Default for InMemoryRelationshipDecisionCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelationshipDecisionCache for InMemoryRelationshipDecisionCache {
+    async fn get(
+        &self,
+        key: &RelationshipDecisionCacheKey,
+    ) -> Result<Option<bool>, RelationshipDecisionCacheError> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(state.decisions.get(key).copied())
+    }
+
+    async fn insert(
+        &self,
+        key: RelationshipDecisionCacheKey,
+        decision: bool,
+    ) -> Result<(), RelationshipDecisionCacheError> {
+        if self.max_entries == 0 {
+            return Ok(());
+        }
+
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(cached) = state.decisions.get_mut(&key) {
+            *cached = decision;
+            return Ok(());
+        }
+
+        while state.decisions.len() >= self.max_entries {
+            let Some(oldest) = state.insertion_order.pop_front() else {
+                break;
+            };
+            state.decisions.remove(&oldest);
+        }
+
+        state.insertion_order.push_back(key.clone());
+        state.decisions.insert(key, decision);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::InMemoryRelationshipDecisionCache;
+    use crate::authorization::{
+        AggregateRef, RelationName, RelationRefOwned, RelationshipDecisionCache,
+        RelationshipDecisionCacheKey, RelationshipRequirement, RelationshipRevision,
+    };
+    use crate::event::{AggregateIdValue, AggregateTypeOwned};
+
+    fn aggregate_ref(ty: &str, id: u128) -> AggregateRef {
+        AggregateRef::new(
+            ty.parse::<AggregateTypeOwned>()
+                .expect("aggregate type should be valid"),
+            AggregateIdValue::from(Uuid::from_u128(id)),
+        )
+    }
+
+    fn key(subject_id: u128, revision: i64) -> RelationshipDecisionCacheKey {
+        RelationshipDecisionCacheKey::new(
+            aggregate_ref("user", subject_id),
+            RelationshipRequirement::Check {
+                aggregate: aggregate_ref("document", 1),
+                relation: RelationRefOwned::new(
+                    "document".parse().expect("aggregate type should be valid"),
+                    RelationName::new("viewer").into(),
+                ),
+            },
+            RelationshipRevision::try_from(revision).expect("revision should be valid"),
+        )
+    }
+
+    #[tokio::test]
+    async fn returns_inserted_decision_for_same_revision() {
+        let cache = InMemoryRelationshipDecisionCache::new();
+
+        cache.insert(key(1, 3), true).await.expect("insert");
+
+        assert_eq!(cache.get(&key(1, 3)).await.expect("get"), Some(true));
+        assert_eq!(cache.get(&key(2, 3)).await.expect("get"), None);
+    }
+
+    #[tokio::test]
+    async fn decisions_of_different_revisions_coexist() {
+        let cache = InMemoryRelationshipDecisionCache::new();
+
+        cache.insert(key(1, 3), true).await.expect("insert");
+        cache.insert(key(1, 4), false).await.expect("insert");
+        cache.insert(key(2, 3), true).await.expect("insert");
+
+        assert_eq!(cache.get(&key(1, 3)).await.expect("get"), Some(true));
+        assert_eq!(cache.get(&key(1, 4)).await.expect("get"), Some(false));
+        assert_eq!(cache.get(&key(2, 3)).await.expect("get"), Some(true));
+        assert_eq!(cache.get(&key(2, 4)).await.expect("get"), None);
+    }
+
+    #[tokio::test]
+    async fn evicts_the_oldest_entry_when_max_entries_is_reached() {
+        let cache = InMemoryRelationshipDecisionCache::with_max_entries(2);
+
+        cache.insert(key(1, 1), true).await.expect("insert");
+        cache.insert(key(2, 1), true).await.expect("insert");
+        cache.insert(key(3, 1), true).await.expect("insert");
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&key(1, 1)).await.expect("get"), None);
+        assert_eq!(cache.get(&key(2, 1)).await.expect("get"), Some(true));
+        assert_eq!(cache.get(&key(3, 1)).await.expect("get"), Some(true));
+    }
+}
@@ -0,0 +1,19 @@
+use super::{RelationshipDecisionCacheError, RelationshipDecisionCacheKey};
+
+/// Stores relationship decisions across requests.
+///
+/// Implementations may evict entries at any time; a missing entry only causes
+/// the decision to be evaluated again.
+#[allow(async_fn_in_trait)]
+pub trait RelationshipDecisionCache: Send + Sync {
+    async fn get(
+        &self,
+        key: &RelationshipDecisionCacheKey,
+    ) -> Result<Option<bool>, RelationshipDecisionCacheError>;
+
+    async fn insert(
+        &self,
+        key: RelationshipDecisionCacheKey,
+        decision: bool,
+    ) -> Result<(), RelationshipDecisionCacheError>;
+}
@@ -0,0 +1,18 @@
+use std::error::Error;
+
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum RelationshipDecisionCacheError {
+    #[error("relationship decision cache backend error: {0}")]
+    Backend(#[source] Box<dyn Error + Send + Sync + 'static>),
+}
+
+impl RelationshipDecisionCacheError {
+    pub fn backend<E>(error: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        Self::Backend(Box::new(error))
+    }
+}
@@ -0,0 +1,26 @@
+use super::{AggregateRef, RelationshipRequirement, RelationshipRevision};
+
+/// Identifies a cached relationship decision.
+///
+/// The revision is part of the key, so a decision can never be served once the
+/// relationship graph has moved to another revision.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RelationshipDecisionCacheKey {
+    pub subject: AggregateRef,
+    pub requirement: RelationshipRequirement,
+    pub revision: RelationshipRevision,
+}
+
+impl RelationshipDecisionCacheKey {
+    pub fn new(
+        subject: AggregateRef,
+        requirement: RelationshipRequirement,
+        revision: RelationshipRevision,
+    ) -> Self {
+        Self {
+            subject,
+            requirement,
+            revision,
+        }
+    }
+}
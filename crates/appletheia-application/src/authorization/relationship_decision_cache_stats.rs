@@ -0,0 +1,6 @@
+/// Hit and miss counters reported by `CachedRelationshipResolver`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct RelationshipDecisionCacheStats {
+    pub hit_count: u64,
+    pub miss_count: u64,
+}
@@ -1,8 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
+use super::RelationshipSubject;
 use super::relationship_eval_node_count::RelationshipEvalNodeCount;
 use super::relationship_eval_scanned_relationship_count::RelationshipEvalScannedRelationshipCount;
 use super::relationship_memo_key::RelationshipMemoKey;
+use super::relationship_read_key::RelationshipReadKey;
 
 #[derive(Default)]
 pub struct RelationshipEvalState {
@@ -10,4 +12,5 @@ pub struct RelationshipEvalState {
     pub in_progress: HashSet<RelationshipMemoKey>,
     pub node_count: RelationshipEvalNodeCount,
     pub scanned_relationship_count: RelationshipEvalScannedRelationshipCount,
+    pub prefetched: HashMap<RelationshipReadKey, Vec<RelationshipSubject>>,
 }
@@ -0,0 +1,13 @@
+use std::collections::HashSet;
+
+use super::{AggregateRef, RelationRefOwned};
+
+/// Aggregates whose subjects for one relation are read together while prefetching.
+#[derive(Debug, Default)]
+pub struct RelationshipPrefetchGroup {
+    pub aggregates: HashSet<AggregateRef>,
+    /// Whether `<type>:<id>#<relation>` subjects are evaluated further (`This`).
+    pub follows_aggregate_sets: bool,
+    /// Relations evaluated on aggregate subjects (`TupleToUserset`).
+    pub computed_usersets: HashSet<RelationRefOwned>,
+}
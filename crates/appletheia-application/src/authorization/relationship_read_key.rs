@@ -0,0 +1,10 @@
+use crate::event::AggregateTypeOwned;
+
+use super::{AggregateRef, RelationRefOwned};
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RelationshipReadKey {
+    pub aggregate: AggregateRef,
+    pub relation: RelationRefOwned,
+    pub subject_aggregate_type: Option<AggregateTypeOwned>,
+}
@@ -1,6 +1,6 @@
 use crate::unit_of_work::UnitOfWork;
 
-use super::{AggregateRef, RelationshipRequirement, RelationshipResolverError};
+use super::{AggregateRef, RelationRefOwned, RelationshipRequirement, RelationshipResolverError};
 
 #[allow(async_fn_in_trait)]
 pub trait RelationshipResolver: Send + Sync {
@@ -12,4 +12,29 @@ pub trait RelationshipResolver: Send + Sync {
         subject: &AggregateRef,
         requirement: &RelationshipRequirement,
     ) -> Result<bool, RelationshipResolverError>;
+
+    /// Returns the candidates on which `subject` satisfies `relation`, preserving
+    /// their order.
+    ///
+    /// The default implementation evaluates one `satisfies` call per candidate;
+    /// resolvers should override it to share relationship reads across candidates.
+    async fn filter_satisfying(
+        &self,
+        uow: &mut Self::Uow,
+        subject: &AggregateRef,
+        candidates: &[AggregateRef],
+        relation: &RelationRefOwned,
+    ) -> Result<Vec<AggregateRef>, RelationshipResolverError> {
+        let mut allowed = Vec::new();
+        for candidate in candidates {
+            let requirement = RelationshipRequirement::Check {
+                aggregate: candidate.clone(),
+                relation: relation.clone(),
+            };
+            if self.satisfies(uow, subject, &requirement).await? {
+                allowed.push(candidate.clone());
+            }
+        }
+        Ok(allowed)
+    }
 }
@@ -4,13 +4,16 @@ use std::error::Error;
 
 use thiserror::Error as ThisError;
 
-use super::{RelationRefOwned, RelationshipStoreError};
+use super::{RelationRefOwned, RelationshipDecisionCacheError, RelationshipStoreError};
 
 #[derive(Debug, ThisError)]
 pub enum RelationshipResolverError {
     #[error("relationship store error: {0}")]
     RelationshipStore(#[from] RelationshipStoreError),
 
+    #[error("relationship decision cache error: {0}")]
+    DecisionCache(#[from] RelationshipDecisionCacheError),
+
     #[error("relationship resolver evaluation limit exceeded: {0}")]
     EvaluationLimitExceeded(&'static str),
 
@@ -0,0 +1,84 @@
+use std::{fmt, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use super::RelationshipRevisionError;
+
+/// Identifies a version of the relationship graph.
+///
+/// The revision changes whenever `RelationshipStore::apply_changes` persists a
+/// change, so decisions computed at one revision remain valid for as long as
+/// the store reports the same revision.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RelationshipRevision(i64);
+
+impl RelationshipRevision {
+    pub const fn initial() -> Self {
+        Self(0)
+    }
+
+    pub fn value(&self) -> i64 {
+        self.0
+    }
+}
+
+impl Default for RelationshipRevision {
+    fn default() -> Self {
+        Self::initial()
+    }
+}
+
+impl TryFrom<i64> for RelationshipRevision {
+    type Error = RelationshipRevisionError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        if value < 0 {
+            Err(RelationshipRevisionError::NegativeValue(value))
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl From<RelationshipRevision> for i64 {
+    fn from(value: RelationshipRevision) -> Self {
+        value.0
+    }
+}
+
+impl Display for RelationshipRevision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initial_is_zero() {
+        assert_eq!(RelationshipRevision::initial().value(), 0);
+        assert_eq!(
+            RelationshipRevision::default(),
+            RelationshipRevision::initial()
+        );
+    }
+
+    #[test]
+    fn try_from_rejects_negative_values() {
+        let err =
+            RelationshipRevision::try_from(-1).expect_err("negative value should be rejected");
+        match err {
+            RelationshipRevisionError::NegativeValue(v) => assert_eq!(v, -1),
+        }
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let revision = RelationshipRevision::try_from(7).unwrap();
+        let as_i64: i64 = revision.into();
+        assert_eq!(as_i64, 7);
+    }
+}
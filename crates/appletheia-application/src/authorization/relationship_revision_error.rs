@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RelationshipRevisionError {
+    #[error("relationship revision must be non-negative, got {0}")]
+    NegativeValue(i64),
+}
@@ -1,13 +1,19 @@
+use std::collections::HashMap;
+
 use crate::event::AggregateTypeOwned;
 use crate::unit_of_work::UnitOfWork;
 
 use super::RelationshipStoreError;
-use super::{AggregateRef, RelationRefOwned, RelationshipChange, RelationshipSubject};
+use super::{
+    AggregateRef, RelationRefOwned, RelationshipChange, RelationshipRevision, RelationshipSubject,
+};
 
 #[allow(async_fn_in_trait)]
 pub trait RelationshipStore: Send + Sync {
     type Uow: UnitOfWork;
 
+    /// Applies relationship changes and moves the store to a new revision when
+    /// any relationship was actually inserted or deleted.
     async fn apply_changes(
         &self,
         uow: &mut Self::Uow,
@@ -28,4 +34,41 @@ pub trait RelationshipStore: Send + Sync {
         relation: &RelationRefOwned,
         subject_aggregate_type: Option<&AggregateTypeOwned>,
     ) -> Result<Vec<RelationshipSubject>, RelationshipStoreError>;
+
+    /// Reads the subjects of `relation` for several aggregates at once.
+    ///
+    /// The returned map contains an entry for every requested aggregate. The default
+    /// implementation issues one `read_subjects_by_aggregate` call per aggregate;
+    /// backends should override it with a single batched read.
+    async fn read_subjects_by_aggregates(
+        &self,
+        uow: &mut Self::Uow,
+        aggregates: &[AggregateRef],
+        relation: &RelationRefOwned,
+        subject_aggregate_type: Option<&AggregateTypeOwned>,
+    ) -> Result<HashMap<AggregateRef, Vec<RelationshipSubject>>, RelationshipStoreError> {
+        let mut subjects_by_aggregate = HashMap::with_capacity(aggregates.len());
+        for aggregate in aggregates {
+            if subjects_by_aggregate.contains_key(aggregate) {
+                continue;
+            }
+            let subjects = self
+                .read_subjects_by_aggregate(uow, aggregate, relation, subject_aggregate_type)
+                .await?;
+            subjects_by_aggregate.insert(aggregate.clone(), subjects);
+        }
+        Ok(subjects_by_aggregate)
+    }
+
+    /// Returns the current relationship revision as seen by the unit of work.
+    ///
+    /// Stores that do not track revisions keep the default, which returns
+    /// `RelationshipStoreError::RevisionsUnsupported`; decision caching is then skipped.
+    async fn read_revision(
+        &self,
+        uow: &mut Self::Uow,
+    ) -> Result<RelationshipRevision, RelationshipStoreError> {
+        let _ = uow;
+        Err(RelationshipStoreError::RevisionsUnsupported)
+    }
 }
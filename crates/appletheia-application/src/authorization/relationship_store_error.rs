@@ -15,4 +15,7 @@ pub enum RelationshipStoreError {
 
     #[error("invalid relationship row")]
     InvalidRow,
+
+    #[error("relationship revisions are not supported by this store")]
+    RevisionsUnsupported,
 }
@@ -1,3 +1,9 @@
+pub mod batch_command_dispatcher;
+pub mod command_batch_atomicity;
+pub mod command_batch_error;
+pub mod command_batch_item;
+pub mod command_batch_item_result;
+pub mod command_batch_options;
 pub mod command_consistency;
 pub mod command_dispatch_result;
 pub mod command_dispatcher;
@@ -5,12 +11,18 @@ pub mod command_dispatcher_error;
 pub mod command_failure_reaction;
 pub mod command_failure_reaction_error;
 pub mod command_failure_report;
+pub mod command_field_error;
+pub mod command_field_length;
 pub mod command_handled;
 pub mod command_handler;
 pub mod command_hash;
 pub mod command_hash_error;
 pub mod command_hasher;
 pub mod command_hasher_error;
+pub mod command_invocation;
+pub mod command_middleware;
+pub mod command_middleware_error;
+pub mod command_middleware_stack;
 pub mod command_name;
 pub mod command_name_owned;
 pub mod command_name_owned_error;
@@ -19,6 +31,8 @@ pub mod command_request;
 pub mod command_request_owned;
 pub mod command_request_owned_error;
 pub mod command_selector;
+pub mod command_validation_error;
+pub mod command_validator;
 pub mod command_worker;
 pub mod command_worker_error;
 pub mod default_command_dispatcher;
@@ -32,7 +46,14 @@ pub mod idempotency_output_error;
 pub mod idempotency_service;
 pub mod idempotency_service_error;
 pub mod idempotency_state;
+pub mod noop_command_middleware;
 
+pub use batch_command_dispatcher::BatchCommandDispatcher;
+pub use command_batch_atomicity::CommandBatchAtomicity;
+pub use command_batch_error::CommandBatchError;
+pub use command_batch_item::CommandBatchItem;
+pub use command_batch_item_result::CommandBatchItemResult;
+pub use command_batch_options::CommandBatchOptions;
 pub use command_consistency::CommandConsistency;
 pub use command_dispatch_result::CommandDispatchResult;
 pub use command_dispatcher::CommandDispatcher;
@@ -40,12 +61,18 @@ pub use command_dispatcher_error::CommandDispatcherError;
 pub use command_failure_reaction::CommandFailureReaction;
 pub use command_failure_reaction_error::CommandFailureReactionError;
 pub use command_failure_report::CommandFailureReport;
+pub use command_field_error::CommandFieldError;
+pub use command_field_length::CommandFieldLength;
 pub use command_handled::CommandHandled;
 pub use command_handler::CommandHandler;
 pub use command_hash::CommandHash;
 pub use command_hash_error::CommandHashError;
 pub use command_hasher::CommandHasher;
 pub use command_hasher_error::CommandHasherError;
+pub use command_invocation::CommandInvocation;
+pub use command_middleware::CommandMiddleware;
+pub use command_middleware_error::CommandMiddlewareError;
+pub use command_middleware_stack::CommandMiddlewareStack;
 pub use command_name::CommandName;
 pub use command_name_owned::CommandNameOwned;
 pub use command_name_owned_error::CommandNameOwnedError;
@@ -54,6 +81,8 @@ pub use command_request::CommandRequest;
 pub use command_request_owned::CommandRequestOwned;
 pub use command_request_owned_error::CommandRequestOwnedError;
 pub use command_selector::CommandSelector;
+pub use command_validation_error::CommandValidationError;
+pub use command_validator::CommandValidator;
 pub use command_worker::CommandWorker;
 pub use command_worker_error::CommandWorkerError;
 pub use default_command_dispatcher::DefaultCommandDispatcher;
@@ -67,6 +96,7 @@ pub use idempotency_output_error::IdempotencyOutputError;
 pub use idempotency_service::IdempotencyService;
 pub use idempotency_service_error::IdempotencyServiceError;
 pub use idempotency_state::IdempotencyState;
+pub use noop_command_middleware::NoopCommandMiddleware;
 
 use serde::Serialize;
 use serde::de::DeserializeOwned;
@@ -78,4 +108,11 @@ use serde::de::DeserializeOwned;
 pub trait Command: Serialize + DeserializeOwned + Send + 'static {
     /// Returns the stable identifier for the command type.
     const NAME: CommandName;
+
+    /// Returns the validator the dispatcher runs before authorization and idempotency.
+    ///
+    /// `#[command(...)]` returns `Some(self)` for commands that derive `CommandValidator`.
+    fn validator(&self) -> Option<&dyn CommandValidator> {
+        None
+    }
 }
@@ -0,0 +1,29 @@
+use crate::command::{
+    Command, CommandBatchError, CommandBatchItem, CommandBatchItemResult, CommandBatchOptions,
+    CommandDispatcher, CommandHandler,
+};
+
+/// Command dispatcher that can also handle several commands in one unit of work.
+#[allow(async_fn_in_trait)]
+pub trait BatchCommandDispatcher: CommandDispatcher {
+    /// Authorizes every item, then handles them in order within a single unit of work.
+    ///
+    /// With [`CommandBatchAtomicity::AllOrNothing`](crate::command::CommandBatchAtomicity) the
+    /// first failing item fails the whole batch, nothing is committed and no item is recorded
+    /// for idempotency, so the batch can be retried as is. With
+    /// [`CommandBatchAtomicity::PerItem`](crate::command::CommandBatchAtomicity) each item runs
+    /// in its own savepoint and its result is reported in the returned list, in item order;
+    /// each item is recorded for idempotency under its own message id.
+    async fn dispatch_batch<H>(
+        &self,
+        handler: &H,
+        items: Vec<CommandBatchItem<H::Command>>,
+        options: CommandBatchOptions,
+    ) -> Result<
+        Vec<CommandBatchItemResult<H::Output, H::ReplayOutput, H::Error>>,
+        CommandBatchError<H::Error>,
+    >
+    where
+        H: CommandHandler<Uow = Self::Uow>,
+        H::Command: Command;
+}
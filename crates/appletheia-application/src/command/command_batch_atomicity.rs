@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// How failures of individual commands affect the rest of a batch.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandBatchAtomicity {
+    /// The first failing command rolls back the whole batch.
+    #[default]
+    AllOrNothing,
+    /// Each command runs inside its own savepoint; a failing command is rolled back alone and
+    /// the rest of the batch is committed. Requires a unit of work with savepoint support.
+    ///
+    /// Every command costs a savepoint, plus a nested one to record its failure. In PostgreSQL
+    /// that is a round trip each; the in-memory unit of work copies every table a command
+    /// writes.
+    PerItem,
+}
@@ -0,0 +1,21 @@
+use std::error::Error;
+
+use thiserror::Error;
+
+use super::CommandDispatcherError;
+
+#[derive(Debug, Error)]
+pub enum CommandBatchError<HE>
+where
+    HE: Error + Send + Sync + 'static,
+{
+    #[error("command batch item {index} failed: {source}")]
+    ItemFailed {
+        index: usize,
+        #[source]
+        source: CommandDispatcherError<HE>,
+    },
+
+    #[error(transparent)]
+    Dispatcher(#[from] CommandDispatcherError<HE>),
+}
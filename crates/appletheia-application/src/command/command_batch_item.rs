@@ -0,0 +1,21 @@
+use crate::request_context::RequestContext;
+
+use super::Command;
+
+/// One command of a batch together with the request context it is dispatched under.
+///
+/// Every item needs its own `message_id`; idempotency is recorded per item.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommandBatchItem<C: Command> {
+    pub request_context: RequestContext,
+    pub command: C,
+}
+
+impl<C: Command> CommandBatchItem<C> {
+    pub fn new(request_context: RequestContext, command: C) -> Self {
+        Self {
+            request_context,
+            command,
+        }
+    }
+}
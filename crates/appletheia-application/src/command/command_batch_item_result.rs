@@ -0,0 +1,5 @@
+use super::{CommandDispatchResult, CommandDispatcherError};
+
+/// The outcome of one item of a batch, in the same shape as a single dispatch.
+pub type CommandBatchItemResult<O, R, HE> =
+    Result<CommandDispatchResult<O, R>, CommandDispatcherError<HE>>;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CommandBatchAtomicity, CommandConsistency, CommandOptions};
+
+/// Options shared by every command of a batch.
+///
+/// Failure reactions are not supported for batches; a failing item is reported in the batch
+/// result instead.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CommandBatchOptions {
+    pub atomicity: CommandBatchAtomicity,
+    pub consistency: CommandConsistency,
+}
+
+impl CommandBatchOptions {
+    /// Options each item is authorized and dispatched with.
+    pub fn item_options(&self) -> CommandOptions {
+        CommandOptions {
+            consistency: self.consistency,
+            ..CommandOptions::default()
+        }
+    }
+}
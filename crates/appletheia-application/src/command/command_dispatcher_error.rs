@@ -4,7 +4,8 @@ use thiserror::Error;
 
 use crate::authorization::AuthorizerError;
 use crate::command::{
-    CommandFailureReactionError, CommandFailureReport, CommandHasherError, IdempotencyServiceError,
+    CommandFailureReactionError, CommandFailureReport, CommandHasherError, CommandMiddlewareError,
+    CommandValidationError, IdempotencyServiceError,
 };
 use crate::projection::ReadYourWritesWaitError;
 use crate::request_context::MessageId;
@@ -48,4 +49,10 @@ where
 
     #[error("authorizer error: {0}")]
     Authorizer(#[from] AuthorizerError),
+
+    #[error(transparent)]
+    Middleware(#[from] CommandMiddlewareError),
+
+    #[error(transparent)]
+    Validation(#[from] CommandValidationError),
 }
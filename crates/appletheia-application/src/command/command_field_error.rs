@@ -0,0 +1,34 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+/// A validation failure for one command field.
+///
+/// `path` names the field as it appears in the serialized command, `code` is a stable
+/// machine-readable identifier such as `length_min`, and `message` is meant for humans.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CommandFieldError {
+    pub path: String,
+    pub code: String,
+    pub message: String,
+}
+
+impl CommandFieldError {
+    pub fn new(
+        path: impl Into<String>,
+        code: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for CommandFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} ({})", self.path, self.message, self.code)
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// Length used by `#[validate(length(...))]`: characters for strings, items for collections.
+pub trait CommandFieldLength {
+    fn field_length(&self) -> usize;
+}
+
+impl CommandFieldLength for str {
+    fn field_length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl CommandFieldLength for String {
+    fn field_length(&self) -> usize {
+        self.as_str().field_length()
+    }
+}
+
+impl<T> CommandFieldLength for [T] {
+    fn field_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> CommandFieldLength for Vec<T> {
+    fn field_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> CommandFieldLength for BTreeSet<T> {
+    fn field_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> CommandFieldLength for BTreeMap<K, V> {
+    fn field_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, S> CommandFieldLength for HashSet<T, S> {
+    fn field_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V, S> CommandFieldLength for HashMap<K, V, S> {
+    fn field_length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandFieldLength;
+
+    #[test]
+    fn counts_characters_for_strings() {
+        assert_eq!("héllo".to_string().field_length(), 5);
+        assert_eq!(vec![1, 2, 3].field_length(), 3);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::outbox::command::{SerializedCommand, SerializedCommandError};
+use crate::request_context::RequestContext;
+
+use super::{Command, CommandName, CommandOptions};
+
+/// A command dispatch as seen by [`CommandMiddleware`](super::CommandMiddleware) hooks.
+#[derive(Debug)]
+pub struct CommandInvocation<'a, C: Command> {
+    request_context: &'a RequestContext,
+    command: &'a C,
+    options: &'a CommandOptions,
+}
+
+impl<'a, C: Command> CommandInvocation<'a, C> {
+    pub fn new(
+        request_context: &'a RequestContext,
+        command: &'a C,
+        options: &'a CommandOptions,
+    ) -> Self {
+        Self {
+            request_context,
+            command,
+            options,
+        }
+    }
+
+    pub fn request_context(&self) -> &'a RequestContext {
+        self.request_context
+    }
+
+    pub fn command_name(&self) -> CommandName {
+        C::NAME
+    }
+
+    pub fn command(&self) -> &'a C {
+        self.command
+    }
+
+    pub fn options(&self) -> &'a CommandOptions {
+        self.options
+    }
+
+    /// Serializes the command the same way it is stored in the command outbox.
+    pub fn serialized_command(&self) -> Result<SerializedCommand, SerializedCommandError> {
+        SerializedCommand::new(serde_json::to_value(self.command)?)
+    }
+}
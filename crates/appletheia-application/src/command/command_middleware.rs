@@ -0,0 +1,34 @@
+use std::error::Error;
+
+use super::{Command, CommandDispatchResult, CommandInvocation, CommandMiddlewareError};
+
+/// Cross-cutting behavior wrapped around every command dispatch.
+///
+/// `before` runs ahead of authorization and idempotency and can reject the command. Once `before`
+/// completed, exactly one of `after` or `on_error` runs when the dispatch finishes; `on_error`
+/// also sees rejections from the `before` of middlewares nested inside. A middleware whose
+/// `before` rejected or never ran sees neither hook. Middlewares are combined with
+/// [`CommandMiddlewareStack`](super::CommandMiddlewareStack).
+#[allow(async_fn_in_trait)]
+pub trait CommandMiddleware: Send + Sync {
+    async fn before<C: Command>(
+        &self,
+        _invocation: &CommandInvocation<'_, C>,
+    ) -> Result<(), CommandMiddlewareError> {
+        Ok(())
+    }
+
+    async fn after<C: Command, O, R>(
+        &self,
+        _invocation: &CommandInvocation<'_, C>,
+        _result: &CommandDispatchResult<O, R>,
+    ) {
+    }
+
+    async fn on_error<C: Command>(
+        &self,
+        _invocation: &CommandInvocation<'_, C>,
+        _error: &(dyn Error + Send + Sync + 'static),
+    ) {
+    }
+}
@@ -0,0 +1,69 @@
+use std::error::Error;
+
+use super::{
+    Command, CommandDispatchResult, CommandInvocation, CommandMiddleware, CommandMiddlewareError,
+};
+
+/// Two middlewares nested like layers: `outer` wraps `inner`.
+///
+/// `before` hooks run outer first and stop at the first rejection, which unwinds `outer` through
+/// `on_error` if its `before` completed. `after` and `on_error` hooks run inner first.
+#[derive(Clone, Debug, Default)]
+pub struct CommandMiddlewareStack<Outer, Inner> {
+    outer: Outer,
+    inner: Inner,
+}
+
+impl<Outer, Inner> CommandMiddlewareStack<Outer, Inner>
+where
+    Outer: CommandMiddleware,
+    Inner: CommandMiddleware,
+{
+    pub fn new(outer: Outer, inner: Inner) -> Self {
+        Self { outer, inner }
+    }
+
+    pub fn outer(&self) -> &Outer {
+        &self.outer
+    }
+
+    pub fn inner(&self) -> &Inner {
+        &self.inner
+    }
+}
+
+impl<Outer, Inner> CommandMiddleware for CommandMiddlewareStack<Outer, Inner>
+where
+    Outer: CommandMiddleware,
+    Inner: CommandMiddleware,
+{
+    async fn before<C: Command>(
+        &self,
+        invocation: &CommandInvocation<'_, C>,
+    ) -> Result<(), CommandMiddlewareError> {
+        self.outer.before(invocation).await?;
+        if let Err(error) = self.inner.before(invocation).await {
+            self.outer.on_error(invocation, &error).await;
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    async fn after<C: Command, O, R>(
+        &self,
+        invocation: &CommandInvocation<'_, C>,
+        result: &CommandDispatchResult<O, R>,
+    ) {
+        self.inner.after(invocation, result).await;
+        self.outer.after(invocation, result).await;
+    }
+
+    async fn on_error<C: Command>(
+        &self,
+        invocation: &CommandInvocation<'_, C>,
+        error: &(dyn Error + Send + Sync + 'static),
+    ) {
+        self.inner.on_error(invocation, error).await;
+        self.outer.on_error(invocation, error).await;
+    }
+}
@@ -37,6 +37,7 @@ impl CommandRequestOwned {
             message_id: MessageId::new(),
             causation_id: CausationId::from(request_context.message_id),
             options: self.options,
+            trace_context: request_context.trace_context.clone(),
         }
     }
 }
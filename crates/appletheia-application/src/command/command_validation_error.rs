@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::CommandFieldError;
+
+/// Field-level errors collected while validating a command.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Error)]
+#[error("command validation failed: {}", field_errors_summary(.field_errors))]
+pub struct CommandValidationError {
+    field_errors: Vec<CommandFieldError>,
+}
+
+impl CommandValidationError {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_field_error(mut self, field_error: CommandFieldError) -> Self {
+        self.push(field_error);
+        self
+    }
+
+    pub fn push(&mut self, field_error: CommandFieldError) {
+        self.field_errors.push(field_error);
+    }
+
+    pub fn field_errors(&self) -> &[CommandFieldError] {
+        &self.field_errors
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.field_errors.is_empty()
+    }
+
+    /// Returns `Ok(())` when no field error was collected.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+impl From<CommandFieldError> for CommandValidationError {
+    fn from(field_error: CommandFieldError) -> Self {
+        Self::new().with_field_error(field_error)
+    }
+}
+
+fn field_errors_summary(field_errors: &[CommandFieldError]) -> String {
+    field_errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandFieldError, CommandValidationError};
+
+    #[test]
+    fn into_result_is_ok_without_field_errors() {
+        assert!(CommandValidationError::new().into_result().is_ok());
+    }
+
+    #[test]
+    fn display_lists_field_errors() {
+        let error = CommandValidationError::new()
+            .with_field_error(CommandFieldError::new(
+                "name",
+                "non_empty",
+                "must not be empty",
+            ))
+            .with_field_error(CommandFieldError::new(
+                "amount",
+                "range_min",
+                "must be at least 1",
+            ));
+
+        assert_eq!(
+            error.to_string(),
+            "command validation failed: name: must not be empty (non_empty), \
+             amount: must be at least 1 (range_min)"
+        );
+        assert!(error.into_result().is_err());
+    }
+}
@@ -0,0 +1,9 @@
+use super::CommandValidationError;
+
+/// Checks a command's fields before it is authorized or recorded for idempotency.
+///
+/// Usually derived with `#[derive(CommandValidator)]` and `#[validate(...)]` field attributes;
+/// `#[command(...)]` then exposes it through [`Command::validator`](super::Command::validator).
+pub trait CommandValidator {
+    fn validate(&self) -> Result<(), CommandValidationError>;
+}
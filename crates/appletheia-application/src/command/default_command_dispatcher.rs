@@ -1,16 +1,25 @@
+use std::error::Error;
+
+use metrics::counter;
+use tracing::{Instrument, info_span};
+
 use crate::authorization::{AuthorizationPlan, Authorizer, PrincipalRequirement};
 use crate::command::{
-    Command, CommandConsistency, CommandDispatchResult, CommandDispatcher, CommandDispatcherError,
-    CommandFailureReaction, CommandFailureReport, CommandHandler, CommandHasher, CommandOptions,
-    IdempotencyBeginResult, IdempotencyService, IdempotencyState,
+    BatchCommandDispatcher, Command, CommandBatchAtomicity, CommandBatchError, CommandBatchItem,
+    CommandBatchItemResult, CommandBatchOptions, CommandConsistency, CommandDispatchResult,
+    CommandDispatcher, CommandDispatcherError, CommandFailureReaction, CommandFailureReport,
+    CommandHandler, CommandHash, CommandHasher, CommandInvocation, CommandMiddleware,
+    CommandMiddlewareError, CommandMiddlewareStack, CommandName, CommandOptions,
+    IdempotencyBeginResult, IdempotencyService, IdempotencyState, NoopCommandMiddleware,
 };
 use crate::outbox::command::CommandOutboxEnqueuer;
 use crate::projection::{ProjectorDependencies, ProjectorDescriptor, ReadYourWritesWaiter};
 use crate::request_context::{Principal, RequestContext};
+use crate::telemetry::{MetricName, TraceContext};
 use crate::unit_of_work::UnitOfWork;
 use crate::unit_of_work::UnitOfWorkFactory;
 
-pub struct DefaultCommandDispatcher<CH, IS, W, U, AZ, Q>
+pub struct DefaultCommandDispatcher<CH, IS, W, U, AZ, Q, M = NoopCommandMiddleware>
 where
     CH: CommandHasher,
     IS: IdempotencyService,
@@ -19,6 +28,7 @@ where
     U: UnitOfWorkFactory<Uow = IS::Uow>,
     AZ: Authorizer,
     Q: CommandOutboxEnqueuer<Uow = IS::Uow>,
+    M: CommandMiddleware,
 {
     command_hasher: CH,
     idempotency_service: IS,
@@ -26,9 +36,10 @@ where
     uow_factory: U,
     authorizer: AZ,
     command_outbox_enqueuer: Q,
+    middleware: M,
 }
 
-impl<CH, IS, W, U, AZ, Q> DefaultCommandDispatcher<CH, IS, W, U, AZ, Q>
+impl<CH, IS, W, U, AZ, Q, M> DefaultCommandDispatcher<CH, IS, W, U, AZ, Q, M>
 where
     CH: CommandHasher,
     IS: IdempotencyService,
@@ -36,6 +47,7 @@ where
     U: UnitOfWorkFactory<Uow = IS::Uow>,
     AZ: Authorizer,
     Q: CommandOutboxEnqueuer<Uow = IS::Uow>,
+    M: CommandMiddleware,
 {
     fn authorization_dependencies(
         principal: &Principal,
@@ -95,11 +107,12 @@ where
             uow_factory,
             authorizer,
             command_outbox_enqueuer,
+            middleware: NoopCommandMiddleware::new(),
         }
     }
 }
 
-impl<CH, IS, W, U, AZ, Q> CommandDispatcher for DefaultCommandDispatcher<CH, IS, W, U, AZ, Q>
+impl<CH, IS, W, U, AZ, Q, M> DefaultCommandDispatcher<CH, IS, W, U, AZ, Q, M>
 where
     CH: CommandHasher,
     IS: IdempotencyService,
@@ -107,10 +120,28 @@ where
     U: UnitOfWorkFactory<Uow = IS::Uow>,
     AZ: Authorizer,
     Q: CommandOutboxEnqueuer<Uow = IS::Uow>,
+    M: CommandMiddleware,
 {
-    type Uow = IS::Uow;
+    /// Adds `middleware` inside the ones already configured, so its `before` hook runs last.
+    pub fn with_middleware<NextM>(
+        self,
+        middleware: NextM,
+    ) -> DefaultCommandDispatcher<CH, IS, W, U, AZ, Q, CommandMiddlewareStack<M, NextM>>
+    where
+        NextM: CommandMiddleware,
+    {
+        DefaultCommandDispatcher {
+            command_hasher: self.command_hasher,
+            idempotency_service: self.idempotency_service,
+            read_your_writes_waiter: self.read_your_writes_waiter,
+            uow_factory: self.uow_factory,
+            authorizer: self.authorizer,
+            command_outbox_enqueuer: self.command_outbox_enqueuer,
+            middleware: CommandMiddlewareStack::new(self.middleware, middleware),
+        }
+    }
 
-    async fn dispatch<H>(
+    async fn dispatch_with_middleware<H>(
         &self,
         handler: &H,
         request_context: &RequestContext,
@@ -118,12 +149,99 @@ where
         options: CommandOptions,
     ) -> Result<CommandDispatchResult<H::Output, H::ReplayOutput>, CommandDispatcherError<H::Error>>
     where
-        H: CommandHandler<Uow = Self::Uow>,
+        H: CommandHandler<Uow = IS::Uow>,
+        H::Command: Command,
+    {
+        let invocation = CommandInvocation::new(request_context, &command, &options);
+        self.middleware
+            .before(&invocation)
+            .await
+            .map_err(CommandDispatcherError::Middleware)?;
+
+        let result = self
+            .dispatch_in_span(handler, request_context, &command, &options)
+            .await;
+
+        match &result {
+            Ok(dispatch_result) => self.middleware.after(&invocation, dispatch_result).await,
+            Err(error) => self.middleware.on_error(&invocation, error).await,
+        }
+
+        result
+    }
+
+    async fn dispatch_in_span<H>(
+        &self,
+        handler: &H,
+        request_context: &RequestContext,
+        command: &H::Command,
+        options: &CommandOptions,
+    ) -> Result<CommandDispatchResult<H::Output, H::ReplayOutput>, CommandDispatcherError<H::Error>>
+    where
+        H: CommandHandler<Uow = IS::Uow>,
+        H::Command: Command,
+    {
+        let command_hash = self
+            .prepare(handler, request_context, command, options)
+            .await?;
+
+        let mut uow = self.uow_factory.begin().await?;
+
+        match self
+            .execute_in_uow(handler, &mut uow, request_context, command, &command_hash)
+            .await
+        {
+            Ok(dispatch_result) => {
+                uow.commit().await?;
+                Ok(dispatch_result)
+            }
+            Err(CommandDispatcherError::PreviousFailure(error)) => {
+                uow.commit().await?;
+                Err(CommandDispatcherError::PreviousFailure(error))
+            }
+            Err(CommandDispatcherError::InProgress { message_id }) => match uow.rollback().await {
+                Ok(()) => Err(CommandDispatcherError::InProgress { message_id }),
+                Err(rollback_error) => Err(rollback_error.into()),
+            },
+            Err(CommandDispatcherError::Handler(operation_error)) => {
+                let operation_error = uow
+                    .rollback_with_operation_error(operation_error)
+                    .await
+                    .map_err(CommandDispatcherError::UnitOfWork)?;
+
+                self.record_failure(
+                    request_context,
+                    H::Command::NAME,
+                    &command_hash,
+                    CommandFailureReport::from(&operation_error),
+                    options.failure_reaction.clone(),
+                )
+                .await;
+                Err(CommandDispatcherError::Handler(operation_error))
+            }
+            Err(operation_error) => Err(uow.rollback_with_operation_error(operation_error).await?),
+        }
+    }
+
+    /// Validates, waits for the requested consistency and authorizes `command`, then hashes it
+    /// for the idempotency check.
+    async fn prepare<H>(
+        &self,
+        handler: &H,
+        request_context: &RequestContext,
+        command: &H::Command,
+        options: &CommandOptions,
+    ) -> Result<CommandHash, CommandDispatcherError<H::Error>>
+    where
+        H: CommandHandler<Uow = IS::Uow>,
         H::Command: Command,
     {
-        let command_name = H::Command::NAME;
+        if let Some(validator) = command.validator() {
+            validator.validate()?;
+        }
+
         let authorization_plan = handler
-            .authorization_plan(&command)
+            .authorization_plan(command)
             .map_err(CommandDispatcherError::Handler)?;
         let authorization_dependencies =
             Self::authorization_dependencies(&request_context.principal, &authorization_plan);
@@ -161,124 +279,443 @@ where
             }
         }
 
-        let command_hash = self.command_hasher.command_hash(&command)?;
-        let message_id = request_context.message_id;
+        Ok(self.command_hasher.command_hash(command)?)
+    }
 
-        let mut uow = self.uow_factory.begin().await?;
+    /// Claims the idempotency record of the command and runs the handler inside `uow`, leaving
+    /// commit or rollback to the caller.
+    async fn execute_in_uow<H>(
+        &self,
+        handler: &H,
+        uow: &mut IS::Uow,
+        request_context: &RequestContext,
+        command: &H::Command,
+        command_hash: &CommandHash,
+    ) -> Result<CommandDispatchResult<H::Output, H::ReplayOutput>, CommandDispatcherError<H::Error>>
+    where
+        H: CommandHandler<Uow = IS::Uow>,
+        H::Command: Command,
+    {
+        let message_id = request_context.message_id;
 
-        let idempotency_begin_result = self
+        match self
             .idempotency_service
-            .begin(&mut uow, message_id, command_name, &command_hash)
-            .await;
-
-        let idempotency_begin_result = match idempotency_begin_result {
-            Ok(value) => value,
-            Err(operation_error) => {
-                let operation_error = uow.rollback_with_operation_error(operation_error).await?;
-                return Err(operation_error.into());
-            }
-        };
-
-        match idempotency_begin_result {
+            .begin(uow, message_id, H::Command::NAME, command_hash)
+            .await?
+        {
             IdempotencyBeginResult::New => {}
-            IdempotencyBeginResult::InProgress => match uow.rollback().await {
-                Ok(()) => return Err(CommandDispatcherError::InProgress { message_id }),
-                Err(rollback_error) => return Err(rollback_error.into()),
-            },
+            IdempotencyBeginResult::InProgress => {
+                return Err(CommandDispatcherError::InProgress { message_id });
+            }
             IdempotencyBeginResult::Existing { state } => match state {
                 IdempotencyState::Succeeded { output } => {
                     let decoded = serde_json::from_value(output.into())?;
-                    uow.commit().await?;
                     return Ok(CommandDispatchResult::Replayed(decoded));
                 }
                 IdempotencyState::Failed { error } => {
-                    uow.commit().await?;
                     return Err(CommandDispatcherError::PreviousFailure(error));
                 }
             },
         }
 
-        let handler_result = handler.handle(&mut uow, request_context, &command).await;
+        let handled = handler
+            .handle(uow, request_context, command)
+            .await
+            .map_err(CommandDispatcherError::Handler)?;
+        let replay_output = handled.idempotency_output()?;
+        let output = handled.into_output();
+        self.idempotency_service
+            .complete_success(uow, message_id, replay_output)
+            .await?;
+
+        Ok(CommandDispatchResult::Executed(output))
+    }
+
+    /// Records a handler failure in a fresh unit of work so that redeliveries replay it, and
+    /// enqueues the follow-up commands of `failure_reaction`. Errors are ignored; the handler
+    /// error is what the caller reports.
+    async fn record_failure(
+        &self,
+        request_context: &RequestContext,
+        command_name: CommandName,
+        command_hash: &CommandHash,
+        report: CommandFailureReport,
+        failure_reaction: CommandFailureReaction,
+    ) {
+        let message_id = request_context.message_id;
+        let Ok(mut uow) = self.uow_factory.begin().await else {
+            return;
+        };
 
-        match handler_result {
-            Ok(handled) => {
-                let replay_output = handled.idempotency_output()?;
-                let output = handled.into_output();
+        let idempotency_begin_result = self
+            .idempotency_service
+            .begin(&mut uow, message_id, command_name, command_hash)
+            .await;
+        match idempotency_begin_result {
+            Ok(IdempotencyBeginResult::New) => {
                 match self
                     .idempotency_service
-                    .complete_success(&mut uow, message_id, replay_output)
+                    .complete_failure(&mut uow, message_id, report)
                     .await
                 {
-                    Ok(()) => {}
-                    Err(operation_error) => {
-                        let operation_error =
-                            uow.rollback_with_operation_error(operation_error).await?;
-                        return Err(operation_error.into());
-                    }
-                }
-                uow.commit().await?;
-                Ok(CommandDispatchResult::Executed(output))
-            }
-            Err(operation_error) => {
-                let operation_error = uow
-                    .rollback_with_operation_error(operation_error)
-                    .await
-                    .map_err(CommandDispatcherError::UnitOfWork)?;
-                let command_failure_reaction = options.failure_reaction.clone();
-
-                let report = CommandFailureReport::from(&operation_error);
-                if let Ok(mut uow) = self.uow_factory.begin().await {
-                    let idempotency_begin_result = self
-                        .idempotency_service
-                        .begin(&mut uow, message_id, command_name, &command_hash)
-                        .await;
-                    match idempotency_begin_result {
-                        Ok(IdempotencyBeginResult::New) => {
+                    Ok(()) => match failure_reaction {
+                        CommandFailureReaction::None => {
+                            let _ = uow.commit().await;
+                        }
+                        CommandFailureReaction::FollowUpCommand(_) => {
+                            let commands = failure_reaction.into_command_envelopes(request_context);
                             match self
-                                .idempotency_service
-                                .complete_failure(&mut uow, message_id, report)
+                                .command_outbox_enqueuer
+                                .enqueue_commands(&mut uow, &commands)
                                 .await
                             {
-                                Ok(()) => match command_failure_reaction {
-                                    CommandFailureReaction::None => {
-                                        let _ = uow.commit().await;
-                                    }
-                                    CommandFailureReaction::FollowUpCommand(_) => {
-                                        let commands = command_failure_reaction
-                                            .into_command_envelopes(request_context);
-                                        match self
-                                            .command_outbox_enqueuer
-                                            .enqueue_commands(&mut uow, &commands)
-                                            .await
-                                        {
-                                            Ok(()) => {
-                                                let _ = uow.commit().await;
-                                            }
-                                            Err(_) => {
-                                                let _ = uow.rollback().await;
-                                            }
-                                        }
-                                    }
-                                },
+                                Ok(()) => {
+                                    let _ = uow.commit().await;
+                                }
                                 Err(_) => {
                                     let _ = uow.rollback().await;
                                 }
                             }
                         }
-                        Ok(IdempotencyBeginResult::Existing { .. }) => {
-                            let _ = uow.commit().await;
-                        }
-                        Ok(IdempotencyBeginResult::InProgress) => {
-                            let _ = uow.rollback().await;
-                        }
-                        Err(_) => {
-                            let _ = uow.rollback().await;
-                        }
+                    },
+                    Err(_) => {
+                        let _ = uow.rollback().await;
                     }
                 }
-                Err(CommandDispatcherError::Handler(operation_error))
+            }
+            Ok(IdempotencyBeginResult::Existing { .. }) => {
+                let _ = uow.commit().await;
+            }
+            Ok(IdempotencyBeginResult::InProgress) => {
+                let _ = uow.rollback().await;
+            }
+            Err(_) => {
+                let _ = uow.rollback().await;
+            }
+        }
+    }
+
+    async fn dispatch_batch_with_middleware<H>(
+        &self,
+        handler: &H,
+        items: &[CommandBatchItem<H::Command>],
+        options: CommandBatchOptions,
+    ) -> Result<
+        Vec<CommandBatchItemResult<H::Output, H::ReplayOutput, H::Error>>,
+        CommandBatchError<H::Error>,
+    >
+    where
+        H: CommandHandler<Uow = IS::Uow>,
+        H::Command: Command,
+    {
+        let item_options = options.item_options();
+
+        let mut rejections = Vec::with_capacity(items.len());
+        for item in items {
+            let invocation =
+                CommandInvocation::new(&item.request_context, &item.command, &item_options);
+            rejections.push(self.middleware.before(&invocation).await.err());
+        }
+        let admitted = rejections.iter().map(Option::is_none).collect::<Vec<_>>();
+
+        let result = self
+            .dispatch_batch_in_span(handler, items, options, &item_options, rejections)
+            .await;
+
+        for (index, item) in items.iter().enumerate() {
+            if !admitted[index] {
+                continue;
+            }
+            let invocation =
+                CommandInvocation::new(&item.request_context, &item.command, &item_options);
+            match &result {
+                Ok(item_results) => match &item_results[index] {
+                    Ok(dispatch_result) => {
+                        self.middleware.after(&invocation, dispatch_result).await;
+                    }
+                    Err(error) => self.middleware.on_error(&invocation, error).await,
+                },
+                Err(error) => self.middleware.on_error(&invocation, error).await,
             }
         }
+
+        result
+    }
+
+    /// Prepares every item, then executes the prepared ones in a single unit of work.
+    async fn dispatch_batch_in_span<H>(
+        &self,
+        handler: &H,
+        items: &[CommandBatchItem<H::Command>],
+        options: CommandBatchOptions,
+        item_options: &CommandOptions,
+        rejections: Vec<Option<CommandMiddlewareError>>,
+    ) -> Result<
+        Vec<CommandBatchItemResult<H::Output, H::ReplayOutput, H::Error>>,
+        CommandBatchError<H::Error>,
+    >
+    where
+        H: CommandHandler<Uow = IS::Uow>,
+        H::Command: Command,
+    {
+        let all_or_nothing = options.atomicity == CommandBatchAtomicity::AllOrNothing;
+
+        let mut prepared = Vec::with_capacity(items.len());
+        for ((index, item), rejection) in items.iter().enumerate().zip(rejections) {
+            let prepared_item = match rejection {
+                Some(rejection) => Err(CommandDispatcherError::Middleware(rejection)),
+                None => {
+                    self.prepare(handler, &item.request_context, &item.command, item_options)
+                        .await
+                }
+            };
+            match prepared_item {
+                Err(source) if all_or_nothing => {
+                    return Err(CommandBatchError::ItemFailed { index, source });
+                }
+                prepared_item => prepared.push(prepared_item),
+            }
+        }
+
+        let mut uow = self
+            .uow_factory
+            .begin()
+            .await
+            .map_err(CommandDispatcherError::from)?;
+
+        let mut item_results = Vec::with_capacity(items.len());
+        for ((index, item), prepared_item) in items.iter().enumerate().zip(prepared) {
+            let command_hash = match prepared_item {
+                Ok(command_hash) => command_hash,
+                Err(error) => {
+                    item_results.push(Err(error));
+                    continue;
+                }
+            };
+
+            if all_or_nothing {
+                let item_result = self
+                    .execute_in_uow(
+                        handler,
+                        &mut uow,
+                        &item.request_context,
+                        &item.command,
+                        &command_hash,
+                    )
+                    .await;
+                match item_result {
+                    Ok(dispatch_result) => item_results.push(Ok(dispatch_result)),
+                    Err(source) => {
+                        let source = uow
+                            .rollback_with_operation_error(source)
+                            .await
+                            .map_err(CommandDispatcherError::UnitOfWork)?;
+                        return Err(CommandBatchError::ItemFailed { index, source });
+                    }
+                }
+                continue;
+            }
+
+            uow.savepoint()
+                .await
+                .map_err(CommandDispatcherError::from)?;
+            let item_result = self
+                .execute_in_uow(
+                    handler,
+                    &mut uow,
+                    &item.request_context,
+                    &item.command,
+                    &command_hash,
+                )
+                .await;
+            match &item_result {
+                Ok(_) => uow.release_savepoint().await,
+                Err(_) => uow.rollback_to_savepoint().await,
+            }
+            .map_err(CommandDispatcherError::from)?;
+
+            if let Err(CommandDispatcherError::Handler(operation_error)) = &item_result {
+                self.record_failure_in_savepoint(
+                    &mut uow,
+                    &item.request_context,
+                    H::Command::NAME,
+                    &command_hash,
+                    CommandFailureReport::from(operation_error),
+                )
+                .await?;
+            }
+            item_results.push(item_result);
+        }
+
+        uow.commit().await.map_err(CommandDispatcherError::from)?;
+
+        Ok(item_results)
+    }
+
+    /// Records a failed batch item inside the batch unit of work. A failure to record is
+    /// rolled back on its own so the rest of the batch can still commit.
+    async fn record_failure_in_savepoint<HE>(
+        &self,
+        uow: &mut IS::Uow,
+        request_context: &RequestContext,
+        command_name: CommandName,
+        command_hash: &CommandHash,
+        report: CommandFailureReport,
+    ) -> Result<(), CommandBatchError<HE>>
+    where
+        HE: Error + Send + Sync + 'static,
+    {
+        let message_id = request_context.message_id;
+        uow.savepoint()
+            .await
+            .map_err(CommandDispatcherError::from)?;
+
+        let recorded = match self
+            .idempotency_service
+            .begin(uow, message_id, command_name, command_hash)
+            .await
+        {
+            Ok(IdempotencyBeginResult::New) => self
+                .idempotency_service
+                .complete_failure(uow, message_id, report)
+                .await
+                .is_ok(),
+            _ => false,
+        };
+
+        if recorded {
+            uow.release_savepoint().await
+        } else {
+            uow.rollback_to_savepoint().await
+        }
+        .map_err(CommandDispatcherError::from)?;
+        Ok(())
+    }
+
+    fn count_dispatch<C: Command, O, R, HE>(
+        result: &Result<CommandDispatchResult<O, R>, CommandDispatcherError<HE>>,
+    ) where
+        HE: Error + Send + Sync + 'static,
+    {
+        let outcome = match result {
+            Ok(CommandDispatchResult::Executed(_)) => "executed",
+            Ok(CommandDispatchResult::Replayed(_)) => "replayed",
+            Err(CommandDispatcherError::InProgress { .. }) => "in_progress",
+            Err(_) => "failed",
+        };
+        counter!(
+            MetricName::CommandDispatched.as_str(),
+            "command" => C::NAME.value(),
+            "outcome" => outcome,
+        )
+        .increment(1);
+    }
+}
+
+impl<CH, IS, W, U, AZ, Q, M> CommandDispatcher for DefaultCommandDispatcher<CH, IS, W, U, AZ, Q, M>
+where
+    CH: CommandHasher,
+    IS: IdempotencyService,
+    W: ReadYourWritesWaiter,
+    U: UnitOfWorkFactory<Uow = IS::Uow>,
+    AZ: Authorizer,
+    Q: CommandOutboxEnqueuer<Uow = IS::Uow>,
+    M: CommandMiddleware,
+{
+    type Uow = IS::Uow;
+
+    /// Dispatches inside a `command.dispatch` span whose trace context is passed on to the
+    /// events and commands written by the handler, and counts the outcome.
+    async fn dispatch<H>(
+        &self,
+        handler: &H,
+        request_context: &RequestContext,
+        command: H::Command,
+        options: CommandOptions,
+    ) -> Result<CommandDispatchResult<H::Output, H::ReplayOutput>, CommandDispatcherError<H::Error>>
+    where
+        H: CommandHandler<Uow = Self::Uow>,
+        H::Command: Command,
+    {
+        let span = info_span!(
+            "command.dispatch",
+            command.name = %H::Command::NAME,
+            correlation_id = %request_context.correlation_id,
+            message_id = %request_context.message_id,
+        );
+        let request_context = match TraceContext::from_span(&span) {
+            Some(trace_context) => request_context.clone().with_trace_context(trace_context),
+            None => request_context.clone(),
+        };
+
+        let result = self
+            .dispatch_with_middleware(handler, &request_context, command, options)
+            .instrument(span)
+            .await;
+
+        Self::count_dispatch::<H::Command, _, _, _>(&result);
+
+        result
+    }
+}
+
+impl<CH, IS, W, U, AZ, Q, M> BatchCommandDispatcher
+    for DefaultCommandDispatcher<CH, IS, W, U, AZ, Q, M>
+where
+    CH: CommandHasher,
+    IS: IdempotencyService,
+    W: ReadYourWritesWaiter,
+    U: UnitOfWorkFactory<Uow = IS::Uow>,
+    AZ: Authorizer,
+    Q: CommandOutboxEnqueuer<Uow = IS::Uow>,
+    M: CommandMiddleware,
+{
+    /// Dispatches the batch inside a `command.dispatch_batch` span and counts the outcome of
+    /// every item; items of a batch that failed as a whole count as failed.
+    async fn dispatch_batch<H>(
+        &self,
+        handler: &H,
+        mut items: Vec<CommandBatchItem<H::Command>>,
+        options: CommandBatchOptions,
+    ) -> Result<
+        Vec<CommandBatchItemResult<H::Output, H::ReplayOutput, H::Error>>,
+        CommandBatchError<H::Error>,
+    >
+    where
+        H: CommandHandler<Uow = Self::Uow>,
+        H::Command: Command,
+    {
+        let span = info_span!(
+            "command.dispatch_batch",
+            command.name = %H::Command::NAME,
+            batch.size = items.len(),
+        );
+        if let Some(trace_context) = TraceContext::from_span(&span) {
+            for item in &mut items {
+                item.request_context.trace_context = Some(trace_context.clone());
+            }
+        }
+
+        let result = self
+            .dispatch_batch_with_middleware(handler, &items, options)
+            .instrument(span)
+            .await;
+
+        match &result {
+            Ok(item_results) => {
+                for item_result in item_results {
+                    Self::count_dispatch::<H::Command, _, _, _>(item_result);
+                }
+            }
+            Err(_) => counter!(
+                MetricName::CommandDispatched.as_str(),
+                "command" => H::Command::NAME.value(),
+                "outcome" => "failed",
+            )
+            .increment(items.len() as u64),
+        }
+
+        result
     }
 }
 
@@ -295,10 +732,13 @@ mod tests {
         RelationName, RelationRefOwned, RelationshipRequirement,
     };
     use crate::command::{
-        Command, CommandDispatcher, CommandDispatcherError, CommandFailureReaction,
-        CommandFailureReport, CommandHandled, CommandHandler, CommandHash, CommandHasher,
-        CommandHasherError, CommandName, CommandOptions, CommandRequest, IdempotencyBeginResult,
-        IdempotencyOutput, IdempotencyService, IdempotencyServiceError,
+        BatchCommandDispatcher, Command, CommandBatchAtomicity, CommandBatchError,
+        CommandBatchItem, CommandBatchOptions, CommandDispatchResult, CommandDispatcher,
+        CommandDispatcherError, CommandFailureReaction, CommandFailureReport, CommandFieldError,
+        CommandHandled, CommandHandler, CommandHash, CommandHasher, CommandHasherError,
+        CommandInvocation, CommandMiddleware, CommandMiddlewareError, CommandName, CommandOptions,
+        CommandRequest, CommandValidationError, CommandValidator, IdempotencyBeginResult,
+        IdempotencyOutput, IdempotencyService, IdempotencyServiceError, IdempotencyState,
     };
     use crate::event::{AggregateIdValue, AggregateTypeOwned};
     use crate::messaging::Subscription;
@@ -341,6 +781,18 @@ mod tests {
         async fn rollback(self) -> Result<(), UnitOfWorkError> {
             Ok(())
         }
+
+        async fn savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+            Ok(())
+        }
+
+        async fn release_savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+            Ok(())
+        }
+
+        async fn rollback_to_savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+            Ok(())
+        }
     }
 
     struct TestUowFactory;
@@ -444,6 +896,58 @@ mod tests {
         }
     }
 
+    /// Remembers failures so a retried message replays them.
+    #[derive(Default)]
+    struct FailureRecordingIdempotencyService {
+        failures: Mutex<Vec<(MessageId, CommandFailureReport)>>,
+    }
+
+    impl IdempotencyService for FailureRecordingIdempotencyService {
+        type Uow = TestUow;
+
+        async fn begin(
+            &self,
+            _uow: &mut Self::Uow,
+            message_id: MessageId,
+            _command_name: CommandName,
+            _command_hash: &CommandHash,
+        ) -> Result<IdempotencyBeginResult, IdempotencyServiceError> {
+            let failures = self.failures.lock().expect("lock");
+            Ok(
+                match failures.iter().find(|(failed, _)| *failed == message_id) {
+                    Some((_, error)) => IdempotencyBeginResult::Existing {
+                        state: IdempotencyState::Failed {
+                            error: error.clone(),
+                        },
+                    },
+                    None => IdempotencyBeginResult::New,
+                },
+            )
+        }
+
+        async fn complete_success(
+            &self,
+            _uow: &mut Self::Uow,
+            _message_id: MessageId,
+            _output: IdempotencyOutput,
+        ) -> Result<(), IdempotencyServiceError> {
+            Ok(())
+        }
+
+        async fn complete_failure(
+            &self,
+            _uow: &mut Self::Uow,
+            message_id: MessageId,
+            error: CommandFailureReport,
+        ) -> Result<(), IdempotencyServiceError> {
+            self.failures
+                .lock()
+                .expect("lock")
+                .push((message_id, error));
+            Ok(())
+        }
+    }
+
     #[derive(Clone, Default)]
     struct TestCommandOutboxEnqueuer {
         commands: Arc<Mutex<Vec<CommandEnvelope>>>,
@@ -650,4 +1154,423 @@ mod tests {
             crate::request_context::CausationId::from(request_context.message_id)
         );
     }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("rejected by test middleware")]
+    struct TestRejection;
+
+    struct RecordingMiddleware {
+        name: &'static str,
+        rejects: bool,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl RecordingMiddleware {
+        fn new(name: &'static str, rejects: bool, log: &Arc<Mutex<Vec<String>>>) -> Self {
+            Self {
+                name,
+                rejects,
+                log: Arc::clone(log),
+            }
+        }
+
+        fn record(&self, hook: &str) {
+            self.log
+                .lock()
+                .expect("lock")
+                .push(format!("{}.{hook}", self.name));
+        }
+    }
+
+    impl CommandMiddleware for RecordingMiddleware {
+        async fn before<C: Command>(
+            &self,
+            invocation: &CommandInvocation<'_, C>,
+        ) -> Result<(), CommandMiddlewareError> {
+            let serialized_command = invocation.serialized_command().expect("serializable");
+            self.record(&format!(
+                "before({}, {serialized_command})",
+                invocation.command_name()
+            ));
+            if self.rejects {
+                return Err(CommandMiddlewareError::Rejected(Box::new(TestRejection)));
+            }
+            Ok(())
+        }
+
+        async fn on_error<C: Command>(
+            &self,
+            _invocation: &CommandInvocation<'_, C>,
+            _error: &(dyn std::error::Error + Send + Sync + 'static),
+        ) {
+            self.record("on_error");
+        }
+    }
+
+    fn system_request_context() -> crate::request_context::RequestContext {
+        crate::request_context::RequestContext::new(
+            crate::request_context::CorrelationId::from(Uuid::now_v7()),
+            crate::request_context::MessageId::new(),
+            Principal::System,
+        )
+        .expect("request context should be valid")
+    }
+
+    #[tokio::test]
+    async fn dispatch_runs_middleware_hooks_as_nested_layers() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = DefaultCommandDispatcher::new(
+            TestCommandHasher,
+            TestNewIdempotencyService,
+            TestWaiter,
+            TestUowFactory,
+            TestAuthorizer,
+            TestCommandOutboxEnqueuer::default(),
+        )
+        .with_middleware(RecordingMiddleware::new("outer", false, &log))
+        .with_middleware(RecordingMiddleware::new("inner", false, &log));
+
+        let result = dispatcher
+            .dispatch(
+                &TestCommandFailureHandler,
+                &system_request_context(),
+                TestCommand {},
+                CommandOptions::default(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CommandDispatcherError::Handler(_))));
+        assert_eq!(
+            *log.lock().expect("lock"),
+            vec![
+                "outer.before(test, {})",
+                "inner.before(test, {})",
+                "inner.on_error",
+                "outer.on_error",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn dispatch_skips_handler_when_middleware_rejects() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let outbox_enqueuer = TestCommandOutboxEnqueuer::default();
+        let dispatcher = DefaultCommandDispatcher::new(
+            TestCommandHasher,
+            TestNewIdempotencyService,
+            TestWaiter,
+            TestUowFactory,
+            TestAuthorizer,
+            outbox_enqueuer.clone(),
+        )
+        .with_middleware(RecordingMiddleware::new("outer", true, &log))
+        .with_middleware(RecordingMiddleware::new("inner", false, &log));
+
+        let result = dispatcher
+            .dispatch(
+                &TestCommandFailureHandler,
+                &system_request_context(),
+                TestCommand {},
+                CommandOptions {
+                    failure_reaction: CommandFailureReaction::follow_up_command(
+                        CommandRequest::new(FollowUpTestCommand {}),
+                    )
+                    .expect("reaction should serialize"),
+                    ..CommandOptions::default()
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CommandDispatcherError::Middleware(_))));
+        assert!(outbox_enqueuer.recorded_commands().is_empty());
+        assert_eq!(*log.lock().expect("lock"), vec!["outer.before(test, {})"]);
+    }
+
+    #[tokio::test]
+    async fn rejection_unwinds_only_the_middlewares_whose_before_completed() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = DefaultCommandDispatcher::new(
+            TestCommandHasher,
+            TestNewIdempotencyService,
+            TestWaiter,
+            TestUowFactory,
+            TestAuthorizer,
+            TestCommandOutboxEnqueuer::default(),
+        )
+        .with_middleware(RecordingMiddleware::new("outer", false, &log))
+        .with_middleware(RecordingMiddleware::new("inner", true, &log));
+
+        let result = dispatcher
+            .dispatch(
+                &TestCommandFailureHandler,
+                &system_request_context(),
+                TestCommand {},
+                CommandOptions::default(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CommandDispatcherError::Middleware(_))));
+        assert_eq!(
+            *log.lock().expect("lock"),
+            vec![
+                "outer.before(test, {})",
+                "inner.before(test, {})",
+                "outer.on_error"
+            ]
+        );
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    struct ValidatedTestCommand {
+        name: String,
+    }
+
+    impl Command for ValidatedTestCommand {
+        const NAME: CommandName = CommandName::new("validated_test");
+
+        fn validator(&self) -> Option<&dyn CommandValidator> {
+            Some(self)
+        }
+    }
+
+    impl CommandValidator for ValidatedTestCommand {
+        fn validate(&self) -> Result<(), CommandValidationError> {
+            let mut error = CommandValidationError::new();
+            if self.name.is_empty() {
+                error.push(CommandFieldError::new(
+                    "name",
+                    "non_empty",
+                    "must not be empty",
+                ));
+            }
+            error.into_result()
+        }
+    }
+
+    struct ValidatedTestCommandHandler;
+
+    impl CommandHandler for ValidatedTestCommandHandler {
+        type Command = ValidatedTestCommand;
+        type Output = ();
+        type ReplayOutput = ();
+        type Error = TestHandlerError;
+        type Uow = TestUow;
+
+        async fn handle(
+            &self,
+            _uow: &mut Self::Uow,
+            _request_context: &crate::request_context::RequestContext,
+            _command: &Self::Command,
+        ) -> Result<CommandHandled<Self::Output, Self::ReplayOutput>, Self::Error> {
+            panic!("handler must not run for invalid commands")
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_rejects_invalid_command_before_idempotency_begins() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = DefaultCommandDispatcher::new(
+            TestCommandHasher,
+            TestIdempotencyService,
+            TestWaiter,
+            TestUowFactory,
+            TestAuthorizer,
+            TestCommandOutboxEnqueuer::default(),
+        )
+        .with_middleware(RecordingMiddleware::new("outer", false, &log));
+
+        let result = dispatcher
+            .dispatch(
+                &ValidatedTestCommandHandler,
+                &system_request_context(),
+                ValidatedTestCommand {
+                    name: String::new(),
+                },
+                CommandOptions::default(),
+            )
+            .await;
+
+        let Err(CommandDispatcherError::Validation(error)) = result else {
+            panic!("expected a validation error");
+        };
+        assert_eq!(
+            error.field_errors(),
+            [CommandFieldError::new(
+                "name",
+                "non_empty",
+                "must not be empty"
+            )]
+        );
+        assert_eq!(
+            *log.lock().expect("lock"),
+            vec![
+                "outer.before(validated_test, {\"name\":\"\"})",
+                "outer.on_error"
+            ]
+        );
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    struct BatchTestCommand {
+        fails: bool,
+    }
+
+    impl Command for BatchTestCommand {
+        const NAME: CommandName = CommandName::new("batch_test");
+    }
+
+    #[derive(Default)]
+    struct BatchTestCommandHandler {
+        handled: Mutex<usize>,
+    }
+
+    impl CommandHandler for BatchTestCommandHandler {
+        type Command = BatchTestCommand;
+        type Output = ();
+        type ReplayOutput = ();
+        type Error = TestHandlerError;
+        type Uow = TestUow;
+
+        async fn handle(
+            &self,
+            _uow: &mut Self::Uow,
+            _request_context: &crate::request_context::RequestContext,
+            command: &Self::Command,
+        ) -> Result<CommandHandled<Self::Output, Self::ReplayOutput>, Self::Error> {
+            *self.handled.lock().expect("lock") += 1;
+            if command.fails {
+                return Err(TestHandlerError);
+            }
+            Ok(CommandHandled::same(()))
+        }
+    }
+
+    fn batch_items(fails: &[bool]) -> Vec<CommandBatchItem<BatchTestCommand>> {
+        fails
+            .iter()
+            .map(|&fails| {
+                CommandBatchItem::new(system_request_context(), BatchTestCommand { fails })
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn dispatch_batch_stops_at_first_failure_when_all_or_nothing() {
+        let handler = BatchTestCommandHandler::default();
+        let dispatcher = DefaultCommandDispatcher::new(
+            TestCommandHasher,
+            TestNewIdempotencyService,
+            TestWaiter,
+            TestUowFactory,
+            TestAuthorizer,
+            TestCommandOutboxEnqueuer::default(),
+        );
+
+        let result = dispatcher
+            .dispatch_batch(
+                &handler,
+                batch_items(&[false, true, false]),
+                CommandBatchOptions::default(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CommandBatchError::ItemFailed {
+                index: 1,
+                source: CommandDispatcherError::Handler(_),
+            })
+        ));
+        assert_eq!(*handler.handled.lock().expect("lock"), 2);
+    }
+
+    #[tokio::test]
+    async fn dispatch_batch_reports_each_item_when_per_item() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let handler = BatchTestCommandHandler::default();
+        let dispatcher = DefaultCommandDispatcher::new(
+            TestCommandHasher,
+            TestNewIdempotencyService,
+            TestWaiter,
+            TestUowFactory,
+            TestAuthorizer,
+            TestCommandOutboxEnqueuer::default(),
+        )
+        .with_middleware(RecordingMiddleware::new("outer", false, &log));
+
+        let results = dispatcher
+            .dispatch_batch(
+                &handler,
+                batch_items(&[false, true, false]),
+                CommandBatchOptions {
+                    atomicity: CommandBatchAtomicity::PerItem,
+                    ..CommandBatchOptions::default()
+                },
+            )
+            .await
+            .expect("per-item batch should commit");
+
+        assert_eq!(results.len(), 3);
+        assert!(matches!(
+            results[0],
+            Ok(CommandDispatchResult::Executed(()))
+        ));
+        assert!(matches!(
+            results[1],
+            Err(CommandDispatcherError::Handler(_))
+        ));
+        assert!(matches!(
+            results[2],
+            Ok(CommandDispatchResult::Executed(()))
+        ));
+        assert_eq!(*handler.handled.lock().expect("lock"), 3);
+        assert_eq!(
+            *log.lock().expect("lock"),
+            vec![
+                "outer.before(batch_test, {\"fails\":false})",
+                "outer.before(batch_test, {\"fails\":true})",
+                "outer.before(batch_test, {\"fails\":false})",
+                "outer.on_error",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_all_or_nothing_batch_can_be_retried() {
+        let handler = BatchTestCommandHandler::default();
+        let dispatcher = DefaultCommandDispatcher::new(
+            TestCommandHasher,
+            FailureRecordingIdempotencyService::default(),
+            TestWaiter,
+            TestUowFactory,
+            TestAuthorizer,
+            TestCommandOutboxEnqueuer::default(),
+        );
+        let items = batch_items(&[false, true]);
+        let retried_items = items
+            .iter()
+            .map(|item| {
+                CommandBatchItem::new(
+                    item.request_context.clone(),
+                    BatchTestCommand { fails: false },
+                )
+            })
+            .collect();
+
+        dispatcher
+            .dispatch_batch(&handler, items, CommandBatchOptions::default())
+            .await
+            .expect_err("the failing item should fail the batch");
+        let results = dispatcher
+            .dispatch_batch(&handler, retried_items, CommandBatchOptions::default())
+            .await
+            .expect("the retried batch should commit");
+
+        assert!(
+            results
+                .iter()
+                .all(|result| matches!(result, Ok(CommandDispatchResult::Executed(()))))
+        );
+        assert_eq!(*handler.handled.lock().expect("lock"), 4);
+    }
 }
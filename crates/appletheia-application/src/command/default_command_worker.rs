@@ -1,10 +1,15 @@
-use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::error::Error;
+
+use tracing::{Instrument, info_span};
 
 use crate::command::{Command, CommandDispatcher, CommandHandler, CommandSelector, CommandWorker};
 use crate::messaging::Subscription;
 use crate::outbox::command::{CommandEnvelope, CommandEnvelopeError};
 use crate::request_context::{ActorRef, Principal, RequestContext};
-use crate::{Consumer, ConsumerGroup, Delivery, Subscriber};
+use crate::{
+    ConcurrentDeliveryProcessor, Consumer, ConsumerGroup, Delivery, DeliveryFlowControl,
+    Subscriber, SupervisedWorker, WorkerStopSignal,
+};
 
 use super::CommandWorkerError;
 
@@ -21,7 +26,8 @@ where
     handler: H,
     subscriber: S,
     consumer_group: ConsumerGroup,
-    stop_requested: AtomicBool,
+    delivery_processor: ConcurrentDeliveryProcessor,
+    stop_signal: WorkerStopSignal,
 }
 
 impl<H, D, S> DefaultCommandWorker<H, D, S>
@@ -39,9 +45,16 @@ where
             handler,
             subscriber,
             consumer_group,
-            stop_requested: AtomicBool::new(false),
+            delivery_processor: ConcurrentDeliveryProcessor::default(),
+            stop_signal: WorkerStopSignal::new(),
         }
     }
+
+    /// Processes deliveries concurrently within `flow_control`; the default is sequential.
+    pub fn with_flow_control(mut self, flow_control: DeliveryFlowControl) -> Self {
+        self.delivery_processor = ConcurrentDeliveryProcessor::new(flow_control);
+        self
+    }
 }
 
 impl<H, D, S> CommandWorker for DefaultCommandWorker<H, D, S>
@@ -54,53 +67,60 @@ where
     <S::Consumer as Consumer<CommandEnvelope>>::Delivery: Delivery<CommandEnvelope>,
 {
     fn is_stop_requested(&self) -> bool {
-        self.stop_requested.load(AtomicOrdering::SeqCst)
+        self.stop_signal.is_requested()
     }
 
     fn request_graceful_stop(&mut self) {
-        self.stop_requested.store(true, AtomicOrdering::SeqCst);
+        self.stop_signal.request();
     }
 
     async fn run_forever(&mut self) -> Result<(), CommandWorkerError> {
         let selectors = [CommandSelector::new(H::Command::NAME)];
 
-        let mut consumer = self
+        let consumer = self
             .subscriber
             .subscribe(&self.consumer_group, Subscription::AnyOf(&selectors))
             .await?;
 
-        while !self.is_stop_requested() {
-            let mut delivery = consumer.next().await?;
+        let dispatcher = &self.dispatcher;
+        let handler = &self.handler;
 
-            let command = match delivery.message().try_into_command::<H::Command>() {
-                Ok(command) => Some(command),
-                Err(CommandEnvelopeError::CommandNameMismatch { .. }) => {
-                    delivery.ack().await?;
-                    None
-                }
-                Err(error) => {
-                    delivery.nack().await?;
-                    return Err(error.into());
-                }
-            };
+        self.delivery_processor
+            .run(consumer, &self.stop_signal, |mut delivery| async move {
+                let command = match delivery.message().try_into_command::<H::Command>() {
+                    Ok(command) => command,
+                    Err(CommandEnvelopeError::CommandNameMismatch { .. }) => {
+                        delivery.ack().await?;
+                        return Ok(());
+                    }
+                    Err(error) => {
+                        delivery.nack().await?;
+                        return Err(error.into());
+                    }
+                };
 
-            if let Some(command) = command {
                 let envelope = delivery.message();
                 let request_context = RequestContext {
                     correlation_id: envelope.correlation_id,
                     message_id: envelope.message_id,
                     actor: ActorRef::System,
                     principal: Principal::System,
+                    trace_context: envelope.trace_context.clone(),
                 };
 
-                let result = self
-                    .dispatcher
-                    .dispatch(
-                        &self.handler,
-                        &request_context,
-                        command,
-                        envelope.options.clone(),
-                    )
+                let span = info_span!(
+                    "command.consume",
+                    command.name = %envelope.command_name,
+                    correlation_id = %envelope.correlation_id,
+                    message_id = %envelope.message_id,
+                );
+                if let Some(trace_context) = &envelope.trace_context {
+                    trace_context.set_as_parent_of(&span);
+                }
+
+                let result = dispatcher
+                    .dispatch(handler, &request_context, command, envelope.options.clone())
+                    .instrument(span)
                     .await;
 
                 match result {
@@ -110,9 +130,27 @@ where
                         return Err(CommandWorkerError::Dispatch(Box::new(error)));
                     }
                 }
-            }
-        }
 
-        Ok(())
+                Ok(())
+            })
+            .await
+    }
+}
+
+impl<H, D, S> SupervisedWorker for DefaultCommandWorker<H, D, S>
+where
+    H: CommandHandler,
+    H::Command: Command,
+    D: CommandDispatcher<Uow = H::Uow>,
+    S: Subscriber<CommandEnvelope, Selector = CommandSelector>,
+    S::Consumer: Consumer<CommandEnvelope>,
+    <S::Consumer as Consumer<CommandEnvelope>>::Delivery: Delivery<CommandEnvelope>,
+{
+    fn stop_signal(&self) -> WorkerStopSignal {
+        self.stop_signal.clone()
+    }
+
+    async fn run_supervised(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(self.run_forever().await?)
     }
 }
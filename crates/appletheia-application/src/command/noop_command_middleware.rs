@@ -0,0 +1,13 @@
+use super::CommandMiddleware;
+
+/// Middleware that does nothing; the default for `DefaultCommandDispatcher`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopCommandMiddleware;
+
+impl NoopCommandMiddleware {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CommandMiddleware for NoopCommandMiddleware {}
@@ -0,0 +1,27 @@
+//! Backend-agnostic conformance checks for storage adapters.
+//!
+//! Implement `StorageConformanceHarness` for a backend and call
+//! `StorageConformanceSuite::<Harness>::run_all()` from a test to verify that it behaves like
+//! the reference adapters.
+
+pub mod conformance_aggregate;
+pub mod conformance_aggregate_error;
+pub mod conformance_aggregate_id;
+pub mod conformance_aggregate_id_error;
+pub mod conformance_aggregate_state;
+pub mod conformance_aggregate_state_error;
+pub mod conformance_event_payload;
+pub mod conformance_event_payload_error;
+pub mod storage_conformance_harness;
+pub mod storage_conformance_suite;
+
+pub use conformance_aggregate::ConformanceAggregate;
+pub use conformance_aggregate_error::ConformanceAggregateError;
+pub use conformance_aggregate_id::ConformanceAggregateId;
+pub use conformance_aggregate_id_error::ConformanceAggregateIdError;
+pub use conformance_aggregate_state::ConformanceAggregateState;
+pub use conformance_aggregate_state_error::ConformanceAggregateStateError;
+pub use conformance_event_payload::ConformanceEventPayload;
+pub use conformance_event_payload_error::ConformanceEventPayloadError;
+pub use storage_conformance_harness::StorageConformanceHarness;
+pub use storage_conformance_suite::StorageConformanceSuite;
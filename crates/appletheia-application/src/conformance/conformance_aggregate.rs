@@ -0,0 +1,50 @@
+use appletheia_domain::{Aggregate, AggregateApply, AggregateCore, AggregateType};
+
+use super::{
+    ConformanceAggregateError, ConformanceAggregateId, ConformanceAggregateState,
+    ConformanceEventPayload,
+};
+
+/// Minimal aggregate whose events are written and read back by the conformance suite.
+#[derive(Clone, Debug, Default)]
+pub struct ConformanceAggregate {
+    core: AggregateCore<ConformanceAggregateState, ConformanceEventPayload>,
+}
+
+impl AggregateApply<ConformanceEventPayload, ConformanceAggregateError> for ConformanceAggregate {
+    fn apply(
+        &mut self,
+        payload: &ConformanceEventPayload,
+    ) -> Result<(), ConformanceAggregateError> {
+        match payload {
+            ConformanceEventPayload::Created { id, name } => {
+                self.set_state(Some(ConformanceAggregateState {
+                    id: *id,
+                    name: name.clone(),
+                }));
+            }
+            ConformanceEventPayload::Renamed { name } => {
+                self.state_required_mut()?.name = name.clone();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Aggregate for ConformanceAggregate {
+    type Id = ConformanceAggregateId;
+    type State = ConformanceAggregateState;
+    type EventPayload = ConformanceEventPayload;
+    type Error = ConformanceAggregateError;
+
+    const TYPE: AggregateType = AggregateType::new("conformance_aggregate");
+
+    fn core(&self) -> &AggregateCore<Self::State, Self::EventPayload> {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut AggregateCore<Self::State, Self::EventPayload> {
+        &mut self.core
+    }
+}
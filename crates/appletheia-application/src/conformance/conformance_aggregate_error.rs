@@ -0,0 +1,10 @@
+use appletheia_domain::AggregateError;
+use thiserror::Error;
+
+use super::ConformanceAggregateId;
+
+#[derive(Debug, Error)]
+pub enum ConformanceAggregateError {
+    #[error("aggregate error: {0}")]
+    Aggregate(#[from] AggregateError<ConformanceAggregateId>),
+}
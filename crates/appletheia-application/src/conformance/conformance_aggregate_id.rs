@@ -0,0 +1,45 @@
+use std::{fmt, fmt::Display};
+
+use appletheia_domain::AggregateId;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::ConformanceAggregateIdError;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ConformanceAggregateId(Uuid);
+
+impl ConformanceAggregateId {
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+}
+
+impl Default for ConformanceAggregateId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AggregateId for ConformanceAggregateId {
+    type Error = ConformanceAggregateIdError;
+
+    fn value(&self) -> Uuid {
+        self.0
+    }
+
+    fn try_from_uuid(value: Uuid) -> Result<Self, Self::Error> {
+        if value.is_nil() {
+            return Err(ConformanceAggregateIdError::NilUuid);
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl Display for ConformanceAggregateId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum ConformanceAggregateIdError {
+    #[error("nil uuid is not allowed")]
+    NilUuid,
+}
@@ -0,0 +1,21 @@
+use appletheia_domain::{AggregateState, UniqueConstraints};
+use serde::{Deserialize, Serialize};
+
+use super::{ConformanceAggregateId, ConformanceAggregateStateError};
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ConformanceAggregateState {
+    pub id: ConformanceAggregateId,
+    pub name: String,
+}
+
+impl UniqueConstraints<ConformanceAggregateStateError> for ConformanceAggregateState {}
+
+impl AggregateState for ConformanceAggregateState {
+    type Id = ConformanceAggregateId;
+    type Error = ConformanceAggregateStateError;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+}
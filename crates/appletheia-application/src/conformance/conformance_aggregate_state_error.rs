@@ -0,0 +1,8 @@
+use appletheia_domain::AggregateStateError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConformanceAggregateStateError {
+    #[error(transparent)]
+    AggregateState(#[from] AggregateStateError),
+}
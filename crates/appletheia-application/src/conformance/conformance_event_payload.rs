@@ -0,0 +1,32 @@
+use appletheia_domain::{EventName, EventPayload};
+use serde::{Deserialize, Serialize};
+
+use super::{ConformanceAggregateId, ConformanceEventPayloadError};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum ConformanceEventPayload {
+    Created {
+        id: ConformanceAggregateId,
+        name: String,
+    },
+    Renamed {
+        name: String,
+    },
+}
+
+impl ConformanceEventPayload {
+    pub const CREATED: EventName = EventName::new("created");
+    pub const RENAMED: EventName = EventName::new("renamed");
+}
+
+impl EventPayload for ConformanceEventPayload {
+    type Error = ConformanceEventPayloadError;
+
+    fn name(&self) -> EventName {
+        match self {
+            Self::Created { .. } => Self::CREATED,
+            Self::Renamed { .. } => Self::RENAMED,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConformanceEventPayloadError {
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
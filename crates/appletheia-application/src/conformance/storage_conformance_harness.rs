@@ -0,0 +1,48 @@
+use crate::authorization::RelationshipStore;
+use crate::command::IdempotencyService;
+use crate::event::{EventFeedReader, EventReader, EventWriter};
+use crate::outbox::event::EventOutbox;
+use crate::outbox::{OutboxFetcher, OutboxWriter};
+use crate::repository::UniqueKeyReservationStore;
+use crate::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+
+use super::ConformanceAggregate;
+
+/// Wires a storage backend into the `StorageConformanceSuite`.
+///
+/// Every component must share the same unit of work so the suite can combine them in one
+/// transaction. `setup` is called once per check and must return a harness backed by an empty
+/// store, isolated from any previously created harness.
+#[allow(async_fn_in_trait)]
+pub trait StorageConformanceHarness: Sized {
+    type Uow: UnitOfWork;
+    type UnitOfWorkFactory: UnitOfWorkFactory<Uow = Self::Uow>;
+    type EventWriter: EventWriter<ConformanceAggregate, Uow = Self::Uow>;
+    type EventReader: EventReader<ConformanceAggregate, Uow = Self::Uow>;
+    type EventFeedReader: EventFeedReader<Uow = Self::Uow>;
+    type EventOutboxFetcher: OutboxFetcher<Uow = Self::Uow, Outbox = EventOutbox>;
+    type EventOutboxWriter: OutboxWriter<Uow = Self::Uow, Outbox = EventOutbox>;
+    type IdempotencyService: IdempotencyService<Uow = Self::Uow>;
+    type UniqueKeyReservationStore: UniqueKeyReservationStore<Uow = Self::Uow>;
+    type RelationshipStore: RelationshipStore<Uow = Self::Uow>;
+
+    async fn setup() -> Self;
+
+    fn unit_of_work_factory(&self) -> &Self::UnitOfWorkFactory;
+
+    fn event_writer(&self) -> &Self::EventWriter;
+
+    fn event_reader(&self) -> &Self::EventReader;
+
+    fn event_feed_reader(&self) -> &Self::EventFeedReader;
+
+    fn event_outbox_fetcher(&self) -> &Self::EventOutboxFetcher;
+
+    fn event_outbox_writer(&self) -> &Self::EventOutboxWriter;
+
+    fn idempotency_service(&self) -> &Self::IdempotencyService;
+
+    fn unique_key_reservation_store(&self) -> &Self::UniqueKeyReservationStore;
+
+    fn relationship_store(&self) -> &Self::RelationshipStore;
+}
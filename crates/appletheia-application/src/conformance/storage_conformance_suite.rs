@@ -0,0 +1,882 @@
+use std::collections::HashSet;
+use std::marker::PhantomData;
+use std::num::NonZeroU32;
+use std::ops::Bound;
+
+use appletheia_domain::{
+    Aggregate, AggregateType, AggregateVersion, AggregateVersionRange, Event, EventId,
+    UniqueEntries, UniqueKey, UniqueValue, UniqueValuePart, UniqueValues,
+};
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::authorization::{
+    AggregateRef, RelationName, RelationRef, RelationRefOwned, Relationship, RelationshipChange,
+    RelationshipStore, RelationshipSubject,
+};
+use crate::command::{
+    CommandFailureReport, CommandHash, CommandName, IdempotencyBeginResult, IdempotencyOutput,
+    IdempotencyService, IdempotencyServiceError, IdempotencyState,
+};
+use crate::event::{
+    EventEnvelope, EventFeedBatchSize, EventFeedReader, EventReader, EventSelector, EventSequence,
+    EventWriter, EventWriterError,
+};
+use crate::messaging::{PublishDispatchError, Subscription};
+use crate::outbox::event::EventOutbox;
+use crate::outbox::{
+    Outbox, OutboxBatchSize, OutboxFetcher, OutboxLeaseDuration, OutboxLifecycle,
+    OutboxMaxAttempts, OutboxRelayInstance, OutboxRelayInstanceId, OutboxRelayProcessId,
+    OutboxRetryDelay, OutboxRetryOptions, OutboxRetrySchedule, OutboxWriter,
+};
+use crate::repository::{UniqueKeyReservationStore, UniqueKeyReservationStoreError};
+use crate::request_context::{CorrelationId, MessageId, Principal, RequestContext};
+use crate::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+
+use super::{
+    ConformanceAggregate, ConformanceAggregateId, ConformanceEventPayload,
+    StorageConformanceHarness,
+};
+
+const MEMBER: RelationRef =
+    RelationRef::new(ConformanceAggregate::TYPE, RelationName::new("member"));
+const EMAIL: UniqueKey = UniqueKey::new("email");
+
+/// Backend-agnostic checks that every storage adapter is expected to pass.
+///
+/// Each check builds a fresh harness through `StorageConformanceHarness::setup` and panics with
+/// a descriptive message when the backend deviates from the behaviour of the reference adapters.
+pub struct StorageConformanceSuite<H> {
+    _harness: PhantomData<H>,
+}
+
+impl<H: StorageConformanceHarness> StorageConformanceSuite<H> {
+    /// Runs every check in sequence.
+    pub async fn run_all() {
+        Self::event_writer_rejects_version_conflicts().await;
+        Self::event_feed_reader_reads_in_sequence_order().await;
+        Self::event_feed_reader_filters_by_subscription().await;
+        Self::outbox_fetcher_orders_pending_entries_per_aggregate().await;
+        Self::outbox_ack_publishes_leased_entries().await;
+        Self::outbox_nack_schedules_retries_and_dead_letters().await;
+        Self::outbox_redrive_restores_dead_lettered_entries().await;
+        Self::idempotency_service_tracks_states().await;
+        Self::unique_key_reservation_store_rejects_conflicts().await;
+        Self::relationship_store_applies_upserts_and_deletes().await;
+    }
+
+    /// Writing an event at an already persisted aggregate version fails and leaves the stream
+    /// untouched.
+    pub async fn event_writer_rejects_version_conflicts() {
+        let harness = H::setup().await;
+        let aggregate_id = ConformanceAggregateId::new();
+        let first = Self::created(aggregate_id);
+        let second = Self::renamed(aggregate_id, first.aggregate_version(), "renamed");
+        Self::write_events(&harness, &[first.clone(), second.clone()]).await;
+
+        let conflicting = Self::renamed(aggregate_id, first.aggregate_version(), "conflicting");
+        let mut uow = Self::begin(&harness).await;
+        let result = harness
+            .event_writer()
+            .write_events_and_outbox(&mut uow, &Self::request_context(), &[conflicting])
+            .await;
+        assert!(
+            matches!(result, Err(EventWriterError::Persistence(_))),
+            "writing an existing aggregate version should fail with a persistence error, got {result:?}",
+        );
+        uow.rollback().await.expect("rollback should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let events = harness
+            .event_reader()
+            .read_events(
+                &mut uow,
+                aggregate_id,
+                AggregateVersionRange::new(Bound::Unbounded, Bound::Unbounded),
+            )
+            .await
+            .expect("events should be readable");
+        uow.commit().await.expect("commit should succeed");
+
+        let read = events
+            .iter()
+            .map(|event| {
+                (
+                    event.id(),
+                    event.aggregate_version(),
+                    event.payload().clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let written = [first, second]
+            .iter()
+            .map(|event| {
+                (
+                    event.id(),
+                    event.aggregate_version(),
+                    event.payload().clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(read, written, "the conflicting write should not be visible");
+    }
+
+    /// The feed returns events in strictly increasing sequence order, honours the cursor and the
+    /// batch size.
+    pub async fn event_feed_reader_reads_in_sequence_order() {
+        let harness = H::setup().await;
+        let first_id = ConformanceAggregateId::new();
+        let second_id = ConformanceAggregateId::new();
+        let first_created = Self::created(first_id);
+        let first_renamed = Self::renamed(first_id, first_created.aggregate_version(), "a");
+        let second_created = Self::created(second_id);
+        let first_renamed_again = Self::renamed(first_id, first_renamed.aggregate_version(), "b");
+        Self::write_events(&harness, &[first_created.clone(), first_renamed.clone()]).await;
+        Self::write_events(&harness, std::slice::from_ref(&second_created)).await;
+        Self::write_events(&harness, std::slice::from_ref(&first_renamed_again)).await;
+
+        let envelopes = Self::read_feed(&harness, None, 100, Subscription::All).await;
+        assert_eq!(
+            Self::event_ids(&envelopes),
+            vec![
+                first_created.id(),
+                first_renamed.id(),
+                second_created.id(),
+                first_renamed_again.id(),
+            ],
+            "the feed should return events in write order",
+        );
+        assert!(
+            envelopes
+                .windows(2)
+                .all(|pair| pair[0].event_sequence < pair[1].event_sequence),
+            "event sequences should be strictly increasing",
+        );
+
+        let after = Some(envelopes[1].event_sequence);
+        let tail = Self::read_feed(&harness, after, 100, Subscription::All).await;
+        assert_eq!(
+            Self::event_ids(&tail),
+            vec![second_created.id(), first_renamed_again.id()],
+            "the feed should only return events after the cursor",
+        );
+
+        let limited = Self::read_feed(&harness, None, 2, Subscription::All).await;
+        assert_eq!(
+            Self::event_ids(&limited),
+            vec![first_created.id(), first_renamed.id()],
+            "the feed should honour the batch size",
+        );
+    }
+
+    /// The feed only returns events matched by the subscription.
+    pub async fn event_feed_reader_filters_by_subscription() {
+        let harness = H::setup().await;
+        let first_id = ConformanceAggregateId::new();
+        let second_id = ConformanceAggregateId::new();
+        let first_created = Self::created(first_id);
+        let first_renamed = Self::renamed(first_id, first_created.aggregate_version(), "a");
+        let second_created = Self::created(second_id);
+        Self::write_events(&harness, &[first_created.clone(), first_renamed.clone()]).await;
+        Self::write_events(&harness, std::slice::from_ref(&second_created)).await;
+
+        let renamed =
+            EventSelector::new(ConformanceAggregate::TYPE, ConformanceEventPayload::RENAMED);
+        let envelopes = Self::read_feed(&harness, None, 100, Subscription::One(&renamed)).await;
+        assert_eq!(
+            Self::event_ids(&envelopes),
+            vec![first_renamed.id()],
+            "a single selector should only match its event name",
+        );
+
+        let selectors = [
+            EventSelector::new(ConformanceAggregate::TYPE, ConformanceEventPayload::CREATED),
+            EventSelector::new(
+                AggregateType::new("unrelated_aggregate"),
+                ConformanceEventPayload::RENAMED,
+            ),
+        ];
+        let envelopes = Self::read_feed(&harness, None, 100, Subscription::AnyOf(&selectors)).await;
+        assert_eq!(
+            Self::event_ids(&envelopes),
+            vec![first_created.id(), second_created.id()],
+            "any-of selectors should match on both aggregate type and event name",
+        );
+
+        let unrelated = EventSelector::new(
+            AggregateType::new("unrelated_aggregate"),
+            ConformanceEventPayload::CREATED,
+        );
+        let envelopes = Self::read_feed(&harness, None, 100, Subscription::One(&unrelated)).await;
+        assert!(
+            envelopes.is_empty(),
+            "a selector for another aggregate type should not match",
+        );
+    }
+
+    /// Only the oldest unpublished outbox entry of an aggregate is fetched.
+    pub async fn outbox_fetcher_orders_pending_entries_per_aggregate() {
+        let harness = H::setup().await;
+        let aggregate_id = ConformanceAggregateId::new();
+        let created = Self::created(aggregate_id);
+        let renamed = Self::renamed(aggregate_id, created.aggregate_version(), "renamed");
+        Self::write_events(&harness, &[created.clone(), renamed.clone()]).await;
+
+        let mut uow = Self::begin(&harness).await;
+        let mut pending = Self::fetch_pending(&harness, &mut uow).await;
+        assert_eq!(
+            Self::outbox_event_ids(&pending),
+            vec![created.id()],
+            "later versions should wait for earlier versions of the same aggregate",
+        );
+        pending[0].ack().expect("ack should succeed");
+        Self::write_outbox(&harness, &mut uow, &pending).await;
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let pending = Self::fetch_pending(&harness, &mut uow).await;
+        uow.commit().await.expect("commit should succeed");
+        assert_eq!(
+            Self::outbox_event_ids(&pending),
+            vec![renamed.id()],
+            "the next version should be fetched once the previous one is published",
+        );
+    }
+
+    /// Leased entries are skipped until the lease expires and acknowledged entries are never
+    /// fetched again.
+    pub async fn outbox_ack_publishes_leased_entries() {
+        let harness = H::setup().await;
+        let created = Self::created(ConformanceAggregateId::new());
+        Self::write_events(&harness, std::slice::from_ref(&created)).await;
+
+        let mut uow = Self::begin(&harness).await;
+        let mut pending = Self::fetch_pending(&harness, &mut uow).await;
+        assert_eq!(Self::outbox_event_ids(&pending), vec![created.id()]);
+        pending[0]
+            .acquire_lease(&Self::relay_instance(), Self::lease_duration())
+            .expect("pending entries should be leasable");
+        Self::write_outbox(&harness, &mut uow, &pending).await;
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let leased = Self::fetch_pending(&harness, &mut uow).await;
+        assert!(
+            leased.is_empty(),
+            "entries with an active lease should not be fetched",
+        );
+
+        pending[0].ack().expect("ack should succeed");
+        Self::write_outbox(&harness, &mut uow, &pending).await;
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let published = Self::fetch_pending(&harness, &mut uow).await;
+        let dead_lettered = Self::fetch_dead_lettered(&harness, &mut uow).await;
+        uow.commit().await.expect("commit should succeed");
+        assert!(
+            published.is_empty(),
+            "published entries should not be fetched",
+        );
+        assert!(
+            dead_lettered.is_empty(),
+            "published entries should not be dead-lettered",
+        );
+    }
+
+    /// Transient failures reschedule the entry with an incremented attempt count; permanent
+    /// failures and exhausted attempts move it to the dead letters.
+    pub async fn outbox_nack_schedules_retries_and_dead_letters() {
+        let harness = H::setup().await;
+        let transient = PublishDispatchError::Transient {
+            code: "unavailable".to_owned(),
+            message: "broker unavailable".to_owned(),
+        };
+        let permanent = PublishDispatchError::Permanent {
+            code: "rejected".to_owned(),
+            message: "message rejected".to_owned(),
+        };
+        let immediate_retry = Self::retry_options(Duration::zero(), 10);
+        let delayed_retry = Self::retry_options(Duration::hours(1), 10);
+
+        let first = Self::created(ConformanceAggregateId::new());
+        Self::write_events(&harness, std::slice::from_ref(&first)).await;
+
+        let mut uow = Self::begin(&harness).await;
+        let mut pending = Self::fetch_pending(&harness, &mut uow).await;
+        pending[0]
+            .nack(&transient, &immediate_retry)
+            .expect("nack should succeed");
+        Self::write_outbox(&harness, &mut uow, &pending).await;
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let mut retried = Self::fetch_pending(&harness, &mut uow).await;
+        assert_eq!(Self::outbox_event_ids(&retried), vec![first.id()]);
+        assert_eq!(
+            retried[0].state().attempt_count().value(),
+            1,
+            "a transient nack should increment the attempt count",
+        );
+        assert_eq!(
+            retried[0].last_error(),
+            &Some(transient.clone()),
+            "a nack should record the last error",
+        );
+
+        retried[0]
+            .nack(&transient, &delayed_retry)
+            .expect("nack should succeed");
+        Self::write_outbox(&harness, &mut uow, &retried).await;
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let delayed = Self::fetch_pending(&harness, &mut uow).await;
+        assert!(
+            delayed.is_empty(),
+            "entries should not be fetched before their next attempt is due",
+        );
+        uow.commit().await.expect("commit should succeed");
+
+        let second = Self::created(ConformanceAggregateId::new());
+        Self::write_events(&harness, std::slice::from_ref(&second)).await;
+
+        let mut uow = Self::begin(&harness).await;
+        let mut pending = Self::fetch_pending(&harness, &mut uow).await;
+        assert_eq!(Self::outbox_event_ids(&pending), vec![second.id()]);
+        pending[0]
+            .nack(&permanent, &immediate_retry)
+            .expect("nack should succeed");
+        Self::write_outbox(&harness, &mut uow, &pending).await;
+        uow.commit().await.expect("commit should succeed");
+
+        let third = Self::created(ConformanceAggregateId::new());
+        Self::write_events(&harness, std::slice::from_ref(&third)).await;
+
+        let exhausted_retry = Self::retry_options(Duration::zero(), 1);
+        for _ in 0..2 {
+            let mut uow = Self::begin(&harness).await;
+            let mut pending = Self::fetch_pending(&harness, &mut uow).await;
+            assert_eq!(Self::outbox_event_ids(&pending), vec![third.id()]);
+            pending[0]
+                .nack(&transient, &exhausted_retry)
+                .expect("nack should succeed");
+            Self::write_outbox(&harness, &mut uow, &pending).await;
+            uow.commit().await.expect("commit should succeed");
+        }
+
+        let mut uow = Self::begin(&harness).await;
+        let pending = Self::fetch_pending(&harness, &mut uow).await;
+        let dead_lettered = Self::fetch_dead_lettered(&harness, &mut uow).await;
+        uow.commit().await.expect("commit should succeed");
+        assert!(
+            pending.is_empty(),
+            "dead-lettered entries should not be fetched as pending",
+        );
+        assert_eq!(
+            Self::outbox_event_ids(&dead_lettered),
+            vec![second.id(), third.id()],
+            "permanent failures and exhausted attempts should be dead-lettered",
+        );
+        assert!(
+            dead_lettered
+                .iter()
+                .all(|outbox| matches!(outbox.lifecycle(), OutboxLifecycle::DeadLettered { .. })),
+            "dead letters should report a dead-lettered lifecycle",
+        );
+        assert_eq!(dead_lettered[0].last_error(), &Some(permanent));
+        assert_eq!(dead_lettered[1].last_error(), &Some(transient));
+    }
+
+    /// Redriving a dead letter makes it pending again with a reset attempt count.
+    pub async fn outbox_redrive_restores_dead_lettered_entries() {
+        let harness = H::setup().await;
+        let permanent = PublishDispatchError::Permanent {
+            code: "rejected".to_owned(),
+            message: "message rejected".to_owned(),
+        };
+        let created = Self::created(ConformanceAggregateId::new());
+        Self::write_events(&harness, std::slice::from_ref(&created)).await;
+
+        let mut uow = Self::begin(&harness).await;
+        let mut pending = Self::fetch_pending(&harness, &mut uow).await;
+        pending[0]
+            .nack(&permanent, &Self::retry_options(Duration::zero(), 10))
+            .expect("nack should succeed");
+        Self::write_outbox(&harness, &mut uow, &pending).await;
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let mut dead_lettered = Self::fetch_dead_lettered(&harness, &mut uow).await;
+        assert_eq!(Self::outbox_event_ids(&dead_lettered), vec![created.id()]);
+        dead_lettered[0].redrive().expect("redrive should succeed");
+        Self::write_outbox(&harness, &mut uow, &dead_lettered).await;
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let pending = Self::fetch_pending(&harness, &mut uow).await;
+        let dead_lettered = Self::fetch_dead_lettered(&harness, &mut uow).await;
+        uow.commit().await.expect("commit should succeed");
+        assert_eq!(
+            Self::outbox_event_ids(&pending),
+            vec![created.id()],
+            "redriven entries should be pending again",
+        );
+        assert_eq!(pending[0].state().attempt_count().value(), 0);
+        assert_eq!(pending[0].last_error(), &None);
+        assert!(
+            dead_lettered.is_empty(),
+            "redriven entries should leave the dead letters",
+        );
+    }
+
+    /// `begin` reports new, in-progress and completed executions and rejects mismatching
+    /// commands or repeated completions.
+    pub async fn idempotency_service_tracks_states() {
+        let harness = H::setup().await;
+        let service = harness.idempotency_service();
+        let command_name = CommandName::new("conformance_command");
+        let command_hash = Self::command_hash('a');
+        let succeeded_id = MessageId::new();
+
+        let mut uow = Self::begin(&harness).await;
+        let begun = service
+            .begin(&mut uow, succeeded_id, command_name, &command_hash)
+            .await
+            .expect("begin should succeed");
+        assert_eq!(begun, IdempotencyBeginResult::New);
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let begun = service
+            .begin(&mut uow, succeeded_id, command_name, &command_hash)
+            .await
+            .expect("begin should succeed");
+        assert_eq!(
+            begun,
+            IdempotencyBeginResult::InProgress,
+            "an uncompleted execution should be reported as in progress",
+        );
+        let output = IdempotencyOutput::new(serde_json::json!({ "value": 1 }));
+        service
+            .complete_success(&mut uow, succeeded_id, output.clone())
+            .await
+            .expect("complete_success should succeed");
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let begun = service
+            .begin(&mut uow, succeeded_id, command_name, &command_hash)
+            .await
+            .expect("begin should succeed");
+        assert_eq!(
+            begun,
+            IdempotencyBeginResult::Existing {
+                state: IdempotencyState::Succeeded { output },
+            },
+        );
+        let repeated = service
+            .complete_success(
+                &mut uow,
+                succeeded_id,
+                IdempotencyOutput::new(serde_json::Value::Null),
+            )
+            .await;
+        assert!(
+            matches!(
+                repeated,
+                Err(IdempotencyServiceError::InvalidStateTransition)
+            ),
+            "completing twice should be rejected, got {repeated:?}",
+        );
+        uow.rollback().await.expect("rollback should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let mismatched = service
+            .begin(
+                &mut uow,
+                succeeded_id,
+                command_name,
+                &Self::command_hash('b'),
+            )
+            .await;
+        assert!(
+            matches!(mismatched, Err(IdempotencyServiceError::Conflict { message_id }) if message_id == succeeded_id),
+            "reusing a message id for another command should conflict, got {mismatched:?}",
+        );
+        uow.rollback().await.expect("rollback should succeed");
+
+        let failed_id = MessageId::new();
+        let report = CommandFailureReport {
+            message: "command failed".to_owned(),
+            chain: vec!["command failed".to_owned()],
+        };
+        let mut uow = Self::begin(&harness).await;
+        service
+            .begin(&mut uow, failed_id, command_name, &command_hash)
+            .await
+            .expect("begin should succeed");
+        service
+            .complete_failure(&mut uow, failed_id, report.clone())
+            .await
+            .expect("complete_failure should succeed");
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let begun = service
+            .begin(&mut uow, failed_id, command_name, &command_hash)
+            .await
+            .expect("begin should succeed");
+        uow.commit().await.expect("commit should succeed");
+        assert_eq!(
+            begun,
+            IdempotencyBeginResult::Existing {
+                state: IdempotencyState::Failed { error: report },
+            },
+        );
+    }
+
+    /// A unique value can only be reserved by one aggregate at a time and is released when its
+    /// owner replaces its reservations.
+    pub async fn unique_key_reservation_store_rejects_conflicts() {
+        let harness = H::setup().await;
+        let store = harness.unique_key_reservation_store();
+        let first_owner = ConformanceAggregateId::new();
+        let second_owner = ConformanceAggregateId::new();
+        let alice = Self::unique_entries(&["alice@example.com"]);
+        let bob = Self::unique_entries(&["bob@example.com"]);
+
+        let mut uow = Self::begin(&harness).await;
+        store
+            .replace(&mut uow, ConformanceAggregate::TYPE, first_owner, &alice)
+            .await
+            .expect("reserving a free value should succeed");
+        store
+            .replace(&mut uow, ConformanceAggregate::TYPE, first_owner, &alice)
+            .await
+            .expect("the owner should be able to reserve its own value again");
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let conflict = store
+            .replace(&mut uow, ConformanceAggregate::TYPE, second_owner, &alice)
+            .await;
+        assert!(
+            matches!(
+                &conflict,
+                Err(UniqueKeyReservationStoreError::Conflict { namespace, .. }) if *namespace == EMAIL
+            ),
+            "reserving a value owned by another aggregate should conflict, got {conflict:?}",
+        );
+        uow.rollback().await.expect("rollback should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        store
+            .replace(&mut uow, ConformanceAggregate::TYPE, first_owner, &bob)
+            .await
+            .expect("replacing reservations should succeed");
+        store
+            .replace(&mut uow, ConformanceAggregate::TYPE, second_owner, &alice)
+            .await
+            .expect("a released value should be reservable by another aggregate");
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        store
+            .replace(
+                &mut uow,
+                ConformanceAggregate::TYPE,
+                second_owner,
+                &UniqueEntries::new(),
+            )
+            .await
+            .expect("clearing reservations should succeed");
+        store
+            .replace(&mut uow, ConformanceAggregate::TYPE, first_owner, &alice)
+            .await
+            .expect("a cleared value should be reservable again");
+        uow.commit().await.expect("commit should succeed");
+    }
+
+    /// Upserts and deletes are idempotent, visible to reads, and only advance the revision when
+    /// they change the stored relationships.
+    pub async fn relationship_store_applies_upserts_and_deletes() {
+        let harness = H::setup().await;
+        let store = harness.relationship_store();
+        let aggregate_id = ConformanceAggregateId::new();
+        let aggregate = AggregateRef::from_id::<ConformanceAggregate>(aggregate_id);
+        let other = AggregateRef::from_id::<ConformanceAggregate>(ConformanceAggregateId::new());
+        let relation = RelationRefOwned::from(MEMBER);
+        let first_subject =
+            RelationshipSubject::aggregate::<ConformanceAggregate>(ConformanceAggregateId::new());
+        let second_subject = RelationshipSubject::wildcard::<ConformanceAggregate>();
+        let first =
+            Relationship::new::<ConformanceAggregate>(aggregate_id, MEMBER, first_subject.clone());
+        let second =
+            Relationship::new::<ConformanceAggregate>(aggregate_id, MEMBER, second_subject.clone());
+
+        let mut uow = Self::begin(&harness).await;
+        let initial = store
+            .read_revision(&mut uow)
+            .await
+            .expect("revision should be readable");
+        store
+            .apply_changes(
+                &mut uow,
+                &[
+                    RelationshipChange::Upsert(first.clone()),
+                    RelationshipChange::Upsert(second.clone()),
+                ],
+            )
+            .await
+            .expect("upserts should succeed");
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let inserted = store
+            .read_revision(&mut uow)
+            .await
+            .expect("revision should be readable");
+        assert!(inserted > initial, "inserting should advance the revision");
+        let subjects = store
+            .read_subjects_by_aggregate(&mut uow, &aggregate, &relation, None)
+            .await
+            .expect("subjects should be readable");
+        assert_eq!(
+            subjects.into_iter().collect::<HashSet<_>>(),
+            HashSet::from([first_subject.clone(), second_subject.clone()]),
+        );
+        let aggregates = store
+            .read_aggregates_by_subject(&mut uow, &first_subject, &relation)
+            .await
+            .expect("aggregates should be readable");
+        assert_eq!(aggregates, vec![aggregate.clone()]);
+
+        store
+            .apply_changes(&mut uow, &[RelationshipChange::Upsert(first.clone())])
+            .await
+            .expect("repeated upserts should succeed");
+        let repeated = store
+            .read_revision(&mut uow)
+            .await
+            .expect("revision should be readable");
+        assert_eq!(
+            repeated, inserted,
+            "upserting an existing relationship should not advance the revision",
+        );
+
+        store
+            .apply_changes(&mut uow, &[RelationshipChange::Delete(first.clone())])
+            .await
+            .expect("deletes should succeed");
+        let deleted = store
+            .read_revision(&mut uow)
+            .await
+            .expect("revision should be readable");
+        assert!(deleted > inserted, "deleting should advance the revision");
+
+        store
+            .apply_changes(&mut uow, &[RelationshipChange::Delete(first)])
+            .await
+            .expect("repeated deletes should succeed");
+        let repeated = store
+            .read_revision(&mut uow)
+            .await
+            .expect("revision should be readable");
+        assert_eq!(
+            repeated, deleted,
+            "deleting a missing relationship should not advance the revision",
+        );
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = Self::begin(&harness).await;
+        let subjects_by_aggregate = store
+            .read_subjects_by_aggregates(
+                &mut uow,
+                &[aggregate.clone(), other.clone()],
+                &relation,
+                None,
+            )
+            .await
+            .expect("subjects should be readable");
+        let aggregates = store
+            .read_aggregates_by_subject(&mut uow, &first_subject, &relation)
+            .await
+            .expect("aggregates should be readable");
+        uow.commit().await.expect("commit should succeed");
+        assert_eq!(
+            subjects_by_aggregate.get(&aggregate),
+            Some(&vec![second_subject])
+        );
+        assert_eq!(
+            subjects_by_aggregate.get(&other),
+            Some(&Vec::new()),
+            "aggregates without relationships should map to no subjects",
+        );
+        assert!(
+            aggregates.is_empty(),
+            "deleted relationships should not be readable",
+        );
+    }
+
+    fn request_context() -> RequestContext {
+        RequestContext::new(
+            CorrelationId::from(Uuid::now_v7()),
+            MessageId::new(),
+            Principal::System,
+        )
+        .expect("system request context should be valid")
+    }
+
+    fn created(
+        aggregate_id: ConformanceAggregateId,
+    ) -> Event<ConformanceAggregateId, ConformanceEventPayload> {
+        Event::new(
+            aggregate_id,
+            AggregateVersion::new().next(),
+            ConformanceEventPayload::Created {
+                id: aggregate_id,
+                name: "created".to_owned(),
+            },
+        )
+    }
+
+    fn renamed(
+        aggregate_id: ConformanceAggregateId,
+        previous: AggregateVersion,
+        name: &str,
+    ) -> Event<ConformanceAggregateId, ConformanceEventPayload> {
+        Event::new(
+            aggregate_id,
+            previous.next(),
+            ConformanceEventPayload::Renamed {
+                name: name.to_owned(),
+            },
+        )
+    }
+
+    fn event_ids(envelopes: &[EventEnvelope]) -> Vec<EventId> {
+        envelopes.iter().map(|envelope| envelope.event_id).collect()
+    }
+
+    fn outbox_event_ids(outboxes: &[EventOutbox]) -> Vec<EventId> {
+        outboxes
+            .iter()
+            .map(|outbox| outbox.event.event_id)
+            .collect()
+    }
+
+    fn relay_instance() -> OutboxRelayInstance {
+        OutboxRelayInstance::new(
+            OutboxRelayInstanceId::new("conformance".to_owned())
+                .expect("relay instance id should be valid"),
+            OutboxRelayProcessId::from(1),
+        )
+    }
+
+    fn lease_duration() -> OutboxLeaseDuration {
+        OutboxLeaseDuration::new(Duration::minutes(5))
+    }
+
+    fn retry_options(backoff: Duration, max_attempts: u32) -> OutboxRetryOptions {
+        OutboxRetryOptions {
+            schedule: OutboxRetrySchedule::Fixed(OutboxRetryDelay::new(backoff)),
+            max_attempts: OutboxMaxAttempts::new(
+                NonZeroU32::new(max_attempts).expect("max attempts should be non-zero"),
+            ),
+        }
+    }
+
+    fn command_hash(digit: char) -> CommandHash {
+        CommandHash::new(digit.to_string().repeat(CommandHash::LENGTH))
+            .expect("command hash should be valid")
+    }
+
+    fn unique_entries(values: &[&str]) -> UniqueEntries {
+        let values = values
+            .iter()
+            .map(|value| {
+                UniqueValue::new(vec![
+                    UniqueValuePart::try_from(*value).expect("unique value part should be valid"),
+                ])
+                .expect("unique value should be valid")
+            })
+            .collect();
+        let mut entries = UniqueEntries::new();
+        entries.insert(
+            EMAIL,
+            UniqueValues::new(values).expect("unique values should be valid"),
+        );
+        entries
+    }
+
+    async fn begin(harness: &H) -> H::Uow {
+        harness
+            .unit_of_work_factory()
+            .begin()
+            .await
+            .expect("unit of work should begin")
+    }
+
+    async fn write_events(
+        harness: &H,
+        events: &[Event<ConformanceAggregateId, ConformanceEventPayload>],
+    ) {
+        let mut uow = Self::begin(harness).await;
+        harness
+            .event_writer()
+            .write_events_and_outbox(&mut uow, &Self::request_context(), events)
+            .await
+            .expect("events should be written");
+        uow.commit().await.expect("commit should succeed");
+    }
+
+    async fn read_feed(
+        harness: &H,
+        after: Option<EventSequence>,
+        limit: u32,
+        subscription: Subscription<'_, EventSelector>,
+    ) -> Vec<EventEnvelope> {
+        let mut uow = Self::begin(harness).await;
+        let envelopes = harness
+            .event_feed_reader()
+            .read_after(
+                &mut uow,
+                after,
+                EventFeedBatchSize::new(NonZeroU32::new(limit).expect("limit should be non-zero")),
+                subscription,
+            )
+            .await
+            .expect("event feed should be readable");
+        uow.commit().await.expect("commit should succeed");
+        envelopes
+    }
+
+    async fn fetch_pending(harness: &H, uow: &mut H::Uow) -> Vec<EventOutbox> {
+        harness
+            .event_outbox_fetcher()
+            .fetch_pending(uow, Self::outbox_batch_size())
+            .await
+            .expect("pending outbox entries should be fetched")
+    }
+
+    async fn fetch_dead_lettered(harness: &H, uow: &mut H::Uow) -> Vec<EventOutbox> {
+        harness
+            .event_outbox_fetcher()
+            .fetch_dead_lettered(uow, Self::outbox_batch_size())
+            .await
+            .expect("dead-lettered outbox entries should be fetched")
+    }
+
+    async fn write_outbox(harness: &H, uow: &mut H::Uow, outboxes: &[EventOutbox]) {
+        harness
+            .event_outbox_writer()
+            .write_outbox(uow, outboxes)
+            .await
+            .expect("outbox entries should be written");
+    }
+
+    fn outbox_batch_size() -> OutboxBatchSize {
+        OutboxBatchSize::new(NonZeroU32::new(100).expect("batch size should be non-zero"))
+    }
+}
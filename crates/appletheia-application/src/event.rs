@@ -16,6 +16,10 @@ pub mod event_reader_error;
 pub mod event_selector;
 pub mod event_sequence;
 pub mod event_sequence_error;
+pub mod event_topic_matcher;
+pub mod event_topic_router;
+pub mod event_topic_router_error;
+pub mod event_topic_rule;
 pub mod event_writer;
 pub mod event_writer_error;
 pub mod serialized_event_payload;
@@ -40,6 +44,10 @@ pub use event_reader_error::EventReaderError;
 pub use event_selector::EventSelector;
 pub use event_sequence::EventSequence;
 pub use event_sequence_error::EventSequenceError;
+pub use event_topic_matcher::EventTopicMatcher;
+pub use event_topic_router::EventTopicRouter;
+pub use event_topic_router_error::EventTopicRouterError;
+pub use event_topic_rule::EventTopicRule;
 pub use event_writer::EventWriter;
 pub use event_writer_error::EventWriterError;
 pub use serialized_event_payload::SerializedEventPayload;
@@ -7,6 +7,8 @@ use appletheia_domain::{
 use crate::event::{
     AggregateIdValue, AggregateTypeOwned, EventNameOwned, EventSequence, SerializedEventPayload,
 };
+use crate::messaging::OrderedMessage;
+use crate::outbox::OrderingKey;
 use crate::request_context::{CausationId, CorrelationId, RequestContext};
 
 use super::EventEnvelopeError;
@@ -63,6 +65,19 @@ impl EventEnvelope {
     }
 }
 
+impl OrderedMessage for EventEnvelope {
+    fn ordering_key(&self) -> Option<OrderingKey> {
+        Some(OrderingKey::from((
+            &self.aggregate_type,
+            &self.aggregate_id,
+        )))
+    }
+
+    fn approximate_size(&self) -> usize {
+        serde_json::to_vec(self).map_or(0, |bytes| bytes.len())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::fmt::{self, Display};
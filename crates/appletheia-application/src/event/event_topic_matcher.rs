@@ -0,0 +1,88 @@
+use std::fmt;
+use std::sync::Arc;
+
+use super::{AggregateTypeOwned, EventEnvelope, EventNameOwned, EventSelector};
+
+/// Decides whether an `EventTopicRule` applies to an event.
+#[derive(Clone)]
+pub enum EventTopicMatcher {
+    /// Matches one event of one aggregate type.
+    Selector(EventSelector),
+
+    /// Matches every event of an aggregate type.
+    AggregateType(AggregateTypeOwned),
+
+    /// Matches every event with this name, whatever its aggregate type.
+    EventName(EventNameOwned),
+
+    /// Matches events accepted by an arbitrary predicate.
+    ///
+    /// Predicates are opaque, so router validation cannot detect rules they shadow.
+    Predicate(Arc<dyn Fn(&EventEnvelope) -> bool + Send + Sync>),
+}
+
+impl EventTopicMatcher {
+    pub fn predicate<F>(predicate: F) -> Self
+    where
+        F: Fn(&EventEnvelope) -> bool + Send + Sync + 'static,
+    {
+        Self::Predicate(Arc::new(predicate))
+    }
+
+    pub fn matches(&self, event: &EventEnvelope) -> bool {
+        match self {
+            Self::Selector(selector) => selector.matches(event),
+            Self::AggregateType(aggregate_type) => event.aggregate_type == *aggregate_type,
+            Self::EventName(event_name) => event.event_name == *event_name,
+            Self::Predicate(predicate) => predicate(event),
+        }
+    }
+
+    /// Returns `true` when every event matched by `other` is also matched by `self`.
+    pub fn covers(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Selector(left), Self::Selector(right)) => left == right,
+            (Self::AggregateType(left), Self::AggregateType(right)) => left == right,
+            (Self::AggregateType(aggregate_type), Self::Selector(selector)) => {
+                aggregate_type.value() == selector.aggregate_type.value()
+            }
+            (Self::EventName(left), Self::EventName(right)) => left == right,
+            (Self::EventName(event_name), Self::Selector(selector)) => {
+                event_name.value() == selector.event_name.value()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<EventSelector> for EventTopicMatcher {
+    fn from(value: EventSelector) -> Self {
+        Self::Selector(value)
+    }
+}
+
+impl From<AggregateTypeOwned> for EventTopicMatcher {
+    fn from(value: AggregateTypeOwned) -> Self {
+        Self::AggregateType(value)
+    }
+}
+
+impl From<EventNameOwned> for EventTopicMatcher {
+    fn from(value: EventNameOwned) -> Self {
+        Self::EventName(value)
+    }
+}
+
+impl fmt::Debug for EventTopicMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Selector(selector) => f.debug_tuple("Selector").field(selector).finish(),
+            Self::AggregateType(aggregate_type) => f
+                .debug_tuple("AggregateType")
+                .field(aggregate_type)
+                .finish(),
+            Self::EventName(event_name) => f.debug_tuple("EventName").field(event_name).finish(),
+            Self::Predicate(_) => f.write_str("Predicate(..)"),
+        }
+    }
+}
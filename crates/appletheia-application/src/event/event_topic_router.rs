@@ -0,0 +1,189 @@
+use crate::messaging::TopicId;
+
+use super::{EventEnvelope, EventTopicRouterError, EventTopicRule};
+
+/// Maps events to the topic they are published on.
+///
+/// Rules are evaluated in order and the first match wins; events matching no rule go to the
+/// default topic. Construction rejects rules that an earlier rule fully shadows, so a
+/// misordered configuration fails at startup instead of silently misrouting events.
+#[derive(Clone, Debug)]
+pub struct EventTopicRouter {
+    rules: Vec<EventTopicRule>,
+    default_topic: TopicId,
+}
+
+impl EventTopicRouter {
+    pub fn new(
+        rules: Vec<EventTopicRule>,
+        default_topic: TopicId,
+    ) -> Result<Self, EventTopicRouterError> {
+        for (index, rule) in rules.iter().enumerate() {
+            if let Some(shadowed_by) = rules[..index]
+                .iter()
+                .position(|earlier| earlier.matcher.covers(&rule.matcher))
+            {
+                return Err(EventTopicRouterError::UnreachableRule { index, shadowed_by });
+            }
+        }
+
+        Ok(Self {
+            rules,
+            default_topic,
+        })
+    }
+
+    /// Routes every event to `topic`.
+    pub fn single(topic: TopicId) -> Self {
+        Self {
+            rules: Vec::new(),
+            default_topic: topic,
+        }
+    }
+
+    pub fn rules(&self) -> &[EventTopicRule] {
+        &self.rules
+    }
+
+    pub fn default_topic(&self) -> &TopicId {
+        &self.default_topic
+    }
+
+    pub fn route(&self, event: &EventEnvelope) -> &TopicId {
+        self.rules
+            .iter()
+            .find(|rule| rule.matcher.matches(event))
+            .map(|rule| &rule.topic)
+            .unwrap_or(&self.default_topic)
+    }
+
+    /// Returns every topic this router can route to, without duplicates, in rule order
+    /// followed by the default topic.
+    pub fn topics(&self) -> Vec<&TopicId> {
+        let mut topics: Vec<&TopicId> = Vec::new();
+        for topic in self
+            .rules
+            .iter()
+            .map(|rule| &rule.topic)
+            .chain([&self.default_topic])
+        {
+            if !topics.contains(&topic) {
+                topics.push(topic);
+            }
+        }
+        topics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use appletheia_domain::{AggregateType, AggregateVersion, EventId, EventName, EventOccurredAt};
+
+    use super::*;
+    use crate::event::{
+        AggregateIdValue, AggregateTypeOwned, EventNameOwned, EventSelector, EventSequence,
+        EventTopicMatcher, SerializedEventPayload,
+    };
+    use crate::request_context::{
+        CausationId, CorrelationId, MessageId, Principal, RequestContext,
+    };
+
+    const ACCOUNT: AggregateType = AggregateType::new("account");
+    const USER: AggregateType = AggregateType::new("user");
+    const OPENED: EventName = EventName::new("opened");
+    const CLOSED: EventName = EventName::new("closed");
+
+    fn topic(value: &str) -> TopicId {
+        TopicId::new(value.to_owned()).expect("topic id should be valid")
+    }
+
+    fn event_envelope(aggregate_type: AggregateType, event_name: EventName) -> EventEnvelope {
+        let correlation_id = CorrelationId::from(Uuid::now_v7());
+        let message_id = MessageId::from(Uuid::now_v7());
+
+        EventEnvelope {
+            event_sequence: EventSequence::try_from(1).expect("event sequence"),
+            event_id: EventId::new(),
+            aggregate_type: AggregateTypeOwned::from(aggregate_type),
+            aggregate_id: AggregateIdValue::from(Uuid::now_v7()),
+            aggregate_version: AggregateVersion::try_from(1).expect("aggregate version"),
+            event_name: EventNameOwned::from(event_name),
+            payload: SerializedEventPayload::try_from(json!({})).expect("payload"),
+            occurred_at: EventOccurredAt::now(),
+            correlation_id,
+            causation_id: CausationId::from(message_id),
+            context: RequestContext::new(correlation_id, message_id, Principal::System)
+                .expect("request context should be valid"),
+        }
+    }
+
+    #[test]
+    fn route_uses_first_matching_rule_and_falls_back_to_default() {
+        let router = EventTopicRouter::new(
+            vec![
+                EventTopicRule::new(EventSelector::new(ACCOUNT, CLOSED), topic("account-closed")),
+                EventTopicRule::new(AggregateTypeOwned::from(ACCOUNT), topic("accounts")),
+                EventTopicRule::new(
+                    EventTopicMatcher::predicate(|event| event.event_name.value() == "opened"),
+                    topic("openings"),
+                ),
+            ],
+            topic("default"),
+        )
+        .expect("router should be valid");
+
+        assert_eq!(
+            router.route(&event_envelope(ACCOUNT, CLOSED)),
+            &topic("account-closed")
+        );
+        assert_eq!(
+            router.route(&event_envelope(ACCOUNT, OPENED)),
+            &topic("accounts")
+        );
+        assert_eq!(
+            router.route(&event_envelope(USER, OPENED)),
+            &topic("openings")
+        );
+        assert_eq!(
+            router.route(&event_envelope(USER, CLOSED)),
+            &topic("default")
+        );
+    }
+
+    #[test]
+    fn new_rejects_rules_shadowed_by_earlier_rules() {
+        let error = EventTopicRouter::new(
+            vec![
+                EventTopicRule::new(AggregateTypeOwned::from(ACCOUNT), topic("accounts")),
+                EventTopicRule::new(EventSelector::new(ACCOUNT, CLOSED), topic("account-closed")),
+            ],
+            topic("default"),
+        )
+        .expect_err("shadowed rule should be rejected");
+
+        assert!(matches!(
+            error,
+            EventTopicRouterError::UnreachableRule {
+                index: 1,
+                shadowed_by: 0
+            }
+        ));
+    }
+
+    #[test]
+    fn topics_lists_each_topic_once() {
+        let router = EventTopicRouter::new(
+            vec![
+                EventTopicRule::new(AggregateTypeOwned::from(ACCOUNT), topic("domain")),
+                EventTopicRule::new(AggregateTypeOwned::from(USER), topic("domain")),
+            ],
+            topic("default"),
+        )
+        .expect("router should be valid");
+
+        assert_eq!(router.topics(), vec![&topic("domain"), &topic("default")]);
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum EventTopicRouterError {
+    #[error("event topic rule {index} can never match because rule {shadowed_by} precedes it")]
+    UnreachableRule { index: usize, shadowed_by: usize },
+}
@@ -0,0 +1,18 @@
+use crate::messaging::TopicId;
+
+use super::EventTopicMatcher;
+
+#[derive(Clone, Debug)]
+pub struct EventTopicRule {
+    pub matcher: EventTopicMatcher,
+    pub topic: TopicId,
+}
+
+impl EventTopicRule {
+    pub fn new(matcher: impl Into<EventTopicMatcher>, topic: TopicId) -> Self {
+        Self {
+            matcher: matcher.into(),
+            topic,
+        }
+    }
+}
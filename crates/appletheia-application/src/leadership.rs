@@ -0,0 +1,21 @@
+pub mod fenced_worker;
+pub mod fencing_token;
+pub mod leader_elector;
+pub mod leader_elector_error;
+pub mod leadership_config;
+pub mod leadership_fence;
+pub mod leadership_key;
+pub mod leadership_key_error;
+pub mod leadership_lease;
+pub mod singleton_worker;
+
+pub use fenced_worker::FencedWorker;
+pub use fencing_token::FencingToken;
+pub use leader_elector::LeaderElector;
+pub use leader_elector_error::LeaderElectorError;
+pub use leadership_config::LeadershipConfig;
+pub use leadership_fence::LeadershipFence;
+pub use leadership_key::LeadershipKey;
+pub use leadership_key_error::LeadershipKeyError;
+pub use leadership_lease::LeadershipLease;
+pub use singleton_worker::SingletonWorker;
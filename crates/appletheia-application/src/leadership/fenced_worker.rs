@@ -0,0 +1,20 @@
+use std::error::Error;
+
+use crate::WorkerStopSignal;
+
+use super::FencingToken;
+
+/// Worker that a `SingletonWorker` runs while this process holds the leadership lease.
+///
+/// `run_fenced` receives the fencing token of the lease it runs under and should pass it to a
+/// `LeadershipFence` in every unit of work it commits, so writes made after the lease changed
+/// hands are rejected. It may be called again, with a newer token, after leadership was lost.
+#[allow(async_fn_in_trait)]
+pub trait FencedWorker {
+    fn stop_signal(&self) -> WorkerStopSignal;
+
+    async fn run_fenced(
+        &mut self,
+        fencing_token: FencingToken,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
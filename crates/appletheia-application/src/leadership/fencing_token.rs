@@ -0,0 +1,28 @@
+/// Number that grows every time a leadership lease changes hands.
+///
+/// Writers guarded by a lease can reject requests carrying a token lower than one they have
+/// already seen, which fences off a former leader that has not noticed it lost the lease.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct FencingToken(i64);
+
+impl FencingToken {
+    pub fn new(value: i64) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> i64 {
+        self.0
+    }
+}
+
+impl From<i64> for FencingToken {
+    fn from(value: i64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<FencingToken> for i64 {
+    fn from(value: FencingToken) -> Self {
+        value.value()
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::Duration;
+
+use crate::outbox::OutboxRelayInstance;
+
+use super::{LeaderElectorError, LeadershipKey, LeadershipLease};
+
+/// Grants at most one holder at a time an expiring lease on a leadership key.
+#[allow(async_fn_in_trait)]
+pub trait LeaderElector: Send + Sync {
+    /// Acquires the lease if nobody holds it or the previous lease expired, issuing a fencing
+    /// token greater than every earlier one for `key`. Returns `None` while it is held.
+    async fn try_acquire(
+        &self,
+        key: &LeadershipKey,
+        holder: &OutboxRelayInstance,
+        lease_duration: Duration,
+    ) -> Result<Option<LeadershipLease>, LeaderElectorError>;
+
+    /// Extends a lease that is still held. Returns `None` once leadership has been lost.
+    async fn renew(
+        &self,
+        lease: &LeadershipLease,
+        lease_duration: Duration,
+    ) -> Result<Option<LeadershipLease>, LeaderElectorError>;
+
+    /// Gives up a held lease so a standby can take over without waiting for it to expire.
+    async fn release(&self, lease: &LeadershipLease) -> Result<(), LeaderElectorError>;
+}
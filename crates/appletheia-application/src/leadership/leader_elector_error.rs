@@ -0,0 +1,17 @@
+use std::error::Error;
+
+use thiserror::Error as ThisError;
+
+use super::{FencingToken, LeadershipKey};
+
+#[derive(Debug, ThisError)]
+pub enum LeaderElectorError {
+    #[error("leadership of {key} is no longer held with fencing token {fencing_token:?}")]
+    Fenced {
+        key: LeadershipKey,
+        fencing_token: FencingToken,
+    },
+
+    #[error("persistence error: {0}")]
+    Persistence(#[source] Box<dyn Error + Send + Sync + 'static>),
+}
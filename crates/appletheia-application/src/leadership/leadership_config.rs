@@ -0,0 +1,30 @@
+use chrono::Duration;
+
+use crate::outbox::OutboxRelayInstance;
+
+use super::LeadershipKey;
+
+/// How a `SingletonWorker` contests and keeps leadership.
+///
+/// `renew_interval` must be comfortably shorter than `lease_duration`, otherwise the lease
+/// can expire between renewals.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeadershipConfig {
+    pub key: LeadershipKey,
+    pub holder: OutboxRelayInstance,
+    pub lease_duration: Duration,
+    pub renew_interval: Duration,
+    pub acquire_interval: Duration,
+}
+
+impl LeadershipConfig {
+    pub fn new(key: LeadershipKey, holder: OutboxRelayInstance) -> Self {
+        Self {
+            key,
+            holder,
+            lease_duration: Duration::seconds(30),
+            renew_interval: Duration::seconds(10),
+            acquire_interval: Duration::seconds(5),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::unit_of_work::UnitOfWork;
+
+use super::{FencingToken, LeaderElectorError, LeadershipKey};
+
+/// Rejects writes made under a leadership lease that has changed hands.
+#[allow(async_fn_in_trait)]
+pub trait LeadershipFence: Send + Sync {
+    type Uow: UnitOfWork;
+
+    /// Fails with `LeaderElectorError::Fenced` unless `fencing_token` still holds the lease on
+    /// `key`. The lease cannot change hands until `uow` ends, so the writes made in it commit
+    /// before any successor's.
+    async fn check(
+        &self,
+        uow: &mut Self::Uow,
+        key: &LeadershipKey,
+        fencing_token: FencingToken,
+    ) -> Result<(), LeaderElectorError>;
+}
@@ -0,0 +1,41 @@
+use std::fmt::{self, Display};
+
+use crate::projection::ProjectorName;
+use crate::saga::SagaName;
+
+use super::LeadershipKeyError;
+
+/// Names the singleton job whose leadership is contested.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LeadershipKey(String);
+
+impl LeadershipKey {
+    pub fn new(value: String) -> Result<Self, LeadershipKeyError> {
+        if value.is_empty() {
+            return Err(LeadershipKeyError::Empty);
+        }
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<ProjectorName> for LeadershipKey {
+    fn from(value: ProjectorName) -> Self {
+        Self(value.value().to_string())
+    }
+}
+
+impl From<SagaName> for LeadershipKey {
+    fn from(value: SagaName) -> Self {
+        Self(value.value().to_string())
+    }
+}
+
+impl Display for LeadershipKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum LeadershipKeyError {
+    #[error("leadership key is empty")]
+    Empty,
+}
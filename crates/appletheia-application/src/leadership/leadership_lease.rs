@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+
+use crate::outbox::OutboxRelayInstance;
+
+use super::{FencingToken, LeadershipKey};
+
+/// Leadership of `key` held by `holder` until `expires_at` unless renewed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeadershipLease {
+    key: LeadershipKey,
+    holder: OutboxRelayInstance,
+    fencing_token: FencingToken,
+    expires_at: DateTime<Utc>,
+}
+
+impl LeadershipLease {
+    pub fn new(
+        key: LeadershipKey,
+        holder: OutboxRelayInstance,
+        fencing_token: FencingToken,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            key,
+            holder,
+            fencing_token,
+            expires_at,
+        }
+    }
+
+    pub fn key(&self) -> &LeadershipKey {
+        &self.key
+    }
+
+    pub fn holder(&self) -> &OutboxRelayInstance {
+        &self.holder
+    }
+
+    pub fn fencing_token(&self) -> FencingToken {
+        self.fencing_token
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub fn with_expires_at(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = expires_at;
+        self
+    }
+}
@@ -0,0 +1,363 @@
+use std::error::Error;
+use std::time::Duration as StdDuration;
+
+use chrono::Utc;
+use tokio::time::{Instant, sleep, sleep_until};
+use tracing::warn;
+
+use crate::{SupervisedWorker, WorkerStopSignal};
+
+use super::{FencedWorker, LeaderElector, LeaderElectorError, LeadershipConfig, LeadershipLease};
+
+/// Runs the wrapped worker only while this process holds the leadership lease.
+///
+/// Standbys retry acquiring the lease every `acquire_interval`. The leader renews it every
+/// `renew_interval`, retrying renewals that fail. When a renewal finds the lease lost, or the
+/// lease expires before a renewal succeeds, the worker's run is cancelled at its next await
+/// point and the process becomes a standby again, so work must be safe to redeliver. The worker
+/// receives the lease's fencing token to reject writes that race such a hand-over. The lease is
+/// released once the worker stops.
+pub struct SingletonWorker<E, W> {
+    config: LeadershipConfig,
+    elector: E,
+    worker: W,
+}
+
+impl<E, W> SingletonWorker<E, W>
+where
+    E: LeaderElector,
+    W: FencedWorker,
+{
+    pub fn new(config: LeadershipConfig, elector: E, worker: W) -> Self {
+        Self {
+            config,
+            elector,
+            worker,
+        }
+    }
+
+    pub fn worker(&self) -> &W {
+        &self.worker
+    }
+
+    async fn acquire(&self) -> Result<LeadershipLease, LeaderElectorError> {
+        let acquire_interval = self
+            .config
+            .acquire_interval
+            .to_std()
+            .unwrap_or(StdDuration::ZERO);
+
+        loop {
+            let lease = self
+                .elector
+                .try_acquire(
+                    &self.config.key,
+                    &self.config.holder,
+                    self.config.lease_duration,
+                )
+                .await?;
+            if let Some(lease) = lease {
+                return Ok(lease);
+            }
+
+            sleep(acquire_interval).await;
+        }
+    }
+
+    /// Renews `lease` until leadership is lost or the lease expires.
+    async fn hold(elector: &E, config: &LeadershipConfig, mut lease: LeadershipLease) {
+        let renew_interval = config.renew_interval.to_std().unwrap_or(StdDuration::ZERO);
+
+        loop {
+            let expires_at = Instant::now()
+                + (lease.expires_at() - Utc::now())
+                    .to_std()
+                    .unwrap_or(StdDuration::ZERO);
+            let renewed = tokio::select! {
+                renewed = async {
+                    sleep(renew_interval).await;
+                    elector.renew(&lease, config.lease_duration).await
+                } => renewed,
+                () = sleep_until(expires_at) => return,
+            };
+
+            match renewed {
+                Ok(Some(renewed)) => lease = renewed,
+                Ok(None) => return,
+                Err(error) => warn!(
+                    error = &error as &dyn Error,
+                    key = %lease.key(),
+                    "failed to renew leadership lease; retrying"
+                ),
+            }
+        }
+    }
+}
+
+impl<E, W> SupervisedWorker for SingletonWorker<E, W>
+where
+    E: LeaderElector,
+    W: FencedWorker,
+{
+    fn stop_signal(&self) -> WorkerStopSignal {
+        self.worker.stop_signal()
+    }
+
+    async fn run_supervised(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let stop_signal = self.worker.stop_signal();
+
+        loop {
+            let lease = tokio::select! {
+                lease = self.acquire() => lease?,
+                () = stop_signal.requested() => return Ok(()),
+            };
+
+            let result = tokio::select! {
+                result = self.worker.run_fenced(lease.fencing_token()) => Some(result),
+                () = Self::hold(&self.elector, &self.config, lease.clone()) => None,
+            };
+
+            if let Some(result) = result {
+                self.elector.release(&lease).await?;
+                return result;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use chrono::{DateTime, Duration, Utc};
+
+    use crate::outbox::{OutboxRelayInstance, OutboxRelayInstanceId, OutboxRelayProcessId};
+
+    use super::super::{FencingToken, LeadershipKey};
+    use super::*;
+
+    #[derive(Default)]
+    struct TestElectorState {
+        holder: Option<OutboxRelayInstance>,
+        expires_at: Option<DateTime<Utc>>,
+        fencing_token: i64,
+        revoked: bool,
+        failing_renewals: usize,
+    }
+
+    #[derive(Clone, Default)]
+    struct TestElector {
+        state: Arc<Mutex<TestElectorState>>,
+    }
+
+    impl LeaderElector for TestElector {
+        async fn try_acquire(
+            &self,
+            key: &LeadershipKey,
+            holder: &OutboxRelayInstance,
+            lease_duration: Duration,
+        ) -> Result<Option<LeadershipLease>, LeaderElectorError> {
+            let mut state = self.state.lock().expect("lock");
+            let expired = state
+                .expires_at
+                .is_some_and(|expires_at| expires_at <= Utc::now());
+            if state.holder.is_some() && !expired {
+                return Ok(None);
+            }
+
+            let expires_at = Utc::now() + lease_duration;
+            state.holder = Some(holder.clone());
+            state.expires_at = Some(expires_at);
+            state.fencing_token += 1;
+            Ok(Some(LeadershipLease::new(
+                key.clone(),
+                holder.clone(),
+                FencingToken::new(state.fencing_token),
+                expires_at,
+            )))
+        }
+
+        async fn renew(
+            &self,
+            lease: &LeadershipLease,
+            lease_duration: Duration,
+        ) -> Result<Option<LeadershipLease>, LeaderElectorError> {
+            let mut state = self.state.lock().expect("lock");
+            if state.failing_renewals > 0 {
+                state.failing_renewals -= 1;
+                return Err(LeaderElectorError::Persistence("unavailable".into()));
+            }
+            if state.revoked {
+                state.revoked = false;
+                state.holder = None;
+                return Ok(None);
+            }
+
+            let expires_at = Utc::now() + lease_duration;
+            state.expires_at = Some(expires_at);
+            Ok(Some(lease.clone().with_expires_at(expires_at)))
+        }
+
+        async fn release(&self, lease: &LeadershipLease) -> Result<(), LeaderElectorError> {
+            let mut state = self.state.lock().expect("lock");
+            if state.holder.as_ref() == Some(lease.holder()) {
+                state.holder = None;
+            }
+            Ok(())
+        }
+    }
+
+    type Runs = Arc<Mutex<Vec<(u32, FencingToken)>>>;
+
+    struct TestWorker {
+        stop_signal: WorkerStopSignal,
+        runs: Runs,
+        process: u32,
+    }
+
+    impl FencedWorker for TestWorker {
+        fn stop_signal(&self) -> WorkerStopSignal {
+            self.stop_signal.clone()
+        }
+
+        async fn run_fenced(
+            &mut self,
+            fencing_token: FencingToken,
+        ) -> Result<(), Box<dyn Error + Send + Sync>> {
+            self.runs
+                .lock()
+                .expect("lock")
+                .push((self.process, fencing_token));
+            self.stop_signal.requested().await;
+            Ok(())
+        }
+    }
+
+    fn singleton(
+        elector: &TestElector,
+        runs: &Runs,
+        process: u32,
+    ) -> SingletonWorker<TestElector, TestWorker> {
+        let holder = OutboxRelayInstance::new(
+            OutboxRelayInstanceId::new("instance".to_owned()).expect("instance id"),
+            OutboxRelayProcessId::new(process),
+        );
+        let mut config = LeadershipConfig::new(
+            LeadershipKey::new("projector".to_owned()).expect("key"),
+            holder,
+        );
+        config.renew_interval = Duration::milliseconds(1);
+        config.acquire_interval = Duration::milliseconds(1);
+
+        SingletonWorker::new(
+            config,
+            elector.clone(),
+            TestWorker {
+                stop_signal: WorkerStopSignal::new(),
+                runs: Arc::clone(runs),
+                process,
+            },
+        )
+    }
+
+    async fn wait_for_runs(runs: &Runs, count: usize) {
+        while runs.lock().expect("lock").len() < count {
+            sleep(StdDuration::from_millis(1)).await;
+        }
+    }
+
+    fn run(process: u32, fencing_token: i64) -> (u32, FencingToken) {
+        (process, FencingToken::new(fencing_token))
+    }
+
+    #[tokio::test]
+    async fn standby_takes_over_when_leader_stops() {
+        let elector = TestElector::default();
+        let runs = Runs::default();
+        let mut leader = singleton(&elector, &runs, 1);
+        let mut standby = singleton(&elector, &runs, 2);
+        let leader_stop = leader.stop_signal();
+        let standby_stop = standby.stop_signal();
+
+        let orchestrating = async {
+            wait_for_runs(&runs, 1).await;
+            sleep(StdDuration::from_millis(10)).await;
+            assert_eq!(*runs.lock().expect("lock"), vec![run(1, 1)]);
+
+            leader_stop.request();
+            wait_for_runs(&runs, 2).await;
+            standby_stop.request();
+        };
+        let (leader_result, standby_result, ()) = tokio::join!(
+            leader.run_supervised(),
+            standby.run_supervised(),
+            orchestrating
+        );
+
+        assert!(leader_result.is_ok());
+        assert!(standby_result.is_ok());
+        assert_eq!(*runs.lock().expect("lock"), vec![run(1, 1), run(2, 2)]);
+        assert_eq!(elector.state.lock().expect("lock").fencing_token, 2);
+    }
+
+    #[tokio::test]
+    async fn lost_leadership_cancels_the_run_and_contests_again() {
+        let elector = TestElector::default();
+        let runs = Runs::default();
+        let mut worker = singleton(&elector, &runs, 1);
+        let stop_signal = worker.stop_signal();
+
+        let orchestrating = async {
+            wait_for_runs(&runs, 1).await;
+            elector.state.lock().expect("lock").revoked = true;
+            wait_for_runs(&runs, 2).await;
+            stop_signal.request();
+        };
+        let (result, ()) = tokio::join!(worker.run_supervised(), orchestrating);
+
+        assert!(result.is_ok());
+        assert_eq!(*runs.lock().expect("lock"), vec![run(1, 1), run(1, 2)]);
+        assert!(elector.state.lock().expect("lock").holder.is_none());
+    }
+
+    #[tokio::test]
+    async fn failed_renewals_are_retried_while_the_lease_is_valid() {
+        let elector = TestElector::default();
+        elector.state.lock().expect("lock").failing_renewals = 3;
+        let runs = Runs::default();
+        let mut worker = singleton(&elector, &runs, 1);
+        let stop_signal = worker.stop_signal();
+
+        let orchestrating = async {
+            wait_for_runs(&runs, 1).await;
+            while elector.state.lock().expect("lock").failing_renewals > 0 {
+                sleep(StdDuration::from_millis(1)).await;
+            }
+            sleep(StdDuration::from_millis(10)).await;
+            stop_signal.request();
+        };
+        let (result, ()) = tokio::join!(worker.run_supervised(), orchestrating);
+
+        assert!(result.is_ok());
+        assert_eq!(*runs.lock().expect("lock"), vec![run(1, 1)]);
+    }
+
+    #[tokio::test]
+    async fn lease_expiring_without_renewal_cancels_the_run() {
+        let elector = TestElector::default();
+        elector.state.lock().expect("lock").failing_renewals = usize::MAX;
+        let runs = Runs::default();
+        let mut worker = singleton(&elector, &runs, 1);
+        worker.config.lease_duration = Duration::milliseconds(20);
+        let stop_signal = worker.stop_signal();
+
+        let orchestrating = async {
+            wait_for_runs(&runs, 2).await;
+            stop_signal.request();
+        };
+        let (result, ()) = tokio::join!(worker.run_supervised(), orchestrating);
+
+        assert!(result.is_ok());
+        assert_eq!(runs.lock().expect("lock")[..2], [run(1, 1), run(1, 2)]);
+    }
+}
@@ -1,28 +1,40 @@
 pub mod authentication;
 pub mod authorization;
 pub mod command;
+#[cfg(feature = "conformance")]
+pub mod conformance;
 pub mod event;
+pub mod leadership;
 pub mod messaging;
 pub mod object_storage;
 pub mod outbox;
 pub mod projection;
 pub mod query;
+pub mod rate_limit;
 pub mod repository;
 pub mod request_context;
+pub mod retention;
 pub mod saga;
 pub mod snapshot;
+pub mod supervision;
+pub mod telemetry;
 pub mod unit_of_work;
 
 pub use authentication::*;
 pub use authorization::*;
 pub use command::*;
 pub use event::*;
+pub use leadership::*;
 pub use messaging::*;
 pub use object_storage::*;
 pub use projection::*;
 pub use query::*;
+pub use rate_limit::*;
 pub use repository::*;
 pub use request_context::*;
+pub use retention::*;
 pub use saga::*;
 pub use snapshot::*;
+pub use supervision::*;
+pub use telemetry::*;
 pub use unit_of_work::*;
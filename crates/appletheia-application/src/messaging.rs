@@ -1,8 +1,12 @@
+pub mod concurrent_delivery_processor;
 pub mod consumer;
 pub mod consumer_error;
 pub mod consumer_group;
 pub mod consumer_group_error;
 pub mod delivery;
+mod delivery_backlog;
+pub mod delivery_flow_control;
+pub mod ordered_message;
 pub mod publish_dispatch_error;
 pub mod publish_result;
 pub mod publisher;
@@ -13,12 +17,16 @@ pub mod subscriber_error;
 pub mod subscription;
 pub mod topic_id;
 pub mod topic_id_error;
+pub mod undecodable_message;
 
+pub use concurrent_delivery_processor::ConcurrentDeliveryProcessor;
 pub use consumer::Consumer;
 pub use consumer_error::ConsumerError;
 pub use consumer_group::ConsumerGroup;
 pub use consumer_group_error::ConsumerGroupError;
 pub use delivery::Delivery;
+pub use delivery_flow_control::DeliveryFlowControl;
+pub use ordered_message::OrderedMessage;
 pub use publish_dispatch_error::PublishDispatchError;
 pub use publish_result::PublishResult;
 pub use publisher::Publisher;
@@ -29,3 +37,4 @@ pub use subscriber_error::SubscriberError;
 pub use subscription::Subscription;
 pub use topic_id::TopicId;
 pub use topic_id_error::TopicIdError;
+pub use undecodable_message::UndecodableMessage;
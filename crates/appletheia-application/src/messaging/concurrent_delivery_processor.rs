@@ -0,0 +1,323 @@
+use std::future::Future;
+
+use futures_util::future::Either;
+use futures_util::stream::{FuturesUnordered, StreamExt};
+
+use super::delivery_backlog::DeliveryBacklog;
+use super::{Consumer, ConsumerError, Delivery, DeliveryFlowControl, OrderedMessage};
+use crate::WorkerStopSignal;
+
+/// Pulls deliveries from a consumer and processes them within a `DeliveryFlowControl`.
+///
+/// Deliveries sharing an ordering key are processed one after another in pull order. The
+/// `process` future settles its delivery. Undecodable messages are skipped.
+///
+/// When stop is requested, a pending pull is abandoned and every delivery already pulled is
+/// processed. When
+/// pulling or processing fails, pulling stops, in-flight deliveries finish, deliveries that have
+/// not started are nacked and the first error is returned.
+#[derive(Clone, Debug, Default)]
+pub struct ConcurrentDeliveryProcessor {
+    flow_control: DeliveryFlowControl,
+}
+
+impl ConcurrentDeliveryProcessor {
+    pub fn new(flow_control: DeliveryFlowControl) -> Self {
+        Self { flow_control }
+    }
+
+    pub fn flow_control(&self) -> &DeliveryFlowControl {
+        &self.flow_control
+    }
+
+    pub async fn run<M, C, F, Fut, E>(
+        &self,
+        consumer: C,
+        stop_signal: &WorkerStopSignal,
+        process: F,
+    ) -> Result<(), E>
+    where
+        M: OrderedMessage,
+        C: Consumer<M>,
+        F: Fn(C::Delivery) -> Fut,
+        Fut: Future<Output = Result<(), E>>,
+        E: From<ConsumerError>,
+    {
+        let max_in_flight = self.flow_control.max_in_flight().get();
+        let measures_bytes = self.flow_control.max_outstanding_bytes().is_some();
+
+        let mut backlog = DeliveryBacklog::new();
+        let mut in_flight = FuturesUnordered::new();
+        let mut outstanding_messages = 0usize;
+        let mut outstanding_bytes = 0usize;
+        let mut idle_consumer = Some(consumer);
+        let mut pull = None;
+        let mut failure: Option<E> = None;
+
+        loop {
+            while failure.is_none() && in_flight.len() < max_in_flight {
+                let Some((ordering_key, delivery, size)) = backlog.pop_ready() else {
+                    break;
+                };
+                let processing = process(delivery);
+                in_flight.push(async move { (ordering_key, size, processing.await) });
+            }
+
+            if failure.is_some() || stop_signal.is_requested() {
+                pull = None;
+            } else if pull.is_none()
+                && self
+                    .flow_control
+                    .has_capacity(outstanding_messages, outstanding_bytes)
+                && let Some(consumer) = idle_consumer.take()
+            {
+                pull = Some(Box::pin(Self::pull(consumer)));
+            }
+
+            let event = match pull.as_mut() {
+                Some(pulling) => tokio::select! {
+                    pulled = pulling => Some(Either::Left(pulled)),
+                    Some(completed) = in_flight.next(), if !in_flight.is_empty() => {
+                        Some(Either::Right(completed))
+                    }
+                    () = stop_signal.requested() => None,
+                },
+                None => match in_flight.next().await {
+                    Some(completed) => Some(Either::Right(completed)),
+                    None => break,
+                },
+            };
+            let Some(event) = event else {
+                pull = None;
+                continue;
+            };
+
+            match event {
+                Either::Left((consumer, pulled)) => {
+                    pull = None;
+                    idle_consumer = Some(consumer);
+
+                    match pulled {
+                        Ok(delivery) => {
+                            let message = delivery.message();
+                            let ordering_key = message.ordering_key();
+                            let size = if measures_bytes {
+                                message.approximate_size()
+                            } else {
+                                0
+                            };
+                            outstanding_messages += 1;
+                            outstanding_bytes = outstanding_bytes.saturating_add(size);
+                            backlog.push(ordering_key, delivery, size);
+                        }
+                        Err(ConsumerError::Undecodable(_)) => {}
+                        Err(error) => {
+                            failure.get_or_insert(error.into());
+                        }
+                    }
+                }
+                Either::Right((ordering_key, size, result)) => {
+                    outstanding_messages -= 1;
+                    outstanding_bytes = outstanding_bytes.saturating_sub(size);
+                    backlog.complete(ordering_key);
+
+                    if let Err(error) = result {
+                        failure.get_or_insert(error);
+                    }
+                }
+            }
+        }
+
+        for mut delivery in backlog.drain() {
+            if let Err(error) = delivery.nack().await {
+                failure.get_or_insert(error.into());
+            }
+        }
+
+        match failure {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    async fn pull<M, C>(mut consumer: C) -> (C, Result<C::Delivery, ConsumerError>)
+    where
+        C: Consumer<M>,
+    {
+        let pulled = consumer.next().await;
+        (consumer, pulled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::num::NonZeroUsize;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use crate::outbox::OrderingKey;
+
+    use super::*;
+
+    #[derive(Clone, Debug)]
+    struct TestMessage {
+        key: &'static str,
+        value: u32,
+    }
+
+    impl OrderedMessage for TestMessage {
+        fn ordering_key(&self) -> Option<OrderingKey> {
+            Some(OrderingKey::new(self.key.to_owned()).expect("ordering key should be valid"))
+        }
+
+        fn approximate_size(&self) -> usize {
+            1
+        }
+    }
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    struct TestDelivery {
+        message: TestMessage,
+        log: Log,
+    }
+
+    impl Delivery<TestMessage> for TestDelivery {
+        fn message(&self) -> &TestMessage {
+            &self.message
+        }
+
+        async fn ack(&mut self) -> Result<(), ConsumerError> {
+            record(&self.log, format!("ack {}", self.message.value));
+            Ok(())
+        }
+
+        async fn nack(&mut self) -> Result<(), ConsumerError> {
+            record(&self.log, format!("nack {}", self.message.value));
+            Ok(())
+        }
+    }
+
+    struct TestConsumer {
+        messages: Arc<Mutex<VecDeque<TestMessage>>>,
+        log: Log,
+        stop_signal: WorkerStopSignal,
+    }
+
+    impl Consumer<TestMessage> for TestConsumer {
+        type Delivery = TestDelivery;
+
+        async fn next(&mut self) -> Result<Self::Delivery, ConsumerError> {
+            let message = self.messages.lock().expect("lock").pop_front();
+            match message {
+                Some(message) => Ok(TestDelivery {
+                    message,
+                    log: Arc::clone(&self.log),
+                }),
+                None => {
+                    self.stop_signal.request();
+                    std::future::pending().await
+                }
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct TestError(u32);
+
+    impl From<ConsumerError> for TestError {
+        fn from(_: ConsumerError) -> Self {
+            Self(0)
+        }
+    }
+
+    fn record(log: &Log, entry: String) {
+        log.lock().expect("lock").push(entry);
+    }
+
+    fn position(log: &[String], entry: &str) -> usize {
+        log.iter()
+            .position(|logged| logged == entry)
+            .unwrap_or_else(|| panic!("{entry} should be logged"))
+    }
+
+    async fn run(
+        flow_control: DeliveryFlowControl,
+        messages: Vec<TestMessage>,
+        failing_value: Option<u32>,
+    ) -> (Result<(), TestError>, Vec<String>) {
+        let log = Log::default();
+        let stop_signal = WorkerStopSignal::new();
+        let consumer = TestConsumer {
+            messages: Arc::new(Mutex::new(VecDeque::from(messages))),
+            log: Arc::clone(&log),
+            stop_signal: stop_signal.clone(),
+        };
+
+        let result = ConcurrentDeliveryProcessor::new(flow_control)
+            .run(consumer, &stop_signal, |mut delivery: TestDelivery| {
+                let log = Arc::clone(&log);
+                async move {
+                    let value = delivery.message().value;
+                    record(&log, format!("start {value}"));
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    record(&log, format!("end {value}"));
+
+                    if failing_value == Some(value) {
+                        delivery.nack().await?;
+                        return Err(TestError(value));
+                    }
+                    delivery.ack().await?;
+                    Ok(())
+                }
+            })
+            .await;
+
+        let log = log.lock().expect("lock").clone();
+        (result, log)
+    }
+
+    fn message(key: &'static str, value: u32) -> TestMessage {
+        TestMessage { key, value }
+    }
+
+    #[tokio::test]
+    async fn processes_other_keys_concurrently_and_same_key_in_order() {
+        let flow_control = DeliveryFlowControl::concurrent(NonZeroUsize::new(2).expect("2"))
+            .with_max_outstanding_messages(NonZeroUsize::new(3).expect("3"));
+
+        let (result, log) = run(
+            flow_control,
+            vec![message("a", 1), message("a", 2), message("b", 3)],
+            None,
+        )
+        .await;
+
+        assert_eq!(result, Ok(()));
+        assert!(position(&log, "start 3") < position(&log, "end 1"));
+        assert!(position(&log, "end 1") < position(&log, "start 2"));
+        assert_eq!(
+            log.iter().filter(|entry| entry.starts_with("ack")).count(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn failure_nacks_deliveries_that_have_not_started() {
+        let flow_control = DeliveryFlowControl::sequential()
+            .with_max_outstanding_messages(NonZeroUsize::new(3).expect("3"));
+
+        let (result, log) = run(
+            flow_control,
+            vec![message("a", 1), message("a", 2), message("a", 3)],
+            Some(1),
+        )
+        .await;
+
+        assert_eq!(result, Err(TestError(1)));
+        assert!(!log.contains(&"start 2".to_owned()));
+        assert!(log.contains(&"nack 2".to_owned()));
+        assert!(log.contains(&"nack 3".to_owned()));
+    }
+}
@@ -2,6 +2,8 @@ use std::error::Error;
 
 use thiserror::Error;
 
+use super::UndecodableMessage;
+
 #[derive(Debug, Error)]
 pub enum ConsumerError {
     #[error("consumer next error")]
@@ -12,4 +14,8 @@ pub enum ConsumerError {
 
     #[error("consumer nack error")]
     Nack(#[source] Box<dyn Error + Send + Sync>),
+
+    /// The next message could not be decoded; consumers keep running after this error.
+    #[error("consumer received an undecodable message: {}", .0.reason)]
+    Undecodable(UndecodableMessage),
 }
@@ -0,0 +1,122 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::outbox::OrderingKey;
+
+/// Pulled deliveries that have not started processing yet.
+///
+/// A delivery becomes ready once no earlier delivery with the same ordering key is being
+/// processed; deliveries without a key are ready immediately.
+pub(crate) struct DeliveryBacklog<D> {
+    ready: VecDeque<(Option<OrderingKey>, D, usize)>,
+    waiting: HashMap<OrderingKey, VecDeque<(D, usize)>>,
+    active_keys: HashSet<OrderingKey>,
+}
+
+impl<D> DeliveryBacklog<D> {
+    pub(crate) fn new() -> Self {
+        Self {
+            ready: VecDeque::new(),
+            waiting: HashMap::new(),
+            active_keys: HashSet::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, ordering_key: Option<OrderingKey>, delivery: D, size: usize) {
+        match ordering_key {
+            Some(key) if self.active_keys.contains(&key) => {
+                self.waiting
+                    .entry(key)
+                    .or_default()
+                    .push_back((delivery, size));
+            }
+            Some(key) => {
+                self.active_keys.insert(key.clone());
+                self.ready.push_back((Some(key), delivery, size));
+            }
+            None => self.ready.push_back((None, delivery, size)),
+        }
+    }
+
+    pub(crate) fn pop_ready(&mut self) -> Option<(Option<OrderingKey>, D, usize)> {
+        self.ready.pop_front()
+    }
+
+    /// Releases `ordering_key` after its delivery settled, readying the next one for the key.
+    pub(crate) fn complete(&mut self, ordering_key: Option<OrderingKey>) {
+        let Some(key) = ordering_key else {
+            return;
+        };
+
+        let next = self.waiting.get_mut(&key).and_then(VecDeque::pop_front);
+        match next {
+            Some((delivery, size)) => {
+                if self.waiting.get(&key).is_some_and(VecDeque::is_empty) {
+                    self.waiting.remove(&key);
+                }
+                self.ready.push_back((Some(key), delivery, size));
+            }
+            None => {
+                self.active_keys.remove(&key);
+            }
+        }
+    }
+
+    pub(crate) fn drain(&mut self) -> Vec<D> {
+        self.active_keys.clear();
+        self.ready
+            .drain(..)
+            .map(|(_, delivery, _)| delivery)
+            .chain(
+                self.waiting.drain().flat_map(|(_, deliveries)| {
+                    deliveries.into_iter().map(|(delivery, _)| delivery)
+                }),
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(value: &str) -> Option<OrderingKey> {
+        Some(OrderingKey::new(value.to_owned()).expect("ordering key should be valid"))
+    }
+
+    fn ready_values(backlog: &mut DeliveryBacklog<u32>) -> Vec<u32> {
+        std::iter::from_fn(|| backlog.pop_ready())
+            .map(|(_, delivery, _)| delivery)
+            .collect()
+    }
+
+    #[test]
+    fn holds_back_deliveries_of_an_active_key() {
+        let mut backlog = DeliveryBacklog::new();
+        backlog.push(key("a"), 1, 0);
+        backlog.push(key("a"), 2, 0);
+        backlog.push(key("b"), 3, 0);
+        backlog.push(None, 4, 0);
+
+        assert_eq!(ready_values(&mut backlog), vec![1, 3, 4]);
+
+        backlog.complete(key("a"));
+        assert_eq!(ready_values(&mut backlog), vec![2]);
+
+        backlog.complete(key("a"));
+        backlog.push(key("a"), 5, 0);
+        assert_eq!(ready_values(&mut backlog), vec![5]);
+    }
+
+    #[test]
+    fn drain_returns_ready_and_waiting_deliveries() {
+        let mut backlog = DeliveryBacklog::new();
+        backlog.push(key("a"), 1, 0);
+        backlog.push(key("a"), 2, 0);
+
+        let mut drained = backlog.drain();
+        drained.sort();
+
+        assert_eq!(drained, vec![1, 2]);
+        assert!(backlog.pop_ready().is_none());
+    }
+}
@@ -0,0 +1,72 @@
+use std::num::NonZeroUsize;
+
+/// Limits how many deliveries a worker processes and holds at once.
+///
+/// `max_in_flight` deliveries are processed concurrently. Outstanding deliveries include
+/// those waiting behind an earlier delivery with the same ordering key; the worker stops
+/// pulling from its consumer while either outstanding limit is reached. A single delivery is
+/// always accepted, even when it alone exceeds `max_outstanding_bytes`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeliveryFlowControl {
+    max_in_flight: NonZeroUsize,
+    max_outstanding_messages: NonZeroUsize,
+    max_outstanding_bytes: Option<NonZeroUsize>,
+}
+
+impl DeliveryFlowControl {
+    /// Processes one delivery at a time, pulling the next one after the previous is settled.
+    pub fn sequential() -> Self {
+        Self::concurrent(NonZeroUsize::MIN)
+    }
+
+    /// Processes up to `max_in_flight` deliveries at once and holds no more than that.
+    pub fn concurrent(max_in_flight: NonZeroUsize) -> Self {
+        Self {
+            max_in_flight,
+            max_outstanding_messages: max_in_flight,
+            max_outstanding_bytes: None,
+        }
+    }
+
+    /// Lets the worker hold more deliveries than it processes, so deliveries of other ordering
+    /// keys can start while a busy key has deliveries waiting.
+    pub fn with_max_outstanding_messages(mut self, max_outstanding_messages: NonZeroUsize) -> Self {
+        self.max_outstanding_messages = max_outstanding_messages;
+        self
+    }
+
+    pub fn with_max_outstanding_bytes(mut self, max_outstanding_bytes: NonZeroUsize) -> Self {
+        self.max_outstanding_bytes = Some(max_outstanding_bytes);
+        self
+    }
+
+    pub fn max_in_flight(&self) -> NonZeroUsize {
+        self.max_in_flight
+    }
+
+    pub fn max_outstanding_messages(&self) -> NonZeroUsize {
+        self.max_outstanding_messages
+    }
+
+    pub fn max_outstanding_bytes(&self) -> Option<NonZeroUsize> {
+        self.max_outstanding_bytes
+    }
+
+    pub(crate) fn has_capacity(
+        &self,
+        outstanding_messages: usize,
+        outstanding_bytes: usize,
+    ) -> bool {
+        outstanding_messages == 0
+            || (outstanding_messages < self.max_outstanding_messages.get()
+                && self
+                    .max_outstanding_bytes
+                    .is_none_or(|max| outstanding_bytes < max.get()))
+    }
+}
+
+impl Default for DeliveryFlowControl {
+    fn default() -> Self {
+        Self::sequential()
+    }
+}
@@ -0,0 +1,12 @@
+use crate::outbox::OrderingKey;
+
+/// Message that carries the ordering key its transport delivers it under.
+///
+/// Concurrent delivery processing keeps messages sharing a key sequential and uses the
+/// approximate size for byte-based flow control.
+pub trait OrderedMessage {
+    fn ordering_key(&self) -> Option<OrderingKey>;
+
+    /// Size of the encoded message in bytes; it only needs to be roughly proportional.
+    fn approximate_size(&self) -> usize;
+}
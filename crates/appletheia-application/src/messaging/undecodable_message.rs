@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+
+/// Raw message a consumer could not decode into its message type.
+///
+/// The transport has already settled the message according to its decode-failure policy, so
+/// the receiver may keep it for inspection but cannot ack or nack it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UndecodableMessage {
+    pub transport_message_id: Option<String>,
+    pub attributes: HashMap<String, String>,
+    pub data: Vec<u8>,
+    pub reason: String,
+}
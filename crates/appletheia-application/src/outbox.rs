@@ -6,6 +6,9 @@ mod ordering_key;
 mod outbox_attempt_count;
 mod outbox_attempt_count_error;
 mod outbox_batch_size;
+mod outbox_dead_letter_discard;
+mod outbox_dead_letter_service;
+mod outbox_dead_letter_service_error;
 mod outbox_dead_lettered_at;
 mod outbox_error;
 mod outbox_fetcher;
@@ -15,6 +18,7 @@ mod outbox_lease_expires_at;
 mod outbox_lifecycle;
 mod outbox_max_attempts;
 mod outbox_next_attempt_at;
+mod outbox_ordering_mode;
 mod outbox_poll_backoff_multiplier;
 mod outbox_poll_backoff_multiplier_error;
 mod outbox_poll_interval;
@@ -31,11 +35,23 @@ mod outbox_relay_instance_error;
 mod outbox_relay_instance_id;
 mod outbox_relay_process_id;
 mod outbox_relay_run_report;
+mod outbox_retry_backoff;
+mod outbox_retry_backoff_multiplier;
+mod outbox_retry_backoff_multiplier_error;
 mod outbox_retry_delay;
+mod outbox_retry_jitter;
 mod outbox_retry_options;
+mod outbox_retry_schedule;
+mod outbox_retry_schedule_error;
+mod outbox_retry_steps;
+mod outbox_retry_steps_error;
 mod outbox_state;
+mod outbox_wakeup;
+mod outbox_wakeup_listener;
+mod outbox_wakeup_listener_error;
 mod outbox_writer;
 mod outbox_writer_error;
+mod polling_outbox_wakeup_listener;
 mod processed_outbox_count;
 
 pub use default_outbox_relay::DefaultOutboxRelay;
@@ -43,6 +59,9 @@ pub use ordering_key::{OrderingKey, OrderingKeyError};
 pub use outbox_attempt_count::OutboxAttemptCount;
 pub use outbox_attempt_count_error::OutboxAttemptCountError;
 pub use outbox_batch_size::OutboxBatchSize;
+pub use outbox_dead_letter_discard::OutboxDeadLetterDiscard;
+pub use outbox_dead_letter_service::OutboxDeadLetterService;
+pub use outbox_dead_letter_service_error::OutboxDeadLetterServiceError;
 pub use outbox_dead_lettered_at::OutboxDeadLetteredAt;
 pub use outbox_error::OutboxError;
 pub use outbox_fetcher::OutboxFetcher;
@@ -52,6 +71,7 @@ pub use outbox_lease_expires_at::OutboxLeaseExpiresAt;
 pub use outbox_lifecycle::OutboxLifecycle;
 pub use outbox_max_attempts::OutboxMaxAttempts;
 pub use outbox_next_attempt_at::OutboxNextAttemptAt;
+pub use outbox_ordering_mode::OutboxOrderingMode;
 pub use outbox_poll_backoff_multiplier::OutboxPollBackoffMultiplier;
 pub use outbox_poll_backoff_multiplier_error::OutboxPollBackoffMultiplierError;
 pub use outbox_poll_interval::OutboxPollInterval;
@@ -68,11 +88,23 @@ pub use outbox_relay_instance_error::OutboxRelayInstanceError;
 pub use outbox_relay_instance_id::OutboxRelayInstanceId;
 pub use outbox_relay_process_id::OutboxRelayProcessId;
 pub use outbox_relay_run_report::OutboxRelayRunReport;
+pub use outbox_retry_backoff::OutboxRetryBackoff;
+pub use outbox_retry_backoff_multiplier::OutboxRetryBackoffMultiplier;
+pub use outbox_retry_backoff_multiplier_error::OutboxRetryBackoffMultiplierError;
 pub use outbox_retry_delay::OutboxRetryDelay;
+pub use outbox_retry_jitter::OutboxRetryJitter;
 pub use outbox_retry_options::OutboxRetryOptions;
+pub use outbox_retry_schedule::OutboxRetrySchedule;
+pub use outbox_retry_schedule_error::OutboxRetryScheduleError;
+pub use outbox_retry_steps::OutboxRetrySteps;
+pub use outbox_retry_steps_error::OutboxRetryStepsError;
 pub use outbox_state::OutboxState;
+pub use outbox_wakeup::OutboxWakeup;
+pub use outbox_wakeup_listener::OutboxWakeupListener;
+pub use outbox_wakeup_listener_error::OutboxWakeupListenerError;
 pub use outbox_writer::OutboxWriter;
 pub use outbox_writer_error::OutboxWriterError;
+pub use polling_outbox_wakeup_listener::PollingOutboxWakeupListener;
 pub use processed_outbox_count::ProcessedOutboxCount;
 
 use crate::messaging::PublishDispatchError;
@@ -81,6 +113,9 @@ pub trait Outbox {
     type Id: Copy + Eq + 'static;
     type Message;
 
+    /// Short label identifying the outbox family in metrics, e.g. `event`.
+    const KIND: &'static str;
+
     fn id(&self) -> Self::Id;
 
     fn ordering_key(&self) -> OrderingKey;
@@ -162,7 +197,8 @@ pub trait Outbox {
                     *self.lifecycle_mut() = OutboxLifecycle::DeadLettered { dead_lettered_at };
                 }
                 PublishDispatchError::Transient { .. } => {
-                    let next_attempt_at = OutboxNextAttemptAt::now().next(retry_options.backoff);
+                    let retry_delay = retry_options.schedule.delay_for(next_attempt_count);
+                    let next_attempt_at = OutboxNextAttemptAt::now().next(retry_delay);
 
                     *self.state_mut() = OutboxState::Pending {
                         attempt_count: next_attempt_count,
@@ -1,6 +1,7 @@
 pub mod command_envelope;
 pub mod command_envelope_error;
 pub mod command_outbox;
+pub mod command_outbox_dead_letter_filter;
 pub mod command_outbox_enqueue_error;
 pub mod command_outbox_enqueuer;
 pub mod command_outbox_id;
@@ -11,6 +12,7 @@ pub mod serialized_command_error;
 pub use command_envelope::CommandEnvelope;
 pub use command_envelope_error::CommandEnvelopeError;
 pub use command_outbox::CommandOutbox;
+pub use command_outbox_dead_letter_filter::CommandOutboxDeadLetterFilter;
 pub use command_outbox_enqueue_error::CommandOutboxEnqueueError;
 pub use command_outbox_enqueuer::CommandOutboxEnqueuer;
 pub use command_outbox_id::CommandOutboxId;
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::command::{Command, CommandNameOwned, CommandOptions};
+use crate::messaging::OrderedMessage;
+use crate::outbox::OrderingKey;
 use crate::request_context::{CausationId, CorrelationId, MessageId};
+use crate::telemetry::TraceContext;
 
 use super::CommandEnvelopeError;
 use super::SerializedCommand;
@@ -14,9 +17,14 @@ pub struct CommandEnvelope {
     pub message_id: MessageId,
     pub causation_id: CausationId,
     pub options: CommandOptions,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_context: Option<TraceContext>,
 }
 
 impl CommandEnvelope {
+    /// Serializes `command` into an envelope without a trace context; attach one with
+    /// `with_trace_context` to continue the caller's trace in the command worker.
     pub fn new<C: Command>(
         command: &C,
         correlation_id: CorrelationId,
@@ -30,9 +38,15 @@ impl CommandEnvelope {
             message_id: MessageId::new(),
             causation_id,
             options,
+            trace_context: None,
         })
     }
 
+    pub fn with_trace_context(mut self, trace_context: TraceContext) -> Self {
+        self.trace_context = Some(trace_context);
+        self
+    }
+
     pub fn try_into_command<C>(&self) -> Result<C, CommandEnvelopeError>
     where
         C: Command,
@@ -49,3 +63,13 @@ impl CommandEnvelope {
         Ok(serde_json::from_value(json)?)
     }
 }
+
+impl OrderedMessage for CommandEnvelope {
+    fn ordering_key(&self) -> Option<OrderingKey> {
+        Some(OrderingKey::from(self.correlation_id))
+    }
+
+    fn approximate_size(&self) -> usize {
+        serde_json::to_vec(self).map_or(0, |bytes| bytes.len())
+    }
+}
@@ -16,6 +16,8 @@ impl Outbox for CommandOutbox {
     type Id = CommandOutboxId;
     type Message = CommandEnvelope;
 
+    const KIND: &'static str = "command";
+
     fn id(&self) -> Self::Id {
         self.id
     }
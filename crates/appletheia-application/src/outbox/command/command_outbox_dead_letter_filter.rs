@@ -0,0 +1,14 @@
+use crate::command::CommandNameOwned;
+use crate::outbox::OutboxDeadLetteredAt;
+
+/// Narrows the command dead letters returned by an `OutboxDeadLetterService`.
+///
+/// Unset fields do not filter. The time range is inclusive at both ends and `error_contains`
+/// matches the serialized `last_error` case-insensitively.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CommandOutboxDeadLetterFilter {
+    pub command_name: Option<CommandNameOwned>,
+    pub dead_lettered_from: Option<OutboxDeadLetteredAt>,
+    pub dead_lettered_until: Option<OutboxDeadLetteredAt>,
+    pub error_contains: Option<String>,
+}
@@ -1,33 +1,46 @@
+use std::error::Error;
 use std::marker::PhantomData;
-use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
-use std::time::Duration as StdDuration;
+use std::time::Instant;
 
-use chrono::Duration;
-use tokio::time::sleep;
+use metrics::{counter, histogram};
+use tracing::{Instrument, info, info_span, warn};
 
 use crate::messaging::{PublishResult, Publisher};
+use crate::telemetry::MetricName;
 use crate::unit_of_work::UnitOfWork;
 use crate::unit_of_work::UnitOfWorkFactory;
+use crate::{SupervisedWorker, WorkerStopSignal};
 
 use super::{
-    Outbox, OutboxFetcher, OutboxRelay, OutboxRelayConfig, OutboxRelayError, OutboxRelayRunReport,
-    OutboxState, OutboxWriter, ProcessedOutboxCount,
+    Outbox, OutboxFetcher, OutboxLifecycle, OutboxRelay, OutboxRelayConfig, OutboxRelayError,
+    OutboxRelayRunReport, OutboxState, OutboxWakeup, OutboxWakeupListener, OutboxWriter,
+    PollingOutboxWakeupListener, ProcessedOutboxCount,
 };
 
-pub struct DefaultOutboxRelay<UowFactory, O, F, W, P>
+/// Relays outbox entries to a publisher.
+///
+/// While idle the relay waits on its wakeup listener for at most the current poll interval.
+/// The default `PollingOutboxWakeupListener` just sleeps; a listener backed by database
+/// notifications wakes the relay as soon as new work is committed. A graceful stop interrupts
+/// the wait. When the listener fails, the relay sleeps for the poll interval instead and asks
+/// the listener again on the next idle round. It logs once when it falls back to polling and
+/// once when the listener recovers.
+pub struct DefaultOutboxRelay<UowFactory, O, F, W, P, L = PollingOutboxWakeupListener>
 where
     UowFactory: UnitOfWorkFactory,
     O: Outbox,
     F: OutboxFetcher<Uow = UowFactory::Uow, Outbox = O>,
     W: OutboxWriter<Uow = UowFactory::Uow, Outbox = O>,
     P: Publisher<O::Message>,
+    L: OutboxWakeupListener,
 {
     config: OutboxRelayConfig,
     publisher: P,
     fetcher: F,
     writer: W,
     uow_factory: UowFactory,
-    stop_requested: AtomicBool,
+    wakeup_listener: L,
+    stop_signal: WorkerStopSignal,
     _marker: PhantomData<fn() -> O>,
 }
 
@@ -52,33 +65,65 @@ where
             fetcher,
             writer,
             uow_factory,
-            stop_requested: AtomicBool::new(false),
+            wakeup_listener: PollingOutboxWakeupListener::new(),
+            stop_signal: WorkerStopSignal::new(),
             _marker: PhantomData,
         }
     }
 }
 
-impl<UowFactory, O, F, W, P> OutboxRelay for DefaultOutboxRelay<UowFactory, O, F, W, P>
+impl<UowFactory, O, F, W, P, L> DefaultOutboxRelay<UowFactory, O, F, W, P, L>
 where
     UowFactory: UnitOfWorkFactory,
     O: Outbox,
     F: OutboxFetcher<Uow = UowFactory::Uow, Outbox = O>,
     W: OutboxWriter<Uow = UowFactory::Uow, Outbox = O>,
     P: Publisher<O::Message>,
+    L: OutboxWakeupListener,
+{
+    pub fn with_wakeup_listener<NextL>(
+        self,
+        wakeup_listener: NextL,
+    ) -> DefaultOutboxRelay<UowFactory, O, F, W, P, NextL>
+    where
+        NextL: OutboxWakeupListener,
+    {
+        DefaultOutboxRelay {
+            config: self.config,
+            publisher: self.publisher,
+            fetcher: self.fetcher,
+            writer: self.writer,
+            uow_factory: self.uow_factory,
+            wakeup_listener,
+            stop_signal: self.stop_signal,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<UowFactory, O, F, W, P, L> OutboxRelay for DefaultOutboxRelay<UowFactory, O, F, W, P, L>
+where
+    UowFactory: UnitOfWorkFactory,
+    O: Outbox,
+    F: OutboxFetcher<Uow = UowFactory::Uow, Outbox = O>,
+    W: OutboxWriter<Uow = UowFactory::Uow, Outbox = O>,
+    P: Publisher<O::Message>,
+    L: OutboxWakeupListener,
 {
     type Outbox = O;
 
     fn is_stop_requested(&self) -> bool {
-        self.stop_requested.load(AtomicOrdering::SeqCst)
+        self.stop_signal.is_requested()
     }
 
     fn request_graceful_stop(&mut self) {
-        self.stop_requested.store(true, AtomicOrdering::SeqCst);
+        self.stop_signal.request();
     }
 
     async fn run_forever(&self) -> Result<(), OutboxRelayError> {
         let polling_options = &self.config.polling_options;
         let mut poll_interval = polling_options.base;
+        let mut listener_failed = false;
 
         while !self.is_stop_requested() {
             let run_report = self.run_once().await?;
@@ -88,20 +133,46 @@ where
                     poll_interval = polling_options.base;
                 }
                 OutboxRelayRunReport::Idle | OutboxRelayRunReport::Throttled => {
-                    let duration: Duration = poll_interval.into();
-                    let sleep_duration = duration
-                        .to_std()
-                        .unwrap_or_else(|_| StdDuration::from_secs(0));
+                    let wakeup = tokio::select! {
+                        wakeup = self.wakeup_listener.wait(poll_interval) => wakeup,
+                        () = self.stop_signal.requested() => break,
+                    };
+                    let wakeup = match wakeup {
+                        Ok(wakeup) => {
+                            if listener_failed {
+                                listener_failed = false;
+                                info!("outbox wakeup listener recovered");
+                            }
+                            wakeup
+                        }
+                        Err(error) => {
+                            if !listener_failed {
+                                listener_failed = true;
+                                warn!(
+                                    error = &error as &dyn Error,
+                                    "outbox wakeup listener failed; polling until it recovers"
+                                );
+                            }
+                            let polling = PollingOutboxWakeupListener::new();
+                            tokio::select! {
+                                wakeup = polling.wait(poll_interval) => wakeup?,
+                                () = self.stop_signal.requested() => break,
+                            }
+                        }
+                    };
 
-                    if sleep_duration > StdDuration::from_secs(0) {
-                        sleep(sleep_duration).await;
+                    match wakeup {
+                        OutboxWakeup::Notified => {
+                            poll_interval = polling_options.base;
+                        }
+                        OutboxWakeup::TimedOut => {
+                            poll_interval = poll_interval.next(
+                                polling_options.multiplier,
+                                polling_options.jitter,
+                                polling_options.max,
+                            );
+                        }
                     }
-
-                    poll_interval = poll_interval.next(
-                        polling_options.multiplier,
-                        polling_options.jitter,
-                        polling_options.max,
-                    );
                 }
             }
         }
@@ -113,7 +184,8 @@ where
         let relay_instance = &self.config.instance;
         let lease_duration = self.config.lease_duration;
         let batch_size = self.config.batch_size;
-        let retry_options = self.config.retry_options;
+        let retry_options = &self.config.retry_options;
+        let started_at = Instant::now();
 
         let mut uow = self.uow_factory.begin().await?;
         let outboxes = self.fetcher.fetch_pending(&mut uow, batch_size).await;
@@ -155,18 +227,33 @@ where
             }
         };
 
+        let publish_span = info_span!(
+            "outbox.publish",
+            relay.instance = %relay_instance,
+            outbox.count = outboxes.len(),
+        );
         let publish_results = self
             .publisher
             .publish(outboxes.iter().map(Outbox::message))
+            .instrument(publish_span)
             .await?;
 
+        let mut published_count = 0;
+        let mut nacked_count = 0;
+        let mut dead_lettered_count = 0;
         for publish_result in publish_results {
             match publish_result {
                 PublishResult::Success { input_index, .. } => {
                     outboxes[input_index].ack()?;
+                    published_count += 1;
                 }
                 PublishResult::Failed { input_index, cause } => {
-                    outboxes[input_index].nack(&cause, &retry_options)?;
+                    let outbox = &mut outboxes[input_index];
+                    outbox.nack(&cause, retry_options)?;
+                    nacked_count += 1;
+                    if matches!(outbox.lifecycle(), OutboxLifecycle::DeadLettered { .. }) {
+                        dead_lettered_count += 1;
+                    }
                 }
             }
         }
@@ -182,8 +269,266 @@ where
 
         uow.commit().await?;
 
+        counter!(MetricName::OutboxPublished.as_str(), "outbox" => O::KIND)
+            .increment(published_count);
+        counter!(MetricName::OutboxNacked.as_str(), "outbox" => O::KIND).increment(nacked_count);
+        counter!(MetricName::OutboxDeadLettered.as_str(), "outbox" => O::KIND)
+            .increment(dead_lettered_count);
+        histogram!(MetricName::OutboxRelayBatchDuration.as_str(), "outbox" => O::KIND)
+            .record(started_at.elapsed());
+
         Ok(OutboxRelayRunReport::Progress {
             processed_outbox_count,
         })
     }
 }
+
+impl<UowFactory, O, F, W, P, L> SupervisedWorker for DefaultOutboxRelay<UowFactory, O, F, W, P, L>
+where
+    UowFactory: UnitOfWorkFactory,
+    O: Outbox,
+    F: OutboxFetcher<Uow = UowFactory::Uow, Outbox = O>,
+    W: OutboxWriter<Uow = UowFactory::Uow, Outbox = O>,
+    P: Publisher<O::Message>,
+    L: OutboxWakeupListener,
+{
+    fn stop_signal(&self) -> WorkerStopSignal {
+        self.stop_signal.clone()
+    }
+
+    async fn run_supervised(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(self.run_forever().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::num::NonZeroU32;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration as StdDuration;
+
+    use chrono::Duration;
+    use tokio::time::timeout;
+    use tracing::{Event, Level, Subscriber};
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+    use super::DefaultOutboxRelay;
+    use crate::messaging::{PublishResult, Publisher, PublisherError};
+    use crate::outbox::event::EventOutbox;
+    use crate::outbox::{
+        OutboxBatchSize, OutboxFetcher, OutboxFetcherError, OutboxLeaseDuration, OutboxMaxAttempts,
+        OutboxPollBackoffMultiplier, OutboxPollInterval, OutboxPollJitterRatio,
+        OutboxPollingOptions, OutboxRelay, OutboxRelayConfig, OutboxRelayInstance,
+        OutboxRelayInstanceId, OutboxRelayProcessId, OutboxRetryDelay, OutboxRetryOptions,
+        OutboxRetrySchedule, OutboxWakeup, OutboxWakeupListener, OutboxWakeupListenerError,
+        OutboxWriter, OutboxWriterError, PollingOutboxWakeupListener,
+    };
+    use crate::unit_of_work::{
+        UnitOfWork, UnitOfWorkError, UnitOfWorkFactory, UnitOfWorkFactoryError,
+    };
+
+    struct TestUow;
+
+    impl UnitOfWork for TestUow {
+        async fn commit(self) -> Result<(), UnitOfWorkError> {
+            Ok(())
+        }
+
+        async fn rollback(self) -> Result<(), UnitOfWorkError> {
+            Ok(())
+        }
+    }
+
+    struct TestUowFactory;
+
+    impl UnitOfWorkFactory for TestUowFactory {
+        type Uow = TestUow;
+
+        async fn begin(&self) -> Result<Self::Uow, UnitOfWorkFactoryError> {
+            Ok(TestUow)
+        }
+    }
+
+    struct EmptyOutboxFetcher;
+
+    impl OutboxFetcher for EmptyOutboxFetcher {
+        type Uow = TestUow;
+        type Outbox = EventOutbox;
+
+        async fn fetch_pending(
+            &self,
+            _uow: &mut Self::Uow,
+            _limit: OutboxBatchSize,
+        ) -> Result<Vec<Self::Outbox>, OutboxFetcherError> {
+            Ok(Vec::new())
+        }
+
+        async fn fetch_dead_lettered(
+            &self,
+            _uow: &mut Self::Uow,
+            _limit: OutboxBatchSize,
+        ) -> Result<Vec<Self::Outbox>, OutboxFetcherError> {
+            Ok(Vec::new())
+        }
+    }
+
+    struct NoopOutboxWriter;
+
+    impl OutboxWriter for NoopOutboxWriter {
+        type Uow = TestUow;
+        type Outbox = EventOutbox;
+
+        async fn write_outbox(
+            &self,
+            _uow: &mut Self::Uow,
+            _outboxes: &[Self::Outbox],
+        ) -> Result<(), OutboxWriterError> {
+            Ok(())
+        }
+    }
+
+    struct NoopPublisher;
+
+    impl<M> Publisher<M> for NoopPublisher {
+        async fn publish<'a, I>(&self, _messages: I) -> Result<Vec<PublishResult>, PublisherError>
+        where
+            I: IntoIterator<Item = &'a M>,
+            M: 'a,
+        {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Fails its first `failures` waits and polls afterwards.
+    struct FlakyWakeupListener {
+        failures: usize,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl OutboxWakeupListener for FlakyWakeupListener {
+        async fn wait(
+            &self,
+            poll_interval: OutboxPollInterval,
+        ) -> Result<OutboxWakeup, OutboxWakeupListenerError> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+                return Err(OutboxWakeupListenerError::Connection(Box::new(
+                    io::Error::from(io::ErrorKind::ConnectionRefused),
+                )));
+            }
+            PollingOutboxWakeupListener::new().wait(poll_interval).await
+        }
+    }
+
+    /// Records the level of every event logged by the relay.
+    #[derive(Clone, Default)]
+    struct LevelRecorder(Arc<Mutex<Vec<Level>>>);
+
+    impl LevelRecorder {
+        fn count(&self, level: Level) -> usize {
+            self.0
+                .lock()
+                .expect("lock should succeed")
+                .iter()
+                .filter(|recorded| **recorded == level)
+                .count()
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for LevelRecorder {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            if event
+                .metadata()
+                .target()
+                .starts_with(module_path!().trim_end_matches("::tests"))
+            {
+                self.0
+                    .lock()
+                    .expect("lock should succeed")
+                    .push(*event.metadata().level());
+            }
+        }
+    }
+
+    fn config() -> OutboxRelayConfig {
+        let poll_interval = OutboxPollInterval::new(Duration::milliseconds(5));
+        OutboxRelayConfig {
+            instance: OutboxRelayInstance::new(
+                OutboxRelayInstanceId::new("relay-test".to_owned())
+                    .expect("instance id should be valid"),
+                OutboxRelayProcessId::new(1),
+            ),
+            batch_size: OutboxBatchSize::new(
+                NonZeroU32::new(10).expect("batch size should be non-zero"),
+            ),
+            lease_duration: OutboxLeaseDuration::new(Duration::seconds(30)),
+            retry_options: OutboxRetryOptions {
+                schedule: OutboxRetrySchedule::Fixed(OutboxRetryDelay::new(Duration::zero())),
+                max_attempts: OutboxMaxAttempts::new(
+                    NonZeroU32::new(3).expect("max attempts should be non-zero"),
+                ),
+            },
+            polling_options: OutboxPollingOptions::new(
+                poll_interval,
+                poll_interval,
+                OutboxPollBackoffMultiplier::new(),
+                OutboxPollJitterRatio::new(),
+            )
+            .expect("polling options should be valid"),
+        }
+    }
+
+    fn relay(
+        listener: FlakyWakeupListener,
+    ) -> DefaultOutboxRelay<
+        TestUowFactory,
+        EventOutbox,
+        EmptyOutboxFetcher,
+        NoopOutboxWriter,
+        NoopPublisher,
+        FlakyWakeupListener,
+    > {
+        DefaultOutboxRelay::new(
+            config(),
+            NoopPublisher,
+            EmptyOutboxFetcher,
+            NoopOutboxWriter,
+            TestUowFactory,
+        )
+        .with_wakeup_listener(listener)
+    }
+
+    #[tokio::test]
+    async fn run_forever_keeps_polling_when_the_wakeup_listener_fails() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let relay = relay(FlakyWakeupListener {
+            failures: usize::MAX,
+            calls: Arc::clone(&calls),
+        });
+
+        let outcome = timeout(StdDuration::from_millis(100), relay.run_forever()).await;
+
+        assert!(outcome.is_err(), "relay should still be running");
+        assert!(calls.load(Ordering::SeqCst) > 1);
+    }
+
+    #[tokio::test]
+    async fn logs_once_on_fallback_and_once_on_recovery() {
+        let recorder = LevelRecorder::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let relay = relay(FlakyWakeupListener {
+            failures: 3,
+            calls: Arc::clone(&calls),
+        });
+
+        let outcome = timeout(StdDuration::from_millis(100), relay.run_forever()).await;
+
+        assert!(outcome.is_err(), "relay should still be running");
+        assert!(calls.load(Ordering::SeqCst) > 3);
+        assert_eq!(recorder.count(Level::WARN), 1);
+        assert_eq!(recorder.count(Level::INFO), 1);
+    }
+}
@@ -1,7 +1,9 @@
 pub mod event_outbox;
+pub mod event_outbox_dead_letter_filter;
 pub mod event_outbox_id;
 pub mod event_outbox_id_error;
 
 pub use event_outbox::EventOutbox;
+pub use event_outbox_dead_letter_filter::EventOutboxDeadLetterFilter;
 pub use event_outbox_id::EventOutboxId;
 pub use event_outbox_id_error::EventOutboxIdError;
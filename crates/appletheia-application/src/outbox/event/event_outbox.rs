@@ -18,6 +18,8 @@ impl Outbox for EventOutbox {
     type Id = EventOutboxId;
     type Message = EventEnvelope;
 
+    const KIND: &'static str = "event";
+
     fn id(&self) -> Self::Id {
         self.id
     }
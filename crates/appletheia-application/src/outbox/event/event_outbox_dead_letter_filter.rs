@@ -0,0 +1,15 @@
+use crate::event::{AggregateTypeOwned, EventNameOwned};
+use crate::outbox::OutboxDeadLetteredAt;
+
+/// Narrows the event dead letters returned by an `OutboxDeadLetterService`.
+///
+/// Unset fields do not filter. The time range is inclusive at both ends and `error_contains`
+/// matches the serialized `last_error` case-insensitively.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventOutboxDeadLetterFilter {
+    pub aggregate_type: Option<AggregateTypeOwned>,
+    pub event_name: Option<EventNameOwned>,
+    pub dead_lettered_from: Option<OutboxDeadLetteredAt>,
+    pub dead_lettered_until: Option<OutboxDeadLetteredAt>,
+    pub error_contains: Option<String>,
+}
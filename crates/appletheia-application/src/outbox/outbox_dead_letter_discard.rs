@@ -0,0 +1,17 @@
+use crate::request_context::ActorRef;
+
+/// Audit information recorded when dead-lettered outbox entries are discarded.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct OutboxDeadLetterDiscard {
+    pub discarded_by: ActorRef,
+    pub reason: String,
+}
+
+impl OutboxDeadLetterDiscard {
+    pub fn new(discarded_by: ActorRef, reason: impl Into<String>) -> Self {
+        Self {
+            discarded_by,
+            reason: reason.into(),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::unit_of_work::UnitOfWork;
+
+use super::{
+    OrderingKey, Outbox, OutboxBatchSize, OutboxDeadLetterDiscard, OutboxDeadLetterServiceError,
+    ProcessedOutboxCount,
+};
+
+/// Operator-facing management of dead-lettered outbox entries.
+///
+/// Listed entries keep their `last_error`, so callers can inspect the `PublishDispatchError`
+/// that moved them to the dead letters before deciding to redrive or discard them.
+#[allow(async_fn_in_trait)]
+pub trait OutboxDeadLetterService: Send + Sync {
+    type Uow: UnitOfWork;
+    type Outbox: Outbox;
+    type Filter: Send + Sync;
+
+    /// Lists dead letters matching `filter`, oldest first.
+    async fn list(
+        &self,
+        uow: &mut Self::Uow,
+        filter: &Self::Filter,
+        limit: OutboxBatchSize,
+    ) -> Result<Vec<Self::Outbox>, OutboxDeadLetterServiceError>;
+
+    async fn find(
+        &self,
+        uow: &mut Self::Uow,
+        id: <Self::Outbox as Outbox>::Id,
+    ) -> Result<Option<Self::Outbox>, OutboxDeadLetterServiceError>;
+
+    /// Moves the given dead letters back to the outbox as pending entries.
+    ///
+    /// Unknown ids are ignored; the returned count only includes redriven entries.
+    async fn redrive(
+        &self,
+        uow: &mut Self::Uow,
+        ids: &[<Self::Outbox as Outbox>::Id],
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError>;
+
+    /// Moves up to `limit` dead letters matching `filter` back to the outbox, oldest first.
+    ///
+    /// Callers draining a large backlog repeat the call, committing in between, until it
+    /// returns zero.
+    async fn redrive_matching(
+        &self,
+        uow: &mut Self::Uow,
+        filter: &Self::Filter,
+        limit: OutboxBatchSize,
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError>;
+
+    /// Permanently deletes the given dead letters and records an audit entry for each of them.
+    async fn discard(
+        &self,
+        uow: &mut Self::Uow,
+        ids: &[<Self::Outbox as Outbox>::Id],
+        discard: &OutboxDeadLetterDiscard,
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError>;
+
+    /// Stops the dead letters of `ordering_key` from blocking later entries under
+    /// `OutboxOrderingMode::Strict`.
+    ///
+    /// The dead letters themselves are kept and can still be redriven or discarded; a redriven
+    /// entry blocks its key again until it is published.
+    async fn unblock_ordering_key(
+        &self,
+        uow: &mut Self::Uow,
+        ordering_key: &OrderingKey,
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError>;
+}
@@ -0,0 +1,23 @@
+use std::error::Error;
+
+use thiserror::Error;
+
+use super::{OutboxError, OutboxWriterError};
+
+#[derive(Debug, Error)]
+pub enum OutboxDeadLetterServiceError {
+    #[error("outbox mapping failed: {0}")]
+    MappingFailed(#[source] Box<dyn Error + Send + Sync + 'static>),
+
+    #[error("outbox persistence error: {0}")]
+    Persistence(#[source] Box<dyn Error + Send + Sync + 'static>),
+
+    #[error("outbox transition failed: {0}")]
+    Outbox(#[from] OutboxError),
+
+    #[error("outbox writer error: {0}")]
+    Writer(#[from] OutboxWriterError),
+
+    #[error("transaction is not active")]
+    NotInTransaction,
+}
@@ -0,0 +1,14 @@
+/// How strictly an outbox fetcher preserves the order of entries sharing an `OrderingKey`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OutboxOrderingMode {
+    /// Keeps the fetcher's built-in ordering and lets dead-lettered entries fall out of it.
+    #[default]
+    Relaxed,
+
+    /// Never leases an entry while an earlier entry with the same ordering key is pending,
+    /// leased or dead-lettered.
+    ///
+    /// A dead-lettered entry keeps blocking its key until it is redriven, discarded or the
+    /// key is unblocked through `OutboxDeadLetterService::unblock_ordering_key`.
+    Strict,
+}
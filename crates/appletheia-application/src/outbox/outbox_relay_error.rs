@@ -1,7 +1,9 @@
 use thiserror::Error;
 
 use crate::messaging::PublisherError;
-use crate::outbox::{OutboxError, OutboxFetcherError, OutboxState, OutboxWriterError};
+use crate::outbox::{
+    OutboxError, OutboxFetcherError, OutboxState, OutboxWakeupListenerError, OutboxWriterError,
+};
 use crate::unit_of_work::UnitOfWorkError;
 use crate::unit_of_work::UnitOfWorkFactoryError;
 
@@ -22,6 +24,9 @@ pub enum OutboxRelayError {
     #[error("unit of work factory error: {0}")]
     UnitOfWorkFactory(#[from] UnitOfWorkFactoryError),
 
+    #[error("outbox wakeup listener failed: {0}")]
+    WakeupListener(#[from] OutboxWakeupListenerError),
+
     #[error("outbox error: {0}")]
     Outbox(#[from] OutboxError),
 
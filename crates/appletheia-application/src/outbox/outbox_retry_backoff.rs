@@ -0,0 +1,82 @@
+use chrono::Duration;
+use rand::Rng;
+
+use super::{
+    OutboxAttemptCount, OutboxRetryBackoffMultiplier, OutboxRetryDelay, OutboxRetryJitter,
+    OutboxRetryScheduleError,
+};
+
+/// Exponential backoff: multiplies `base` by `multiplier` for every further attempt, capped at
+/// `max`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutboxRetryBackoff {
+    base: OutboxRetryDelay,
+    multiplier: OutboxRetryBackoffMultiplier,
+    max: OutboxRetryDelay,
+    jitter: OutboxRetryJitter,
+}
+
+impl OutboxRetryBackoff {
+    pub fn new(
+        base: OutboxRetryDelay,
+        multiplier: OutboxRetryBackoffMultiplier,
+        max: OutboxRetryDelay,
+        jitter: OutboxRetryJitter,
+    ) -> Result<Self, OutboxRetryScheduleError> {
+        if base.value() < Duration::zero() {
+            return Err(OutboxRetryScheduleError::NegativeDelay(base));
+        }
+        if base.value() > max.value() {
+            return Err(OutboxRetryScheduleError::BaseGreaterThanMax { base, max });
+        }
+
+        Ok(Self {
+            base,
+            multiplier,
+            max,
+            jitter,
+        })
+    }
+
+    pub fn base(&self) -> OutboxRetryDelay {
+        self.base
+    }
+
+    pub fn multiplier(&self) -> OutboxRetryBackoffMultiplier {
+        self.multiplier
+    }
+
+    pub fn max(&self) -> OutboxRetryDelay {
+        self.max
+    }
+
+    pub fn jitter(&self) -> OutboxRetryJitter {
+        self.jitter
+    }
+
+    pub fn delay_for(&self, attempt_count: OutboxAttemptCount) -> OutboxRetryDelay {
+        let base_ms = self.base.value().num_milliseconds() as f64;
+        let max_ms = self.max.value().num_milliseconds() as f64;
+        let exponential_ms = |attempt: i64| {
+            let exponent = attempt.saturating_sub(1).clamp(0, i32::MAX as i64) as i32;
+            (base_ms * self.multiplier.value().powi(exponent))
+                .min(max_ms)
+                .max(0.0)
+        };
+
+        let delay_ms = match self.jitter {
+            OutboxRetryJitter::None => exponential_ms(attempt_count.value()),
+            OutboxRetryJitter::Full => {
+                let upper = exponential_ms(attempt_count.value());
+                rand::rng().random_range(0.0..=upper)
+            }
+            OutboxRetryJitter::Decorrelated => {
+                let previous = exponential_ms(attempt_count.value().saturating_sub(1));
+                let upper = (previous * 3.0).min(max_ms).max(base_ms);
+                rand::rng().random_range(base_ms..=upper)
+            }
+        };
+
+        OutboxRetryDelay::new(Duration::milliseconds(delay_ms.round() as i64))
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum OutboxRetryBackoffMultiplierError {
+    #[error("retry backoff multiplier must be finite and >= 1.0, got {0}")]
+    Invalid(f64),
+}
@@ -0,0 +1,14 @@
+/// Randomisation applied on top of an exponential retry delay.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum OutboxRetryJitter {
+    /// Uses the exponential delay as is.
+    #[default]
+    None,
+
+    /// Picks a delay uniformly between zero and the exponential delay.
+    Full,
+
+    /// Picks a delay uniformly between the base delay and three times the previous exponential
+    /// delay, capped at the maximum delay.
+    Decorrelated,
+}
@@ -1,7 +1,7 @@
-use super::{OutboxMaxAttempts, OutboxRetryDelay};
+use super::{OutboxMaxAttempts, OutboxRetrySchedule};
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct OutboxRetryOptions {
-    pub backoff: OutboxRetryDelay,
+    pub schedule: OutboxRetrySchedule,
     pub max_attempts: OutboxMaxAttempts,
 }
@@ -0,0 +1,160 @@
+use super::{
+    OutboxAttemptCount, OutboxRetryBackoff, OutboxRetryBackoffMultiplier, OutboxRetryDelay,
+    OutboxRetryJitter, OutboxRetryScheduleError, OutboxRetrySteps,
+};
+
+/// Computes how long a failed outbox entry waits before its next attempt.
+///
+/// The attempt count passed to `delay_for` is the count after the failed attempt has been
+/// recorded, so the first retry is computed for attempt `1`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OutboxRetrySchedule {
+    /// Waits the same delay after every failure.
+    Fixed(OutboxRetryDelay),
+
+    /// Grows the delay exponentially with every further attempt.
+    Exponential(OutboxRetryBackoff),
+
+    /// Uses an explicit delay per attempt.
+    Steps(OutboxRetrySteps),
+}
+
+impl OutboxRetrySchedule {
+    pub fn exponential(
+        base: OutboxRetryDelay,
+        multiplier: OutboxRetryBackoffMultiplier,
+        max: OutboxRetryDelay,
+        jitter: OutboxRetryJitter,
+    ) -> Result<Self, OutboxRetryScheduleError> {
+        OutboxRetryBackoff::new(base, multiplier, max, jitter).map(Self::Exponential)
+    }
+
+    pub fn delay_for(&self, attempt_count: OutboxAttemptCount) -> OutboxRetryDelay {
+        match self {
+            Self::Fixed(delay) => *delay,
+            Self::Exponential(backoff) => backoff.delay_for(attempt_count),
+            Self::Steps(steps) => steps.delay_for(attempt_count),
+        }
+    }
+}
+
+impl From<OutboxRetryDelay> for OutboxRetrySchedule {
+    fn from(value: OutboxRetryDelay) -> Self {
+        Self::Fixed(value)
+    }
+}
+
+impl From<OutboxRetrySteps> for OutboxRetrySchedule {
+    fn from(value: OutboxRetrySteps) -> Self {
+        Self::Steps(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    fn attempt(value: i64) -> OutboxAttemptCount {
+        OutboxAttemptCount::try_from(value).expect("attempt count should be valid")
+    }
+
+    fn exponential(jitter: OutboxRetryJitter) -> OutboxRetrySchedule {
+        OutboxRetrySchedule::exponential(
+            OutboxRetryDelay::from(Duration::seconds(1)),
+            OutboxRetryBackoffMultiplier::try_from(2.0).expect("multiplier should be valid"),
+            OutboxRetryDelay::from(Duration::seconds(30)),
+            jitter,
+        )
+        .expect("schedule should be valid")
+    }
+
+    #[test]
+    fn fixed_returns_same_delay_for_every_attempt() {
+        let delay = OutboxRetryDelay::from(Duration::seconds(5));
+        let schedule = OutboxRetrySchedule::from(delay);
+
+        assert_eq!(schedule.delay_for(attempt(1)), delay);
+        assert_eq!(schedule.delay_for(attempt(10)), delay);
+    }
+
+    #[test]
+    fn exponential_grows_by_multiplier_and_caps_at_max() {
+        let schedule = exponential(OutboxRetryJitter::None);
+
+        let delays = (1..=7)
+            .map(|value| schedule.delay_for(attempt(value)).value().num_seconds())
+            .collect::<Vec<_>>();
+
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 30, 30]);
+        assert_eq!(
+            schedule.delay_for(attempt(i64::MAX)).value(),
+            Duration::seconds(30)
+        );
+    }
+
+    #[test]
+    fn full_jitter_stays_between_zero_and_exponential_delay() {
+        let schedule = exponential(OutboxRetryJitter::Full);
+
+        for _ in 0..100 {
+            let delay = schedule.delay_for(attempt(3)).value();
+            assert!(delay >= Duration::zero() && delay <= Duration::seconds(4));
+        }
+    }
+
+    #[test]
+    fn decorrelated_jitter_stays_between_base_and_capped_previous_delay() {
+        let schedule = exponential(OutboxRetryJitter::Decorrelated);
+
+        for _ in 0..100 {
+            let delay = schedule.delay_for(attempt(3)).value();
+            assert!(delay >= Duration::seconds(1) && delay <= Duration::seconds(6));
+
+            let delay = schedule.delay_for(attempt(20)).value();
+            assert!(delay >= Duration::seconds(1) && delay <= Duration::seconds(30));
+        }
+    }
+
+    #[test]
+    fn exponential_rejects_base_greater_than_max() {
+        let error = OutboxRetrySchedule::exponential(
+            OutboxRetryDelay::from(Duration::seconds(10)),
+            OutboxRetryBackoffMultiplier::default(),
+            OutboxRetryDelay::from(Duration::seconds(1)),
+            OutboxRetryJitter::None,
+        )
+        .expect_err("base greater than max should be rejected");
+
+        assert!(matches!(
+            error,
+            OutboxRetryScheduleError::BaseGreaterThanMax { .. }
+        ));
+    }
+
+    #[test]
+    fn exponential_rejects_negative_base() {
+        let error = OutboxRetrySchedule::exponential(
+            OutboxRetryDelay::from(Duration::seconds(-1)),
+            OutboxRetryBackoffMultiplier::default(),
+            OutboxRetryDelay::from(Duration::seconds(1)),
+            OutboxRetryJitter::None,
+        )
+        .expect_err("negative base should be rejected");
+
+        assert!(matches!(error, OutboxRetryScheduleError::NegativeDelay(_)));
+    }
+
+    #[test]
+    fn steps_use_explicit_delays() {
+        let steps = OutboxRetrySteps::new(vec![
+            OutboxRetryDelay::from(Duration::seconds(1)),
+            OutboxRetryDelay::from(Duration::minutes(1)),
+        ])
+        .expect("steps should be valid");
+        let schedule = OutboxRetrySchedule::from(steps);
+
+        assert_eq!(schedule.delay_for(attempt(2)).value(), Duration::minutes(1));
+    }
+}
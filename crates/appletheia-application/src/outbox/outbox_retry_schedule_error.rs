@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+use super::OutboxRetryDelay;
+
+#[derive(Debug, Error)]
+pub enum OutboxRetryScheduleError {
+    #[error("base delay must be less than or equal to max delay (base={base:?}, max={max:?})")]
+    BaseGreaterThanMax {
+        base: OutboxRetryDelay,
+        max: OutboxRetryDelay,
+    },
+
+    #[error("retry delays must not be negative, got {0:?}")]
+    NegativeDelay(OutboxRetryDelay),
+}
@@ -0,0 +1,78 @@
+use chrono::Duration;
+
+use super::{OutboxAttemptCount, OutboxRetryDelay, OutboxRetryStepsError};
+
+/// Explicit retry delays indexed by attempt; the last step repeats once the list is exhausted.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct OutboxRetrySteps(Vec<OutboxRetryDelay>);
+
+impl OutboxRetrySteps {
+    pub fn new(steps: Vec<OutboxRetryDelay>) -> Result<Self, OutboxRetryStepsError> {
+        if steps.is_empty() {
+            return Err(OutboxRetryStepsError::Empty);
+        }
+        if let Some(step) = steps.iter().find(|step| step.value() < Duration::zero()) {
+            return Err(OutboxRetryStepsError::NegativeDelay(*step));
+        }
+        Ok(Self(steps))
+    }
+
+    pub fn value(&self) -> &[OutboxRetryDelay] {
+        &self.0
+    }
+
+    /// Returns the delay for the given attempt, where attempt `1` uses the first step.
+    pub fn delay_for(&self, attempt_count: OutboxAttemptCount) -> OutboxRetryDelay {
+        let index = usize::try_from(attempt_count.value().saturating_sub(1))
+            .unwrap_or(0)
+            .min(self.0.len() - 1);
+        self.0[index]
+    }
+}
+
+impl TryFrom<Vec<OutboxRetryDelay>> for OutboxRetrySteps {
+    type Error = OutboxRetryStepsError;
+
+    fn try_from(value: Vec<OutboxRetryDelay>) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(value: i64) -> OutboxAttemptCount {
+        OutboxAttemptCount::try_from(value).expect("attempt count should be valid")
+    }
+
+    #[test]
+    fn new_rejects_empty_steps() {
+        let error = OutboxRetrySteps::new(Vec::new()).expect_err("empty steps should be rejected");
+        assert!(matches!(error, OutboxRetryStepsError::Empty));
+    }
+
+    #[test]
+    fn new_rejects_negative_steps() {
+        let error = OutboxRetrySteps::new(vec![
+            OutboxRetryDelay::from(Duration::seconds(1)),
+            OutboxRetryDelay::from(Duration::seconds(-1)),
+        ])
+        .expect_err("negative steps should be rejected");
+        assert!(matches!(error, OutboxRetryStepsError::NegativeDelay(_)));
+    }
+
+    #[test]
+    fn delay_for_repeats_last_step() {
+        let steps = OutboxRetrySteps::new(vec![
+            OutboxRetryDelay::from(Duration::seconds(1)),
+            OutboxRetryDelay::from(Duration::seconds(10)),
+        ])
+        .expect("steps should be valid");
+
+        assert_eq!(steps.delay_for(attempt(0)).value(), Duration::seconds(1));
+        assert_eq!(steps.delay_for(attempt(1)).value(), Duration::seconds(1));
+        assert_eq!(steps.delay_for(attempt(2)).value(), Duration::seconds(10));
+        assert_eq!(steps.delay_for(attempt(7)).value(), Duration::seconds(10));
+    }
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+
+use super::OutboxRetryDelay;
+
+#[derive(Debug, Error)]
+pub enum OutboxRetryStepsError {
+    #[error("retry steps must contain at least one delay")]
+    Empty,
+
+    #[error("retry delays must not be negative, got {0:?}")]
+    NegativeDelay(OutboxRetryDelay),
+}
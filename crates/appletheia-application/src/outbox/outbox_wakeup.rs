@@ -0,0 +1,8 @@
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OutboxWakeup {
+    /// A writer signalled that new work may be available.
+    Notified,
+
+    /// The poll interval elapsed without a signal.
+    TimedOut,
+}
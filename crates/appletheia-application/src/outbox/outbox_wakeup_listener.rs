@@ -0,0 +1,13 @@
+use super::{OutboxPollInterval, OutboxWakeup, OutboxWakeupListenerError};
+
+/// Lets an idle relay wake up as soon as new outbox work is signalled.
+///
+/// Implementations must return no later than `timeout`, so polling keeps working as a safety
+/// net when a signal is lost.
+#[allow(async_fn_in_trait)]
+pub trait OutboxWakeupListener: Send + Sync {
+    async fn wait(
+        &self,
+        timeout: OutboxPollInterval,
+    ) -> Result<OutboxWakeup, OutboxWakeupListenerError>;
+}
@@ -0,0 +1,9 @@
+use std::error::Error;
+
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum OutboxWakeupListenerError {
+    #[error("wakeup listener connection failed: {0}")]
+    Connection(#[source] Box<dyn Error + Send + Sync + 'static>),
+}
@@ -0,0 +1,33 @@
+use std::time::Duration as StdDuration;
+
+use tokio::time::sleep;
+
+use super::{OutboxPollInterval, OutboxWakeup, OutboxWakeupListener, OutboxWakeupListenerError};
+
+/// Never receives signals; simply sleeps for the poll interval.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PollingOutboxWakeupListener;
+
+impl PollingOutboxWakeupListener {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl OutboxWakeupListener for PollingOutboxWakeupListener {
+    async fn wait(
+        &self,
+        timeout: OutboxPollInterval,
+    ) -> Result<OutboxWakeup, OutboxWakeupListenerError> {
+        let sleep_duration = timeout
+            .value()
+            .to_std()
+            .unwrap_or_else(|_| StdDuration::from_secs(0));
+
+        if sleep_duration > StdDuration::from_secs(0) {
+            sleep(sleep_duration).await;
+        }
+
+        Ok(OutboxWakeup::TimedOut)
+    }
+}
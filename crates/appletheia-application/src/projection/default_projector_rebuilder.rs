@@ -1,6 +1,9 @@
 use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
 
+use metrics::counter;
+
 use crate::event::EventFeedReader;
+use crate::telemetry::MetricName;
 use crate::unit_of_work::UnitOfWork;
 use crate::unit_of_work::UnitOfWorkFactory;
 
@@ -141,6 +144,11 @@ where
 
                 uow.commit().await?;
                 processed_event_count = processed_event_count.saturating_add(1);
+                counter!(
+                    MetricName::ProjectorRebuildEvents.as_str(),
+                    "projector" => descriptor.name.value(),
+                )
+                .increment(1);
             }
         }
 
@@ -1,4 +1,11 @@
+use std::time::Instant;
+
+use chrono::Utc;
+use metrics::{counter, gauge, histogram};
+use tracing::{Instrument, info_span};
+
 use crate::event::EventEnvelope;
+use crate::telemetry::MetricName;
 use crate::unit_of_work::UnitOfWork;
 use crate::unit_of_work::UnitOfWorkFactory;
 
@@ -63,15 +70,49 @@ where
         projector: &PJ,
         event: &EventEnvelope,
     ) -> Result<ProjectorRunReport, ProjectorRunnerError> {
-        let mut uow = self.uow_factory.begin().await?;
+        let descriptor = <PJ::Spec as ProjectorSpec>::DESCRIPTOR;
+        let span = info_span!(
+            "projector.project",
+            projector.name = %descriptor.name,
+            event.name = %event.event_name,
+            event.id = %event.event_id,
+            correlation_id = %event.correlation_id,
+        );
+        if let Some(trace_context) = &event.context.trace_context {
+            trace_context.set_as_parent_of(&span);
+        }
 
-        let result = self.project_inner(&mut uow, projector, event).await;
-        match result {
-            Ok(report) => {
-                uow.commit().await?;
-                Ok(report)
+        let started_at = Instant::now();
+        let report = async {
+            let mut uow = self.uow_factory.begin().await?;
+
+            let result = self.project_inner(&mut uow, projector, event).await;
+            match result {
+                Ok(report) => {
+                    uow.commit().await?;
+                    Ok(report)
+                }
+                Err(error) => Err(uow.rollback_with_operation_error(error).await?),
             }
-            Err(error) => Err(uow.rollback_with_operation_error(error).await?),
         }
+        .instrument(span)
+        .await?;
+
+        let projector_name = descriptor.name.value();
+        counter!(
+            MetricName::ProjectorEvents.as_str(),
+            "projector" => projector_name,
+            "report" => report.as_str(),
+        )
+        .increment(1);
+        if report == ProjectorRunReport::Applied {
+            histogram!(MetricName::ProjectorProcessingDuration.as_str(), "projector" => projector_name)
+                .record(started_at.elapsed());
+            let lag = Utc::now() - event.occurred_at.value();
+            gauge!(MetricName::ProjectorLag.as_str(), "projector" => projector_name)
+                .set(lag.num_milliseconds().max(0) as f64 / 1000.0);
+        }
+
+        Ok(report)
     }
 }
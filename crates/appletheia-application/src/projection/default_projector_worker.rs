@@ -1,7 +1,8 @@
-use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::error::Error;
 
 use crate::{
-    Consumer, ConsumerGroup, Delivery, Subscriber,
+    ConcurrentDeliveryProcessor, Consumer, ConsumerGroup, Delivery, DeliveryFlowControl,
+    Subscriber, SupervisedWorker, WorkerStopSignal,
     event::{EventEnvelope, EventSelector},
 };
 
@@ -11,7 +12,8 @@ pub struct DefaultProjectorWorker<PJ, S, R> {
     runner: R,
     subscriber: S,
     projector: PJ,
-    stop_requested: AtomicBool,
+    delivery_processor: ConcurrentDeliveryProcessor,
+    stop_signal: WorkerStopSignal,
 }
 
 impl<PJ, S, R> DefaultProjectorWorker<PJ, S, R> {
@@ -20,9 +22,16 @@ impl<PJ, S, R> DefaultProjectorWorker<PJ, S, R> {
             runner,
             subscriber,
             projector,
-            stop_requested: AtomicBool::new(false),
+            delivery_processor: ConcurrentDeliveryProcessor::default(),
+            stop_signal: WorkerStopSignal::new(),
         }
     }
+
+    /// Processes deliveries concurrently within `flow_control`; the default is sequential.
+    pub fn with_flow_control(mut self, flow_control: DeliveryFlowControl) -> Self {
+        self.delivery_processor = ConcurrentDeliveryProcessor::new(flow_control);
+        self
+    }
 }
 
 impl<PJ, S, R> ProjectorWorker for DefaultProjectorWorker<PJ, S, R>
@@ -36,42 +45,58 @@ where
     type Projector = PJ;
 
     fn is_stop_requested(&self) -> bool {
-        self.stop_requested.load(AtomicOrdering::SeqCst)
+        self.stop_signal.is_requested()
     }
 
     fn request_graceful_stop(&mut self) {
-        self.stop_requested.store(true, AtomicOrdering::SeqCst);
+        self.stop_signal.request();
     }
 
     async fn run_forever(&mut self) -> Result<(), ProjectorWorkerError> {
         let descriptor = <PJ::Spec as ProjectorSpec>::DESCRIPTOR;
         let consumer_group = ConsumerGroup::from(descriptor.name);
-        let mut consumer = self
+        let consumer = self
             .subscriber
             .subscribe(&consumer_group, descriptor.subscription)
             .await?;
 
-        while !self.is_stop_requested() {
-            let mut delivery = consumer.next().await?;
+        let runner = &self.runner;
+        let projector = &self.projector;
 
-            if !descriptor.subscription.matches(delivery.message()) {
-                delivery.ack().await?;
-            } else {
-                let result = self
-                    .runner
-                    .project(&self.projector, delivery.message())
-                    .await;
+        self.delivery_processor
+            .run(consumer, &self.stop_signal, |mut delivery| async move {
+                if !descriptor.subscription.matches(delivery.message()) {
+                    delivery.ack().await?;
+                    return Ok(());
+                }
 
-                match result {
+                match runner.project(projector, delivery.message()).await {
                     Ok(_) => delivery.ack().await?,
                     Err(error) => {
                         delivery.nack().await?;
                         return Err(error.into());
                     }
                 }
-            }
-        }
 
-        Ok(())
+                Ok(())
+            })
+            .await
+    }
+}
+
+impl<PJ, S, R> SupervisedWorker for DefaultProjectorWorker<PJ, S, R>
+where
+    PJ: Projector,
+    S: Subscriber<EventEnvelope, Selector = EventSelector>,
+    S::Consumer: Consumer<EventEnvelope>,
+    <S::Consumer as Consumer<EventEnvelope>>::Delivery: Delivery<EventEnvelope>,
+    R: ProjectorRunner<Uow = PJ::Uow>,
+{
+    fn stop_signal(&self) -> WorkerStopSignal {
+        self.stop_signal.clone()
+    }
+
+    async fn run_supervised(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(self.run_forever().await?)
     }
 }
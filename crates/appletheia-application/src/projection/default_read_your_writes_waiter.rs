@@ -1,11 +1,13 @@
 use std::time::Duration as StdDuration;
 
+use metrics::histogram;
 use tokio::time::Instant;
 
 use appletheia_domain::EventId;
 
 use crate::event::{EventEnvelope, EventLookup};
 use crate::request_context::{CausationId, CorrelationId, MessageId};
+use crate::telemetry::MetricName;
 use crate::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
 
 use super::{
@@ -185,16 +187,8 @@ where
             }
         }
     }
-}
 
-impl<U, L, P> ReadYourWritesWaiter for DefaultReadYourWritesWaiter<U, L, P>
-where
-    U: UnitOfWorkFactory,
-    U::Uow: UnitOfWork,
-    L: EventLookup<Uow = U::Uow>,
-    P: ProjectorProcessedEventStore<Uow = U::Uow>,
-{
-    async fn wait(
+    async fn wait_for_target(
         &self,
         target: ReadYourWritesTarget,
         timeout: ReadYourWritesTimeout,
@@ -241,6 +235,37 @@ where
     }
 }
 
+impl<U, L, P> ReadYourWritesWaiter for DefaultReadYourWritesWaiter<U, L, P>
+where
+    U: UnitOfWorkFactory,
+    U::Uow: UnitOfWork,
+    L: EventLookup<Uow = U::Uow>,
+    P: ProjectorProcessedEventStore<Uow = U::Uow>,
+{
+    async fn wait(
+        &self,
+        target: ReadYourWritesTarget,
+        timeout: ReadYourWritesTimeout,
+        poll_interval: ReadYourWritesPollInterval,
+        projector_dependencies: ProjectorDependencies<'_>,
+    ) -> Result<(), ReadYourWritesWaitError> {
+        let started_at = Instant::now();
+        let result = self
+            .wait_for_target(target, timeout, poll_interval, projector_dependencies)
+            .await;
+
+        let outcome = match &result {
+            Ok(()) => "caught_up",
+            Err(ReadYourWritesWaitError::Timeout { .. }) => "timed_out",
+            Err(_) => "failed",
+        };
+        histogram!(MetricName::ReadYourWritesWaitDuration.as_str(), "outcome" => outcome)
+            .record(started_at.elapsed());
+
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
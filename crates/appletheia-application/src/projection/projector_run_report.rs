@@ -3,3 +3,12 @@ pub enum ProjectorRunReport {
     Applied,
     SkippedAlreadyProcessed,
 }
+
+impl ProjectorRunReport {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Applied => "applied",
+            Self::SkippedAlreadyProcessed => "skipped_already_processed",
+        }
+    }
+}
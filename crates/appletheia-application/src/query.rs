@@ -1,16 +1,26 @@
 pub mod default_query_dispatcher;
+pub mod noop_query_middleware;
 pub mod query_consistency;
 pub mod query_dispatcher;
 pub mod query_dispatcher_error;
 pub mod query_handler;
+pub mod query_invocation;
+pub mod query_middleware;
+pub mod query_middleware_error;
+pub mod query_middleware_stack;
 pub mod query_name;
 pub mod query_options;
 
 pub use default_query_dispatcher::DefaultQueryDispatcher;
+pub use noop_query_middleware::NoopQueryMiddleware;
 pub use query_consistency::QueryConsistency;
 pub use query_dispatcher::QueryDispatcher;
 pub use query_dispatcher_error::QueryDispatcherError;
 pub use query_handler::QueryHandler;
+pub use query_invocation::QueryInvocation;
+pub use query_middleware::QueryMiddleware;
+pub use query_middleware_error::QueryMiddlewareError;
+pub use query_middleware_stack::QueryMiddlewareStack;
 pub use query_name::QueryName;
 pub use query_options::QueryOptions;
 
@@ -1,27 +1,35 @@
+use tracing::{Instrument, info_span};
+
 use crate::authorization::{AuthorizationPlan, Authorizer, PrincipalRequirement};
 use crate::projection::{ProjectorDependencies, ProjectorDescriptor, ReadYourWritesWaiter};
 use crate::request_context::{Principal, RequestContext};
 use crate::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
 
-use super::{QueryConsistency, QueryDispatcher, QueryDispatcherError, QueryHandler, QueryOptions};
+use super::{
+    NoopQueryMiddleware, Query, QueryConsistency, QueryDispatcher, QueryDispatcherError,
+    QueryHandler, QueryInvocation, QueryMiddleware, QueryMiddlewareStack, QueryOptions,
+};
 
-pub struct DefaultQueryDispatcher<W, U, AZ>
+pub struct DefaultQueryDispatcher<W, U, AZ, M = NoopQueryMiddleware>
 where
     W: ReadYourWritesWaiter,
     U: UnitOfWorkFactory,
     U::Uow: UnitOfWork,
+    M: QueryMiddleware,
 {
     read_your_writes_waiter: W,
     uow_factory: U,
     authorizer: AZ,
+    middleware: M,
 }
 
-impl<W, U, AZ> DefaultQueryDispatcher<W, U, AZ>
+impl<W, U, AZ, M> DefaultQueryDispatcher<W, U, AZ, M>
 where
     W: ReadYourWritesWaiter,
     U: UnitOfWorkFactory,
     U::Uow: UnitOfWork,
     AZ: Authorizer,
+    M: QueryMiddleware,
 {
     fn authorization_dependencies(
         principal: &Principal,
@@ -69,20 +77,36 @@ where
             read_your_writes_waiter,
             uow_factory,
             authorizer,
+            middleware: NoopQueryMiddleware::new(),
         }
     }
 }
 
-impl<W, U, AZ> QueryDispatcher for DefaultQueryDispatcher<W, U, AZ>
+impl<W, U, AZ, M> DefaultQueryDispatcher<W, U, AZ, M>
 where
     W: ReadYourWritesWaiter,
     U: UnitOfWorkFactory,
     U::Uow: UnitOfWork,
     AZ: Authorizer,
+    M: QueryMiddleware,
 {
-    type Uow = U::Uow;
+    /// Adds `middleware` inside the ones already configured, so its `before` hook runs last.
+    pub fn with_middleware<NextM>(
+        self,
+        middleware: NextM,
+    ) -> DefaultQueryDispatcher<W, U, AZ, QueryMiddlewareStack<M, NextM>>
+    where
+        NextM: QueryMiddleware,
+    {
+        DefaultQueryDispatcher {
+            read_your_writes_waiter: self.read_your_writes_waiter,
+            uow_factory: self.uow_factory,
+            authorizer: self.authorizer,
+            middleware: QueryMiddlewareStack::new(self.middleware, middleware),
+        }
+    }
 
-    async fn dispatch<H>(
+    async fn dispatch_with_middleware<H>(
         &self,
         handler: &H,
         request_context: &RequestContext,
@@ -90,7 +114,35 @@ where
         options: QueryOptions,
     ) -> Result<H::Output, QueryDispatcherError<H::Error>>
     where
-        H: QueryHandler<Uow = Self::Uow>,
+        H: QueryHandler<Uow = U::Uow>,
+    {
+        self.middleware
+            .before(&QueryInvocation::new(request_context, &query, options))
+            .await
+            .map_err(QueryDispatcherError::Middleware)?;
+
+        let result = self
+            .dispatch_in_span(handler, request_context, query, options)
+            .await;
+
+        let invocation = QueryInvocation::<H::Query>::handled(request_context, options);
+        match &result {
+            Ok(output) => self.middleware.after(&invocation, output).await,
+            Err(error) => self.middleware.on_error(&invocation, error).await,
+        }
+
+        result
+    }
+
+    async fn dispatch_in_span<H>(
+        &self,
+        handler: &H,
+        request_context: &RequestContext,
+        query: H::Query,
+        options: QueryOptions,
+    ) -> Result<H::Output, QueryDispatcherError<H::Error>>
+    where
+        H: QueryHandler<Uow = U::Uow>,
     {
         let authorization_plan = handler
             .authorization_plan(&query)
@@ -150,8 +202,43 @@ where
     }
 }
 
+impl<W, U, AZ, M> QueryDispatcher for DefaultQueryDispatcher<W, U, AZ, M>
+where
+    W: ReadYourWritesWaiter,
+    U: UnitOfWorkFactory,
+    U::Uow: UnitOfWork,
+    AZ: Authorizer,
+    M: QueryMiddleware,
+{
+    type Uow = U::Uow;
+
+    async fn dispatch<H>(
+        &self,
+        handler: &H,
+        request_context: &RequestContext,
+        query: H::Query,
+        options: QueryOptions,
+    ) -> Result<H::Output, QueryDispatcherError<H::Error>>
+    where
+        H: QueryHandler<Uow = Self::Uow>,
+    {
+        let span = info_span!(
+            "query.dispatch",
+            query.name = %H::Query::NAME,
+            correlation_id = %request_context.correlation_id,
+            message_id = %request_context.message_id,
+        );
+
+        self.dispatch_with_middleware(handler, request_context, query, options)
+            .instrument(span)
+            .await
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::{Arc, Mutex};
+
     use uuid::Uuid;
 
     use super::DefaultQueryDispatcher;
@@ -166,7 +253,11 @@ mod tests {
         ProjectorDependencies, ProjectorDescriptor, ProjectorName, ReadYourWritesPollInterval,
         ReadYourWritesTimeout, ReadYourWritesWaitError, ReadYourWritesWaiter,
     };
-    use crate::request_context::Principal;
+    use crate::query::{
+        Query, QueryDispatcher, QueryDispatcherError, QueryHandler, QueryInvocation,
+        QueryMiddleware, QueryMiddlewareError, QueryName, QueryOptions,
+    };
+    use crate::request_context::{CorrelationId, MessageId, Principal, RequestContext};
     use crate::unit_of_work::{
         UnitOfWork, UnitOfWorkError, UnitOfWorkFactory, UnitOfWorkFactoryError,
     };
@@ -309,4 +400,142 @@ mod tests {
             vec![PROJECTOR]
         );
     }
+
+    struct TestQuery;
+
+    impl Query for TestQuery {
+        const NAME: QueryName = QueryName::new("test");
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("query handler failed")]
+    struct TestHandlerError;
+
+    struct TestQueryHandler;
+
+    impl QueryHandler for TestQueryHandler {
+        type Query = TestQuery;
+        type Output = u32;
+        type Error = TestHandlerError;
+        type Uow = TestUow;
+
+        async fn handle(
+            &self,
+            _uow: &mut Self::Uow,
+            _request_context: &RequestContext,
+            _query: Self::Query,
+        ) -> Result<Self::Output, Self::Error> {
+            Ok(7)
+        }
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("rejected by test middleware")]
+    struct TestRejection;
+
+    struct RecordingMiddleware {
+        name: &'static str,
+        rejects: bool,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl RecordingMiddleware {
+        fn new(name: &'static str, rejects: bool, log: &Arc<Mutex<Vec<String>>>) -> Self {
+            Self {
+                name,
+                rejects,
+                log: Arc::clone(log),
+            }
+        }
+
+        fn record(&self, hook: String) {
+            self.log.lock().expect("lock").push(hook);
+        }
+    }
+
+    impl QueryMiddleware for RecordingMiddleware {
+        async fn before<Q: Query>(
+            &self,
+            invocation: &QueryInvocation<'_, Q>,
+        ) -> Result<(), QueryMiddlewareError> {
+            self.record(format!("{}.before({})", self.name, invocation.query_name()));
+            if self.rejects {
+                return Err(QueryMiddlewareError::Rejected(Box::new(TestRejection)));
+            }
+            Ok(())
+        }
+
+        async fn after<Q: Query, O>(&self, _invocation: &QueryInvocation<'_, Q>, _output: &O) {
+            self.record(format!("{}.after", self.name));
+        }
+
+        async fn on_error<Q: Query>(
+            &self,
+            _invocation: &QueryInvocation<'_, Q>,
+            _error: &(dyn std::error::Error + Send + Sync + 'static),
+        ) {
+            self.record(format!("{}.on_error", self.name));
+        }
+    }
+
+    fn system_request_context() -> RequestContext {
+        RequestContext::new(
+            CorrelationId::from(Uuid::now_v7()),
+            MessageId::new(),
+            Principal::System,
+        )
+        .expect("request context should be valid")
+    }
+
+    #[tokio::test]
+    async fn dispatch_runs_middleware_hooks_as_nested_layers() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = TestDispatcher::new(TestWaiter, TestUowFactory, TestAuthorizer)
+            .with_middleware(RecordingMiddleware::new("outer", false, &log))
+            .with_middleware(RecordingMiddleware::new("inner", false, &log));
+
+        let output = dispatcher
+            .dispatch(
+                &TestQueryHandler,
+                &system_request_context(),
+                TestQuery,
+                QueryOptions::default(),
+            )
+            .await
+            .expect("query should succeed");
+
+        assert_eq!(output, 7);
+        assert_eq!(
+            *log.lock().expect("lock"),
+            vec![
+                "outer.before(test)",
+                "inner.before(test)",
+                "inner.after",
+                "outer.after",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn dispatch_returns_middleware_error_when_rejected() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = TestDispatcher::new(TestWaiter, TestUowFactory, TestAuthorizer)
+            .with_middleware(RecordingMiddleware::new("outer", false, &log))
+            .with_middleware(RecordingMiddleware::new("inner", true, &log));
+
+        let result = dispatcher
+            .dispatch(
+                &TestQueryHandler,
+                &system_request_context(),
+                TestQuery,
+                QueryOptions::default(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(QueryDispatcherError::Middleware(_))));
+        assert_eq!(
+            *log.lock().expect("lock"),
+            vec!["outer.before(test)", "inner.before(test)", "outer.on_error",]
+        );
+    }
 }
@@ -0,0 +1,13 @@
+use super::QueryMiddleware;
+
+/// Middleware that does nothing; the default for `DefaultQueryDispatcher`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopQueryMiddleware;
+
+impl NoopQueryMiddleware {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl QueryMiddleware for NoopQueryMiddleware {}
@@ -6,6 +6,8 @@ use crate::authorization::AuthorizerError;
 use crate::projection::ReadYourWritesWaitError;
 use crate::unit_of_work::{UnitOfWorkError, UnitOfWorkFactoryError};
 
+use super::QueryMiddlewareError;
+
 #[derive(Debug, ThisError)]
 pub enum QueryDispatcherError<HE>
 where
@@ -25,4 +27,7 @@ where
 
     #[error("authorizer error: {0}")]
     Authorizer(#[from] AuthorizerError),
+
+    #[error(transparent)]
+    Middleware(#[from] QueryMiddlewareError),
 }
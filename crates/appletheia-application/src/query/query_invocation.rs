@@ -0,0 +1,50 @@
+use crate::request_context::RequestContext;
+
+use super::{Query, QueryName, QueryOptions};
+
+/// A query dispatch as seen by [`QueryMiddleware`](super::QueryMiddleware) hooks.
+///
+/// The query is moved into the handler, so only `before` hooks see it; `after` and `on_error`
+/// hooks receive an invocation built with `handled`.
+#[derive(Debug)]
+pub struct QueryInvocation<'a, Q: Query> {
+    request_context: &'a RequestContext,
+    query: Option<&'a Q>,
+    options: QueryOptions,
+}
+
+impl<'a, Q: Query> QueryInvocation<'a, Q> {
+    pub fn new(request_context: &'a RequestContext, query: &'a Q, options: QueryOptions) -> Self {
+        Self {
+            request_context,
+            query: Some(query),
+            options,
+        }
+    }
+
+    /// Invocation of a query that has already been moved into its handler.
+    pub fn handled(request_context: &'a RequestContext, options: QueryOptions) -> Self {
+        Self {
+            request_context,
+            query: None,
+            options,
+        }
+    }
+
+    pub fn request_context(&self) -> &'a RequestContext {
+        self.request_context
+    }
+
+    pub fn query_name(&self) -> QueryName {
+        Q::NAME
+    }
+
+    /// Returns the query until it has been moved into its handler.
+    pub fn query(&self) -> Option<&'a Q> {
+        self.query
+    }
+
+    pub fn options(&self) -> QueryOptions {
+        self.options
+    }
+}
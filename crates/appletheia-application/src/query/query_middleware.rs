@@ -0,0 +1,29 @@
+use std::error::Error;
+
+use super::{Query, QueryInvocation, QueryMiddlewareError};
+
+/// Cross-cutting behavior wrapped around every query dispatch.
+///
+/// `before` runs ahead of authorization and can reject the query. Once `before` completed,
+/// exactly one of `after` or `on_error` runs when the dispatch finishes; `on_error` also sees
+/// rejections from the `before` of middlewares nested inside. A middleware whose `before`
+/// rejected or never ran sees neither hook. Middlewares are combined with
+/// [`QueryMiddlewareStack`](super::QueryMiddlewareStack).
+#[allow(async_fn_in_trait)]
+pub trait QueryMiddleware: Send + Sync {
+    async fn before<Q: Query>(
+        &self,
+        _invocation: &QueryInvocation<'_, Q>,
+    ) -> Result<(), QueryMiddlewareError> {
+        Ok(())
+    }
+
+    async fn after<Q: Query, O>(&self, _invocation: &QueryInvocation<'_, Q>, _output: &O) {}
+
+    async fn on_error<Q: Query>(
+        &self,
+        _invocation: &QueryInvocation<'_, Q>,
+        _error: &(dyn Error + Send + Sync + 'static),
+    ) {
+    }
+}
@@ -0,0 +1,19 @@
+use std::error::Error;
+
+use thiserror::Error;
+
+use crate::rate_limit::RateLimitError;
+
+/// Rejection of a query by a middleware's `before` hook.
+///
+/// Rate limits are reported as [`QueryMiddlewareError::RateLimited`] so callers can read
+/// `retry_after` without downcasting; other middlewares reject with their own error as the
+/// source of [`QueryMiddlewareError::Rejected`].
+#[derive(Debug, Error)]
+pub enum QueryMiddlewareError {
+    #[error("query rate limited: {0}")]
+    RateLimited(#[from] RateLimitError),
+
+    #[error("query rejected by middleware: {0}")]
+    Rejected(#[source] Box<dyn Error + Send + Sync + 'static>),
+}
@@ -0,0 +1,63 @@
+use std::error::Error;
+
+use super::{Query, QueryInvocation, QueryMiddleware, QueryMiddlewareError};
+
+/// Two middlewares nested like layers: `outer` wraps `inner`.
+///
+/// `before` hooks run outer first and stop at the first rejection, which unwinds `outer` through
+/// `on_error` if its `before` completed. `after` and `on_error` hooks run inner first.
+#[derive(Clone, Debug, Default)]
+pub struct QueryMiddlewareStack<Outer, Inner> {
+    outer: Outer,
+    inner: Inner,
+}
+
+impl<Outer, Inner> QueryMiddlewareStack<Outer, Inner>
+where
+    Outer: QueryMiddleware,
+    Inner: QueryMiddleware,
+{
+    pub fn new(outer: Outer, inner: Inner) -> Self {
+        Self { outer, inner }
+    }
+
+    pub fn outer(&self) -> &Outer {
+        &self.outer
+    }
+
+    pub fn inner(&self) -> &Inner {
+        &self.inner
+    }
+}
+
+impl<Outer, Inner> QueryMiddleware for QueryMiddlewareStack<Outer, Inner>
+where
+    Outer: QueryMiddleware,
+    Inner: QueryMiddleware,
+{
+    async fn before<Q: Query>(
+        &self,
+        invocation: &QueryInvocation<'_, Q>,
+    ) -> Result<(), QueryMiddlewareError> {
+        self.outer.before(invocation).await?;
+        if let Err(error) = self.inner.before(invocation).await {
+            self.outer.on_error(invocation, &error).await;
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    async fn after<Q: Query, O>(&self, invocation: &QueryInvocation<'_, Q>, output: &O) {
+        self.inner.after(invocation, output).await;
+        self.outer.after(invocation, output).await;
+    }
+
+    async fn on_error<Q: Query>(
+        &self,
+        invocation: &QueryInvocation<'_, Q>,
+        error: &(dyn Error + Send + Sync + 'static),
+    ) {
+        self.inner.on_error(invocation, error).await;
+        self.outer.on_error(invocation, error).await;
+    }
+}
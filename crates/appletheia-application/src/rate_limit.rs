@@ -0,0 +1,23 @@
+pub mod rate_limit_decision;
+pub mod rate_limit_error;
+pub mod rate_limit_key;
+pub mod rate_limit_middleware;
+pub mod rate_limit_policies;
+pub mod rate_limit_policy;
+pub mod rate_limit_policy_error;
+pub mod rate_limit_store;
+pub mod rate_limit_store_error;
+pub mod rate_limit_target;
+pub mod token_bucket;
+
+pub use rate_limit_decision::RateLimitDecision;
+pub use rate_limit_error::RateLimitError;
+pub use rate_limit_key::RateLimitKey;
+pub use rate_limit_middleware::RateLimitMiddleware;
+pub use rate_limit_policies::RateLimitPolicies;
+pub use rate_limit_policy::RateLimitPolicy;
+pub use rate_limit_policy_error::RateLimitPolicyError;
+pub use rate_limit_store::RateLimitStore;
+pub use rate_limit_store_error::RateLimitStoreError;
+pub use rate_limit_target::RateLimitTarget;
+pub use token_bucket::TokenBucket;
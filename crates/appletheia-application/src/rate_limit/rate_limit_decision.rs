@@ -0,0 +1,16 @@
+use chrono::Duration;
+
+/// Outcome of taking a token from a rate limit bucket.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RateLimitDecision {
+    /// A token was taken; `remaining` whole tokens are left in the bucket.
+    Allowed { remaining: u32 },
+    /// The bucket is empty; the next token becomes available after `retry_after`.
+    Limited { retry_after: Duration },
+}
+
+impl RateLimitDecision {
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, Self::Allowed { .. })
+    }
+}
@@ -0,0 +1,26 @@
+use chrono::Duration;
+use thiserror::Error;
+
+use super::{RateLimitKey, RateLimitStoreError};
+
+#[derive(Debug, Error)]
+pub enum RateLimitError {
+    #[error("rate limit exceeded for {key}, retry after {} ms", retry_after.num_milliseconds())]
+    Exceeded {
+        key: RateLimitKey,
+        retry_after: Duration,
+    },
+
+    #[error(transparent)]
+    Store(#[from] RateLimitStoreError),
+}
+
+impl RateLimitError {
+    /// How long the caller should wait before retrying, if the limit was exceeded.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::Exceeded { retry_after, .. } => Some(*retry_after),
+            Self::Store(_) => None,
+        }
+    }
+}
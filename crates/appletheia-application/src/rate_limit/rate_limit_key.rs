@@ -0,0 +1,76 @@
+use std::fmt::{self, Display};
+
+use crate::request_context::ActorRef;
+
+use super::RateLimitTarget;
+
+/// Identifies one token bucket: an actor dispatching one command or query.
+///
+/// Anonymous callers carry no identity, so they all share the bucket of [`ActorRef::Anonymous`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RateLimitKey {
+    actor: ActorRef,
+    target: RateLimitTarget,
+}
+
+impl RateLimitKey {
+    pub fn new(actor: ActorRef, target: RateLimitTarget) -> Self {
+        Self { actor, target }
+    }
+
+    pub fn actor(&self) -> &ActorRef {
+        &self.actor
+    }
+
+    pub fn target(&self) -> RateLimitTarget {
+        self.target
+    }
+}
+
+impl Display for RateLimitKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.actor {
+            ActorRef::Anonymous => write!(f, "anonymous")?,
+            ActorRef::System => write!(f, "system")?,
+            ActorRef::Subject { subject } => write!(
+                f,
+                "subject:{}:{}",
+                subject.aggregate_type, subject.aggregate_id
+            )?,
+        }
+        write!(f, "/{}", self.target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::authorization::AggregateRef;
+    use crate::command::CommandName;
+    use crate::event::{AggregateIdValue, AggregateTypeOwned};
+    use crate::query::QueryName;
+
+    #[test]
+    fn display_combines_actor_and_target() {
+        let subject = AggregateRef {
+            aggregate_type: AggregateTypeOwned::try_from("user").expect("valid aggregate type"),
+            aggregate_id: AggregateIdValue::from(Uuid::nil()),
+        };
+        let command_key = RateLimitKey::new(
+            ActorRef::Subject { subject },
+            RateLimitTarget::Command(CommandName::new("open_account")),
+        );
+        let query_key = RateLimitKey::new(
+            ActorRef::Anonymous,
+            RateLimitTarget::Query(QueryName::new("account_balance")),
+        );
+
+        assert_eq!(
+            command_key.to_string(),
+            format!("subject:user:{}/command:open_account", Uuid::nil())
+        );
+        assert_eq!(query_key.to_string(), "anonymous/query:account_balance");
+    }
+}
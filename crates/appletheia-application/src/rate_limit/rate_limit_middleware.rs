@@ -0,0 +1,222 @@
+use metrics::counter;
+
+use crate::command::{Command, CommandInvocation, CommandMiddleware, CommandMiddlewareError};
+use crate::query::{Query, QueryInvocation, QueryMiddleware, QueryMiddlewareError};
+use crate::request_context::ActorRef;
+use crate::telemetry::MetricName;
+
+use super::{
+    RateLimitDecision, RateLimitError, RateLimitKey, RateLimitPolicies, RateLimitStore,
+    RateLimitTarget,
+};
+
+/// Rejects commands and queries once their actor has used up the configured rate limit.
+///
+/// The same middleware can be installed on both dispatchers; commands and queries are counted
+/// in separate buckets per actor. Dispatches by [`ActorRef::System`] are never limited so that
+/// sagas and workers cannot be throttled by their own traffic.
+///
+/// All anonymous callers share one bucket per command or query, so a single anonymous client
+/// can use up the budget of every other one. Give anonymous dispatches a generous policy and
+/// limit them per client address in front of the application, or require authentication.
+///
+/// A limited dispatch, or a store failure while counting it, is rejected with the middleware
+/// error's `RateLimited` variant.
+pub struct RateLimitMiddleware<S> {
+    store: S,
+    policies: RateLimitPolicies,
+}
+
+impl<S: RateLimitStore> RateLimitMiddleware<S> {
+    pub fn new(store: S, policies: RateLimitPolicies) -> Self {
+        Self { store, policies }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn policies(&self) -> &RateLimitPolicies {
+        &self.policies
+    }
+
+    async fn acquire(
+        &self,
+        actor: &ActorRef,
+        target: RateLimitTarget,
+    ) -> Result<(), RateLimitError> {
+        if matches!(actor, ActorRef::System) {
+            return Ok(());
+        }
+        let Some(policy) = self.policies.policy_for(target) else {
+            return Ok(());
+        };
+
+        let key = RateLimitKey::new(actor.clone(), target);
+        match self.store.try_acquire(&key, &policy).await? {
+            RateLimitDecision::Allowed { .. } => Ok(()),
+            RateLimitDecision::Limited { retry_after } => {
+                counter!(MetricName::RateLimited.as_str(), "target" => target.to_string())
+                    .increment(1);
+                Err(RateLimitError::Exceeded { key, retry_after })
+            }
+        }
+    }
+}
+
+impl<S: RateLimitStore> CommandMiddleware for RateLimitMiddleware<S> {
+    async fn before<C: Command>(
+        &self,
+        invocation: &CommandInvocation<'_, C>,
+    ) -> Result<(), CommandMiddlewareError> {
+        let target = RateLimitTarget::Command(invocation.command_name());
+        self.acquire(&invocation.request_context().actor, target)
+            .await
+            .map_err(CommandMiddlewareError::RateLimited)
+    }
+}
+
+impl<S: RateLimitStore> QueryMiddleware for RateLimitMiddleware<S> {
+    async fn before<Q: Query>(
+        &self,
+        invocation: &QueryInvocation<'_, Q>,
+    ) -> Result<(), QueryMiddlewareError> {
+        let target = RateLimitTarget::Query(invocation.query_name());
+        self.acquire(&invocation.request_context().actor, target)
+            .await
+            .map_err(QueryMiddlewareError::RateLimited)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use chrono::{Duration, Utc};
+    use serde::{Deserialize, Serialize};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::authorization::AggregateRef;
+    use crate::command::{CommandName, CommandOptions};
+    use crate::event::{AggregateIdValue, AggregateTypeOwned};
+    use crate::query::{QueryName, QueryOptions};
+    use crate::rate_limit::{RateLimitPolicy, RateLimitStoreError, TokenBucket};
+    use crate::request_context::{CorrelationId, MessageId, Principal, RequestContext};
+
+    #[derive(Default)]
+    struct TestStore {
+        buckets: Mutex<HashMap<RateLimitKey, TokenBucket>>,
+    }
+
+    impl RateLimitStore for TestStore {
+        async fn try_acquire(
+            &self,
+            key: &RateLimitKey,
+            policy: &RateLimitPolicy,
+        ) -> Result<RateLimitDecision, RateLimitStoreError> {
+            let now = Utc::now();
+            let mut buckets = self.buckets.lock().expect("lock");
+            let bucket = buckets
+                .entry(key.clone())
+                .or_insert_with(|| TokenBucket::full(policy, now));
+            Ok(bucket.try_acquire(policy, now))
+        }
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    struct TestCommand {}
+
+    impl Command for TestCommand {
+        const NAME: CommandName = CommandName::new("test");
+    }
+
+    struct TestQuery;
+
+    impl Query for TestQuery {
+        const NAME: QueryName = QueryName::new("test");
+    }
+
+    fn request_context(principal: Principal) -> RequestContext {
+        RequestContext::new(
+            CorrelationId::from(Uuid::now_v7()),
+            MessageId::from(Uuid::now_v7()),
+            principal,
+        )
+        .expect("request context should be valid")
+    }
+
+    fn authenticated(id: Uuid) -> Principal {
+        Principal::Authenticated {
+            subject: AggregateRef {
+                aggregate_type: AggregateTypeOwned::try_from("user").expect("valid aggregate type"),
+                aggregate_id: AggregateIdValue::from(id),
+            },
+        }
+    }
+
+    fn middleware() -> RateLimitMiddleware<TestStore> {
+        RateLimitMiddleware::new(
+            TestStore::default(),
+            RateLimitPolicies::new().with_default_policy(
+                RateLimitPolicy::new(1, Duration::hours(1)).expect("policy should be valid"),
+            ),
+        )
+    }
+
+    async fn dispatch_command(
+        middleware: &RateLimitMiddleware<TestStore>,
+        request_context: &RequestContext,
+    ) -> Result<(), CommandMiddlewareError> {
+        let options = CommandOptions::default();
+        CommandMiddleware::before(
+            middleware,
+            &CommandInvocation::new(request_context, &TestCommand {}, &options),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn before_rejects_actor_once_bucket_is_empty() {
+        let middleware = middleware();
+        let alice = request_context(authenticated(Uuid::now_v7()));
+        let bob = request_context(authenticated(Uuid::now_v7()));
+
+        dispatch_command(&middleware, &alice)
+            .await
+            .expect("first command should pass");
+        let error = dispatch_command(&middleware, &alice)
+            .await
+            .expect_err("second command should be limited");
+        dispatch_command(&middleware, &bob)
+            .await
+            .expect("other actors have their own bucket");
+        QueryMiddleware::before(
+            &middleware,
+            &QueryInvocation::new(&alice, &TestQuery, QueryOptions::default()),
+        )
+        .await
+        .expect("queries have their own bucket");
+
+        let retry_after = match error {
+            CommandMiddlewareError::RateLimited(error) => {
+                error.retry_after().expect("retry after should be set")
+            }
+            other => panic!("expected RateLimited error, got {other:?}"),
+        };
+        assert!(retry_after > Duration::minutes(59) && retry_after <= Duration::hours(1));
+    }
+
+    #[tokio::test]
+    async fn before_never_limits_system_actor() {
+        let middleware = middleware();
+        let system = request_context(Principal::System);
+
+        for _ in 0..3 {
+            dispatch_command(&middleware, &system)
+                .await
+                .expect("system commands should pass");
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use crate::command::CommandName;
+use crate::query::QueryName;
+
+use super::{RateLimitPolicy, RateLimitTarget};
+
+/// Chooses the policy that applies to each command and query.
+///
+/// Targets without an explicit policy fall back to the default policy, and are not limited
+/// when there is none. A quota is a policy with a long refill period, e.g. 10 000 per day.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimitPolicies {
+    default_policy: Option<RateLimitPolicy>,
+    policies: HashMap<RateLimitTarget, RateLimitPolicy>,
+}
+
+impl RateLimitPolicies {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_default_policy(mut self, policy: RateLimitPolicy) -> Self {
+        self.default_policy = Some(policy);
+        self
+    }
+
+    pub fn with_command_policy(
+        mut self,
+        command_name: CommandName,
+        policy: RateLimitPolicy,
+    ) -> Self {
+        self.policies
+            .insert(RateLimitTarget::Command(command_name), policy);
+        self
+    }
+
+    pub fn with_query_policy(mut self, query_name: QueryName, policy: RateLimitPolicy) -> Self {
+        self.policies
+            .insert(RateLimitTarget::Query(query_name), policy);
+        self
+    }
+
+    pub fn policy_for(&self, target: RateLimitTarget) -> Option<RateLimitPolicy> {
+        self.policies.get(&target).copied().or(self.default_policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_for_prefers_target_policy_over_default() {
+        let default_policy = RateLimitPolicy::per_second(10).expect("policy should be valid");
+        let command_policy = RateLimitPolicy::per_minute(1).expect("policy should be valid");
+        let policies = RateLimitPolicies::new()
+            .with_default_policy(default_policy)
+            .with_command_policy(CommandName::new("open_account"), command_policy);
+
+        assert_eq!(
+            policies.policy_for(RateLimitTarget::Command(CommandName::new("open_account"))),
+            Some(command_policy)
+        );
+        assert_eq!(
+            policies.policy_for(RateLimitTarget::Query(QueryName::new("account_balance"))),
+            Some(default_policy)
+        );
+        assert_eq!(
+            RateLimitPolicies::new()
+                .policy_for(RateLimitTarget::Query(QueryName::new("account_balance"))),
+            None
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use chrono::Duration;
+
+use super::RateLimitPolicyError;
+
+/// Token bucket settings: up to `capacity` dispatches in a burst, refilled evenly so that a full
+/// bucket is restored after `refill_period`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitPolicy {
+    capacity: u32,
+    refill_period: Duration,
+}
+
+impl RateLimitPolicy {
+    pub fn new(capacity: u32, refill_period: Duration) -> Result<Self, RateLimitPolicyError> {
+        if capacity == 0 {
+            return Err(RateLimitPolicyError::ZeroCapacity);
+        }
+        if refill_period <= Duration::zero() {
+            return Err(RateLimitPolicyError::NonPositiveRefillPeriod);
+        }
+
+        Ok(Self {
+            capacity,
+            refill_period,
+        })
+    }
+
+    pub fn per_second(capacity: u32) -> Result<Self, RateLimitPolicyError> {
+        Self::new(capacity, Duration::seconds(1))
+    }
+
+    pub fn per_minute(capacity: u32) -> Result<Self, RateLimitPolicyError> {
+        Self::new(capacity, Duration::minutes(1))
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    pub fn refill_period(&self) -> Duration {
+        self.refill_period
+    }
+
+    /// Time it takes to refill a single token.
+    pub fn token_interval(&self) -> Duration {
+        self.refill_period / i32::try_from(self.capacity).unwrap_or(i32::MAX)
+    }
+
+    /// Time it takes to refill `tokens` tokens, rounded up to at least one millisecond.
+    pub fn refill_time(&self, tokens: f64) -> Duration {
+        let token_interval = self.token_interval().num_milliseconds() as f64;
+        Duration::milliseconds(((tokens * token_interval).ceil() as i64).max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_rejects_empty_buckets_and_non_positive_periods() {
+        assert!(matches!(
+            RateLimitPolicy::new(0, Duration::seconds(1)),
+            Err(RateLimitPolicyError::ZeroCapacity)
+        ));
+        assert!(matches!(
+            RateLimitPolicy::new(1, Duration::zero()),
+            Err(RateLimitPolicyError::NonPositiveRefillPeriod)
+        ));
+    }
+
+    #[test]
+    fn token_interval_spreads_period_over_capacity() {
+        let policy = RateLimitPolicy::per_minute(120).expect("policy should be valid");
+
+        assert_eq!(policy.token_interval(), Duration::milliseconds(500));
+    }
+}
@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RateLimitPolicyError {
+    #[error("rate limit capacity must be greater than zero")]
+    ZeroCapacity,
+
+    #[error("rate limit refill period must be positive")]
+    NonPositiveRefillPeriod,
+}
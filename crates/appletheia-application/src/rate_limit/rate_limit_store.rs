@@ -0,0 +1,14 @@
+use super::{RateLimitDecision, RateLimitKey, RateLimitPolicy, RateLimitStoreError};
+
+/// Keeps the token buckets of rate-limited actors.
+///
+/// A bucket that does not exist yet starts full. Stores shared between processes must take
+/// tokens atomically so concurrent dispatches cannot overdraw a bucket.
+#[allow(async_fn_in_trait)]
+pub trait RateLimitStore: Send + Sync {
+    async fn try_acquire(
+        &self,
+        key: &RateLimitKey,
+        policy: &RateLimitPolicy,
+    ) -> Result<RateLimitDecision, RateLimitStoreError>;
+}
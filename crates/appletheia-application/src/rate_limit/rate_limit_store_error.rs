@@ -0,0 +1,9 @@
+use std::error::Error;
+
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum RateLimitStoreError {
+    #[error("persistence error: {0}")]
+    Persistence(#[source] Box<dyn Error + Send + Sync + 'static>),
+}
@@ -0,0 +1,20 @@
+use std::fmt::{self, Display};
+
+use crate::command::CommandName;
+use crate::query::QueryName;
+
+/// Operation whose dispatches are counted against a rate limit.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RateLimitTarget {
+    Command(CommandName),
+    Query(QueryName),
+}
+
+impl Display for RateLimitTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Command(name) => write!(f, "command:{name}"),
+            Self::Query(name) => write!(f, "query:{name}"),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+
+use super::{RateLimitDecision, RateLimitPolicy};
+
+/// Tokens left in one bucket as of `updated_at`.
+///
+/// Stores keep one bucket per [`RateLimitKey`](super::RateLimitKey) and share this arithmetic,
+/// so the in-memory and persistent stores limit identically.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TokenBucket {
+    tokens: f64,
+    updated_at: DateTime<Utc>,
+}
+
+impl TokenBucket {
+    pub fn new(tokens: f64, updated_at: DateTime<Utc>) -> Self {
+        Self { tokens, updated_at }
+    }
+
+    pub fn full(policy: &RateLimitPolicy, now: DateTime<Utc>) -> Self {
+        Self::new(f64::from(policy.capacity()), now)
+    }
+
+    pub fn tokens(&self) -> f64 {
+        self.tokens
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    /// Refills the bucket up to `now` and takes one token if a whole one is available.
+    pub fn try_acquire(
+        &mut self,
+        policy: &RateLimitPolicy,
+        now: DateTime<Utc>,
+    ) -> RateLimitDecision {
+        self.refill(policy, now);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return RateLimitDecision::Allowed {
+                remaining: self.tokens.floor() as u32,
+            };
+        }
+
+        RateLimitDecision::Limited {
+            retry_after: policy.refill_time(1.0 - self.tokens),
+        }
+    }
+
+    fn refill(&mut self, policy: &RateLimitPolicy, now: DateTime<Utc>) {
+        if now <= self.updated_at {
+            return;
+        }
+
+        let elapsed = (now - self.updated_at).num_milliseconds() as f64;
+        let refill_period = policy.refill_period().num_milliseconds() as f64;
+        let capacity = f64::from(policy.capacity());
+
+        self.tokens = (self.tokens + elapsed * capacity / refill_period).min(capacity);
+        self.updated_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    #[test]
+    fn try_acquire_allows_bursts_up_to_capacity() {
+        let policy = RateLimitPolicy::per_second(2).expect("policy should be valid");
+        let now = Utc::now();
+        let mut bucket = TokenBucket::full(&policy, now);
+
+        assert_eq!(
+            bucket.try_acquire(&policy, now),
+            RateLimitDecision::Allowed { remaining: 1 }
+        );
+        assert_eq!(
+            bucket.try_acquire(&policy, now),
+            RateLimitDecision::Allowed { remaining: 0 }
+        );
+        assert_eq!(
+            bucket.try_acquire(&policy, now),
+            RateLimitDecision::Limited {
+                retry_after: Duration::milliseconds(500),
+            }
+        );
+    }
+
+    #[test]
+    fn try_acquire_refills_over_time_without_exceeding_capacity() {
+        let policy = RateLimitPolicy::per_second(2).expect("policy should be valid");
+        let now = Utc::now();
+        let mut bucket = TokenBucket::new(0.0, now);
+
+        assert_eq!(
+            bucket.try_acquire(&policy, now + Duration::milliseconds(200)),
+            RateLimitDecision::Limited {
+                retry_after: Duration::milliseconds(300),
+            }
+        );
+        assert!(
+            bucket
+                .try_acquire(&policy, now + Duration::milliseconds(500))
+                .is_allowed()
+        );
+        assert_eq!(
+            bucket.try_acquire(&policy, now + Duration::minutes(1)),
+            RateLimitDecision::Allowed { remaining: 1 }
+        );
+    }
+}
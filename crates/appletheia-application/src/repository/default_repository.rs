@@ -2,8 +2,8 @@ use std::marker::PhantomData;
 use std::ops::Bound;
 
 use appletheia_domain::{
-    Aggregate, AggregateError, AggregateState, AggregateVersion, AggregateVersionRange,
-    UniqueConstraints,
+    Aggregate, AggregateError, AggregateId, AggregateState, AggregateVersion,
+    AggregateVersionRange, UniqueConstraints,
 };
 
 use crate::event::{EventReader, EventWriter};
@@ -91,6 +91,11 @@ where
         self.find_at_version(uow, id, None).await
     }
 
+    #[tracing::instrument(
+        name = "repository.load",
+        skip_all,
+        fields(aggregate.type = %A::TYPE, aggregate.id = %id.value())
+    )]
     async fn find_at_version(
         &self,
         uow: &mut Self::Uow,
@@ -140,6 +145,11 @@ where
         self.find(uow, aggregate_id).await
     }
 
+    #[tracing::instrument(
+        name = "repository.save",
+        skip_all,
+        fields(aggregate.type = %A::TYPE, correlation_id = %request_context.correlation_id)
+    )]
     async fn save(
         &self,
         uow: &mut Self::Uow,
@@ -16,10 +16,13 @@ pub use request_context_error::RequestContextError;
 
 use serde::{Deserialize, Serialize};
 
+use crate::telemetry::TraceContext;
+
 /// Carries request-scoped metadata through the application pipeline.
 ///
 /// `principal` is kept out of serialized forms because it represents ambient runtime
-/// authentication context rather than transport metadata.
+/// authentication context rather than transport metadata. `trace_context` is persisted with
+/// the events written under this context so consumers can continue the same trace.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct RequestContext {
     pub correlation_id: CorrelationId,
@@ -28,6 +31,9 @@ pub struct RequestContext {
 
     #[serde(skip)]
     pub principal: Principal,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_context: Option<TraceContext>,
 }
 
 impl RequestContext {
@@ -44,8 +50,14 @@ impl RequestContext {
             message_id,
             actor,
             principal,
+            trace_context: None,
         })
     }
+
+    pub fn with_trace_context(mut self, trace_context: TraceContext) -> Self {
+        self.trace_context = Some(trace_context);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,33 @@
+pub mod default_retention_janitor;
+pub mod removed_row_count;
+pub mod retention_action;
+pub mod retention_batch_size;
+pub mod retention_cutoff;
+pub mod retention_interval;
+pub mod retention_janitor;
+pub mod retention_janitor_config;
+pub mod retention_janitor_error;
+pub mod retention_janitor_run_report;
+pub mod retention_period;
+pub mod retention_period_error;
+pub mod retention_policy;
+pub mod retention_store;
+pub mod retention_store_error;
+pub mod retention_target;
+
+pub use default_retention_janitor::DefaultRetentionJanitor;
+pub use removed_row_count::RemovedRowCount;
+pub use retention_action::RetentionAction;
+pub use retention_batch_size::RetentionBatchSize;
+pub use retention_cutoff::RetentionCutoff;
+pub use retention_interval::RetentionInterval;
+pub use retention_janitor::RetentionJanitor;
+pub use retention_janitor_config::RetentionJanitorConfig;
+pub use retention_janitor_error::RetentionJanitorError;
+pub use retention_janitor_run_report::RetentionJanitorRunReport;
+pub use retention_period::RetentionPeriod;
+pub use retention_period_error::RetentionPeriodError;
+pub use retention_policy::RetentionPolicy;
+pub use retention_store::RetentionStore;
+pub use retention_store_error::RetentionStoreError;
+pub use retention_target::RetentionTarget;
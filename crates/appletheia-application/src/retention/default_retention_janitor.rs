@@ -0,0 +1,130 @@
+use std::error::Error;
+use std::time::Duration as StdDuration;
+
+use tokio::time::sleep;
+
+use crate::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+use crate::{SupervisedWorker, WorkerStopSignal};
+
+use super::{
+    RemovedRowCount, RetentionCutoff, RetentionJanitor, RetentionJanitorConfig,
+    RetentionJanitorError, RetentionJanitorRunReport, RetentionStore,
+};
+
+/// Applies each configured retention policy in bounded batches.
+///
+/// Every batch runs in its own unit of work so locks are held only briefly. `run_forever`
+/// keeps draining while batches remove rows and sleeps for `idle_interval` once a full pass
+/// over all policies removed nothing.
+pub struct DefaultRetentionJanitor<UowFactory, S>
+where
+    UowFactory: UnitOfWorkFactory,
+    S: RetentionStore<Uow = UowFactory::Uow>,
+{
+    config: RetentionJanitorConfig,
+    store: S,
+    uow_factory: UowFactory,
+    stop_signal: WorkerStopSignal,
+}
+
+impl<UowFactory, S> DefaultRetentionJanitor<UowFactory, S>
+where
+    UowFactory: UnitOfWorkFactory,
+    S: RetentionStore<Uow = UowFactory::Uow>,
+{
+    pub fn new(config: RetentionJanitorConfig, store: S, uow_factory: UowFactory) -> Self {
+        Self {
+            config,
+            store,
+            uow_factory,
+            stop_signal: WorkerStopSignal::new(),
+        }
+    }
+}
+
+impl<UowFactory, S> RetentionJanitor for DefaultRetentionJanitor<UowFactory, S>
+where
+    UowFactory: UnitOfWorkFactory,
+    S: RetentionStore<Uow = UowFactory::Uow>,
+{
+    fn is_stop_requested(&self) -> bool {
+        self.stop_signal.is_requested()
+    }
+
+    fn request_graceful_stop(&mut self) {
+        self.stop_signal.request();
+    }
+
+    async fn run_forever(&self) -> Result<(), RetentionJanitorError> {
+        while !self.is_stop_requested() {
+            let run_report = self.run_once().await?;
+
+            if run_report == RetentionJanitorRunReport::Idle {
+                let sleep_duration = self
+                    .config
+                    .idle_interval
+                    .value()
+                    .to_std()
+                    .unwrap_or_else(|_| StdDuration::from_secs(0));
+
+                if sleep_duration > StdDuration::from_secs(0) {
+                    tokio::select! {
+                        () = sleep(sleep_duration) => {}
+                        () = self.stop_signal.requested() => break,
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn run_once(&self) -> Result<RetentionJanitorRunReport, RetentionJanitorError> {
+        let batch_size = self.config.batch_size;
+        let mut removed_row_count = RemovedRowCount::zero();
+
+        for policy in &self.config.policies {
+            let cutoff = RetentionCutoff::before_now(policy.period);
+
+            let mut uow = self.uow_factory.begin().await?;
+            let removed = match self
+                .store
+                .remove_expired(&mut uow, policy, cutoff, batch_size)
+                .await
+            {
+                Ok(removed) => removed,
+                Err(source) => {
+                    return Err(uow
+                        .rollback_with_operation_error(RetentionJanitorError::Store {
+                            target: policy.target,
+                            source,
+                        })
+                        .await?);
+                }
+            };
+            uow.commit().await?;
+
+            removed_row_count = removed_row_count.saturating_add(removed);
+        }
+
+        if removed_row_count.is_zero() {
+            return Ok(RetentionJanitorRunReport::Idle);
+        }
+
+        Ok(RetentionJanitorRunReport::Progress { removed_row_count })
+    }
+}
+
+impl<UowFactory, S> SupervisedWorker for DefaultRetentionJanitor<UowFactory, S>
+where
+    UowFactory: UnitOfWorkFactory,
+    S: RetentionStore<Uow = UowFactory::Uow>,
+{
+    fn stop_signal(&self) -> WorkerStopSignal {
+        self.stop_signal.clone()
+    }
+
+    async fn run_supervised(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(self.run_forever().await?)
+    }
+}
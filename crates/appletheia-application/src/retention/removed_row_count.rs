@@ -0,0 +1,24 @@
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RemovedRowCount(u32);
+
+impl RemovedRowCount {
+    pub const fn zero() -> Self {
+        Self(0)
+    }
+
+    pub const fn value(&self) -> u32 {
+        self.0
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn from_u64_saturating(value: u64) -> Self {
+        Self(value.min(u32::MAX as u64) as u32)
+    }
+
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+}
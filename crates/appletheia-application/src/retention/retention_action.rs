@@ -0,0 +1,10 @@
+/// What happens to rows that fall outside a retention window.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RetentionAction {
+    /// Deletes the rows permanently.
+    #[default]
+    Purge,
+
+    /// Moves the rows into the matching archive table before deleting them.
+    Archive,
+}
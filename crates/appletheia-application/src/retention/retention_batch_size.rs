@@ -0,0 +1,18 @@
+use core::num::NonZeroU32;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct RetentionBatchSize(NonZeroU32);
+
+impl RetentionBatchSize {
+    pub fn new(value: NonZeroU32) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> NonZeroU32 {
+        self.0
+    }
+
+    pub fn as_i64(&self) -> i64 {
+        self.value().get() as i64
+    }
+}
@@ -0,0 +1,37 @@
+use std::{fmt, fmt::Display};
+
+use chrono::{DateTime, Utc};
+
+use super::RetentionPeriod;
+
+/// Rows completed strictly before this instant are eligible for removal.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct RetentionCutoff(DateTime<Utc>);
+
+impl RetentionCutoff {
+    pub fn before_now(period: RetentionPeriod) -> Self {
+        Self(Utc::now() - period.value())
+    }
+
+    pub fn value(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+impl From<DateTime<Utc>> for RetentionCutoff {
+    fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<RetentionCutoff> for DateTime<Utc> {
+    fn from(value: RetentionCutoff) -> Self {
+        value.0
+    }
+}
+
+impl Display for RetentionCutoff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
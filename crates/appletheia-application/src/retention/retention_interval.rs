@@ -0,0 +1,26 @@
+use chrono::Duration;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RetentionInterval(Duration);
+
+impl RetentionInterval {
+    pub fn new(value: Duration) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> Duration {
+        self.0
+    }
+}
+
+impl From<Duration> for RetentionInterval {
+    fn from(value: Duration) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<RetentionInterval> for Duration {
+    fn from(value: RetentionInterval) -> Self {
+        value.value()
+    }
+}
@@ -0,0 +1,12 @@
+use super::{RetentionJanitorError, RetentionJanitorRunReport};
+
+#[allow(async_fn_in_trait)]
+pub trait RetentionJanitor: Send + Sync {
+    fn is_stop_requested(&self) -> bool;
+
+    fn request_graceful_stop(&mut self);
+
+    async fn run_forever(&self) -> Result<(), RetentionJanitorError>;
+
+    async fn run_once(&self) -> Result<RetentionJanitorRunReport, RetentionJanitorError>;
+}
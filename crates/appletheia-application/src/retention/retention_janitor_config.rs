@@ -0,0 +1,8 @@
+use super::{RetentionBatchSize, RetentionInterval, RetentionPolicy};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetentionJanitorConfig {
+    pub policies: Vec<RetentionPolicy>,
+    pub batch_size: RetentionBatchSize,
+    pub idle_interval: RetentionInterval,
+}
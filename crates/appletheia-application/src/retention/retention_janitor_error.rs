@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::unit_of_work::{UnitOfWorkError, UnitOfWorkFactoryError};
+
+use super::{RetentionStoreError, RetentionTarget};
+
+#[derive(Debug, Error)]
+pub enum RetentionJanitorError {
+    #[error("retention store failed for {target}: {source}")]
+    Store {
+        target: RetentionTarget,
+        #[source]
+        source: RetentionStoreError,
+    },
+
+    #[error("unit of work error: {0}")]
+    UnitOfWork(#[from] UnitOfWorkError),
+
+    #[error("unit of work factory error: {0}")]
+    UnitOfWorkFactory(#[from] UnitOfWorkFactoryError),
+}
@@ -0,0 +1,7 @@
+use super::RemovedRowCount;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RetentionJanitorRunReport {
+    Progress { removed_row_count: RemovedRowCount },
+    Idle,
+}
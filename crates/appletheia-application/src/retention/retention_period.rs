@@ -0,0 +1,55 @@
+use chrono::Duration;
+
+use super::RetentionPeriodError;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RetentionPeriod(Duration);
+
+impl RetentionPeriod {
+    pub fn new(value: Duration) -> Result<Self, RetentionPeriodError> {
+        if value < Duration::zero() {
+            return Err(RetentionPeriodError::Negative);
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> Duration {
+        self.0
+    }
+}
+
+impl TryFrom<Duration> for RetentionPeriod {
+    type Error = RetentionPeriodError;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<RetentionPeriod> for Duration {
+    fn from(value: RetentionPeriod) -> Self {
+        value.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_non_negative_durations() {
+        let period = RetentionPeriod::new(Duration::days(7)).expect("period should be valid");
+
+        assert_eq!(period.value(), Duration::days(7));
+        assert!(RetentionPeriod::new(Duration::zero()).is_ok());
+    }
+
+    #[test]
+    fn rejects_negative_durations() {
+        let error = RetentionPeriod::new(Duration::seconds(-1))
+            .expect_err("negative period should be rejected");
+
+        assert!(matches!(error, RetentionPeriodError::Negative));
+    }
+}
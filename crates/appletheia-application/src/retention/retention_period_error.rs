@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RetentionPeriodError {
+    #[error("duration must be non-negative")]
+    Negative,
+}
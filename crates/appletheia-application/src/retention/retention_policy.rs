@@ -0,0 +1,26 @@
+use super::{RetentionAction, RetentionPeriod, RetentionTarget};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RetentionPolicy {
+    pub target: RetentionTarget,
+    pub period: RetentionPeriod,
+    pub action: RetentionAction,
+}
+
+impl RetentionPolicy {
+    pub fn purge(target: RetentionTarget, period: RetentionPeriod) -> Self {
+        Self {
+            target,
+            period,
+            action: RetentionAction::Purge,
+        }
+    }
+
+    pub fn archive(target: RetentionTarget, period: RetentionPeriod) -> Self {
+        Self {
+            target,
+            period,
+            action: RetentionAction::Archive,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::unit_of_work::UnitOfWork;
+
+use super::{
+    RemovedRowCount, RetentionBatchSize, RetentionCutoff, RetentionPolicy, RetentionStoreError,
+};
+
+#[allow(async_fn_in_trait)]
+pub trait RetentionStore: Send + Sync {
+    type Uow: UnitOfWork;
+
+    /// Removes at most `batch_size` rows of the policy's target that were completed before
+    /// `cutoff`, archiving them first when the policy asks for it.
+    ///
+    /// Implementations must skip rows that are still in flight: unpublished outbox rows,
+    /// idempotency entries without a completion, and rows locked by another transaction.
+    async fn remove_expired(
+        &self,
+        uow: &mut Self::Uow,
+        policy: &RetentionPolicy,
+        cutoff: RetentionCutoff,
+        batch_size: RetentionBatchSize,
+    ) -> Result<RemovedRowCount, RetentionStoreError>;
+}
@@ -0,0 +1,34 @@
+use std::{fmt, fmt::Display};
+
+/// Table family a retention policy applies to.
+///
+/// Outbox targets only cover published rows; idempotency only covers completed entries.
+/// Message queue dead letters are removed together with their message once no other consumer
+/// group still references it. Rate limit buckets idle for longer than every policy's refill
+/// period are full again, so they are deleted even by an archive policy.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RetentionTarget {
+    EventOutbox,
+    CommandOutbox,
+    Idempotency,
+    MessageQueueDeadLetters,
+    RateLimitBuckets,
+}
+
+impl RetentionTarget {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::EventOutbox => "event_outbox",
+            Self::CommandOutbox => "command_outbox",
+            Self::Idempotency => "idempotency",
+            Self::MessageQueueDeadLetters => "message_queue_dead_letters",
+            Self::RateLimitBuckets => "rate_limit_buckets",
+        }
+    }
+}
+
+impl Display for RetentionTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -1,6 +1,10 @@
+use metrics::counter;
+use tracing::{Instrument, Span, info_span};
+
 use crate::event::EventEnvelope;
 use crate::outbox::command::{CommandEnvelope, CommandOutboxEnqueuer};
 use crate::request_context::{CausationId, MessageId};
+use crate::telemetry::{MetricName, TraceContext};
 use crate::unit_of_work::UnitOfWork;
 use crate::unit_of_work::UnitOfWorkFactory;
 
@@ -101,6 +105,10 @@ where
                     CausationId::from(event.event_id),
                     command.options,
                 )
+                .map(|envelope| match TraceContext::from_span(&Span::current()) {
+                    Some(trace_context) => envelope.with_trace_context(trace_context),
+                    None => envelope,
+                })
             })
             .transpose()?;
         let dispatched_command_message_id = command.as_ref().map(|command| command.message_id);
@@ -139,15 +147,40 @@ where
         saga: &SG,
         event: &EventEnvelope,
     ) -> Result<SagaRunReport, SagaRunnerError> {
-        let mut uow = self.uow_factory.begin().await?;
+        let descriptor = <SG::Spec as SagaSpec>::DESCRIPTOR;
+        let span = info_span!(
+            "saga.handle_event",
+            saga.name = %descriptor.name,
+            event.name = %event.event_name,
+            event.id = %event.event_id,
+            correlation_id = %event.correlation_id,
+        );
+        if let Some(trace_context) = &event.context.trace_context {
+            trace_context.set_as_parent_of(&span);
+        }
 
-        let result = self.handle_event_inner(&mut uow, saga, event).await;
-        match result {
-            Ok(report) => {
-                uow.commit().await?;
-                Ok(report)
+        let report = async {
+            let mut uow = self.uow_factory.begin().await?;
+
+            let result = self.handle_event_inner(&mut uow, saga, event).await;
+            match result {
+                Ok(report) => {
+                    uow.commit().await?;
+                    Ok(report)
+                }
+                Err(error) => Err(uow.rollback_with_operation_error(error).await?),
             }
-            Err(error) => Err(uow.rollback_with_operation_error(error).await?),
         }
+        .instrument(span)
+        .await?;
+
+        counter!(
+            MetricName::SagaEvents.as_str(),
+            "saga" => descriptor.name.value(),
+            "report" => report.as_str(),
+        )
+        .increment(1);
+
+        Ok(report)
     }
 }
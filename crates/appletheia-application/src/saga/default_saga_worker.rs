@@ -1,8 +1,9 @@
-use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::error::Error;
 
 use super::{Saga, SagaRunner, SagaSpec, SagaWorker, SagaWorkerError};
 use crate::{
-    Consumer, ConsumerGroup, Delivery, Subscriber,
+    ConcurrentDeliveryProcessor, Consumer, ConsumerGroup, Delivery, DeliveryFlowControl,
+    Subscriber, SupervisedWorker, WorkerStopSignal,
     event::{EventEnvelope, EventSelector},
     messaging::Subscription,
 };
@@ -11,7 +12,8 @@ pub struct DefaultSagaWorker<SG, S, R> {
     saga_runner: R,
     subscriber: S,
     saga: SG,
-    stop_requested: AtomicBool,
+    delivery_processor: ConcurrentDeliveryProcessor,
+    stop_signal: WorkerStopSignal,
 }
 
 impl<SG, S, R> DefaultSagaWorker<SG, S, R> {
@@ -20,9 +22,16 @@ impl<SG, S, R> DefaultSagaWorker<SG, S, R> {
             saga_runner,
             subscriber,
             saga,
-            stop_requested: AtomicBool::new(false),
+            delivery_processor: ConcurrentDeliveryProcessor::default(),
+            stop_signal: WorkerStopSignal::new(),
         }
     }
+
+    /// Processes deliveries concurrently within `flow_control`; the default is sequential.
+    pub fn with_flow_control(mut self, flow_control: DeliveryFlowControl) -> Self {
+        self.delivery_processor = ConcurrentDeliveryProcessor::new(flow_control);
+        self
+    }
 }
 
 impl<SG, S, R> SagaWorker for DefaultSagaWorker<SG, S, R>
@@ -36,11 +45,11 @@ where
     type Saga = SG;
 
     fn is_stop_requested(&self) -> bool {
-        self.stop_requested.load(AtomicOrdering::SeqCst)
+        self.stop_signal.is_requested()
     }
 
     fn request_graceful_stop(&mut self) {
-        self.stop_requested.store(true, AtomicOrdering::SeqCst);
+        self.stop_signal.request();
     }
 
     async fn run_forever(&mut self) -> Result<(), SagaWorkerError> {
@@ -48,33 +57,48 @@ where
         let consumer_group = ConsumerGroup::from(descriptor.name);
         let subscription = Subscription::One(&descriptor.trigger_event);
 
-        let mut consumer = self
+        let consumer = self
             .subscriber
             .subscribe(&consumer_group, subscription)
             .await?;
 
-        while !self.is_stop_requested() {
-            let mut delivery = consumer.next().await?;
-
-            if !subscription.matches(delivery.message()) {
-                delivery.ack().await?;
-                continue;
-            }
+        let saga_runner = &self.saga_runner;
+        let saga = &self.saga;
 
-            let result = self
-                .saga_runner
-                .handle_event(&self.saga, delivery.message())
-                .await;
+        self.delivery_processor
+            .run(consumer, &self.stop_signal, |mut delivery| async move {
+                if !subscription.matches(delivery.message()) {
+                    delivery.ack().await?;
+                    return Ok(());
+                }
 
-            match result {
-                Ok(_) => delivery.ack().await?,
-                Err(error) => {
-                    delivery.nack().await?;
-                    return Err(error.into());
+                match saga_runner.handle_event(saga, delivery.message()).await {
+                    Ok(_) => delivery.ack().await?,
+                    Err(error) => {
+                        delivery.nack().await?;
+                        return Err(error.into());
+                    }
                 }
-            }
-        }
 
-        Ok(())
+                Ok(())
+            })
+            .await
+    }
+}
+
+impl<SG, S, R> SupervisedWorker for DefaultSagaWorker<SG, S, R>
+where
+    SG: Saga,
+    S: Subscriber<EventEnvelope, Selector = EventSelector>,
+    S::Consumer: Consumer<EventEnvelope>,
+    <S::Consumer as Consumer<EventEnvelope>>::Delivery: Delivery<EventEnvelope>,
+    R: SagaRunner,
+{
+    fn stop_signal(&self) -> WorkerStopSignal {
+        self.stop_signal.clone()
+    }
+
+    async fn run_supervised(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(self.run_forever().await?)
     }
 }
@@ -6,3 +6,15 @@ pub enum SagaRunReport {
     AlreadyRun,
     EventAlreadyProcessed,
 }
+
+impl SagaRunReport {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::CommandDispatched => "command_dispatched",
+            Self::NoCommandDispatched => "no_command_dispatched",
+            Self::PredecessorRunMissing => "predecessor_run_missing",
+            Self::AlreadyRun => "already_run",
+            Self::EventAlreadyProcessed => "event_already_processed",
+        }
+    }
+}
@@ -0,0 +1,19 @@
+pub mod supervised_worker;
+pub mod worker_name;
+pub mod worker_name_error;
+pub mod worker_status;
+pub mod worker_status_board;
+pub mod worker_stop_signal;
+pub mod worker_supervisor;
+pub mod worker_supervisor_config;
+pub mod worker_supervisor_error;
+
+pub use supervised_worker::SupervisedWorker;
+pub use worker_name::WorkerName;
+pub use worker_name_error::WorkerNameError;
+pub use worker_status::WorkerStatus;
+pub use worker_status_board::WorkerStatusBoard;
+pub use worker_stop_signal::WorkerStopSignal;
+pub use worker_supervisor::WorkerSupervisor;
+pub use worker_supervisor_config::WorkerSupervisorConfig;
+pub use worker_supervisor_error::WorkerSupervisorError;
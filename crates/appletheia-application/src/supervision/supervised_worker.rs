@@ -0,0 +1,14 @@
+use std::error::Error;
+
+use super::WorkerStopSignal;
+
+/// Long-running worker that a `WorkerSupervisor` can run, restart and stop.
+///
+/// `run_supervised` may be called again after it returned an error. It should return once the
+/// stop signal has been requested and the work in hand is settled.
+#[allow(async_fn_in_trait)]
+pub trait SupervisedWorker {
+    fn stop_signal(&self) -> WorkerStopSignal;
+
+    async fn run_supervised(&mut self) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
@@ -0,0 +1,25 @@
+use std::fmt::{self, Display};
+
+use super::WorkerNameError;
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct WorkerName(String);
+
+impl WorkerName {
+    pub fn new(value: String) -> Result<Self, WorkerNameError> {
+        if value.is_empty() {
+            return Err(WorkerNameError::Empty);
+        }
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for WorkerName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum WorkerNameError {
+    #[error("worker name is empty")]
+    Empty,
+}
@@ -0,0 +1,25 @@
+/// Lifecycle state of a supervised worker as reported to health probes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum WorkerStatus {
+    Running,
+
+    /// The worker crashed and is waiting to be restarted.
+    Restarting {
+        restart_count: u32,
+        last_error: String,
+    },
+
+    /// Stop was requested and the worker is draining.
+    Stopping,
+
+    Stopped,
+
+    /// The worker did not stop before the drain deadline and was cancelled.
+    Aborted,
+}
+
+impl WorkerStatus {
+    pub fn is_running(&self) -> bool {
+        matches!(self, Self::Running)
+    }
+}
@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use super::{WorkerName, WorkerStatus};
+
+/// Shared view of the status of every supervised worker.
+#[derive(Clone, Debug, Default)]
+pub struct WorkerStatusBoard {
+    statuses: Arc<Mutex<BTreeMap<WorkerName, WorkerStatus>>>,
+}
+
+impl WorkerStatusBoard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn statuses(&self) -> MutexGuard<'_, BTreeMap<WorkerName, WorkerStatus>> {
+        self.statuses.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn status(&self, name: &WorkerName) -> Option<WorkerStatus> {
+        self.statuses().get(name).cloned()
+    }
+
+    pub fn snapshot(&self) -> Vec<(WorkerName, WorkerStatus)> {
+        self.statuses()
+            .iter()
+            .map(|(name, status)| (name.clone(), status.clone()))
+            .collect()
+    }
+
+    /// Whether every registered worker is running, e.g. for a readiness probe.
+    pub fn all_running(&self) -> bool {
+        self.statuses().values().all(WorkerStatus::is_running)
+    }
+
+    pub(crate) fn set(&self, name: &WorkerName, status: WorkerStatus) {
+        self.statuses().insert(name.clone(), status);
+    }
+}
@@ -0,0 +1,48 @@
+use std::pin::pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+use tokio::sync::Notify;
+
+#[derive(Debug, Default)]
+struct WorkerStopSignalState {
+    requested: AtomicBool,
+    notify: Notify,
+}
+
+/// Cloneable graceful-stop flag shared by a worker and whoever supervises it.
+///
+/// Once requested, a stop stays requested; waiters are woken immediately.
+#[derive(Clone, Debug, Default)]
+pub struct WorkerStopSignal {
+    state: Arc<WorkerStopSignalState>,
+}
+
+impl WorkerStopSignal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn request(&self) {
+        self.state.requested.store(true, AtomicOrdering::SeqCst);
+        self.state.notify.notify_waiters();
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.state.requested.load(AtomicOrdering::SeqCst)
+    }
+
+    /// Completes once stop has been requested.
+    pub async fn requested(&self) {
+        loop {
+            let mut notified = pin!(self.state.notify.notified());
+            notified.as_mut().enable();
+
+            if self.is_requested() {
+                return;
+            }
+
+            notified.await;
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::error::Error;
+use std::future::Future;
+use std::pin::{Pin, pin};
+use std::time::Duration as StdDuration;
+
+use futures_util::stream::{FuturesUnordered, StreamExt};
+use tokio::time::{Instant, sleep};
+
+use super::{
+    SupervisedWorker, WorkerName, WorkerStatus, WorkerStatusBoard, WorkerStopSignal,
+    WorkerSupervisorConfig, WorkerSupervisorError,
+};
+
+type WorkerRun<'a> = Pin<Box<dyn Future<Output = Result<(), Box<dyn Error + Send + Sync>>> + 'a>>;
+
+trait DynSupervisedWorker {
+    fn stop_signal(&self) -> WorkerStopSignal;
+
+    fn run_supervised(&mut self) -> WorkerRun<'_>;
+}
+
+impl<W: SupervisedWorker> DynSupervisedWorker for W {
+    fn stop_signal(&self) -> WorkerStopSignal {
+        SupervisedWorker::stop_signal(self)
+    }
+
+    fn run_supervised(&mut self) -> WorkerRun<'_> {
+        Box::pin(SupervisedWorker::run_supervised(self))
+    }
+}
+
+/// Runs registered workers concurrently until shutdown.
+///
+/// A worker that fails is restarted after an exponential backoff. On shutdown every worker is
+/// asked to stop gracefully; workers still running when the drain deadline passes are dropped
+/// and reported as `Aborted`. All workers run on the task that awaits `run_until`.
+pub struct WorkerSupervisor {
+    config: WorkerSupervisorConfig,
+    workers: Vec<(WorkerName, Box<dyn DynSupervisedWorker>)>,
+    status_board: WorkerStatusBoard,
+}
+
+impl WorkerSupervisor {
+    pub fn new(config: WorkerSupervisorConfig) -> Self {
+        Self {
+            config,
+            workers: Vec::new(),
+            status_board: WorkerStatusBoard::new(),
+        }
+    }
+
+    pub fn with_worker<W>(mut self, name: WorkerName, worker: W) -> Self
+    where
+        W: SupervisedWorker + 'static,
+    {
+        self.workers.push((name, Box::new(worker)));
+        self
+    }
+
+    pub fn status_board(&self) -> WorkerStatusBoard {
+        self.status_board.clone()
+    }
+
+    /// Runs every worker until `shutdown` completes and they have drained, or until all of
+    /// them stopped on their own.
+    pub async fn run_until<F>(self, shutdown: F) -> Result<(), WorkerSupervisorError>
+    where
+        F: Future<Output = ()>,
+    {
+        let Self {
+            config,
+            workers,
+            status_board,
+        } = self;
+
+        let stop_signals = workers
+            .iter()
+            .map(|(name, worker)| (name.clone(), worker.stop_signal()))
+            .collect::<Vec<_>>();
+        let mut running = workers
+            .into_iter()
+            .map(|(name, worker)| Self::supervise(&config, &status_board, name, worker))
+            .collect::<FuturesUnordered<_>>();
+
+        let mut shutdown = pin!(shutdown);
+        loop {
+            tokio::select! {
+                () = &mut shutdown => break,
+                finished = running.next() => {
+                    if finished.is_none() {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+
+        for (name, stop_signal) in &stop_signals {
+            if status_board.status(name) != Some(WorkerStatus::Stopped) {
+                status_board.set(name, WorkerStatus::Stopping);
+            }
+            stop_signal.request();
+        }
+
+        let drain_deadline = config.drain_deadline.to_std().unwrap_or(StdDuration::ZERO);
+        let mut deadline = pin!(sleep(drain_deadline));
+        loop {
+            tokio::select! {
+                () = &mut deadline => break,
+                finished = running.next() => {
+                    if finished.is_none() {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        drop(running);
+
+        let mut workers = Vec::new();
+        for (name, _) in stop_signals {
+            if status_board.status(&name) != Some(WorkerStatus::Stopped) {
+                status_board.set(&name, WorkerStatus::Aborted);
+                workers.push(name);
+            }
+        }
+
+        Err(WorkerSupervisorError::DrainDeadlineExceeded { workers })
+    }
+
+    async fn supervise(
+        config: &WorkerSupervisorConfig,
+        status_board: &WorkerStatusBoard,
+        name: WorkerName,
+        mut worker: Box<dyn DynSupervisedWorker>,
+    ) {
+        let stop_signal = worker.stop_signal();
+        let restart_delay_max = config
+            .restart_delay_max
+            .to_std()
+            .unwrap_or(StdDuration::ZERO);
+        let mut restart_count = 0u32;
+
+        loop {
+            if !stop_signal.is_requested() {
+                status_board.set(&name, WorkerStatus::Running);
+            }
+            let started_at = Instant::now();
+            let result = worker.run_supervised().await;
+
+            let error = match result {
+                Err(error) if !stop_signal.is_requested() => error,
+                _ => break,
+            };
+
+            if started_at.elapsed() >= restart_delay_max {
+                restart_count = 0;
+            }
+            restart_count = restart_count.saturating_add(1);
+            status_board.set(
+                &name,
+                WorkerStatus::Restarting {
+                    restart_count,
+                    last_error: error.to_string(),
+                },
+            );
+
+            let restart_delay = config
+                .restart_delay(restart_count)
+                .to_std()
+                .unwrap_or(StdDuration::ZERO);
+            tokio::select! {
+                () = sleep(restart_delay) => {}
+                () = stop_signal.requested() => break,
+            }
+        }
+
+        status_board.set(&name, WorkerStatus::Stopped);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering as AtomicOrdering};
+
+    use chrono::Duration;
+    use tokio::sync::oneshot;
+
+    use super::*;
+
+    struct TestWorker {
+        stop_signal: WorkerStopSignal,
+        runs: Arc<AtomicU32>,
+        failing_runs: u32,
+        ignores_stop: bool,
+    }
+
+    impl TestWorker {
+        fn new(runs: Arc<AtomicU32>, failing_runs: u32) -> Self {
+            Self {
+                stop_signal: WorkerStopSignal::new(),
+                runs,
+                failing_runs,
+                ignores_stop: false,
+            }
+        }
+    }
+
+    impl SupervisedWorker for TestWorker {
+        fn stop_signal(&self) -> WorkerStopSignal {
+            self.stop_signal.clone()
+        }
+
+        async fn run_supervised(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+            let run = self.runs.fetch_add(1, AtomicOrdering::SeqCst) + 1;
+            if run <= self.failing_runs {
+                return Err(format!("run {run} failed").into());
+            }
+
+            if self.ignores_stop {
+                std::future::pending::<()>().await;
+            }
+            self.stop_signal.requested().await;
+            Ok(())
+        }
+    }
+
+    fn config() -> WorkerSupervisorConfig {
+        WorkerSupervisorConfig {
+            restart_delay_base: Duration::milliseconds(1),
+            restart_delay_max: Duration::milliseconds(10),
+            drain_deadline: Duration::milliseconds(50),
+        }
+    }
+
+    fn name(value: &str) -> WorkerName {
+        WorkerName::new(value.to_owned()).expect("worker name should be valid")
+    }
+
+    #[tokio::test]
+    async fn restarts_failed_worker_and_stops_on_shutdown() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let supervisor = WorkerSupervisor::new(config())
+            .with_worker(name("projector"), TestWorker::new(Arc::clone(&runs), 2));
+        let status_board = supervisor.status_board();
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
+
+        let supervising = supervisor.run_until(async {
+            let _ = shutdown_receiver.await;
+        });
+        let shutting_down = async {
+            while !status_board.all_running() || runs.load(AtomicOrdering::SeqCst) < 3 {
+                sleep(StdDuration::from_millis(1)).await;
+            }
+            let _ = shutdown_sender.send(());
+        };
+        let (result, ()) = tokio::join!(supervising, shutting_down);
+
+        assert!(result.is_ok());
+        assert_eq!(runs.load(AtomicOrdering::SeqCst), 3);
+        assert_eq!(
+            status_board.status(&name("projector")),
+            Some(WorkerStatus::Stopped)
+        );
+    }
+
+    #[tokio::test]
+    async fn aborts_workers_that_miss_the_drain_deadline() {
+        let mut stuck = TestWorker::new(Arc::new(AtomicU32::new(0)), 0);
+        stuck.ignores_stop = true;
+        let supervisor = WorkerSupervisor::new(config())
+            .with_worker(
+                name("relay"),
+                TestWorker::new(Arc::new(AtomicU32::new(0)), 0),
+            )
+            .with_worker(name("stuck"), stuck);
+        let status_board = supervisor.status_board();
+
+        let result = supervisor.run_until(std::future::ready(())).await;
+
+        match result {
+            Err(WorkerSupervisorError::DrainDeadlineExceeded { workers }) => {
+                assert_eq!(workers, vec![name("stuck")]);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+        assert_eq!(
+            status_board.status(&name("relay")),
+            Some(WorkerStatus::Stopped)
+        );
+        assert_eq!(
+            status_board.status(&name("stuck")),
+            Some(WorkerStatus::Aborted)
+        );
+    }
+}
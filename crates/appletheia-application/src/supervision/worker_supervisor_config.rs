@@ -0,0 +1,54 @@
+use chrono::Duration;
+
+/// Restart and shutdown timing of a `WorkerSupervisor`.
+///
+/// The restart delay doubles with every consecutive crash, from `restart_delay_base` up to
+/// `restart_delay_max`; a worker that ran for at least `restart_delay_max` before crashing
+/// starts over at the base delay.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkerSupervisorConfig {
+    pub restart_delay_base: Duration,
+    pub restart_delay_max: Duration,
+    pub drain_deadline: Duration,
+}
+
+impl WorkerSupervisorConfig {
+    pub(crate) fn restart_delay(&self, restart_count: u32) -> Duration {
+        let exponent = restart_count.saturating_sub(1).min(30);
+        self.restart_delay_base
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.restart_delay_max)
+            .min(self.restart_delay_max)
+    }
+}
+
+impl Default for WorkerSupervisorConfig {
+    fn default() -> Self {
+        Self {
+            restart_delay_base: Duration::seconds(1),
+            restart_delay_max: Duration::minutes(1),
+            drain_deadline: Duration::seconds(30),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restart_delay_doubles_up_to_max() {
+        let config = WorkerSupervisorConfig {
+            restart_delay_base: Duration::seconds(1),
+            restart_delay_max: Duration::seconds(5),
+            drain_deadline: Duration::seconds(30),
+        };
+
+        let delays = (1..=5)
+            .map(|restart_count| config.restart_delay(restart_count).num_seconds())
+            .collect::<Vec<_>>();
+
+        assert_eq!(delays, vec![1, 2, 4, 5, 5]);
+        assert_eq!(config.restart_delay(u32::MAX), Duration::seconds(5));
+    }
+}
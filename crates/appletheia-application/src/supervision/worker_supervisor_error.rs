@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+use super::WorkerName;
+
+#[derive(Debug, Error)]
+pub enum WorkerSupervisorError {
+    #[error("workers did not stop before the drain deadline: {workers:?}")]
+    DrainDeadlineExceeded { workers: Vec<WorkerName> },
+}
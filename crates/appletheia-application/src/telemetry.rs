@@ -0,0 +1,7 @@
+pub mod metric_name;
+pub mod trace_context;
+pub mod trace_context_error;
+
+pub use metric_name::MetricName;
+pub use trace_context::TraceContext;
+pub use trace_context_error::TraceContextError;
@@ -0,0 +1,155 @@
+use std::{fmt, fmt::Display};
+
+use metrics::{Unit, describe_counter, describe_gauge, describe_histogram};
+
+/// Metrics recorded through the `metrics` facade.
+///
+/// Nothing is exported until the application installs a recorder, e.g. the Prometheus
+/// recorder from the infrastructure crate. Call [`MetricName::describe_all`] after installing
+/// it to attach units and help texts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetricName {
+    /// Outbox entries acknowledged by the publisher, labelled by `outbox`.
+    OutboxPublished,
+    /// Outbox entries the publisher failed on, labelled by `outbox`.
+    OutboxNacked,
+    /// Nacked outbox entries that exhausted their retries, labelled by `outbox`.
+    OutboxDeadLettered,
+    /// Time from leasing an outbox batch to persisting its publish results, labelled by `outbox`.
+    OutboxRelayBatchDuration,
+    /// Command dispatches, labelled by `command` and `outcome`.
+    CommandDispatched,
+    /// Events handled by projector runners, labelled by `projector` and `report`.
+    ProjectorEvents,
+    /// Time spent projecting one event, labelled by `projector`.
+    ProjectorProcessingDuration,
+    /// Age of the most recently applied event, labelled by `projector`.
+    ProjectorLag,
+    /// Events replayed by projector rebuilds, labelled by `projector`.
+    ProjectorRebuildEvents,
+    /// Events handled by saga runners, labelled by `saga` and `report`.
+    SagaEvents,
+    /// Relationship graph nodes visited per authorization check.
+    AuthorizationEvaluatedNodes,
+    /// Time spent waiting for read-your-writes consistency, labelled by `outcome`.
+    ReadYourWritesWaitDuration,
+    /// Dispatches rejected by a rate limit, labelled by `target`.
+    RateLimited,
+}
+
+impl MetricName {
+    pub const ALL: [Self; 13] = [
+        Self::OutboxPublished,
+        Self::OutboxNacked,
+        Self::OutboxDeadLettered,
+        Self::OutboxRelayBatchDuration,
+        Self::CommandDispatched,
+        Self::ProjectorEvents,
+        Self::ProjectorProcessingDuration,
+        Self::ProjectorLag,
+        Self::ProjectorRebuildEvents,
+        Self::SagaEvents,
+        Self::AuthorizationEvaluatedNodes,
+        Self::ReadYourWritesWaitDuration,
+        Self::RateLimited,
+    ];
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::OutboxPublished => "appletheia_outbox_published_total",
+            Self::OutboxNacked => "appletheia_outbox_nacked_total",
+            Self::OutboxDeadLettered => "appletheia_outbox_dead_lettered_total",
+            Self::OutboxRelayBatchDuration => "appletheia_outbox_relay_batch_duration_seconds",
+            Self::CommandDispatched => "appletheia_command_dispatched_total",
+            Self::ProjectorEvents => "appletheia_projector_events_total",
+            Self::ProjectorProcessingDuration => "appletheia_projector_processing_duration_seconds",
+            Self::ProjectorLag => "appletheia_projector_lag_seconds",
+            Self::ProjectorRebuildEvents => "appletheia_projector_rebuild_events_total",
+            Self::SagaEvents => "appletheia_saga_events_total",
+            Self::AuthorizationEvaluatedNodes => "appletheia_authorization_evaluated_nodes",
+            Self::ReadYourWritesWaitDuration => "appletheia_read_your_writes_wait_duration_seconds",
+            Self::RateLimited => "appletheia_rate_limited_total",
+        }
+    }
+
+    /// Registers the unit and help text of this metric with the installed recorder.
+    pub fn describe(&self) {
+        let name = self.as_str();
+        match self {
+            Self::OutboxPublished => {
+                describe_counter!(name, Unit::Count, "Outbox entries published.");
+            }
+            Self::OutboxNacked => {
+                describe_counter!(name, Unit::Count, "Outbox entries that failed to publish.");
+            }
+            Self::OutboxDeadLettered => {
+                describe_counter!(name, Unit::Count, "Outbox entries moved to dead letters.");
+            }
+            Self::OutboxRelayBatchDuration => {
+                describe_histogram!(name, Unit::Seconds, "Outbox relay batch latency.");
+            }
+            Self::CommandDispatched => {
+                describe_counter!(name, Unit::Count, "Command dispatches by outcome.");
+            }
+            Self::ProjectorEvents => {
+                describe_counter!(name, Unit::Count, "Events handled by projectors.");
+            }
+            Self::ProjectorProcessingDuration => {
+                describe_histogram!(name, Unit::Seconds, "Projector processing time per event.");
+            }
+            Self::ProjectorLag => {
+                describe_gauge!(
+                    name,
+                    Unit::Seconds,
+                    "Age of the last event applied by a projector."
+                );
+            }
+            Self::ProjectorRebuildEvents => {
+                describe_counter!(name, Unit::Count, "Events replayed by projector rebuilds.");
+            }
+            Self::SagaEvents => {
+                describe_counter!(name, Unit::Count, "Events handled by sagas.");
+            }
+            Self::AuthorizationEvaluatedNodes => {
+                describe_histogram!(
+                    name,
+                    Unit::Count,
+                    "Relationship nodes per authorization check."
+                );
+            }
+            Self::ReadYourWritesWaitDuration => {
+                describe_histogram!(name, Unit::Seconds, "Read-your-writes wait time.");
+            }
+            Self::RateLimited => {
+                describe_counter!(name, Unit::Count, "Dispatches rejected by rate limits.");
+            }
+        }
+    }
+
+    pub fn describe_all() {
+        for metric_name in Self::ALL {
+            metric_name.describe();
+        }
+    }
+}
+
+impl Display for MetricName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::MetricName;
+
+    #[test]
+    fn names_are_unique_and_prefixed() {
+        let names: HashSet<&str> = MetricName::ALL.iter().map(MetricName::as_str).collect();
+
+        assert_eq!(names.len(), MetricName::ALL.len());
+        assert!(names.iter().all(|name| name.starts_with("appletheia_")));
+    }
+}
@@ -0,0 +1,238 @@
+use serde::{Deserialize, Serialize};
+use tracing::Span;
+
+use super::TraceContextError;
+
+/// W3C trace context (`traceparent` and `tracestate`) carried across process boundaries.
+///
+/// With the `opentelemetry` feature it is read from and attached to `tracing` spans that are
+/// backed by a `tracing-opentelemetry` layer; without it spans carry no trace context and
+/// `from_span` always returns `None`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "TraceContextFields")]
+pub struct TraceContext {
+    traceparent: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tracestate: Option<String>,
+}
+
+/// Deserialized fields of a `TraceContext`, validated by `TraceContext::new`.
+#[derive(Deserialize)]
+struct TraceContextFields {
+    traceparent: String,
+
+    #[serde(default)]
+    tracestate: Option<String>,
+}
+
+impl TryFrom<TraceContextFields> for TraceContext {
+    type Error = TraceContextError;
+
+    fn try_from(value: TraceContextFields) -> Result<Self, Self::Error> {
+        Self::new(value.traceparent, value.tracestate)
+    }
+}
+
+impl TraceContext {
+    pub const TRACEPARENT: &'static str = "traceparent";
+    pub const TRACESTATE: &'static str = "tracestate";
+
+    pub fn new(traceparent: String, tracestate: Option<String>) -> Result<Self, TraceContextError> {
+        if !Self::is_valid_traceparent(&traceparent) {
+            return Err(TraceContextError::InvalidTraceparent(traceparent));
+        }
+
+        Ok(Self {
+            traceparent,
+            tracestate: tracestate.filter(|tracestate| !tracestate.is_empty()),
+        })
+    }
+
+    pub fn traceparent(&self) -> &str {
+        &self.traceparent
+    }
+
+    pub fn tracestate(&self) -> Option<&str> {
+        self.tracestate.as_deref()
+    }
+
+    fn is_valid_traceparent(traceparent: &str) -> bool {
+        let parts = traceparent.split('-').collect::<Vec<_>>();
+        let [version, trace_id, parent_id, flags] = parts.as_slice() else {
+            return false;
+        };
+
+        let is_hex = |value: &str, len: usize| {
+            value.len() == len
+                && value
+                    .bytes()
+                    .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte))
+        };
+        let is_zero = |value: &str| value.bytes().all(|byte| byte == b'0');
+
+        is_hex(version, 2)
+            && *version != "ff"
+            && is_hex(trace_id, 32)
+            && !is_zero(trace_id)
+            && is_hex(parent_id, 16)
+            && !is_zero(parent_id)
+            && is_hex(flags, 2)
+    }
+
+    /// Returns the trace context of `span`, if it belongs to a sampled or recorded trace.
+    #[cfg(feature = "opentelemetry")]
+    pub fn from_span(span: &Span) -> Option<Self> {
+        use opentelemetry::trace::TraceContextExt;
+        use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+        let context = span.context();
+        let otel_span = context.span();
+        let span_context = otel_span.span_context();
+        if !span_context.is_valid() {
+            return None;
+        }
+
+        let traceparent = format!(
+            "00-{}-{}-{:02x}",
+            span_context.trace_id(),
+            span_context.span_id(),
+            span_context.trace_flags().to_u8()
+        );
+        let tracestate = span_context.trace_state().header();
+
+        Self::new(traceparent, Some(tracestate)).ok()
+    }
+
+    #[cfg(not(feature = "opentelemetry"))]
+    pub fn from_span(_span: &Span) -> Option<Self> {
+        None
+    }
+
+    /// Makes this trace context the remote parent of `span`, which must not have been entered.
+    #[cfg(feature = "opentelemetry")]
+    pub fn set_as_parent_of(&self, span: &Span) {
+        use std::str::FromStr;
+
+        use opentelemetry::Context;
+        use opentelemetry::trace::{
+            SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState,
+        };
+        use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+        let mut parts = self.traceparent.split('-').skip(1);
+        let (Some(trace_id), Some(span_id), Some(flags)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return;
+        };
+        let (Ok(trace_id), Ok(span_id), Ok(flags)) = (
+            TraceId::from_hex(trace_id),
+            SpanId::from_hex(span_id),
+            u8::from_str_radix(flags, 16),
+        ) else {
+            return;
+        };
+        let trace_state = self
+            .tracestate
+            .as_deref()
+            .and_then(|tracestate| TraceState::from_str(tracestate).ok())
+            .unwrap_or_default();
+
+        let span_context =
+            SpanContext::new(trace_id, span_id, TraceFlags::new(flags), true, trace_state);
+        let _ = span.set_parent(Context::new().with_remote_span_context(span_context));
+    }
+
+    #[cfg(not(feature = "opentelemetry"))]
+    pub fn set_as_parent_of(&self, _span: &Span) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn accepts_valid_traceparent() {
+        let trace_context =
+            TraceContext::new(TRACEPARENT.to_owned(), Some("vendor=value".to_owned()))
+                .expect("trace context should be valid");
+
+        assert_eq!(trace_context.traceparent(), TRACEPARENT);
+        assert_eq!(trace_context.tracestate(), Some("vendor=value"));
+    }
+
+    #[test]
+    fn rejects_malformed_traceparent() {
+        for traceparent in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+        ] {
+            assert!(
+                TraceContext::new(traceparent.to_owned(), None).is_err(),
+                "{traceparent} should be rejected"
+            );
+        }
+    }
+
+    #[cfg(feature = "opentelemetry")]
+    #[test]
+    fn continues_the_trace_of_a_span() {
+        use opentelemetry::trace::TracerProvider;
+        use opentelemetry_sdk::trace::SdkTracerProvider;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let provider = SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let producer = tracing::info_span!("producer");
+            let produced = TraceContext::from_span(&producer).expect("span should be traced");
+
+            let consumer = tracing::info_span!("consumer");
+            produced.set_as_parent_of(&consumer);
+            let consumed = TraceContext::from_span(&consumer).expect("span should be traced");
+
+            let trace_id = |trace_context: &TraceContext| {
+                trace_context
+                    .traceparent()
+                    .split('-')
+                    .nth(1)
+                    .map(str::to_owned)
+            };
+            assert_eq!(trace_id(&consumed), trace_id(&produced));
+            assert_ne!(consumed.traceparent(), produced.traceparent());
+        });
+    }
+
+    #[test]
+    fn deserialization_validates_the_traceparent() {
+        let trace_context: TraceContext = serde_json::from_value(serde_json::json!({
+            "traceparent": TRACEPARENT,
+            "tracestate": "",
+        }))
+        .expect("trace context should deserialize");
+        assert_eq!(trace_context.traceparent(), TRACEPARENT);
+        assert_eq!(trace_context.tracestate(), None);
+
+        let malformed = serde_json::from_value::<TraceContext>(serde_json::json!({
+            "traceparent": "not-a-traceparent",
+        }));
+        assert!(malformed.is_err());
+    }
+
+    #[test]
+    fn empty_tracestate_is_dropped() {
+        let trace_context = TraceContext::new(TRACEPARENT.to_owned(), Some(String::new()))
+            .expect("trace context should be valid");
+
+        assert_eq!(trace_context.tracestate(), None);
+    }
+}
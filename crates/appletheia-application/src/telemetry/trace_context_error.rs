@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum TraceContextError {
+    #[error("invalid traceparent: {0}")]
+    InvalidTraceparent(String),
+}
@@ -25,6 +25,24 @@ pub trait UnitOfWork: Send {
     where
         Self: Sized;
 
+    /// Marks a point that [`UnitOfWork::rollback_to_savepoint`] can return to without
+    /// abandoning the rest of the unit of work. Savepoints nest.
+    ///
+    /// Units of work without savepoint support return `UnitOfWorkError::SavepointsUnsupported`.
+    async fn savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+        Err(UnitOfWorkError::SavepointsUnsupported)
+    }
+
+    /// Keeps the changes made since the innermost savepoint and forgets that savepoint.
+    async fn release_savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+        Err(UnitOfWorkError::SavepointsUnsupported)
+    }
+
+    /// Discards the changes made since the innermost savepoint and forgets that savepoint.
+    async fn rollback_to_savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+        Err(UnitOfWorkError::SavepointsUnsupported)
+    }
+
     /// Rolls back the unit of work and returns the original operation error.
     ///
     /// If the rollback itself fails, both errors are combined into
@@ -14,4 +14,13 @@ pub enum UnitOfWorkError {
         operation_error: Box<dyn std::error::Error + Send + Sync + 'static>,
         rollback_error: Box<dyn std::error::Error + Send + Sync + 'static>,
     },
+
+    #[error("savepoint failed {0}")]
+    SavepointFailed(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+
+    #[error("no savepoint is active")]
+    NoActiveSavepoint,
+
+    #[error("savepoints are not supported by this unit of work")]
+    SavepointsUnsupported,
 }
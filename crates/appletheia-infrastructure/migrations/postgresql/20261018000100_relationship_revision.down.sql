@@ -0,0 +1,4 @@
+-- relationship revision
+DROP TABLE IF EXISTS relationship_revision;
+
+DROP SEQUENCE IF EXISTS relationship_revision_seq;
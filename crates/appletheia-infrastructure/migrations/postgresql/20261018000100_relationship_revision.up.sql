@@ -0,0 +1,14 @@
+-- relationship revision
+CREATE SEQUENCE IF NOT EXISTS relationship_revision_seq;
+
+CREATE TABLE IF NOT EXISTS relationship_revision (
+  id          BOOLEAN     PRIMARY KEY DEFAULT true CHECK (id),
+  revision    BIGINT      NOT NULL CHECK (revision >= 0),
+  updated_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+INSERT INTO relationship_revision (id, revision)
+VALUES (true, 0)
+ON CONFLICT (id) DO NOTHING;
+
+COMMENT ON TABLE relationship_revision IS 'Single-row revision of the relationships table; bumped from relationship_revision_seq whenever relationships change.';
@@ -0,0 +1,5 @@
+-- outbox dead letter discards
+DROP INDEX IF EXISTS idx_command_dead_letters_dead_lettered_at;
+DROP INDEX IF EXISTS idx_event_dead_letters_dead_lettered_at;
+
+DROP TABLE IF EXISTS outbox_dead_letter_discards;
@@ -0,0 +1,21 @@
+-- outbox dead letter discards
+CREATE TABLE IF NOT EXISTS outbox_dead_letter_discards (
+  id                UUID        PRIMARY KEY,
+  outbox_kind       TEXT        NOT NULL CHECK (outbox_kind IN ('event', 'command')),
+  outbox_id         UUID        NOT NULL,
+  message_name      TEXT        NOT NULL,
+  message           JSONB       NOT NULL,
+  attempt_count     BIGINT      NOT NULL CHECK (attempt_count >= 0),
+  last_error        JSONB,
+  dead_lettered_at  TIMESTAMPTZ NOT NULL,
+  discarded_by      JSONB       NOT NULL,
+  reason            TEXT        NOT NULL,
+  discarded_at      TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_outbox_dead_letter_discards_outbox_id    ON outbox_dead_letter_discards (outbox_kind, outbox_id);
+CREATE INDEX IF NOT EXISTS idx_outbox_dead_letter_discards_discarded_at ON outbox_dead_letter_discards (discarded_at);
+CREATE INDEX IF NOT EXISTS idx_event_dead_letters_dead_lettered_at      ON event_dead_letters (dead_lettered_at, event_outbox_id);
+CREATE INDEX IF NOT EXISTS idx_command_dead_letters_dead_lettered_at    ON command_dead_letters (dead_lettered_at, command_outbox_id);
+
+COMMENT ON TABLE outbox_dead_letter_discards IS 'Audit trail of dead-lettered outbox entries that were permanently discarded by an operator.';
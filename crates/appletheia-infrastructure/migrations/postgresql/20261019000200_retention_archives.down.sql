@@ -0,0 +1,4 @@
+-- retention archives
+DROP TABLE IF EXISTS idempotency_archive;
+DROP TABLE IF EXISTS command_outbox_archive;
+DROP TABLE IF EXISTS event_outbox_archive;
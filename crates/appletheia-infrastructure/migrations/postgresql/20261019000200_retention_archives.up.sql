@@ -0,0 +1,59 @@
+-- retention archives
+CREATE TABLE IF NOT EXISTS event_outbox_archive (
+  id                 UUID        PRIMARY KEY,
+  event_sequence     BIGINT      NOT NULL,
+  event_id           UUID        NOT NULL,
+  aggregate_type     TEXT        NOT NULL,
+  aggregate_id       UUID        NOT NULL,
+  aggregate_version  BIGINT      NOT NULL CHECK (aggregate_version > 0),
+  event_name         TEXT        NOT NULL,
+  payload            JSONB       NOT NULL,
+  occurred_at        TIMESTAMPTZ NOT NULL,
+  correlation_id     UUID        NOT NULL,
+  causation_id       UUID        NOT NULL,
+  context            JSONB       NOT NULL,
+  published_at       TIMESTAMPTZ NOT NULL,
+  attempt_count      BIGINT      NOT NULL CHECK (attempt_count >= 0),
+  archived_at        TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_event_outbox_archive_published_at ON event_outbox_archive (published_at);
+CREATE INDEX IF NOT EXISTS idx_event_outbox_archive_aggregate    ON event_outbox_archive (aggregate_type, aggregate_id, aggregate_version);
+
+COMMENT ON TABLE event_outbox_archive IS 'Published event outbox rows moved out of event_outbox by the retention janitor.';
+
+CREATE TABLE IF NOT EXISTS command_outbox_archive (
+  id                UUID        PRIMARY KEY,
+  command_sequence  BIGINT      NOT NULL,
+  message_id        UUID        NOT NULL,
+  command_name      TEXT        NOT NULL,
+  payload           JSONB       NOT NULL,
+  correlation_id    UUID        NOT NULL,
+  causation_id      UUID        NOT NULL,
+  options           JSONB       NOT NULL,
+  published_at      TIMESTAMPTZ NOT NULL,
+  attempt_count     BIGINT      NOT NULL CHECK (attempt_count >= 0),
+  archived_at       TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_command_outbox_archive_published_at ON command_outbox_archive (published_at);
+CREATE INDEX IF NOT EXISTS idx_command_outbox_archive_message_id   ON command_outbox_archive (message_id);
+
+COMMENT ON TABLE command_outbox_archive IS 'Published command outbox rows moved out of command_outbox by the retention janitor.';
+
+CREATE TABLE IF NOT EXISTS idempotency_archive (
+  id            UUID        PRIMARY KEY,
+  message_id    UUID        NOT NULL,
+  command_name  TEXT        NOT NULL,
+  command_hash  TEXT        NOT NULL,
+  output        JSONB,
+  error         JSONB,
+  started_at    TIMESTAMPTZ NOT NULL,
+  completed_at  TIMESTAMPTZ NOT NULL,
+  archived_at   TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_idempotency_archive_completed_at ON idempotency_archive (completed_at);
+CREATE INDEX IF NOT EXISTS idx_idempotency_archive_message_id   ON idempotency_archive (message_id);
+
+COMMENT ON TABLE idempotency_archive IS 'Completed idempotency entries moved out of idempotency by the retention janitor.';
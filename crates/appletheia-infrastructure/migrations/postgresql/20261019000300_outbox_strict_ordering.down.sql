@@ -0,0 +1,7 @@
+-- outbox strict ordering
+DROP INDEX IF EXISTS idx_command_outbox_ordering_pending;
+DROP INDEX IF EXISTS idx_command_dead_letters_ordering_blocked;
+DROP INDEX IF EXISTS idx_event_dead_letters_ordering_blocked;
+
+ALTER TABLE command_dead_letters DROP COLUMN IF EXISTS ordering_released_at;
+ALTER TABLE event_dead_letters   DROP COLUMN IF EXISTS ordering_released_at;
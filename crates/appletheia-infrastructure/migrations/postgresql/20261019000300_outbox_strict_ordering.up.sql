@@ -0,0 +1,10 @@
+-- outbox strict ordering
+ALTER TABLE event_dead_letters   ADD COLUMN IF NOT EXISTS ordering_released_at TIMESTAMPTZ;
+ALTER TABLE command_dead_letters ADD COLUMN IF NOT EXISTS ordering_released_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_event_dead_letters_ordering_blocked   ON event_dead_letters (aggregate_type, aggregate_id, aggregate_version) WHERE ordering_released_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_command_dead_letters_ordering_blocked ON command_dead_letters (correlation_id, command_sequence)              WHERE ordering_released_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_command_outbox_ordering_pending       ON command_outbox (correlation_id, command_sequence)                    WHERE published_at IS NULL;
+
+COMMENT ON COLUMN event_dead_letters.ordering_released_at   IS 'Set when an operator unblocked the ordering key; released dead letters no longer hold back later events of the aggregate.';
+COMMENT ON COLUMN command_dead_letters.ordering_released_at IS 'Set when an operator unblocked the ordering key; released dead letters no longer hold back later commands of the correlation.';
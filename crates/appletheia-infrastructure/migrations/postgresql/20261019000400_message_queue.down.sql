@@ -0,0 +1,5 @@
+-- message queue
+DROP TABLE IF EXISTS message_queue_dead_letters;
+DROP TABLE IF EXISTS message_queue_deliveries;
+DROP TABLE IF EXISTS message_queue_messages;
+DROP TABLE IF EXISTS message_queue_subscriptions;
@@ -0,0 +1,52 @@
+-- message queue
+CREATE TABLE IF NOT EXISTS message_queue_subscriptions (
+  consumer_group  TEXT        PRIMARY KEY,
+  topic           TEXT        NOT NULL,
+  filter          JSONB,
+  created_at      TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_message_queue_subscriptions_topic ON message_queue_subscriptions (topic);
+
+COMMENT ON TABLE message_queue_subscriptions IS 'Consumer groups of the PostgreSQL message queue; filter is a JSON array of attribute objects, NULL meaning every message of the topic.';
+
+CREATE TABLE IF NOT EXISTS message_queue_messages (
+  id            BIGINT      GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  topic         TEXT        NOT NULL,
+  ordering_key  TEXT,
+  attributes    JSONB       NOT NULL DEFAULT '{}'::jsonb,
+  payload       JSONB       NOT NULL,
+  published_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+COMMENT ON TABLE message_queue_messages IS 'Messages published to the PostgreSQL message queue; only stored when at least one consumer group receives them.';
+
+CREATE TABLE IF NOT EXISTS message_queue_deliveries (
+  consumer_group  TEXT        NOT NULL REFERENCES message_queue_subscriptions (consumer_group) ON DELETE CASCADE,
+  message_id      BIGINT      NOT NULL REFERENCES message_queue_messages (id) ON DELETE CASCADE,
+  ordering_key    TEXT,
+  attempt_count   BIGINT      NOT NULL DEFAULT 0 CHECK (attempt_count >= 0),
+  visible_after   TIMESTAMPTZ NOT NULL DEFAULT now(),
+  lease_token     UUID,
+  PRIMARY KEY (consumer_group, message_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_message_queue_deliveries_visible  ON message_queue_deliveries (consumer_group, visible_after, message_id);
+CREATE INDEX IF NOT EXISTS idx_message_queue_deliveries_ordering ON message_queue_deliveries (consumer_group, ordering_key, message_id) WHERE ordering_key IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_message_queue_deliveries_message  ON message_queue_deliveries (message_id);
+
+COMMENT ON TABLE message_queue_deliveries IS 'Per consumer group delivery state; a row exists until the message is acked or dead-lettered.';
+
+CREATE TABLE IF NOT EXISTS message_queue_dead_letters (
+  consumer_group    TEXT        NOT NULL,
+  message_id        BIGINT      NOT NULL REFERENCES message_queue_messages (id),
+  ordering_key      TEXT,
+  attempt_count     BIGINT      NOT NULL CHECK (attempt_count >= 0),
+  dead_lettered_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+  PRIMARY KEY (consumer_group, message_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_message_queue_dead_letters_dead_lettered_at ON message_queue_dead_letters (dead_lettered_at);
+CREATE INDEX IF NOT EXISTS idx_message_queue_dead_letters_message          ON message_queue_dead_letters (message_id);
+
+COMMENT ON TABLE message_queue_dead_letters IS 'Deliveries that exhausted their attempts; the message row is kept for inspection.';
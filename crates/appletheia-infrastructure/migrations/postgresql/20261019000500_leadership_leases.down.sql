@@ -0,0 +1,2 @@
+-- leadership leases
+DROP TABLE IF EXISTS leadership_leases;
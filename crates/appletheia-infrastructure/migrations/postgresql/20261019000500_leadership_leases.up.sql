@@ -0,0 +1,10 @@
+-- leadership leases
+CREATE TABLE IF NOT EXISTS leadership_leases (
+  key            TEXT        PRIMARY KEY,
+  holder         TEXT        NOT NULL,
+  fencing_token  BIGINT      NOT NULL CHECK (fencing_token > 0),
+  acquired_at    TIMESTAMPTZ NOT NULL DEFAULT now(),
+  expires_at     TIMESTAMPTZ NOT NULL
+);
+
+COMMENT ON TABLE leadership_leases IS 'Singleton worker leadership; rows are never deleted so fencing tokens keep increasing across holders.';
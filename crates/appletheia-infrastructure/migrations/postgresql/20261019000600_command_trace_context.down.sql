@@ -0,0 +1,4 @@
+-- command trace context
+ALTER TABLE command_outbox_archive DROP COLUMN IF EXISTS trace_context;
+ALTER TABLE command_dead_letters   DROP COLUMN IF EXISTS trace_context;
+ALTER TABLE command_outbox         DROP COLUMN IF EXISTS trace_context;
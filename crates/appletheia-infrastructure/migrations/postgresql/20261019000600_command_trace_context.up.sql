@@ -0,0 +1,6 @@
+-- command trace context
+ALTER TABLE command_outbox         ADD COLUMN IF NOT EXISTS trace_context JSONB;
+ALTER TABLE command_dead_letters   ADD COLUMN IF NOT EXISTS trace_context JSONB;
+ALTER TABLE command_outbox_archive ADD COLUMN IF NOT EXISTS trace_context JSONB;
+
+COMMENT ON COLUMN command_outbox.trace_context IS 'W3C trace context (traceparent and tracestate) of the span that enqueued the command.';
@@ -0,0 +1,2 @@
+-- rate limit buckets
+DROP TABLE IF EXISTS rate_limit_buckets;
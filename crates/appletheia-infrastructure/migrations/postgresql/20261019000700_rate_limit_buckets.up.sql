@@ -0,0 +1,11 @@
+-- rate limit buckets
+CREATE TABLE IF NOT EXISTS rate_limit_buckets (
+  key         TEXT             PRIMARY KEY,
+  tokens      DOUBLE PRECISION NOT NULL CHECK (tokens >= 0),
+  updated_at  TIMESTAMPTZ      NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS rate_limit_buckets_updated_at_idx
+  ON rate_limit_buckets (updated_at);
+
+COMMENT ON TABLE rate_limit_buckets IS 'Token buckets keyed by actor and command or query; rows idle longer than the refill period are full and may be deleted.';
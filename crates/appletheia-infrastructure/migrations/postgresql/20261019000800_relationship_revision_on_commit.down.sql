@@ -0,0 +1,6 @@
+-- relationship revision bumped on commit
+DROP TRIGGER IF EXISTS relationships_revision_bump ON relationships;
+
+DROP FUNCTION IF EXISTS relationship_revision_bump();
+
+COMMENT ON TABLE relationship_revision IS 'Single-row revision of the relationships table; bumped from relationship_revision_seq whenever relationships change.';
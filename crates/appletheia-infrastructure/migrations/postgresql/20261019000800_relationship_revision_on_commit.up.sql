@@ -0,0 +1,28 @@
+-- relationship revision bumped on commit
+--
+-- Bumping relationship_revision while relationships are written held its row lock until the
+-- writing transaction committed, serializing every relationship write. The bump now runs in a
+-- deferred constraint trigger, so the row is only locked while the writing transaction commits.
+-- Until then the writer reads the private revision it drew from relationship_revision_seq,
+-- which no other transaction can observe.
+CREATE OR REPLACE FUNCTION relationship_revision_bump() RETURNS trigger AS $$
+BEGIN
+  IF COALESCE(current_setting('appletheia.relationship_revision_bumped', true), '') = '' THEN
+    PERFORM set_config('appletheia.relationship_revision_bumped', 'on', true);
+    UPDATE relationship_revision
+    SET revision = nextval('relationship_revision_seq'),
+        updated_at = now()
+    WHERE id = true;
+  END IF;
+  RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS relationships_revision_bump ON relationships;
+
+CREATE CONSTRAINT TRIGGER relationships_revision_bump
+  AFTER INSERT OR DELETE ON relationships
+  DEFERRABLE INITIALLY DEFERRED
+  FOR EACH ROW EXECUTE FUNCTION relationship_revision_bump();
+
+COMMENT ON TABLE relationship_revision IS 'Single-row revision of the relationships table; bumped from relationship_revision_seq when a transaction that changed relationships commits.';
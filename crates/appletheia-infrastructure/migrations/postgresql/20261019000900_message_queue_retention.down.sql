@@ -0,0 +1,4 @@
+-- message queue retention
+DROP TABLE IF EXISTS message_queue_dead_letter_archive;
+
+COMMENT ON TABLE message_queue_dead_letters IS 'Deliveries that exhausted their attempts; the message row is kept for inspection.';
@@ -0,0 +1,18 @@
+-- message queue retention
+CREATE TABLE IF NOT EXISTS message_queue_dead_letter_archive (
+  consumer_group    TEXT        NOT NULL,
+  message_id        BIGINT      NOT NULL,
+  topic             TEXT        NOT NULL,
+  ordering_key      TEXT,
+  attributes        JSONB       NOT NULL,
+  payload           JSONB       NOT NULL,
+  attempt_count     BIGINT      NOT NULL CHECK (attempt_count >= 0),
+  dead_lettered_at  TIMESTAMPTZ NOT NULL,
+  archived_at       TIMESTAMPTZ NOT NULL DEFAULT now(),
+  PRIMARY KEY (consumer_group, message_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_message_queue_dead_letter_archive_dead_lettered_at ON message_queue_dead_letter_archive (dead_lettered_at);
+
+COMMENT ON TABLE message_queue_dead_letter_archive IS 'Message queue dead letters, with their message, moved out of message_queue_dead_letters by the retention janitor.';
+COMMENT ON TABLE message_queue_dead_letters IS 'Deliveries that exhausted their attempts or could not be decoded; the message row is kept until the retention janitor removes the dead letter.';
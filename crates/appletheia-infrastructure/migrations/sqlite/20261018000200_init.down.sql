@@ -0,0 +1,41 @@
+-- relationship revision
+DROP TABLE IF EXISTS relationship_revision;
+
+-- relationships (Aggregate × ReBAC)
+DROP TABLE IF EXISTS relationships;
+
+-- idempotency
+DROP TABLE IF EXISTS idempotency;
+
+-- projector processed events
+DROP TABLE IF EXISTS projector_processed_events;
+
+-- projection checkpoints
+DROP TABLE IF EXISTS projection_checkpoints;
+
+-- saga processed events
+DROP TABLE IF EXISTS saga_processed_events;
+
+-- saga runs
+DROP TABLE IF EXISTS saga_runs;
+
+-- command dead letters
+DROP TABLE IF EXISTS command_dead_letters;
+
+-- command_outbox
+DROP TABLE IF EXISTS command_outbox;
+
+-- event dead letters
+DROP TABLE IF EXISTS event_dead_letters;
+
+-- event_outbox
+DROP TABLE IF EXISTS event_outbox;
+
+-- unique key reservations
+DROP TABLE IF EXISTS unique_key_reservations;
+
+-- snapshots
+DROP TABLE IF EXISTS snapshots;
+
+-- events
+DROP TABLE IF EXISTS events;
@@ -0,0 +1,301 @@
+-- Timestamps are stored as RFC 3339 text in UTC so that they compare in time order.
+-- UUIDs are stored as 16-byte blobs and JSON documents as text.
+
+-- events
+CREATE TABLE IF NOT EXISTS events (
+  event_sequence      INTEGER     PRIMARY KEY AUTOINCREMENT,
+  id                  BLOB        NOT NULL UNIQUE,
+  aggregate_type      TEXT        NOT NULL,
+  aggregate_id        BLOB        NOT NULL,
+  aggregate_version   INTEGER     NOT NULL CHECK (aggregate_version > 0),
+  event_name          TEXT        NOT NULL,
+  payload             TEXT        NOT NULL,
+  occurred_at         TEXT        NOT NULL,
+  correlation_id      BLOB        NOT NULL,
+  causation_id        BLOB        NOT NULL,
+  context             TEXT        NOT NULL DEFAULT '{}',
+  CONSTRAINT events_uniq_aggregate_version
+    UNIQUE (aggregate_type, aggregate_id, aggregate_version)
+);
+
+CREATE INDEX IF NOT EXISTS idx_events_occurred_at    ON events (occurred_at);
+CREATE INDEX IF NOT EXISTS idx_events_correlation_id ON events (correlation_id);
+CREATE INDEX IF NOT EXISTS idx_events_causation_id   ON events (causation_id);
+CREATE INDEX IF NOT EXISTS idx_events_event_name     ON events (event_name);
+
+-- snapshots
+CREATE TABLE IF NOT EXISTS snapshots (
+  id                  BLOB        PRIMARY KEY,
+  aggregate_type      TEXT        NOT NULL,
+  aggregate_id        BLOB        NOT NULL,
+  aggregate_version   INTEGER     NOT NULL CHECK (aggregate_version > 0),
+  state               TEXT        NOT NULL,
+  materialized_at     TEXT        NOT NULL,
+  CONSTRAINT snapshots_uniq_aggregate_version
+    UNIQUE (aggregate_type, aggregate_id, aggregate_version)
+);
+
+CREATE INDEX IF NOT EXISTS idx_snapshots_materialized_at
+  ON snapshots (materialized_at);
+
+-- unique key reservations
+CREATE TABLE IF NOT EXISTS unique_key_reservations (
+  id                 BLOB PRIMARY KEY,
+  aggregate_type     TEXT NOT NULL,
+  owner_aggregate_id BLOB NOT NULL,
+  namespace          TEXT NOT NULL,
+  normalized_value   TEXT NOT NULL,
+
+  UNIQUE (aggregate_type, namespace, normalized_value)
+);
+
+CREATE INDEX IF NOT EXISTS idx_unique_key_reservations_owner
+  ON unique_key_reservations (aggregate_type, owner_aggregate_id);
+
+-- event_outbox
+CREATE TABLE IF NOT EXISTS event_outbox (
+  id                   BLOB        PRIMARY KEY,
+  event_sequence       INTEGER     NOT NULL UNIQUE,
+  event_id             BLOB        NOT NULL UNIQUE,
+  aggregate_type       TEXT        NOT NULL,
+  aggregate_id         BLOB        NOT NULL,
+  aggregate_version    INTEGER     NOT NULL CHECK (aggregate_version > 0),
+  event_name           TEXT        NOT NULL,
+  payload              TEXT        NOT NULL,
+  occurred_at          TEXT        NOT NULL,
+  correlation_id       BLOB        NOT NULL,
+  causation_id         BLOB        NOT NULL,
+  context              TEXT        NOT NULL DEFAULT '{}',
+  published_at         TEXT,
+  attempt_count        INTEGER     NOT NULL DEFAULT 0 CHECK (attempt_count >= 0),
+  next_attempt_after   TEXT        NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+  lease_owner          TEXT,
+  lease_until          TEXT,
+  last_error           TEXT,
+  CONSTRAINT event_outbox_uniq_aggregate_version
+    UNIQUE (aggregate_type, aggregate_id, aggregate_version)
+);
+
+CREATE INDEX IF NOT EXISTS idx_event_outbox_published_at         ON event_outbox (published_at);
+CREATE INDEX IF NOT EXISTS idx_event_outbox_next_attempt_pending ON event_outbox (next_attempt_after, event_sequence) WHERE published_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_event_outbox_lease_visible        ON event_outbox (lease_until)     WHERE published_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_event_outbox_correlation_id       ON event_outbox (correlation_id);
+CREATE INDEX IF NOT EXISTS idx_event_outbox_causation_id         ON event_outbox (causation_id);
+CREATE INDEX IF NOT EXISTS idx_event_outbox_event_name           ON event_outbox (event_name);
+
+-- event dead letters
+CREATE TABLE IF NOT EXISTS event_dead_letters (
+  event_outbox_id     BLOB        PRIMARY KEY,
+  event_sequence      INTEGER     NOT NULL,
+  event_id            BLOB        NOT NULL,
+  aggregate_type      TEXT        NOT NULL,
+  aggregate_id        BLOB        NOT NULL,
+  aggregate_version   INTEGER     NOT NULL CHECK (aggregate_version > 0),
+  event_name          TEXT        NOT NULL,
+  payload             TEXT        NOT NULL,
+  occurred_at         TEXT        NOT NULL,
+  correlation_id      BLOB        NOT NULL,
+  causation_id        BLOB        NOT NULL,
+  context             TEXT        NOT NULL,
+  published_at        TEXT,
+  attempt_count       INTEGER     NOT NULL CHECK (attempt_count >= 0),
+  next_attempt_after  TEXT        NOT NULL,
+  lease_owner         TEXT,
+  lease_until         TEXT,
+  last_error          TEXT,
+  dead_lettered_at    TEXT        NOT NULL
+);
+
+-- command_outbox
+CREATE TABLE IF NOT EXISTS command_outbox (
+  command_sequence     INTEGER     PRIMARY KEY AUTOINCREMENT,
+  id                   BLOB        NOT NULL UNIQUE,
+  message_id           BLOB        NOT NULL UNIQUE,
+  command_name         TEXT        NOT NULL,
+  payload              TEXT        NOT NULL,
+  correlation_id       BLOB        NOT NULL,
+  causation_id         BLOB        NOT NULL,
+  options              TEXT        NOT NULL,
+  published_at         TEXT,
+  attempt_count        INTEGER     NOT NULL DEFAULT 0 CHECK (attempt_count >= 0),
+  next_attempt_after   TEXT        NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+  lease_owner          TEXT,
+  lease_until          TEXT,
+  last_error           TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_command_outbox_published_at         ON command_outbox (published_at);
+CREATE INDEX IF NOT EXISTS idx_command_outbox_next_attempt_pending ON command_outbox (next_attempt_after, command_sequence) WHERE published_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_command_outbox_lease_visible        ON command_outbox (lease_until)     WHERE published_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_command_outbox_correlation_id       ON command_outbox (correlation_id);
+CREATE INDEX IF NOT EXISTS idx_command_outbox_causation_id         ON command_outbox (causation_id);
+
+-- command dead letters
+CREATE TABLE IF NOT EXISTS command_dead_letters (
+  command_outbox_id    BLOB        PRIMARY KEY,
+  command_sequence     INTEGER     NOT NULL,
+  message_id           BLOB        NOT NULL,
+  command_name         TEXT        NOT NULL,
+  payload              TEXT        NOT NULL,
+  correlation_id       BLOB        NOT NULL,
+  causation_id         BLOB        NOT NULL,
+  options              TEXT        NOT NULL,
+  published_at         TEXT,
+  attempt_count        INTEGER     NOT NULL CHECK (attempt_count >= 0),
+  next_attempt_after   TEXT        NOT NULL,
+  lease_owner          TEXT,
+  lease_until          TEXT,
+  last_error           TEXT,
+  dead_lettered_at     TEXT        NOT NULL
+);
+
+-- saga runs
+CREATE TABLE IF NOT EXISTS saga_runs (
+  id                            BLOB        PRIMARY KEY,
+  saga_name                     TEXT        NOT NULL,
+  trigger_event_id              BLOB        NOT NULL,
+  dispatched_command_message_id BLOB,
+  context                       TEXT        NOT NULL,
+  created_at                    TEXT        NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+  UNIQUE (saga_name, trigger_event_id),
+  UNIQUE (saga_name, dispatched_command_message_id)
+);
+
+-- saga processed events
+CREATE TABLE IF NOT EXISTS saga_processed_events (
+  id             BLOB        PRIMARY KEY,
+  saga_name      TEXT        NOT NULL,
+  event_id       BLOB        NOT NULL,
+  processed_at   TEXT        NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+  UNIQUE (saga_name, event_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_saga_processed_events_event_id
+  ON saga_processed_events (event_id);
+
+-- projection checkpoints
+CREATE TABLE IF NOT EXISTS projection_checkpoints (
+  id                  BLOB        PRIMARY KEY,
+  projector_name      TEXT        NOT NULL UNIQUE,
+  last_event_sequence INTEGER     NOT NULL,
+  updated_at          TEXT        NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'))
+);
+
+-- projector processed events
+CREATE TABLE IF NOT EXISTS projector_processed_events (
+  id             BLOB        PRIMARY KEY,
+  projector_name TEXT        NOT NULL,
+  event_id       BLOB        NOT NULL,
+  processed_at   TEXT        NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+  UNIQUE (projector_name, event_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_projector_processed_events_event_id
+  ON projector_processed_events (event_id);
+
+-- idempotency
+CREATE TABLE IF NOT EXISTS idempotency (
+  id            BLOB        PRIMARY KEY,
+  message_id    BLOB        NOT NULL UNIQUE,
+  command_name  TEXT        NOT NULL,
+  command_hash  TEXT        NOT NULL,
+  output        TEXT,
+  error         TEXT,
+  started_at    TEXT        NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+  completed_at  TEXT,
+  CONSTRAINT idempotency_output_error_check CHECK (
+    (completed_at IS NULL AND output IS NULL AND error IS NULL) OR
+    (completed_at IS NOT NULL AND output IS NOT NULL AND error IS NULL) OR
+    (completed_at IS NOT NULL AND output IS NULL AND error IS NOT NULL)
+  )
+);
+
+CREATE INDEX IF NOT EXISTS idx_idempotency_started_at
+  ON idempotency (started_at);
+
+CREATE INDEX IF NOT EXISTS idx_idempotency_completed_at
+  ON idempotency (completed_at) WHERE completed_at IS NOT NULL;
+
+-- relationships (Aggregate × ReBAC)
+CREATE TABLE IF NOT EXISTS relationships (
+  id                     BLOB        PRIMARY KEY,
+  aggregate_type         TEXT        NOT NULL,
+  aggregate_id           BLOB        NOT NULL,
+  relation               TEXT        NOT NULL,
+
+  subject_aggregate_type TEXT        NOT NULL,
+  subject_aggregate_id   BLOB,
+  subject_relation       TEXT,
+  subject_is_wildcard    BOOLEAN     NOT NULL DEFAULT FALSE,
+
+  created_at             TEXT        NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+
+  CONSTRAINT relationships_subject_check CHECK (
+    (
+      subject_is_wildcard = TRUE
+      AND subject_aggregate_id IS NULL
+      AND subject_relation IS NULL
+    )
+    OR
+    (
+      subject_is_wildcard = FALSE
+      AND subject_aggregate_id IS NOT NULL
+      AND subject_relation IS NULL
+    )
+    OR
+    (
+      subject_is_wildcard = FALSE
+      AND subject_aggregate_id IS NOT NULL
+      AND subject_relation IS NOT NULL
+    )
+  )
+);
+
+CREATE INDEX IF NOT EXISTS idx_relationships_aggregate_relation
+  ON relationships (aggregate_type, aggregate_id, relation);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_relationships_direct_uniq
+  ON relationships (
+    aggregate_type, aggregate_id, relation,
+    subject_aggregate_type, subject_aggregate_id
+  )
+  WHERE subject_is_wildcard = FALSE AND subject_relation IS NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_relationships_subject_set_uniq
+  ON relationships (
+    aggregate_type, aggregate_id, relation,
+    subject_aggregate_type, subject_aggregate_id, subject_relation
+  )
+  WHERE subject_is_wildcard = FALSE AND subject_relation IS NOT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_relationships_wildcard_uniq
+  ON relationships (
+    aggregate_type, aggregate_id, relation,
+    subject_aggregate_type
+  )
+  WHERE subject_is_wildcard = TRUE;
+
+CREATE INDEX IF NOT EXISTS idx_relationships_subject_direct
+  ON relationships (
+    subject_aggregate_type, subject_aggregate_id, relation,
+    aggregate_type, aggregate_id
+  )
+  WHERE subject_is_wildcard = FALSE;
+
+CREATE INDEX IF NOT EXISTS idx_relationships_subject_wildcard
+  ON relationships (
+    subject_aggregate_type, relation,
+    aggregate_type, aggregate_id
+  )
+  WHERE subject_is_wildcard = TRUE;
+
+-- relationship revision
+CREATE TABLE IF NOT EXISTS relationship_revision (
+  id          INTEGER     PRIMARY KEY CHECK (id = 1),
+  revision    INTEGER     NOT NULL CHECK (revision >= 0),
+  updated_at  TEXT        NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'))
+);
+
+INSERT INTO relationship_revision (id, revision)
+VALUES (1, 0)
+ON CONFLICT (id) DO NOTHING;
@@ -0,0 +1,3 @@
+-- command trace context
+ALTER TABLE command_dead_letters DROP COLUMN trace_context;
+ALTER TABLE command_outbox       DROP COLUMN trace_context;
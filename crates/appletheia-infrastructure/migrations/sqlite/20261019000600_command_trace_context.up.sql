@@ -0,0 +1,3 @@
+-- command trace context
+ALTER TABLE command_outbox       ADD COLUMN trace_context TEXT;
+ALTER TABLE command_dead_letters ADD COLUMN trace_context TEXT;
@@ -0,0 +1,20 @@
+pub mod amqp_command_publisher;
+pub mod amqp_command_subscriber;
+pub mod amqp_consumer;
+pub mod amqp_delivery;
+pub mod amqp_event_publisher;
+pub mod amqp_event_subscriber;
+mod amqp_publish;
+pub mod amqp_queue_options;
+pub mod amqp_routing_key;
+pub mod amqp_routing_key_error;
+
+pub use amqp_command_publisher::AmqpCommandPublisher;
+pub use amqp_command_subscriber::AmqpCommandSubscriber;
+pub use amqp_consumer::AmqpConsumer;
+pub use amqp_delivery::AmqpDelivery;
+pub use amqp_event_publisher::AmqpEventPublisher;
+pub use amqp_event_subscriber::AmqpEventSubscriber;
+pub use amqp_queue_options::AmqpQueueOptions;
+pub use amqp_routing_key::AmqpRoutingKey;
+pub use amqp_routing_key_error::AmqpRoutingKeyError;
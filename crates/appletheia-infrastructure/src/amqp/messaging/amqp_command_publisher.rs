@@ -0,0 +1,89 @@
+use appletheia_application::messaging::{PublishResult, Publisher, PublisherError, TopicId};
+use appletheia_application::outbox::OrderingKey;
+use appletheia_application::outbox::command::CommandEnvelope;
+use lapin::{BasicProperties, Channel};
+
+use super::AmqpRoutingKey;
+use super::amqp_publish::{message_properties, publish_confirmed};
+
+/// Publishes commands to a durable topic exchange named after the topic.
+///
+/// The routing key is the command name. The channel should have publisher confirms enabled;
+/// without them every accepted publish is reported as successful.
+#[derive(Clone)]
+pub struct AmqpCommandPublisher {
+    channel: Channel,
+    topic_id: TopicId,
+}
+
+impl AmqpCommandPublisher {
+    pub fn new(channel: Channel, topic_id: TopicId) -> Self {
+        Self { channel, topic_id }
+    }
+
+    fn build_message(
+        command: &CommandEnvelope,
+    ) -> Result<(String, Vec<u8>, BasicProperties), PublisherError> {
+        let headers = vec![
+            ("command_name", command.command_name.to_string()),
+            ("causation_id", command.causation_id.to_string()),
+            (
+                "ordering_key",
+                OrderingKey::from(command.correlation_id).to_string(),
+            ),
+        ];
+
+        let data = serde_json::to_vec(command)
+            .map_err(|source| PublisherError::Publish(Box::new(source)))?;
+
+        let properties = message_properties(
+            command.message_id.to_string(),
+            command.correlation_id.to_string(),
+            headers,
+        );
+
+        let routing_key = AmqpRoutingKey::command(command.command_name.value())
+            .map_err(|source| PublisherError::Publish(Box::new(source)))?;
+
+        Ok((routing_key.into(), data, properties))
+    }
+}
+
+impl Publisher<CommandEnvelope> for AmqpCommandPublisher {
+    async fn publish<'a, I>(&self, messages: I) -> Result<Vec<PublishResult>, PublisherError>
+    where
+        I: IntoIterator<Item = &'a CommandEnvelope>,
+        CommandEnvelope: 'a,
+    {
+        let amqp_messages = messages
+            .into_iter()
+            .map(|command| {
+                Self::build_message(command).map(|message| (command.message_id, message))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut results = Vec::with_capacity(amqp_messages.len());
+
+        for (input_index, (message_id, (routing_key, data, properties))) in
+            amqp_messages.into_iter().enumerate()
+        {
+            match publish_confirmed(
+                &self.channel,
+                self.topic_id.value(),
+                &routing_key,
+                &data,
+                properties,
+            )
+            .await
+            {
+                Ok(()) => results.push(PublishResult::Success {
+                    input_index,
+                    transport_message_id: Some(message_id.to_string()),
+                }),
+                Err(cause) => results.push(PublishResult::Failed { input_index, cause }),
+            }
+        }
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,89 @@
+use appletheia_application::ConsumerGroup;
+use appletheia_application::Subscriber;
+use appletheia_application::SubscriberError;
+use appletheia_application::command::CommandSelector;
+use appletheia_application::messaging::{Subscription, TopicId};
+use appletheia_application::outbox::command::CommandEnvelope;
+use lapin::Connection;
+use lapin::options::BasicConsumeOptions;
+use lapin::types::FieldTable;
+
+use super::AmqpQueueOptions;
+use super::AmqpRoutingKey;
+
+use super::amqp_consumer::AmqpConsumer;
+
+/// Consumes commands through a queue per consumer group.
+///
+/// Selectors become bindings on the command name and `Subscription::All` binds `#`.
+pub struct AmqpCommandSubscriber {
+    connection: Connection,
+    queue_options: AmqpQueueOptions,
+    topic_id: TopicId,
+}
+
+impl AmqpCommandSubscriber {
+    pub fn new(connection: Connection, queue_options: AmqpQueueOptions, topic_id: TopicId) -> Self {
+        Self {
+            connection,
+            queue_options,
+            topic_id,
+        }
+    }
+
+    fn binding_key_for_selector(selector: &CommandSelector) -> Result<String, SubscriberError> {
+        AmqpRoutingKey::command(selector.command_name.value())
+            .map(String::from)
+            .map_err(|error| SubscriberError::Subscribe(Box::new(error)))
+    }
+}
+
+impl Subscriber<CommandEnvelope> for AmqpCommandSubscriber {
+    type Consumer = AmqpConsumer<CommandEnvelope>;
+    type Selector = CommandSelector;
+
+    async fn subscribe(
+        &self,
+        consumer_group: &ConsumerGroup,
+        subscription: Subscription<'_, Self::Selector>,
+    ) -> Result<Self::Consumer, SubscriberError> {
+        let binding_keys = match subscription {
+            Subscription::All => vec!["#".to_string()],
+            Subscription::AnyOf([]) => {
+                return Err(SubscriberError::InvalidSubscription);
+            }
+            Subscription::AnyOf(selectors) => selectors
+                .iter()
+                .map(Self::binding_key_for_selector)
+                .collect::<Result<_, _>>()?,
+            Subscription::One(selector) => vec![Self::binding_key_for_selector(selector)?],
+        };
+
+        let channel = self
+            .connection
+            .create_channel()
+            .await
+            .map_err(|error| SubscriberError::Subscribe(Box::new(error)))?;
+        self.queue_options
+            .declare(
+                &channel,
+                self.topic_id.value(),
+                consumer_group.value(),
+                &binding_keys,
+            )
+            .await
+            .map_err(|error| SubscriberError::Subscribe(Box::new(error)))?;
+
+        let consumer = channel
+            .basic_consume(
+                consumer_group.value(),
+                "",
+                BasicConsumeOptions::default(),
+                FieldTable::default(),
+            )
+            .await
+            .map_err(|error| SubscriberError::Subscribe(Box::new(error)))?;
+
+        Ok(AmqpConsumer::new(consumer))
+    }
+}
@@ -0,0 +1,70 @@
+use std::marker::PhantomData;
+
+use appletheia_application::{Consumer, ConsumerError, UndecodableMessage};
+use futures_util::StreamExt;
+use lapin::Consumer as LapinConsumer;
+use lapin::options::BasicRejectOptions;
+use serde::de::DeserializeOwned;
+
+use super::amqp_delivery::AmqpDelivery;
+
+pub struct AmqpConsumer<M> {
+    consumer: LapinConsumer,
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<M> AmqpConsumer<M> {
+    pub(crate) fn new(consumer: LapinConsumer) -> Self {
+        Self {
+            consumer,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<M> Consumer<M> for AmqpConsumer<M>
+where
+    M: DeserializeOwned + Send + Sync + 'static,
+{
+    type Delivery = AmqpDelivery<M>;
+
+    async fn next(&mut self) -> Result<Self::Delivery, ConsumerError> {
+        let delivery = self
+            .consumer
+            .next()
+            .await
+            .transpose()
+            .map_err(|error| ConsumerError::Next(Box::new(error)))?
+            .ok_or_else(|| {
+                ConsumerError::Next(Box::new(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "amqp consumer was cancelled",
+                )))
+            })?;
+
+        // An undecodable message can never succeed, so it goes straight to the dead-letter queue.
+        let message: M = match serde_json::from_slice(&delivery.data) {
+            Ok(message) => message,
+            Err(error) => {
+                delivery
+                    .acker
+                    .reject(BasicRejectOptions { requeue: false })
+                    .await
+                    .map_err(|error| ConsumerError::Nack(Box::new(error)))?;
+                return Err(ConsumerError::Undecodable(UndecodableMessage {
+                    transport_message_id: delivery
+                        .properties
+                        .message_id()
+                        .as_ref()
+                        .map(ToString::to_string),
+                    attributes: [("routing_key".to_string(), delivery.routing_key.to_string())]
+                        .into(),
+                    data: delivery.data,
+                    reason: error.to_string(),
+                }));
+            }
+        };
+
+        Ok(AmqpDelivery::new(delivery.acker, message))
+    }
+}
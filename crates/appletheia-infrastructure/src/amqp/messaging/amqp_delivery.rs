@@ -0,0 +1,50 @@
+use appletheia_application::{ConsumerError, Delivery};
+use lapin::acker::Acker;
+use lapin::options::{BasicAckOptions, BasicNackOptions};
+
+pub struct AmqpDelivery<M> {
+    acker: Option<Acker>,
+    message: M,
+}
+
+impl<M> AmqpDelivery<M> {
+    pub(crate) fn new(acker: Acker, message: M) -> Self {
+        Self {
+            acker: Some(acker),
+            message,
+        }
+    }
+}
+
+impl<M> Delivery<M> for AmqpDelivery<M>
+where
+    M: Send,
+{
+    fn message(&self) -> &M {
+        &self.message
+    }
+
+    async fn ack(&mut self) -> Result<(), ConsumerError> {
+        if let Some(acker) = self.acker.take() {
+            acker
+                .ack(BasicAckOptions::default())
+                .await
+                .map_err(|error| ConsumerError::Ack(Box::new(error)))?;
+        }
+        Ok(())
+    }
+
+    /// Requeues the message; the queue dead-letters it once its delivery limit is reached.
+    async fn nack(&mut self) -> Result<(), ConsumerError> {
+        if let Some(acker) = self.acker.take() {
+            acker
+                .nack(BasicNackOptions {
+                    multiple: false,
+                    requeue: true,
+                })
+                .await
+                .map_err(|error| ConsumerError::Nack(Box::new(error)))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use appletheia_application::event::EventEnvelope;
+use appletheia_application::messaging::{PublishResult, Publisher, PublisherError, TopicId};
+use appletheia_application::outbox::OrderingKey;
+use lapin::{BasicProperties, Channel};
+
+use super::AmqpRoutingKey;
+use super::amqp_publish::{message_properties, publish_confirmed};
+
+/// Publishes events to a durable topic exchange named after the topic.
+///
+/// The routing key is `<aggregate_type>.<event_name>`, see `AmqpRoutingKey`. The channel should have publisher
+/// confirms enabled; without them every accepted publish is reported as successful.
+#[derive(Clone)]
+pub struct AmqpEventPublisher {
+    channel: Channel,
+    topic_id: TopicId,
+}
+
+impl AmqpEventPublisher {
+    pub fn new(channel: Channel, topic_id: TopicId) -> Self {
+        Self { channel, topic_id }
+    }
+
+    fn build_message(
+        event: &EventEnvelope,
+    ) -> Result<(String, Vec<u8>, BasicProperties), PublisherError> {
+        let ordering_key =
+            OrderingKey::from((&event.aggregate_type, &event.aggregate_id)).to_string();
+        let headers = vec![
+            ("event_sequence", event.event_sequence.to_string()),
+            ("event_id", event.event_id.to_string()),
+            ("aggregate_type", event.aggregate_type.to_string()),
+            ("aggregate_id", event.aggregate_id.to_string()),
+            ("aggregate_version", event.aggregate_version.to_string()),
+            ("event_name", event.event_name.to_string()),
+            ("occurred_at", event.occurred_at.to_string()),
+            ("causation_id", event.causation_id.to_string()),
+            ("ordering_key", ordering_key),
+        ];
+
+        let data = serde_json::to_vec(event)
+            .map_err(|source| PublisherError::Publish(Box::new(source)))?;
+
+        let routing_key =
+            AmqpRoutingKey::event(event.aggregate_type.value(), event.event_name.value())
+                .map_err(|source| PublisherError::Publish(Box::new(source)))?;
+        let properties = message_properties(
+            event.event_id.to_string(),
+            event.correlation_id.to_string(),
+            headers,
+        );
+
+        Ok((routing_key.into(), data, properties))
+    }
+}
+
+impl Publisher<EventEnvelope> for AmqpEventPublisher {
+    async fn publish<'a, I>(&self, messages: I) -> Result<Vec<PublishResult>, PublisherError>
+    where
+        I: IntoIterator<Item = &'a EventEnvelope>,
+        EventEnvelope: 'a,
+    {
+        let amqp_messages = messages
+            .into_iter()
+            .map(|event| Self::build_message(event).map(|message| (event.event_id, message)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut results = Vec::with_capacity(amqp_messages.len());
+
+        for (input_index, (event_id, (routing_key, data, properties))) in
+            amqp_messages.into_iter().enumerate()
+        {
+            match publish_confirmed(
+                &self.channel,
+                self.topic_id.value(),
+                &routing_key,
+                &data,
+                properties,
+            )
+            .await
+            {
+                Ok(()) => results.push(PublishResult::Success {
+                    input_index,
+                    transport_message_id: Some(event_id.to_string()),
+                }),
+                Err(cause) => results.push(PublishResult::Failed { input_index, cause }),
+            }
+        }
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,77 @@
+use appletheia_application::messaging::PublishDispatchError;
+use lapin::options::BasicPublishOptions;
+use lapin::publisher_confirm::Confirmation;
+use lapin::types::{AMQPValue, FieldTable, LongString, ShortString};
+use lapin::{BasicProperties, Channel, Error as LapinError};
+
+/// Builds the properties shared by every message published to an exchange.
+pub(crate) fn message_properties(
+    message_id: String,
+    correlation_id: String,
+    headers: Vec<(&str, String)>,
+) -> BasicProperties {
+    let mut table = FieldTable::default();
+    for (key, value) in headers {
+        table.insert(
+            ShortString::from(key),
+            AMQPValue::LongString(LongString::from(value)),
+        );
+    }
+
+    BasicProperties::default()
+        .with_content_type(ShortString::from("application/json"))
+        .with_delivery_mode(2)
+        .with_message_id(ShortString::from(message_id))
+        .with_correlation_id(ShortString::from(correlation_id))
+        .with_headers(table)
+}
+
+/// Publishes one persistent message and waits for the broker confirmation.
+///
+/// As with a Pub/Sub topic without subscriptions, a message no queue is bound for is accepted
+/// and dropped by the broker.
+pub(crate) async fn publish_confirmed(
+    channel: &Channel,
+    exchange: &str,
+    routing_key: &str,
+    payload: &[u8],
+    properties: BasicProperties,
+) -> Result<(), PublishDispatchError> {
+    let confirmation = channel
+        .basic_publish(
+            exchange,
+            routing_key,
+            BasicPublishOptions::default(),
+            payload,
+            properties,
+        )
+        .await
+        .map_err(dispatch_error)?
+        .await
+        .map_err(dispatch_error)?;
+
+    match confirmation {
+        Confirmation::Ack(None) | Confirmation::NotRequested => Ok(()),
+        Confirmation::Ack(Some(returned)) => Err(PublishDispatchError::Permanent {
+            code: returned.reply_code.to_string(),
+            message: returned.reply_text.to_string(),
+        }),
+        Confirmation::Nack(_) => Err(PublishDispatchError::Transient {
+            code: "nack".to_string(),
+            message: "broker rejected the message".to_string(),
+        }),
+    }
+}
+
+fn dispatch_error(error: LapinError) -> PublishDispatchError {
+    match error {
+        LapinError::ProtocolError(source) => PublishDispatchError::Permanent {
+            code: source.get_id().to_string(),
+            message: source.to_string(),
+        },
+        other => PublishDispatchError::Transient {
+            code: "connection_error".to_string(),
+            message: other.to_string(),
+        },
+    }
+}
@@ -0,0 +1,146 @@
+use std::num::{NonZeroU16, NonZeroU32};
+
+use lapin::options::{
+    BasicQosOptions, ExchangeDeclareOptions, QueueBindOptions, QueueDeclareOptions,
+};
+use lapin::types::{AMQPValue, FieldTable, LongString, ShortString};
+use lapin::{Channel, Error as LapinError, ExchangeKind};
+
+/// Settings of the queues declared for consumer groups.
+///
+/// Every consumer group gets a durable quorum queue named after the group. A nacked message
+/// is redelivered until it has been delivered `delivery_limit` times, after which the broker
+/// moves it through the `<exchange>.dead-letter` exchange into the `<group>.dead-letter`
+/// queue.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AmqpQueueOptions {
+    delivery_limit: NonZeroU32,
+    prefetch_count: NonZeroU16,
+}
+
+impl AmqpQueueOptions {
+    pub fn new() -> Self {
+        Self {
+            delivery_limit: NonZeroU32::new(5).expect("5 should be non-zero"),
+            prefetch_count: NonZeroU16::MIN,
+        }
+    }
+
+    pub fn with_delivery_limit(mut self, delivery_limit: NonZeroU32) -> Self {
+        self.delivery_limit = delivery_limit;
+        self
+    }
+
+    /// How many unacked messages a consumer may hold; `1` keeps deliveries in queue order.
+    pub fn with_prefetch_count(mut self, prefetch_count: NonZeroU16) -> Self {
+        self.prefetch_count = prefetch_count;
+        self
+    }
+
+    pub fn delivery_limit(&self) -> NonZeroU32 {
+        self.delivery_limit
+    }
+
+    pub fn prefetch_count(&self) -> NonZeroU16 {
+        self.prefetch_count
+    }
+
+    pub(crate) fn dead_letter_name(name: &str) -> String {
+        format!("{name}.dead-letter")
+    }
+
+    /// Declares the exchanges and queues of a consumer group and binds `binding_keys`.
+    ///
+    /// Declarations are idempotent; bindings of an existing queue are only ever added.
+    pub(crate) async fn declare(
+        &self,
+        channel: &Channel,
+        exchange: &str,
+        queue: &str,
+        binding_keys: &[String],
+    ) -> Result<(), LapinError> {
+        let durable_exchange = ExchangeDeclareOptions {
+            durable: true,
+            ..ExchangeDeclareOptions::default()
+        };
+        let durable_queue = QueueDeclareOptions {
+            durable: true,
+            ..QueueDeclareOptions::default()
+        };
+        let dead_letter_exchange = Self::dead_letter_name(exchange);
+        let dead_letter_queue = Self::dead_letter_name(queue);
+
+        channel
+            .exchange_declare(
+                exchange,
+                ExchangeKind::Topic,
+                durable_exchange,
+                FieldTable::default(),
+            )
+            .await?;
+        channel
+            .exchange_declare(
+                &dead_letter_exchange,
+                ExchangeKind::Direct,
+                durable_exchange,
+                FieldTable::default(),
+            )
+            .await?;
+
+        channel
+            .queue_declare(&dead_letter_queue, durable_queue, FieldTable::default())
+            .await?;
+        channel
+            .queue_bind(
+                &dead_letter_queue,
+                &dead_letter_exchange,
+                queue,
+                QueueBindOptions::default(),
+                FieldTable::default(),
+            )
+            .await?;
+
+        let mut arguments = FieldTable::default();
+        arguments.insert(
+            ShortString::from("x-queue-type"),
+            AMQPValue::LongString(LongString::from("quorum")),
+        );
+        arguments.insert(
+            ShortString::from("x-dead-letter-exchange"),
+            AMQPValue::LongString(LongString::from(dead_letter_exchange.as_str())),
+        );
+        arguments.insert(
+            ShortString::from("x-dead-letter-routing-key"),
+            AMQPValue::LongString(LongString::from(queue)),
+        );
+        arguments.insert(
+            ShortString::from("x-delivery-limit"),
+            AMQPValue::LongLongInt(i64::from(self.delivery_limit.get())),
+        );
+        channel
+            .queue_declare(queue, durable_queue, arguments)
+            .await?;
+
+        for binding_key in binding_keys {
+            channel
+                .queue_bind(
+                    queue,
+                    exchange,
+                    binding_key,
+                    QueueBindOptions::default(),
+                    FieldTable::default(),
+                )
+                .await?;
+        }
+
+        channel
+            .basic_qos(self.prefetch_count.get(), BasicQosOptions::default())
+            .await
+    }
+}
+
+impl Default for AmqpQueueOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
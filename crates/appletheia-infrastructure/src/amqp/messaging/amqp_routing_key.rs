@@ -0,0 +1,102 @@
+use std::fmt::{self, Display};
+
+use super::AmqpRoutingKeyError;
+
+/// Routing key of a message on an AMQP topic exchange.
+///
+/// Words are joined with `.`, the separator topic exchanges match on. Words containing `.`,
+/// `*` or `#` are rejected, so a key always splits back into the names it was built from and
+/// never turns into a wildcard when used as a binding key.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AmqpRoutingKey(String);
+
+impl AmqpRoutingKey {
+    pub fn from_words<'a>(
+        words: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, AmqpRoutingKeyError> {
+        let mut routing_key = String::new();
+        for word in words {
+            if word.is_empty() {
+                return Err(AmqpRoutingKeyError::EmptyWord);
+            }
+            if word.contains(['.', '*', '#']) {
+                return Err(AmqpRoutingKeyError::ReservedCharacter {
+                    word: word.to_owned(),
+                });
+            }
+            if !routing_key.is_empty() {
+                routing_key.push('.');
+            }
+            routing_key.push_str(word);
+        }
+        if routing_key.is_empty() {
+            return Err(AmqpRoutingKeyError::EmptyWord);
+        }
+
+        Ok(Self(routing_key))
+    }
+
+    /// `<aggregate_type>.<event_name>`
+    pub fn event(aggregate_type: &str, event_name: &str) -> Result<Self, AmqpRoutingKeyError> {
+        Self::from_words([aggregate_type, event_name])
+    }
+
+    /// The command name as a single word.
+    pub fn command(command_name: &str) -> Result<Self, AmqpRoutingKeyError> {
+        Self::from_words([command_name])
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for AmqpRoutingKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<AmqpRoutingKey> for String {
+    fn from(value: AmqpRoutingKey) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_joins_aggregate_type_and_event_name() {
+        let routing_key =
+            AmqpRoutingKey::event("account", "opened").expect("routing key should be valid");
+
+        assert_eq!(routing_key.as_str(), "account.opened");
+    }
+
+    #[test]
+    fn rejects_words_containing_the_separator() {
+        let error = AmqpRoutingKey::event("account.opened", "closed")
+            .expect_err("a word with `.` should be rejected");
+
+        assert!(matches!(
+            error,
+            AmqpRoutingKeyError::ReservedCharacter { word } if word == "account.opened"
+        ));
+    }
+
+    #[test]
+    fn rejects_wildcard_words() {
+        assert!(AmqpRoutingKey::command("#").is_err());
+        assert!(AmqpRoutingKey::event("account", "*").is_err());
+    }
+
+    #[test]
+    fn rejects_empty_words() {
+        assert!(matches!(
+            AmqpRoutingKey::event("account", ""),
+            Err(AmqpRoutingKeyError::EmptyWord)
+        ));
+    }
+}
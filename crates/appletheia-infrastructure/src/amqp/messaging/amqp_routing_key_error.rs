@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AmqpRoutingKeyError {
+    #[error("routing key word is empty")]
+    EmptyWord,
+
+    #[error("routing key word `{word}` contains `.`, `*` or `#`")]
+    ReservedCharacter { word: String },
+}
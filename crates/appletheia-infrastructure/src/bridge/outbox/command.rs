@@ -1,3 +1,11 @@
+#[cfg(feature = "amqp")]
+pub mod pg_amqp_command_outbox_relay;
+#[cfg(feature = "nats")]
+pub mod pg_nats_command_outbox_relay;
 pub mod pg_pubsub_command_outbox_relay;
 
+#[cfg(feature = "amqp")]
+pub use pg_amqp_command_outbox_relay::PgAmqpCommandOutboxRelay;
+#[cfg(feature = "nats")]
+pub use pg_nats_command_outbox_relay::PgNatsCommandOutboxRelay;
 pub use pg_pubsub_command_outbox_relay::PgPubsubCommandOutboxRelay;
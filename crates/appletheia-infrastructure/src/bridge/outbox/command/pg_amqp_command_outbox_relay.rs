@@ -0,0 +1,15 @@
+use appletheia_application::outbox::command::CommandOutbox;
+use appletheia_application::outbox::{DefaultOutboxRelay, PollingOutboxWakeupListener};
+
+use crate::amqp::messaging::AmqpCommandPublisher;
+use crate::postgresql::outbox::command::{PgCommandOutboxFetcher, PgCommandOutboxWriter};
+use crate::postgresql::unit_of_work::PgUnitOfWorkFactory;
+
+pub type PgAmqpCommandOutboxRelay<L = PollingOutboxWakeupListener> = DefaultOutboxRelay<
+    PgUnitOfWorkFactory,
+    CommandOutbox,
+    PgCommandOutboxFetcher,
+    PgCommandOutboxWriter,
+    AmqpCommandPublisher,
+    L,
+>;
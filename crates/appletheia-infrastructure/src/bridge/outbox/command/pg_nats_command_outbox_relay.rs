@@ -0,0 +1,15 @@
+use appletheia_application::outbox::command::CommandOutbox;
+use appletheia_application::outbox::{DefaultOutboxRelay, PollingOutboxWakeupListener};
+
+use crate::nats::messaging::NatsCommandPublisher;
+use crate::postgresql::outbox::command::{PgCommandOutboxFetcher, PgCommandOutboxWriter};
+use crate::postgresql::unit_of_work::PgUnitOfWorkFactory;
+
+pub type PgNatsCommandOutboxRelay<L = PollingOutboxWakeupListener> = DefaultOutboxRelay<
+    PgUnitOfWorkFactory,
+    CommandOutbox,
+    PgCommandOutboxFetcher,
+    PgCommandOutboxWriter,
+    NatsCommandPublisher,
+    L,
+>;
@@ -1,3 +1,13 @@
+#[cfg(feature = "amqp")]
+pub mod pg_amqp_event_outbox_relay;
+#[cfg(feature = "nats")]
+pub mod pg_nats_event_outbox_relay;
 pub mod pg_pubsub_event_outbox_relay;
+pub mod pg_pubsub_routed_event_outbox_relay;
 
+#[cfg(feature = "amqp")]
+pub use pg_amqp_event_outbox_relay::PgAmqpEventOutboxRelay;
+#[cfg(feature = "nats")]
+pub use pg_nats_event_outbox_relay::PgNatsEventOutboxRelay;
 pub use pg_pubsub_event_outbox_relay::PgPubsubEventOutboxRelay;
+pub use pg_pubsub_routed_event_outbox_relay::PgPubsubRoutedEventOutboxRelay;
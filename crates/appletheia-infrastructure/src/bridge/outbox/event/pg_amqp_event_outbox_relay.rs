@@ -0,0 +1,15 @@
+use appletheia_application::outbox::event::EventOutbox;
+use appletheia_application::outbox::{DefaultOutboxRelay, PollingOutboxWakeupListener};
+
+use crate::amqp::messaging::AmqpEventPublisher;
+use crate::postgresql::outbox::event::{PgEventOutboxFetcher, PgEventOutboxWriter};
+use crate::postgresql::unit_of_work::PgUnitOfWorkFactory;
+
+pub type PgAmqpEventOutboxRelay<L = PollingOutboxWakeupListener> = DefaultOutboxRelay<
+    PgUnitOfWorkFactory,
+    EventOutbox,
+    PgEventOutboxFetcher,
+    PgEventOutboxWriter,
+    AmqpEventPublisher,
+    L,
+>;
@@ -0,0 +1,15 @@
+use appletheia_application::outbox::event::EventOutbox;
+use appletheia_application::outbox::{DefaultOutboxRelay, PollingOutboxWakeupListener};
+
+use crate::nats::messaging::NatsEventPublisher;
+use crate::postgresql::outbox::event::{PgEventOutboxFetcher, PgEventOutboxWriter};
+use crate::postgresql::unit_of_work::PgUnitOfWorkFactory;
+
+pub type PgNatsEventOutboxRelay<L = PollingOutboxWakeupListener> = DefaultOutboxRelay<
+    PgUnitOfWorkFactory,
+    EventOutbox,
+    PgEventOutboxFetcher,
+    PgEventOutboxWriter,
+    NatsEventPublisher,
+    L,
+>;
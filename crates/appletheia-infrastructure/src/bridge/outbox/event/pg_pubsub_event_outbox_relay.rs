@@ -1,14 +1,15 @@
-use appletheia_application::outbox::DefaultOutboxRelay;
 use appletheia_application::outbox::event::EventOutbox;
+use appletheia_application::outbox::{DefaultOutboxRelay, PollingOutboxWakeupListener};
 
 use crate::google_cloud::pubsub::messaging::PubsubEventPublisher;
 use crate::postgresql::outbox::event::{PgEventOutboxFetcher, PgEventOutboxWriter};
 use crate::postgresql::unit_of_work::PgUnitOfWorkFactory;
 
-pub type PgPubsubEventOutboxRelay = DefaultOutboxRelay<
+pub type PgPubsubEventOutboxRelay<L = PollingOutboxWakeupListener> = DefaultOutboxRelay<
     PgUnitOfWorkFactory,
     EventOutbox,
     PgEventOutboxFetcher,
     PgEventOutboxWriter,
     PubsubEventPublisher,
+    L,
 >;
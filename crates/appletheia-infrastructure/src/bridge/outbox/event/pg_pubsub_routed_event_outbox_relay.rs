@@ -0,0 +1,15 @@
+use appletheia_application::outbox::event::EventOutbox;
+use appletheia_application::outbox::{DefaultOutboxRelay, PollingOutboxWakeupListener};
+
+use crate::google_cloud::pubsub::messaging::PubsubRoutedEventPublisher;
+use crate::postgresql::outbox::event::{PgEventOutboxFetcher, PgEventOutboxWriter};
+use crate::postgresql::unit_of_work::PgUnitOfWorkFactory;
+
+pub type PgPubsubRoutedEventOutboxRelay<L = PollingOutboxWakeupListener> = DefaultOutboxRelay<
+    PgUnitOfWorkFactory,
+    EventOutbox,
+    PgEventOutboxFetcher,
+    PgEventOutboxWriter,
+    PubsubRoutedEventPublisher,
+    L,
+>;
@@ -1,3 +1,5 @@
 pub mod migration;
+pub mod shutdown;
 
 pub use migration::*;
+pub use shutdown::*;
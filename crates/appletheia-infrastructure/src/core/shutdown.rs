@@ -0,0 +1,3 @@
+pub mod os_shutdown_signal;
+
+pub use os_shutdown_signal::*;
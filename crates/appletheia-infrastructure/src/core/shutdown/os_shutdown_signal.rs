@@ -0,0 +1,49 @@
+use std::error::Error;
+use std::io;
+
+use tracing::warn;
+
+/// Waits for the process to be asked to shut down: Ctrl-C everywhere and SIGTERM on Unix.
+///
+/// Typically passed to `WorkerSupervisor::run_until` via `requested` so that the orchestrator's
+/// termination signal drains the workers gracefully.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsShutdownSignal;
+
+impl OsShutdownSignal {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Completes when a shutdown signal is received, or fails if a handler cannot be installed.
+    pub async fn received(&self) -> io::Result<()> {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{SignalKind, signal};
+
+            let mut terminate = signal(SignalKind::terminate())?;
+            tokio::select! {
+                interrupted = tokio::signal::ctrl_c() => interrupted,
+                _ = terminate.recv() => Ok(()),
+            }
+        }
+
+        #[cfg(not(unix))]
+        {
+            tokio::signal::ctrl_c().await
+        }
+    }
+
+    /// Completes when a shutdown signal is received.
+    ///
+    /// A signal handler that cannot be installed is logged and treated as a shutdown request,
+    /// so the workers drain instead of running without a way to stop them gracefully.
+    pub async fn requested(&self) {
+        if let Err(error) = self.received().await {
+            warn!(
+                error = &error as &dyn Error,
+                "failed to listen for shutdown signals; shutting down"
+            );
+        }
+    }
+}
@@ -4,8 +4,11 @@ pub mod pubsub_consumer;
 pub mod pubsub_delivery;
 pub mod pubsub_event_publisher;
 pub mod pubsub_event_subscriber;
+pub mod pubsub_routed_event_publisher;
+pub mod pubsub_routed_event_publisher_error;
 pub mod pubsub_subscription_path_prefix;
 pub mod pubsub_subscription_path_prefix_error;
+pub mod pubsub_undecodable_policy;
 
 pub use pubsub_command_publisher::PubsubCommandPublisher;
 pub use pubsub_command_subscriber::PubsubCommandSubscriber;
@@ -13,5 +16,8 @@ pub use pubsub_consumer::PubsubConsumer;
 pub use pubsub_delivery::PubsubDelivery;
 pub use pubsub_event_publisher::PubsubEventPublisher;
 pub use pubsub_event_subscriber::PubsubEventSubscriber;
+pub use pubsub_routed_event_publisher::PubsubRoutedEventPublisher;
+pub use pubsub_routed_event_publisher_error::PubsubRoutedEventPublisherError;
 pub use pubsub_subscription_path_prefix::PubsubSubscriptionPathPrefix;
 pub use pubsub_subscription_path_prefix_error::PubsubSubscriptionPathPrefixError;
+pub use pubsub_undecodable_policy::PubsubUndecodablePolicy;
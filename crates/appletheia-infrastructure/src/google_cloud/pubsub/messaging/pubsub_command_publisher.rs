@@ -5,6 +5,7 @@ use appletheia_application::messaging::{
 };
 use appletheia_application::outbox::OrderingKey;
 use appletheia_application::outbox::command::CommandEnvelope;
+use appletheia_application::telemetry::TraceContext;
 use google_cloud_gax::error::rpc::Code;
 use google_cloud_pubsub::client::Publisher as GooglePublisher;
 use google_cloud_pubsub::error::PublishError;
@@ -30,6 +31,15 @@ impl PubsubCommandPublisher {
             command.correlation_id.to_string(),
         );
         attributes.insert("causation_id".to_string(), command.causation_id.to_string());
+        if let Some(trace_context) = &command.trace_context {
+            attributes.insert(
+                TraceContext::TRACEPARENT.to_string(),
+                trace_context.traceparent().to_string(),
+            );
+            if let Some(tracestate) = trace_context.tracestate() {
+                attributes.insert(TraceContext::TRACESTATE.to_string(), tracestate.to_string());
+            }
+        }
 
         let data = serde_json::to_vec(command)
             .map_err(|source| PublisherError::Publish(Box::new(source)))?;
@@ -9,6 +9,7 @@ use google_cloud_pubsub::client::{Subscriber as GoogleSubscriber, SubscriptionAd
 use google_cloud_pubsub::model::Subscription as PubsubSubscription;
 
 use super::PubsubSubscriptionPathPrefix;
+use super::PubsubUndecodablePolicy;
 use super::pubsub_consumer::PubsubConsumer;
 
 pub struct PubsubCommandSubscriber {
@@ -16,6 +17,7 @@ pub struct PubsubCommandSubscriber {
     subscription_admin: SubscriptionAdmin,
     subscription_path_prefix: PubsubSubscriptionPathPrefix,
     topic_id: TopicId,
+    undecodable_policy: PubsubUndecodablePolicy,
 }
 
 impl PubsubCommandSubscriber {
@@ -30,9 +32,15 @@ impl PubsubCommandSubscriber {
             subscription_admin,
             subscription_path_prefix,
             topic_id,
+            undecodable_policy: PubsubUndecodablePolicy::default(),
         }
     }
 
+    pub fn with_undecodable_policy(mut self, undecodable_policy: PubsubUndecodablePolicy) -> Self {
+        self.undecodable_policy = undecodable_policy;
+        self
+    }
+
     fn filter_expression_for_selector(selector: &CommandSelector) -> String {
         format!(
             "(attributes.command_name = \"{}\")",
@@ -94,7 +102,11 @@ impl Subscriber<CommandEnvelope> for PubsubCommandSubscriber {
             }
         }
 
-        let stream = self.subscriber.subscribe(subscription_name).build();
-        Ok(PubsubConsumer::new(stream))
+        let stream = self.subscriber.subscribe(&subscription_name).build();
+        Ok(PubsubConsumer::new(
+            stream,
+            subscription_name,
+            self.undecodable_policy.clone(),
+        ))
     }
 }
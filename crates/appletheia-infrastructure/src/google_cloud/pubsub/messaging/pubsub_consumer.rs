@@ -1,23 +1,58 @@
 use std::marker::PhantomData;
 
-use appletheia_application::{Consumer, ConsumerError};
+use appletheia_application::{Consumer, ConsumerError, UndecodableMessage};
+use google_cloud_pubsub::model::Message;
 use google_cloud_pubsub::subscriber::MessageStream;
+use google_cloud_pubsub::subscriber::handler::Handler;
 use serde::de::DeserializeOwned;
 
+use super::PubsubUndecodablePolicy;
 use super::pubsub_delivery::PubsubDelivery;
 
 pub struct PubsubConsumer<M> {
     stream: MessageStream,
+    subscription_name: String,
+    undecodable_policy: PubsubUndecodablePolicy,
     _marker: PhantomData<fn() -> M>,
 }
 
 impl<M> PubsubConsumer<M> {
-    pub(crate) fn new(stream: MessageStream) -> Self {
+    pub(crate) fn new(
+        stream: MessageStream,
+        subscription_name: String,
+        undecodable_policy: PubsubUndecodablePolicy,
+    ) -> Self {
         Self {
             stream,
+            subscription_name,
+            undecodable_policy,
             _marker: PhantomData,
         }
     }
+
+    async fn settle_undecodable(
+        &self,
+        message: Message,
+        handler: Handler,
+        reason: String,
+    ) -> UndecodableMessage {
+        if self
+            .undecodable_policy
+            .acks(&message, &self.subscription_name, &reason)
+            .await
+        {
+            handler.ack();
+        } else {
+            handler.nack();
+        }
+
+        UndecodableMessage {
+            transport_message_id: Some(message.message_id),
+            attributes: message.attributes.into_iter().collect(),
+            data: message.data.to_vec(),
+            reason,
+        }
+    }
 }
 
 impl<M> Consumer<M> for PubsubConsumer<M>
@@ -40,9 +75,12 @@ where
                 )))
             })?;
 
-        let message: M = serde_json::from_slice(&message.data)
-            .map_err(|error| ConsumerError::Next(Box::new(error)))?;
-
-        Ok(PubsubDelivery::new(handler, message))
+        match serde_json::from_slice::<M>(&message.data) {
+            Ok(decoded) => Ok(PubsubDelivery::new(handler, decoded)),
+            Err(error) => Err(ConsumerError::Undecodable(
+                self.settle_undecodable(message, handler, error.to_string())
+                    .await,
+            )),
+        }
     }
 }
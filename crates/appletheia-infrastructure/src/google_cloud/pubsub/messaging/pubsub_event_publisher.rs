@@ -5,6 +5,7 @@ use appletheia_application::messaging::{
     PublishDispatchError, PublishResult, Publisher, PublisherError,
 };
 use appletheia_application::outbox::OrderingKey;
+use appletheia_application::telemetry::TraceContext;
 use google_cloud_gax::error::rpc::Code;
 use google_cloud_pubsub::client::Publisher as GooglePublisher;
 use google_cloud_pubsub::error::PublishError;
@@ -20,7 +21,7 @@ impl PubsubEventPublisher {
         Self { publisher }
     }
 
-    fn build_message(event: &EventEnvelope) -> Result<Message, PublisherError> {
+    pub(crate) fn build_message(event: &EventEnvelope) -> Result<Message, PublisherError> {
         let mut attributes = HashMap::new();
 
         attributes.insert(
@@ -44,6 +45,15 @@ impl PubsubEventPublisher {
             event.correlation_id.to_string(),
         );
         attributes.insert("causation_id".to_string(), event.causation_id.to_string());
+        if let Some(trace_context) = &event.context.trace_context {
+            attributes.insert(
+                TraceContext::TRACEPARENT.to_string(),
+                trace_context.traceparent().to_string(),
+            );
+            if let Some(tracestate) = trace_context.tracestate() {
+                attributes.insert(TraceContext::TRACESTATE.to_string(), tracestate.to_string());
+            }
+        }
 
         let data = serde_json::to_vec(event)
             .map_err(|source| PublisherError::Publish(Box::new(source)))?;
@@ -57,7 +67,7 @@ impl PubsubEventPublisher {
             .set_ordering_key(ordering_key))
     }
 
-    fn dispatch_error(error: PublishError) -> PublishDispatchError {
+    pub(crate) fn dispatch_error(error: PublishError) -> PublishDispatchError {
         match error {
             PublishError::Rpc(source) => {
                 let code = source
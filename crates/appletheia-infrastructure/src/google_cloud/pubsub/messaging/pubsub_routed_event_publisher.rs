@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use appletheia_application::event::{EventEnvelope, EventTopicRouter};
+use appletheia_application::messaging::{PublishResult, Publisher, PublisherError, TopicId};
+use google_cloud_pubsub::client::Publisher as GooglePublisher;
+
+use super::{PubsubEventPublisher, PubsubRoutedEventPublisherError};
+
+/// Publishes each event to the topic chosen by an `EventTopicRouter`.
+#[derive(Clone)]
+pub struct PubsubRoutedEventPublisher {
+    router: EventTopicRouter,
+    publishers: HashMap<TopicId, GooglePublisher>,
+}
+
+impl PubsubRoutedEventPublisher {
+    /// Fails when the router can route to a topic that has no publisher.
+    pub fn new(
+        router: EventTopicRouter,
+        publishers: HashMap<TopicId, GooglePublisher>,
+    ) -> Result<Self, PubsubRoutedEventPublisherError> {
+        if let Some(topic) = router
+            .topics()
+            .into_iter()
+            .find(|topic| !publishers.contains_key(*topic))
+        {
+            return Err(PubsubRoutedEventPublisherError::MissingPublisher {
+                topic: topic.clone(),
+            });
+        }
+
+        Ok(Self { router, publishers })
+    }
+
+    pub fn router(&self) -> &EventTopicRouter {
+        &self.router
+    }
+}
+
+impl Publisher<EventEnvelope> for PubsubRoutedEventPublisher {
+    async fn publish<'a, I>(&self, messages: I) -> Result<Vec<PublishResult>, PublisherError>
+    where
+        I: IntoIterator<Item = &'a EventEnvelope>,
+        EventEnvelope: 'a,
+    {
+        let mut publish_futures = Vec::new();
+        for event in messages {
+            let message = PubsubEventPublisher::build_message(event)?;
+            let publisher = &self.publishers[self.router.route(event)];
+            publish_futures.push(publisher.publish(message));
+        }
+
+        let mut results = Vec::with_capacity(publish_futures.len());
+
+        for (input_index, publish_future) in publish_futures.into_iter().enumerate() {
+            match publish_future.await {
+                Ok(message_id) => {
+                    results.push(PublishResult::Success {
+                        input_index,
+                        transport_message_id: Some(message_id),
+                    });
+                }
+                Err(error) => {
+                    let cause = PubsubEventPublisher::dispatch_error(error);
+                    results.push(PublishResult::Failed { input_index, cause });
+                }
+            }
+        }
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+use appletheia_application::messaging::TopicId;
+
+/// Represents a routing configuration that cannot be served by the given publishers.
+#[derive(Debug, Error)]
+pub enum PubsubRoutedEventPublisherError {
+    #[error("no Pub/Sub publisher configured for routed topic `{topic}`")]
+    MissingPublisher { topic: TopicId },
+}
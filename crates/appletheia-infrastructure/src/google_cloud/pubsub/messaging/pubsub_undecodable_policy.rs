@@ -0,0 +1,122 @@
+use google_cloud_pubsub::client::Publisher as GooglePublisher;
+use google_cloud_pubsub::model::Message;
+
+/// What a `PubsubConsumer` does with a message it cannot decode.
+///
+/// Every policy settles the message and returns `ConsumerError::Undecodable`, which workers
+/// skip, so a malformed or newer-versioned message never stops a worker.
+#[derive(Clone, Debug, Default)]
+pub enum PubsubUndecodablePolicy {
+    /// Nacks the message, leaving redelivery and dead-lettering to the subscription.
+    ///
+    /// Only use this with a subscription that has a dead-letter policy; without one the
+    /// message is redelivered forever.
+    Surface,
+
+    /// Acks and drops the message.
+    #[default]
+    Drop,
+
+    /// Republishes the raw message to a dead-letter topic, then acks it.
+    ///
+    /// The copy keeps the original attributes and adds `undecodable_reason`,
+    /// `original_message_id` and `original_subscription`. The message is nacked if the
+    /// republish fails.
+    DeadLetter(GooglePublisher),
+}
+
+impl PubsubUndecodablePolicy {
+    /// Applies the policy to `message` and returns whether it should be acked.
+    pub(crate) async fn acks(
+        &self,
+        message: &Message,
+        subscription_name: &str,
+        reason: &str,
+    ) -> bool {
+        match self {
+            Self::Surface => false,
+            Self::Drop => true,
+            Self::DeadLetter(publisher) => publisher
+                .publish(Self::dead_letter_message(
+                    message,
+                    subscription_name,
+                    reason,
+                ))
+                .await
+                .is_ok(),
+        }
+    }
+
+    fn dead_letter_message(message: &Message, subscription_name: &str, reason: &str) -> Message {
+        let mut attributes = message.attributes.clone();
+        attributes.insert("undecodable_reason".to_string(), reason.to_string());
+        attributes.insert(
+            "original_message_id".to_string(),
+            message.message_id.clone(),
+        );
+        attributes.insert(
+            "original_subscription".to_string(),
+            subscription_name.to_string(),
+        );
+
+        Message::new()
+            .set_data(message.data.clone())
+            .set_attributes(attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn undecodable_message() -> Message {
+        Message::new()
+            .set_data(b"not json".to_vec())
+            .set_attributes([("event_name", "opened")])
+            .set_message_id("message-1")
+    }
+
+    #[test]
+    fn default_policy_drops_the_message() {
+        assert!(matches!(
+            PubsubUndecodablePolicy::default(),
+            PubsubUndecodablePolicy::Drop
+        ));
+    }
+
+    #[tokio::test]
+    async fn surface_nacks_the_message() {
+        let acks = PubsubUndecodablePolicy::Surface
+            .acks(&undecodable_message(), "ledger", "expected value")
+            .await;
+
+        assert!(!acks);
+    }
+
+    #[tokio::test]
+    async fn drop_acks_the_message() {
+        let acks = PubsubUndecodablePolicy::Drop
+            .acks(&undecodable_message(), "ledger", "expected value")
+            .await;
+
+        assert!(acks);
+    }
+
+    #[test]
+    fn dead_letter_copy_keeps_the_data_and_records_the_origin() {
+        let dead_letter = PubsubUndecodablePolicy::dead_letter_message(
+            &undecodable_message(),
+            "ledger",
+            "expected value",
+        );
+
+        assert_eq!(dead_letter.data.as_ref(), b"not json");
+        assert_eq!(dead_letter.attributes["event_name"], "opened");
+        assert_eq!(
+            dead_letter.attributes["undecodable_reason"],
+            "expected value"
+        );
+        assert_eq!(dead_letter.attributes["original_message_id"], "message-1");
+        assert_eq!(dead_letter.attributes["original_subscription"], "ledger");
+    }
+}
@@ -0,0 +1,27 @@
+pub mod authorization;
+pub mod command;
+pub mod event;
+#[cfg(test)]
+mod in_memory_storage_conformance;
+pub mod in_memory_storage_error;
+pub mod messaging;
+pub mod outbox;
+pub mod projection;
+pub mod rate_limit;
+pub mod repository;
+pub mod saga;
+pub mod snapshot;
+pub mod unit_of_work;
+
+pub use authorization::*;
+pub use command::*;
+pub use event::*;
+pub use in_memory_storage_error::InMemoryStorageError;
+pub use messaging::*;
+pub use outbox::*;
+pub use projection::*;
+pub use rate_limit::*;
+pub use repository::*;
+pub use saga::*;
+pub use snapshot::*;
+pub use unit_of_work::*;
@@ -0,0 +1,3 @@
+pub mod in_memory_relationship_store;
+
+pub use in_memory_relationship_store::InMemoryRelationshipStore;
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use appletheia_application::authorization::{
+    AggregateRef, RelationRefOwned, Relationship, RelationshipChange, RelationshipRevision,
+    RelationshipStore, RelationshipStoreError, RelationshipSubject,
+};
+use appletheia_application::event::AggregateTypeOwned;
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+#[derive(Debug)]
+pub struct InMemoryRelationshipStore;
+
+impl InMemoryRelationshipStore {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn is_relation(relationship: &Relationship, relation: &RelationRefOwned) -> bool {
+        relationship.aggregate.aggregate_type == relation.aggregate_type
+            && relationship.relation.relation_name == relation.relation_name
+    }
+
+    fn is_subject_type(
+        subject: &RelationshipSubject,
+        subject_aggregate_type: Option<&AggregateTypeOwned>,
+    ) -> bool {
+        let Some(subject_aggregate_type) = subject_aggregate_type else {
+            return true;
+        };
+
+        match subject {
+            RelationshipSubject::Aggregate(aggregate)
+            | RelationshipSubject::AggregateSet { aggregate, .. } => {
+                &aggregate.aggregate_type == subject_aggregate_type
+            }
+            RelationshipSubject::Wildcard { aggregate_type } => {
+                aggregate_type == subject_aggregate_type
+            }
+        }
+    }
+}
+
+impl Default for InMemoryRelationshipStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelationshipStore for InMemoryRelationshipStore {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn apply_changes(
+        &self,
+        uow: &mut Self::Uow,
+        changes: &[RelationshipChange],
+    ) -> Result<(), RelationshipStoreError> {
+        let mut deduped: HashMap<&Relationship, bool> = HashMap::new();
+        for change in changes {
+            match change {
+                RelationshipChange::Upsert(relationship) => deduped.insert(relationship, true),
+                RelationshipChange::Delete(relationship) => deduped.insert(relationship, false),
+            };
+        }
+
+        let tables = uow.tables_mut();
+        let mut changed = false;
+        for (relationship, is_upsert) in deduped {
+            changed |= if is_upsert {
+                tables.relationships.insert(relationship.clone())
+            } else {
+                tables.relationships.remove(relationship)
+            };
+        }
+
+        if changed {
+            let revision = tables.sequences.next_relationship_revision();
+            tables.relationship_revision = RelationshipRevision::try_from(revision)
+                .map_err(|e| RelationshipStoreError::MappingFailed(Box::new(e)))?;
+        }
+
+        Ok(())
+    }
+
+    async fn read_aggregates_by_subject(
+        &self,
+        uow: &mut Self::Uow,
+        subject: &RelationshipSubject,
+        relation: &RelationRefOwned,
+    ) -> Result<Vec<AggregateRef>, RelationshipStoreError> {
+        let mut aggregates = Vec::new();
+        for relationship in &uow.tables().relationships {
+            if Self::is_relation(relationship, relation)
+                && &relationship.subject == subject
+                && !aggregates.contains(&relationship.aggregate)
+            {
+                aggregates.push(relationship.aggregate.clone());
+            }
+        }
+
+        Ok(aggregates)
+    }
+
+    async fn read_subjects_by_aggregate(
+        &self,
+        uow: &mut Self::Uow,
+        aggregate: &AggregateRef,
+        relation: &RelationRefOwned,
+        subject_aggregate_type: Option<&AggregateTypeOwned>,
+    ) -> Result<Vec<RelationshipSubject>, RelationshipStoreError> {
+        Ok(uow
+            .tables()
+            .relationships
+            .iter()
+            .filter(|relationship| {
+                &relationship.aggregate == aggregate
+                    && Self::is_relation(relationship, relation)
+                    && Self::is_subject_type(&relationship.subject, subject_aggregate_type)
+            })
+            .map(|relationship| relationship.subject.clone())
+            .collect())
+    }
+
+    async fn read_subjects_by_aggregates(
+        &self,
+        uow: &mut Self::Uow,
+        aggregates: &[AggregateRef],
+        relation: &RelationRefOwned,
+        subject_aggregate_type: Option<&AggregateTypeOwned>,
+    ) -> Result<HashMap<AggregateRef, Vec<RelationshipSubject>>, RelationshipStoreError> {
+        let mut subjects_by_aggregate: HashMap<AggregateRef, Vec<RelationshipSubject>> = aggregates
+            .iter()
+            .map(|aggregate| (aggregate.clone(), Vec::new()))
+            .collect();
+
+        for relationship in &uow.tables().relationships {
+            if !Self::is_relation(relationship, relation)
+                || !Self::is_subject_type(&relationship.subject, subject_aggregate_type)
+            {
+                continue;
+            }
+            if let Some(subjects) = subjects_by_aggregate.get_mut(&relationship.aggregate) {
+                subjects.push(relationship.subject.clone());
+            }
+        }
+
+        Ok(subjects_by_aggregate)
+    }
+
+    async fn read_revision(
+        &self,
+        uow: &mut Self::Uow,
+    ) -> Result<RelationshipRevision, RelationshipStoreError> {
+        Ok(uow.tables().relationship_revision)
+    }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod in_memory_idempotency_row;
+pub mod in_memory_idempotency_service;
+
+pub use in_memory_idempotency_service::InMemoryIdempotencyService;
@@ -0,0 +1,9 @@
+use appletheia_application::command::IdempotencyState;
+
+#[derive(Clone, Debug)]
+pub(crate) struct InMemoryIdempotencyRow {
+    pub command_name: String,
+    pub command_hash: String,
+    /// `None` while the command is still in progress.
+    pub state: Option<IdempotencyState>,
+}
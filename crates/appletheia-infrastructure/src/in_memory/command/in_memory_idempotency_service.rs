@@ -0,0 +1,95 @@
+use std::collections::hash_map::Entry;
+
+use appletheia_application::command::{
+    CommandFailureReport, CommandHash, CommandName, IdempotencyBeginResult, IdempotencyOutput,
+    IdempotencyService, IdempotencyServiceError, IdempotencyState,
+};
+use appletheia_application::request_context::MessageId;
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+use super::in_memory_idempotency_row::InMemoryIdempotencyRow;
+
+#[derive(Debug)]
+pub struct InMemoryIdempotencyService;
+
+impl InMemoryIdempotencyService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn complete(
+        uow: &mut InMemoryUnitOfWork,
+        message_id: MessageId,
+        state: IdempotencyState,
+    ) -> Result<(), IdempotencyServiceError> {
+        match uow.tables_mut().idempotency.get_mut(&message_id) {
+            Some(row) if row.state.is_none() => {
+                row.state = Some(state);
+                Ok(())
+            }
+            _ => Err(IdempotencyServiceError::InvalidStateTransition),
+        }
+    }
+}
+
+impl Default for InMemoryIdempotencyService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdempotencyService for InMemoryIdempotencyService {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn begin(
+        &self,
+        uow: &mut Self::Uow,
+        message_id: MessageId,
+        command_name: CommandName,
+        command_hash: &CommandHash,
+    ) -> Result<IdempotencyBeginResult, IdempotencyServiceError> {
+        let command_name = command_name.to_string();
+
+        let row = match uow.tables_mut().idempotency.entry(message_id) {
+            Entry::Vacant(entry) => {
+                entry.insert(InMemoryIdempotencyRow {
+                    command_name,
+                    command_hash: command_hash.as_str().to_owned(),
+                    state: None,
+                });
+                return Ok(IdempotencyBeginResult::New);
+            }
+            Entry::Occupied(entry) => entry.into_mut(),
+        };
+
+        if row.command_name != command_name || row.command_hash != command_hash.as_str() {
+            return Err(IdempotencyServiceError::Conflict { message_id });
+        }
+
+        Ok(match &row.state {
+            None => IdempotencyBeginResult::InProgress,
+            Some(state) => IdempotencyBeginResult::Existing {
+                state: state.clone(),
+            },
+        })
+    }
+
+    async fn complete_success(
+        &self,
+        uow: &mut Self::Uow,
+        message_id: MessageId,
+        output: IdempotencyOutput,
+    ) -> Result<(), IdempotencyServiceError> {
+        Self::complete(uow, message_id, IdempotencyState::Succeeded { output })
+    }
+
+    async fn complete_failure(
+        &self,
+        uow: &mut Self::Uow,
+        message_id: MessageId,
+        error: CommandFailureReport,
+    ) -> Result<(), IdempotencyServiceError> {
+        Self::complete(uow, message_id, IdempotencyState::Failed { error })
+    }
+}
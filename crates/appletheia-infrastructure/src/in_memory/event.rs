@@ -0,0 +1,7 @@
+pub mod in_memory_event_lookup;
+pub mod in_memory_event_reader;
+pub mod in_memory_event_writer;
+
+pub use in_memory_event_lookup::InMemoryEventLookup;
+pub use in_memory_event_reader::InMemoryEventReader;
+pub use in_memory_event_writer::InMemoryEventWriter;
@@ -0,0 +1,80 @@
+use appletheia_application::event::{EventEnvelope, EventLookup, EventLookupError, EventSequence};
+use appletheia_application::request_context::{CausationId, CorrelationId};
+use appletheia_domain::EventId;
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+#[derive(Debug)]
+pub struct InMemoryEventLookup;
+
+impl InMemoryEventLookup {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InMemoryEventLookup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventLookup for InMemoryEventLookup {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn max_event_sequence_by_causation_id(
+        &self,
+        uow: &mut Self::Uow,
+        causation_id: CausationId,
+    ) -> Result<Option<EventSequence>, EventLookupError> {
+        Ok(uow
+            .tables()
+            .events
+            .values()
+            .filter(|event| event.causation_id == causation_id)
+            .map(|event| event.event_sequence)
+            .max())
+    }
+
+    async fn last_event_id_by_causation_id(
+        &self,
+        uow: &mut Self::Uow,
+        causation_id: CausationId,
+    ) -> Result<Option<EventId>, EventLookupError> {
+        Ok(uow
+            .tables()
+            .events
+            .values()
+            .filter(|event| event.causation_id == causation_id)
+            .max_by_key(|event| event.aggregate_version)
+            .map(|event| event.event_id))
+    }
+
+    async fn events_by_causation_id(
+        &self,
+        uow: &mut Self::Uow,
+        causation_id: CausationId,
+    ) -> Result<Vec<EventEnvelope>, EventLookupError> {
+        Ok(uow
+            .tables()
+            .events
+            .values()
+            .filter(|event| event.causation_id == causation_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn events_by_correlation_id(
+        &self,
+        uow: &mut Self::Uow,
+        correlation_id: CorrelationId,
+    ) -> Result<Vec<EventEnvelope>, EventLookupError> {
+        Ok(uow
+            .tables()
+            .events
+            .values()
+            .filter(|event| event.correlation_id == correlation_id)
+            .cloned()
+            .collect())
+    }
+}
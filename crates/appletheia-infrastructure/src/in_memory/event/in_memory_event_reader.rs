@@ -0,0 +1,54 @@
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+
+use appletheia_application::event::{AggregateIdValue, EventReader, EventReaderError};
+use appletheia_domain::{Aggregate, AggregateId, AggregateVersionRange, Event};
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+pub struct InMemoryEventReader<A: Aggregate> {
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Aggregate> InMemoryEventReader<A> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Aggregate> Default for InMemoryEventReader<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Aggregate> EventReader<A> for InMemoryEventReader<A> {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn read_events(
+        &self,
+        uow: &mut Self::Uow,
+        aggregate_id: A::Id,
+        range: AggregateVersionRange,
+    ) -> Result<Vec<Event<A::Id, A::EventPayload>>, EventReaderError> {
+        let aggregate_id = AggregateIdValue::from(aggregate_id.value());
+
+        let mut events = uow
+            .tables()
+            .events
+            .values()
+            .filter(|event| {
+                event.is_for_aggregate::<A>()
+                    && event.aggregate_id == aggregate_id
+                    && range.contains(&event.aggregate_version)
+            })
+            .map(|event| event.try_into_domain_event::<A>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| EventReaderError::MappingFailed(Box::new(e)))?;
+        events.sort_by_key(|event| event.aggregate_version());
+
+        Ok(events)
+    }
+}
@@ -0,0 +1,98 @@
+use std::marker::PhantomData;
+
+use appletheia_application::event::{
+    AggregateIdValue, AggregateTypeOwned, EventEnvelope, EventNameOwned, EventSequence,
+    EventWriter, EventWriterError, SerializedEventPayload,
+};
+use appletheia_application::outbox::event::{EventOutbox, EventOutboxId};
+use appletheia_application::outbox::{
+    OutboxAttemptCount, OutboxLifecycle, OutboxNextAttemptAt, OutboxState,
+};
+use appletheia_application::request_context::{CausationId, RequestContext};
+use appletheia_domain::{Aggregate, AggregateId, Event, EventPayload};
+
+use crate::in_memory::InMemoryStorageError;
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+pub struct InMemoryEventWriter<A: Aggregate> {
+    _aggregate: PhantomData<A>,
+}
+
+impl<A: Aggregate> InMemoryEventWriter<A> {
+    pub fn new() -> Self {
+        Self {
+            _aggregate: PhantomData,
+        }
+    }
+}
+
+impl<A: Aggregate> Default for InMemoryEventWriter<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Aggregate> EventWriter<A> for InMemoryEventWriter<A> {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn write_events_and_outbox(
+        &self,
+        uow: &mut Self::Uow,
+        request_context: &RequestContext,
+        events: &[Event<A::Id, A::EventPayload>],
+    ) -> Result<(), EventWriterError> {
+        let tables = uow.tables_mut();
+        let aggregate_type = AggregateTypeOwned::from(A::TYPE);
+
+        for event in events {
+            let aggregate_id = AggregateIdValue::from(event.aggregate_id().value());
+            let is_duplicate = tables.events.values().any(|existing| {
+                existing.event_id == event.id()
+                    || (existing.aggregate_type == aggregate_type
+                        && existing.aggregate_id == aggregate_id
+                        && existing.aggregate_version == event.aggregate_version())
+            });
+            if is_duplicate {
+                return Err(EventWriterError::Persistence(Box::new(
+                    InMemoryStorageError::UniqueViolation("events"),
+                )));
+            }
+
+            let payload = serde_json::to_value(event.payload()).map_err(EventWriterError::Json)?;
+            let payload = SerializedEventPayload::try_from(payload)
+                .map_err(|e| EventWriterError::Persistence(Box::new(e)))?;
+            let event_sequence = EventSequence::try_from(tables.sequences.next_event_sequence())
+                .map_err(|e| EventWriterError::Persistence(Box::new(e)))?;
+
+            let event_envelope = EventEnvelope {
+                event_sequence,
+                event_id: event.id(),
+                aggregate_type: aggregate_type.clone(),
+                aggregate_id,
+                aggregate_version: event.aggregate_version(),
+                event_name: EventNameOwned::from(event.payload().name()),
+                payload,
+                occurred_at: event.occurred_at(),
+                correlation_id: request_context.correlation_id,
+                causation_id: CausationId::from(request_context.message_id),
+                context: request_context.clone(),
+            };
+
+            let outbox = EventOutbox {
+                id: EventOutboxId::new(),
+                event: event_envelope.clone(),
+                state: OutboxState::Pending {
+                    attempt_count: OutboxAttemptCount::default(),
+                    next_attempt_after: OutboxNextAttemptAt::now(),
+                },
+                last_error: None,
+                lifecycle: OutboxLifecycle::Active,
+            };
+
+            tables.events.insert(event_sequence, event_envelope);
+            tables.event_outbox.insert(outbox.id, outbox);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use appletheia_application::conformance::{
+    ConformanceAggregate, StorageConformanceHarness, StorageConformanceSuite,
+};
+
+use crate::in_memory::{
+    InMemoryDatabase, InMemoryEventFeedReader, InMemoryEventOutboxFetcher,
+    InMemoryEventOutboxWriter, InMemoryEventReader, InMemoryEventWriter,
+    InMemoryIdempotencyService, InMemoryRelationshipStore, InMemoryUniqueKeyReservationStore,
+    InMemoryUnitOfWork, InMemoryUnitOfWorkFactory,
+};
+
+struct InMemoryConformanceHarness {
+    unit_of_work_factory: InMemoryUnitOfWorkFactory,
+    event_writer: InMemoryEventWriter<ConformanceAggregate>,
+    event_reader: InMemoryEventReader<ConformanceAggregate>,
+    event_feed_reader: InMemoryEventFeedReader,
+    event_outbox_fetcher: InMemoryEventOutboxFetcher,
+    event_outbox_writer: InMemoryEventOutboxWriter,
+    idempotency_service: InMemoryIdempotencyService,
+    unique_key_reservation_store: InMemoryUniqueKeyReservationStore,
+    relationship_store: InMemoryRelationshipStore,
+}
+
+impl StorageConformanceHarness for InMemoryConformanceHarness {
+    type Uow = InMemoryUnitOfWork;
+    type UnitOfWorkFactory = InMemoryUnitOfWorkFactory;
+    type EventWriter = InMemoryEventWriter<ConformanceAggregate>;
+    type EventReader = InMemoryEventReader<ConformanceAggregate>;
+    type EventFeedReader = InMemoryEventFeedReader;
+    type EventOutboxFetcher = InMemoryEventOutboxFetcher;
+    type EventOutboxWriter = InMemoryEventOutboxWriter;
+    type IdempotencyService = InMemoryIdempotencyService;
+    type UniqueKeyReservationStore = InMemoryUniqueKeyReservationStore;
+    type RelationshipStore = InMemoryRelationshipStore;
+
+    async fn setup() -> Self {
+        Self {
+            unit_of_work_factory: InMemoryUnitOfWorkFactory::new(InMemoryDatabase::new()),
+            event_writer: InMemoryEventWriter::new(),
+            event_reader: InMemoryEventReader::new(),
+            event_feed_reader: InMemoryEventFeedReader::new(),
+            event_outbox_fetcher: InMemoryEventOutboxFetcher::new(),
+            event_outbox_writer: InMemoryEventOutboxWriter::new(),
+            idempotency_service: InMemoryIdempotencyService::new(),
+            unique_key_reservation_store: InMemoryUniqueKeyReservationStore::new(),
+            relationship_store: InMemoryRelationshipStore::new(),
+        }
+    }
+
+    fn unit_of_work_factory(&self) -> &Self::UnitOfWorkFactory {
+        &self.unit_of_work_factory
+    }
+
+    fn event_writer(&self) -> &Self::EventWriter {
+        &self.event_writer
+    }
+
+    fn event_reader(&self) -> &Self::EventReader {
+        &self.event_reader
+    }
+
+    fn event_feed_reader(&self) -> &Self::EventFeedReader {
+        &self.event_feed_reader
+    }
+
+    fn event_outbox_fetcher(&self) -> &Self::EventOutboxFetcher {
+        &self.event_outbox_fetcher
+    }
+
+    fn event_outbox_writer(&self) -> &Self::EventOutboxWriter {
+        &self.event_outbox_writer
+    }
+
+    fn idempotency_service(&self) -> &Self::IdempotencyService {
+        &self.idempotency_service
+    }
+
+    fn unique_key_reservation_store(&self) -> &Self::UniqueKeyReservationStore {
+        &self.unique_key_reservation_store
+    }
+
+    fn relationship_store(&self) -> &Self::RelationshipStore {
+        &self.relationship_store
+    }
+}
+
+#[tokio::test]
+async fn in_memory_backend_passes_storage_conformance_suite() {
+    StorageConformanceSuite::<InMemoryConformanceHarness>::run_all().await;
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// Reports a violated table constraint in the in-memory backend.
+///
+/// The variants mirror the constraints of the PostgreSQL schema so that both
+/// backends reject the same writes.
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum InMemoryStorageError {
+    #[error("unique constraint violated: {0}")]
+    UniqueViolation(&'static str),
+}
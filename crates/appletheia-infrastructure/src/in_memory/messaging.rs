@@ -0,0 +1,19 @@
+pub mod in_memory_broker;
+pub mod in_memory_command_subscriber;
+pub mod in_memory_consumer;
+pub mod in_memory_delivery;
+pub mod in_memory_event_subscriber;
+pub mod in_memory_message;
+mod in_memory_message_queue;
+pub mod in_memory_publisher;
+mod in_memory_queued_message;
+pub mod in_memory_subscriber;
+
+pub use in_memory_broker::InMemoryBroker;
+pub use in_memory_command_subscriber::InMemoryCommandSubscriber;
+pub use in_memory_consumer::InMemoryConsumer;
+pub use in_memory_delivery::InMemoryDelivery;
+pub use in_memory_event_subscriber::InMemoryEventSubscriber;
+pub use in_memory_message::InMemoryMessage;
+pub use in_memory_publisher::InMemoryPublisher;
+pub use in_memory_subscriber::InMemorySubscriber;
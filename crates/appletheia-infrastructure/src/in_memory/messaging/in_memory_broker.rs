@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use tokio::sync::Notify;
+
+use appletheia_application::messaging::ConsumerGroup;
+
+use super::InMemoryMessage;
+use super::in_memory_message_queue::{InMemoryMessageFilter, InMemoryMessageQueue};
+use super::in_memory_queued_message::InMemoryQueuedMessage;
+
+/// In-process message broker with one queue per consumer group.
+///
+/// Like a Pub/Sub subscription, a consumer group only receives messages published after it
+/// first subscribed, keeps the filter of its first subscription, and shares its queue between
+/// all consumers subscribed under the same group. Cloning the broker yields another handle to
+/// the same queues.
+pub struct InMemoryBroker<M> {
+    queues: Arc<Mutex<HashMap<ConsumerGroup, InMemoryMessageQueue<M>>>>,
+    notify: Arc<Notify>,
+    next_message_id: Arc<AtomicU64>,
+}
+
+impl<M: InMemoryMessage> InMemoryBroker<M> {
+    pub fn new() -> Self {
+        Self {
+            queues: Arc::new(Mutex::new(HashMap::new())),
+            notify: Arc::new(Notify::new()),
+            next_message_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+
+    fn queues(&self) -> MutexGuard<'_, HashMap<ConsumerGroup, InMemoryMessageQueue<M>>> {
+        self.queues.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) fn notify(&self) -> &Notify {
+        &self.notify
+    }
+
+    pub(crate) fn publish(&self, message: &M) -> u64 {
+        let message_id = self.next_message_id.fetch_add(1, AtomicOrdering::SeqCst);
+        let ordering_key = message.ordering_key();
+
+        for queue in self.queues().values_mut() {
+            if queue.accepts(message) {
+                queue.push(InMemoryQueuedMessage {
+                    message_id,
+                    ordering_key: ordering_key.clone(),
+                    message: message.clone(),
+                });
+            }
+        }
+
+        self.notify.notify_waiters();
+        message_id
+    }
+
+    pub(crate) fn register(
+        &self,
+        consumer_group: &ConsumerGroup,
+        filter: InMemoryMessageFilter<M>,
+    ) {
+        self.queues()
+            .entry(consumer_group.clone())
+            .or_insert_with(|| InMemoryMessageQueue::new(filter));
+    }
+
+    pub(crate) fn take_next(
+        &self,
+        consumer_group: &ConsumerGroup,
+    ) -> Option<InMemoryQueuedMessage<M>> {
+        self.queues().get_mut(consumer_group)?.take_next()
+    }
+
+    pub(crate) fn ack(&self, consumer_group: &ConsumerGroup, queued: &InMemoryQueuedMessage<M>) {
+        if let Some(queue) = self.queues().get_mut(consumer_group) {
+            queue.ack(queued);
+        }
+        self.notify.notify_waiters();
+    }
+
+    pub(crate) fn requeue(&self, consumer_group: &ConsumerGroup, queued: InMemoryQueuedMessage<M>) {
+        if let Some(queue) = self.queues().get_mut(consumer_group) {
+            queue.requeue(queued);
+        }
+        self.notify.notify_waiters();
+    }
+}
+
+impl<M: InMemoryMessage> Default for InMemoryBroker<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> Clone for InMemoryBroker<M> {
+    fn clone(&self) -> Self {
+        Self {
+            queues: Arc::clone(&self.queues),
+            notify: Arc::clone(&self.notify),
+            next_message_id: Arc::clone(&self.next_message_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::timeout;
+
+    use appletheia_application::messaging::{
+        Consumer, Delivery, Publisher, Selector, Subscriber, Subscription,
+    };
+    use appletheia_application::outbox::OrderingKey;
+
+    use super::*;
+    use crate::in_memory::messaging::{InMemoryConsumer, InMemoryPublisher, InMemorySubscriber};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct TestMessage {
+        key: &'static str,
+        value: u32,
+    }
+
+    impl InMemoryMessage for TestMessage {
+        fn ordering_key(&self) -> Option<OrderingKey> {
+            Some(OrderingKey::new(self.key.to_owned()).expect("ordering key should be valid"))
+        }
+    }
+
+    #[derive(Clone)]
+    struct KeySelector(&'static str);
+
+    impl Selector<TestMessage> for KeySelector {
+        fn matches(&self, message: &TestMessage) -> bool {
+            message.key == self.0
+        }
+    }
+
+    fn message(key: &'static str, value: u32) -> TestMessage {
+        TestMessage { key, value }
+    }
+
+    fn group(value: &str) -> ConsumerGroup {
+        ConsumerGroup::new(value.to_owned()).expect("consumer group should be valid")
+    }
+
+    async fn subscribe(
+        broker: &InMemoryBroker<TestMessage>,
+        consumer_group: &str,
+        subscription: Subscription<'_, KeySelector>,
+    ) -> InMemoryConsumer<TestMessage> {
+        InMemorySubscriber::<TestMessage, KeySelector>::new(broker.clone())
+            .subscribe(&group(consumer_group), subscription)
+            .await
+            .expect("subscribe should succeed")
+    }
+
+    async fn publish(broker: &InMemoryBroker<TestMessage>, messages: &[TestMessage]) {
+        InMemoryPublisher::new(broker.clone())
+            .publish(messages)
+            .await
+            .expect("publish should succeed");
+    }
+
+    async fn next_value(consumer: &mut InMemoryConsumer<TestMessage>) -> Option<u32> {
+        let mut delivery = timeout(Duration::from_millis(50), consumer.next())
+            .await
+            .ok()?
+            .expect("next should succeed");
+        let value = delivery.message().value;
+        delivery.ack().await.expect("ack should succeed");
+        Some(value)
+    }
+
+    #[tokio::test]
+    async fn delivers_matching_messages_published_after_subscribing() {
+        let broker = InMemoryBroker::new();
+        publish(&broker, &[message("a", 0)]).await;
+
+        let mut consumer = subscribe(&broker, "group", Subscription::One(&KeySelector("a"))).await;
+        publish(&broker, &[message("b", 1), message("a", 2)]).await;
+
+        assert_eq!(next_value(&mut consumer).await, Some(2));
+        assert_eq!(next_value(&mut consumer).await, None);
+    }
+
+    #[tokio::test]
+    async fn each_consumer_group_receives_its_own_copy() {
+        let broker = InMemoryBroker::new();
+        let mut first = subscribe(&broker, "first", Subscription::All).await;
+        let mut second = subscribe(&broker, "second", Subscription::All).await;
+
+        publish(&broker, &[message("a", 1)]).await;
+
+        assert_eq!(next_value(&mut first).await, Some(1));
+        assert_eq!(next_value(&mut second).await, Some(1));
+    }
+
+    #[tokio::test]
+    async fn holds_back_messages_of_a_key_until_the_previous_one_is_settled() {
+        let broker = InMemoryBroker::new();
+        let mut consumer = subscribe(&broker, "group", Subscription::All).await;
+        publish(
+            &broker,
+            &[message("a", 1), message("a", 2), message("b", 3)],
+        )
+        .await;
+
+        let mut first = consumer.next().await.expect("next should succeed");
+        assert_eq!(first.message().value, 1);
+
+        assert_eq!(next_value(&mut consumer).await, Some(3));
+        assert_eq!(next_value(&mut consumer).await, None);
+
+        first.ack().await.expect("ack should succeed");
+        assert_eq!(next_value(&mut consumer).await, Some(2));
+    }
+
+    #[tokio::test]
+    async fn nack_redelivers_before_later_messages_of_the_same_key() {
+        let broker = InMemoryBroker::new();
+        let mut consumer = subscribe(&broker, "group", Subscription::All).await;
+        publish(&broker, &[message("a", 1), message("a", 2)]).await;
+
+        let mut delivery = consumer.next().await.expect("next should succeed");
+        delivery.nack().await.expect("nack should succeed");
+        drop(delivery);
+
+        let dropped = consumer.next().await.expect("next should succeed");
+        assert_eq!(dropped.message().value, 1);
+        drop(dropped);
+
+        assert_eq!(next_value(&mut consumer).await, Some(1));
+        assert_eq!(next_value(&mut consumer).await, Some(2));
+    }
+}
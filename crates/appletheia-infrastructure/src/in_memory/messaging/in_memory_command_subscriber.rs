@@ -0,0 +1,6 @@
+use appletheia_application::command::CommandSelector;
+use appletheia_application::outbox::command::CommandEnvelope;
+
+use super::InMemorySubscriber;
+
+pub type InMemoryCommandSubscriber = InMemorySubscriber<CommandEnvelope, CommandSelector>;
@@ -0,0 +1,40 @@
+use std::pin::pin;
+
+use appletheia_application::messaging::{Consumer, ConsumerError, ConsumerGroup};
+
+use super::{InMemoryBroker, InMemoryDelivery, InMemoryMessage};
+
+pub struct InMemoryConsumer<M> {
+    broker: InMemoryBroker<M>,
+    consumer_group: ConsumerGroup,
+}
+
+impl<M: InMemoryMessage> InMemoryConsumer<M> {
+    pub(crate) fn new(broker: InMemoryBroker<M>, consumer_group: ConsumerGroup) -> Self {
+        Self {
+            broker,
+            consumer_group,
+        }
+    }
+}
+
+impl<M: InMemoryMessage> Consumer<M> for InMemoryConsumer<M> {
+    type Delivery = InMemoryDelivery<M>;
+
+    async fn next(&mut self) -> Result<Self::Delivery, ConsumerError> {
+        loop {
+            let mut notified = pin!(self.broker.notify().notified());
+            notified.as_mut().enable();
+
+            if let Some(queued) = self.broker.take_next(&self.consumer_group) {
+                return Ok(InMemoryDelivery::new(
+                    self.broker.clone(),
+                    self.consumer_group.clone(),
+                    queued,
+                ));
+            }
+
+            notified.await;
+        }
+    }
+}
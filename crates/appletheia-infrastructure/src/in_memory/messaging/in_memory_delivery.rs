@@ -0,0 +1,67 @@
+use appletheia_application::messaging::{ConsumerError, ConsumerGroup, Delivery};
+
+use super::in_memory_queued_message::InMemoryQueuedMessage;
+use super::{InMemoryBroker, InMemoryMessage};
+
+/// Delivery of a queued message.
+///
+/// Nacked deliveries, and deliveries dropped without being settled, are redelivered before
+/// any later message with the same ordering key.
+pub struct InMemoryDelivery<M: InMemoryMessage> {
+    broker: InMemoryBroker<M>,
+    consumer_group: ConsumerGroup,
+    queued: InMemoryQueuedMessage<M>,
+    settled: bool,
+}
+
+impl<M: InMemoryMessage> InMemoryDelivery<M> {
+    pub(crate) fn new(
+        broker: InMemoryBroker<M>,
+        consumer_group: ConsumerGroup,
+        queued: InMemoryQueuedMessage<M>,
+    ) -> Self {
+        Self {
+            broker,
+            consumer_group,
+            queued,
+            settled: false,
+        }
+    }
+
+    pub fn message_id(&self) -> u64 {
+        self.queued.message_id
+    }
+}
+
+impl<M: InMemoryMessage> Delivery<M> for InMemoryDelivery<M> {
+    fn message(&self) -> &M {
+        &self.queued.message
+    }
+
+    async fn ack(&mut self) -> Result<(), ConsumerError> {
+        if !self.settled {
+            self.settled = true;
+            self.broker.ack(&self.consumer_group, &self.queued);
+        }
+        Ok(())
+    }
+
+    async fn nack(&mut self) -> Result<(), ConsumerError> {
+        if !self.settled {
+            self.settled = true;
+            self.broker
+                .requeue(&self.consumer_group, self.queued.clone());
+        }
+        Ok(())
+    }
+}
+
+impl<M: InMemoryMessage> Drop for InMemoryDelivery<M> {
+    fn drop(&mut self) {
+        if !self.settled {
+            self.settled = true;
+            self.broker
+                .requeue(&self.consumer_group, self.queued.clone());
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use appletheia_application::event::{EventEnvelope, EventSelector};
+
+use super::InMemorySubscriber;
+
+pub type InMemoryEventSubscriber = InMemorySubscriber<EventEnvelope, EventSelector>;
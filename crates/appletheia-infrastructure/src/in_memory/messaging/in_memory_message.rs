@@ -0,0 +1,26 @@
+use appletheia_application::event::EventEnvelope;
+use appletheia_application::outbox::OrderingKey;
+use appletheia_application::outbox::command::CommandEnvelope;
+
+/// Message that can travel through an `InMemoryBroker`.
+///
+/// Messages sharing an ordering key are delivered to a consumer group one at a time, in
+/// publish order, matching the ordering keys the Pub/Sub publishers set.
+pub trait InMemoryMessage: Clone + Send + Sync + 'static {
+    fn ordering_key(&self) -> Option<OrderingKey>;
+}
+
+impl InMemoryMessage for EventEnvelope {
+    fn ordering_key(&self) -> Option<OrderingKey> {
+        Some(OrderingKey::from((
+            &self.aggregate_type,
+            &self.aggregate_id,
+        )))
+    }
+}
+
+impl InMemoryMessage for CommandEnvelope {
+    fn ordering_key(&self) -> Option<OrderingKey> {
+        Some(OrderingKey::from(self.correlation_id))
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+
+use appletheia_application::outbox::OrderingKey;
+
+use super::in_memory_queued_message::InMemoryQueuedMessage;
+
+pub(crate) type InMemoryMessageFilter<M> = Arc<dyn Fn(&M) -> bool + Send + Sync>;
+
+/// Queue of one consumer group.
+///
+/// A message whose ordering key is already out for delivery stays queued until that delivery
+/// is acked or nacked, so every key is delivered strictly in order.
+pub(crate) struct InMemoryMessageQueue<M> {
+    filter: InMemoryMessageFilter<M>,
+    pending: VecDeque<InMemoryQueuedMessage<M>>,
+    in_flight_keys: HashSet<OrderingKey>,
+}
+
+impl<M> InMemoryMessageQueue<M> {
+    pub(crate) fn new(filter: InMemoryMessageFilter<M>) -> Self {
+        Self {
+            filter,
+            pending: VecDeque::new(),
+            in_flight_keys: HashSet::new(),
+        }
+    }
+
+    pub(crate) fn accepts(&self, message: &M) -> bool {
+        (self.filter)(message)
+    }
+
+    pub(crate) fn push(&mut self, queued: InMemoryQueuedMessage<M>) {
+        self.pending.push_back(queued);
+    }
+
+    pub(crate) fn take_next(&mut self) -> Option<InMemoryQueuedMessage<M>> {
+        let index = self.pending.iter().position(|queued| {
+            queued
+                .ordering_key
+                .as_ref()
+                .is_none_or(|key| !self.in_flight_keys.contains(key))
+        })?;
+        let queued = self.pending.remove(index)?;
+
+        if let Some(key) = &queued.ordering_key {
+            self.in_flight_keys.insert(key.clone());
+        }
+
+        Some(queued)
+    }
+
+    pub(crate) fn ack(&mut self, queued: &InMemoryQueuedMessage<M>) {
+        if let Some(key) = &queued.ordering_key {
+            self.in_flight_keys.remove(key);
+        }
+    }
+
+    /// Puts a nacked message back in front of the queue, ahead of later messages of its key.
+    pub(crate) fn requeue(&mut self, queued: InMemoryQueuedMessage<M>) {
+        self.ack(&queued);
+        self.pending.push_front(queued);
+    }
+}
@@ -0,0 +1,31 @@
+use appletheia_application::messaging::{PublishResult, Publisher, PublisherError};
+
+use super::{InMemoryBroker, InMemoryMessage};
+
+#[derive(Clone)]
+pub struct InMemoryPublisher<M> {
+    broker: InMemoryBroker<M>,
+}
+
+impl<M: InMemoryMessage> InMemoryPublisher<M> {
+    pub fn new(broker: InMemoryBroker<M>) -> Self {
+        Self { broker }
+    }
+}
+
+impl<M: InMemoryMessage> Publisher<M> for InMemoryPublisher<M> {
+    async fn publish<'a, I>(&self, messages: I) -> Result<Vec<PublishResult>, PublisherError>
+    where
+        I: IntoIterator<Item = &'a M>,
+        M: 'a,
+    {
+        Ok(messages
+            .into_iter()
+            .enumerate()
+            .map(|(input_index, message)| PublishResult::Success {
+                input_index,
+                transport_message_id: Some(self.broker.publish(message).to_string()),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,8 @@
+use appletheia_application::outbox::OrderingKey;
+
+#[derive(Clone, Debug)]
+pub(crate) struct InMemoryQueuedMessage<M> {
+    pub(crate) message_id: u64,
+    pub(crate) ordering_key: Option<OrderingKey>,
+    pub(crate) message: M,
+}
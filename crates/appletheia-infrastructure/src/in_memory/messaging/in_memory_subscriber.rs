@@ -0,0 +1,57 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use appletheia_application::messaging::{
+    ConsumerGroup, Selector, Subscriber, SubscriberError, Subscription,
+};
+
+use super::{InMemoryBroker, InMemoryConsumer, InMemoryMessage};
+
+pub struct InMemorySubscriber<M, S> {
+    broker: InMemoryBroker<M>,
+    _selector: PhantomData<fn() -> S>,
+}
+
+impl<M: InMemoryMessage, S> InMemorySubscriber<M, S> {
+    pub fn new(broker: InMemoryBroker<M>) -> Self {
+        Self {
+            broker,
+            _selector: PhantomData,
+        }
+    }
+}
+
+impl<M, S> Subscriber<M> for InMemorySubscriber<M, S>
+where
+    M: InMemoryMessage,
+    S: Selector<M> + Clone + Send + Sync + 'static,
+{
+    type Consumer = InMemoryConsumer<M>;
+    type Selector = S;
+
+    async fn subscribe(
+        &self,
+        consumer_group: &ConsumerGroup,
+        subscription: Subscription<'_, Self::Selector>,
+    ) -> Result<Self::Consumer, SubscriberError> {
+        let selectors: Option<Vec<S>> = match subscription {
+            Subscription::All => None,
+            Subscription::AnyOf([]) => return Err(SubscriberError::InvalidSubscription),
+            Subscription::AnyOf(selectors) => Some(selectors.to_vec()),
+            Subscription::One(selector) => Some(vec![selector.clone()]),
+        };
+
+        self.broker.register(
+            consumer_group,
+            Arc::new(move |message: &M| match &selectors {
+                None => true,
+                Some(selectors) => selectors.iter().any(|selector| selector.matches(message)),
+            }),
+        );
+
+        Ok(InMemoryConsumer::new(
+            self.broker.clone(),
+            consumer_group.clone(),
+        ))
+    }
+}
@@ -0,0 +1,6 @@
+pub mod command;
+pub mod event;
+mod in_memory_outbox_due;
+
+pub use command::*;
+pub use event::*;
@@ -0,0 +1,9 @@
+pub mod in_memory_command_outbox_enqueuer;
+pub mod in_memory_command_outbox_fetcher;
+pub mod in_memory_command_outbox_relay;
+pub mod in_memory_command_outbox_writer;
+
+pub use in_memory_command_outbox_enqueuer::InMemoryCommandOutboxEnqueuer;
+pub use in_memory_command_outbox_fetcher::InMemoryCommandOutboxFetcher;
+pub use in_memory_command_outbox_relay::InMemoryCommandOutboxRelay;
+pub use in_memory_command_outbox_writer::InMemoryCommandOutboxWriter;
@@ -0,0 +1,54 @@
+use appletheia_application::outbox::command::{
+    CommandEnvelope, CommandOutbox, CommandOutboxEnqueueError, CommandOutboxEnqueuer,
+    CommandOutboxId,
+};
+use appletheia_application::outbox::{
+    OutboxAttemptCount, OutboxLifecycle, OutboxNextAttemptAt, OutboxState,
+};
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+#[derive(Debug)]
+pub struct InMemoryCommandOutboxEnqueuer;
+
+impl InMemoryCommandOutboxEnqueuer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InMemoryCommandOutboxEnqueuer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandOutboxEnqueuer for InMemoryCommandOutboxEnqueuer {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn enqueue_commands(
+        &self,
+        uow: &mut Self::Uow,
+        commands: &[CommandEnvelope],
+    ) -> Result<(), CommandOutboxEnqueueError> {
+        let tables = uow.tables_mut();
+
+        for command in commands {
+            let id = CommandOutboxId::new();
+            let outbox = CommandOutbox {
+                id,
+                sequence: tables.sequences.next_command_sequence(),
+                command: command.clone(),
+                state: OutboxState::Pending {
+                    attempt_count: OutboxAttemptCount::default(),
+                    next_attempt_after: OutboxNextAttemptAt::now(),
+                },
+                last_error: None,
+                lifecycle: OutboxLifecycle::Active,
+            };
+            tables.command_outbox.insert(id, outbox);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use chrono::Utc;
+
+use appletheia_application::outbox::{
+    OutboxBatchSize, OutboxFetcher, OutboxFetcherError, OutboxLifecycle, command::CommandOutbox,
+};
+
+use crate::in_memory::outbox::in_memory_outbox_due::is_due;
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+#[derive(Debug)]
+pub struct InMemoryCommandOutboxFetcher;
+
+impl InMemoryCommandOutboxFetcher {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InMemoryCommandOutboxFetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutboxFetcher for InMemoryCommandOutboxFetcher {
+    type Uow = InMemoryUnitOfWork;
+    type Outbox = CommandOutbox;
+
+    async fn fetch_pending(
+        &self,
+        uow: &mut Self::Uow,
+        limit: OutboxBatchSize,
+    ) -> Result<Vec<CommandOutbox>, OutboxFetcherError> {
+        let now = Utc::now();
+
+        let mut outboxes = uow
+            .tables()
+            .command_outbox
+            .values()
+            .filter(|outbox| is_due(&outbox.state, now))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        outboxes.sort_by_key(|outbox| (outbox.state.next_attempt_after(), outbox.sequence));
+        outboxes.truncate(limit.as_i64() as usize);
+
+        Ok(outboxes)
+    }
+
+    async fn fetch_dead_lettered(
+        &self,
+        uow: &mut Self::Uow,
+        limit: OutboxBatchSize,
+    ) -> Result<Vec<CommandOutbox>, OutboxFetcherError> {
+        let mut outboxes = uow
+            .tables()
+            .command_dead_letters
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        outboxes.sort_by_key(|outbox| {
+            let dead_lettered_at = match outbox.lifecycle {
+                OutboxLifecycle::DeadLettered { dead_lettered_at } => Some(dead_lettered_at),
+                OutboxLifecycle::Active => None,
+            };
+            (dead_lettered_at, outbox.id)
+        });
+        outboxes.truncate(limit.as_i64() as usize);
+
+        Ok(outboxes)
+    }
+}
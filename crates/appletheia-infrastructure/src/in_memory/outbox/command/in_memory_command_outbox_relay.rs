@@ -0,0 +1,16 @@
+use appletheia_application::outbox::command::{CommandEnvelope, CommandOutbox};
+use appletheia_application::outbox::{DefaultOutboxRelay, PollingOutboxWakeupListener};
+
+use crate::in_memory::messaging::InMemoryPublisher;
+use crate::in_memory::unit_of_work::InMemoryUnitOfWorkFactory;
+
+use super::{InMemoryCommandOutboxFetcher, InMemoryCommandOutboxWriter};
+
+pub type InMemoryCommandOutboxRelay<L = PollingOutboxWakeupListener> = DefaultOutboxRelay<
+    InMemoryUnitOfWorkFactory,
+    CommandOutbox,
+    InMemoryCommandOutboxFetcher,
+    InMemoryCommandOutboxWriter,
+    InMemoryPublisher<CommandEnvelope>,
+    L,
+>;
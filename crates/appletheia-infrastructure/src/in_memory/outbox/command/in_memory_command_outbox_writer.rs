@@ -0,0 +1,50 @@
+use appletheia_application::outbox::{
+    OutboxLifecycle, OutboxWriter, OutboxWriterError, command::CommandOutbox,
+};
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+#[derive(Debug)]
+pub struct InMemoryCommandOutboxWriter;
+
+impl InMemoryCommandOutboxWriter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InMemoryCommandOutboxWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutboxWriter for InMemoryCommandOutboxWriter {
+    type Uow = InMemoryUnitOfWork;
+    type Outbox = CommandOutbox;
+
+    async fn write_outbox(
+        &self,
+        uow: &mut Self::Uow,
+        outboxes: &[CommandOutbox],
+    ) -> Result<(), OutboxWriterError> {
+        let tables = uow.tables_mut();
+
+        for outbox in outboxes {
+            match outbox.lifecycle {
+                OutboxLifecycle::Active => {
+                    tables.command_dead_letters.remove(&outbox.id);
+                    tables.command_outbox.insert(outbox.id, outbox.clone());
+                }
+                OutboxLifecycle::DeadLettered { .. } => {
+                    tables.command_outbox.remove(&outbox.id);
+                    tables
+                        .command_dead_letters
+                        .insert(outbox.id, outbox.clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+pub mod in_memory_event_outbox_fetcher;
+pub mod in_memory_event_outbox_relay;
+pub mod in_memory_event_outbox_writer;
+
+pub use in_memory_event_outbox_fetcher::InMemoryEventOutboxFetcher;
+pub use in_memory_event_outbox_relay::InMemoryEventOutboxRelay;
+pub use in_memory_event_outbox_writer::InMemoryEventOutboxWriter;
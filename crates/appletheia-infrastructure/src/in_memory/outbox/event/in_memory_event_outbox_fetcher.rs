@@ -0,0 +1,85 @@
+use chrono::Utc;
+
+use appletheia_application::outbox::{
+    OutboxBatchSize, OutboxFetcher, OutboxFetcherError, OutboxLifecycle, event::EventOutbox,
+};
+
+use crate::in_memory::outbox::in_memory_outbox_due::is_due;
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+#[derive(Debug)]
+pub struct InMemoryEventOutboxFetcher;
+
+impl InMemoryEventOutboxFetcher {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InMemoryEventOutboxFetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutboxFetcher for InMemoryEventOutboxFetcher {
+    type Uow = InMemoryUnitOfWork;
+    type Outbox = EventOutbox;
+
+    async fn fetch_pending(
+        &self,
+        uow: &mut Self::Uow,
+        limit: OutboxBatchSize,
+    ) -> Result<Vec<EventOutbox>, OutboxFetcherError> {
+        let now = Utc::now();
+        let event_outbox = &uow.tables().event_outbox;
+
+        let mut outboxes = event_outbox
+            .values()
+            .filter(|outbox| is_due(&outbox.state, now))
+            .filter(|outbox| {
+                !event_outbox.values().any(|earlier| {
+                    earlier.state.published_at().is_none()
+                        && earlier.event.aggregate_type == outbox.event.aggregate_type
+                        && earlier.event.aggregate_id == outbox.event.aggregate_id
+                        && earlier.event.aggregate_version < outbox.event.aggregate_version
+                })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        outboxes.sort_by_key(|outbox| {
+            (
+                outbox.state.next_attempt_after(),
+                outbox.event.event_sequence,
+            )
+        });
+        outboxes.truncate(limit.as_i64() as usize);
+
+        Ok(outboxes)
+    }
+
+    async fn fetch_dead_lettered(
+        &self,
+        uow: &mut Self::Uow,
+        limit: OutboxBatchSize,
+    ) -> Result<Vec<EventOutbox>, OutboxFetcherError> {
+        let mut outboxes = uow
+            .tables()
+            .event_dead_letters
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        outboxes.sort_by_key(|outbox| {
+            let dead_lettered_at = match outbox.lifecycle {
+                OutboxLifecycle::DeadLettered { dead_lettered_at } => Some(dead_lettered_at),
+                OutboxLifecycle::Active => None,
+            };
+            (dead_lettered_at, outbox.id)
+        });
+        outboxes.truncate(limit.as_i64() as usize);
+
+        Ok(outboxes)
+    }
+}
@@ -0,0 +1,17 @@
+use appletheia_application::event::EventEnvelope;
+use appletheia_application::outbox::event::EventOutbox;
+use appletheia_application::outbox::{DefaultOutboxRelay, PollingOutboxWakeupListener};
+
+use crate::in_memory::messaging::InMemoryPublisher;
+use crate::in_memory::unit_of_work::InMemoryUnitOfWorkFactory;
+
+use super::{InMemoryEventOutboxFetcher, InMemoryEventOutboxWriter};
+
+pub type InMemoryEventOutboxRelay<L = PollingOutboxWakeupListener> = DefaultOutboxRelay<
+    InMemoryUnitOfWorkFactory,
+    EventOutbox,
+    InMemoryEventOutboxFetcher,
+    InMemoryEventOutboxWriter,
+    InMemoryPublisher<EventEnvelope>,
+    L,
+>;
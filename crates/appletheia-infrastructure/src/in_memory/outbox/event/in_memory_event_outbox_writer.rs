@@ -0,0 +1,48 @@
+use appletheia_application::outbox::{
+    OutboxLifecycle, OutboxWriter, OutboxWriterError, event::EventOutbox,
+};
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+#[derive(Debug)]
+pub struct InMemoryEventOutboxWriter;
+
+impl InMemoryEventOutboxWriter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InMemoryEventOutboxWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutboxWriter for InMemoryEventOutboxWriter {
+    type Uow = InMemoryUnitOfWork;
+    type Outbox = EventOutbox;
+
+    async fn write_outbox(
+        &self,
+        uow: &mut Self::Uow,
+        outboxes: &[EventOutbox],
+    ) -> Result<(), OutboxWriterError> {
+        let tables = uow.tables_mut();
+
+        for outbox in outboxes {
+            match outbox.lifecycle {
+                OutboxLifecycle::Active => {
+                    tables.event_dead_letters.remove(&outbox.id);
+                    tables.event_outbox.insert(outbox.id, outbox.clone());
+                }
+                OutboxLifecycle::DeadLettered { .. } => {
+                    tables.event_outbox.remove(&outbox.id);
+                    tables.event_dead_letters.insert(outbox.id, outbox.clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+
+use appletheia_application::outbox::OutboxState;
+
+/// Returns whether an outbox row in `state` may be fetched at `now`.
+///
+/// Mirrors the `published_at IS NULL AND next_attempt_after <= now AND (lease_owner IS NULL OR
+/// lease_until <= now)` predicate used by the PostgreSQL fetchers.
+pub(crate) fn is_due(state: &OutboxState, now: DateTime<Utc>) -> bool {
+    match state {
+        OutboxState::Pending {
+            next_attempt_after, ..
+        } => next_attempt_after.value() <= now,
+        OutboxState::Leased {
+            next_attempt_after,
+            lease_until,
+            ..
+        } => next_attempt_after.value() <= now && lease_until.value() <= now,
+        OutboxState::Published { .. } => false,
+    }
+}
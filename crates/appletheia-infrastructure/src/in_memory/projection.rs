@@ -0,0 +1,7 @@
+pub mod in_memory_event_feed_reader;
+pub mod in_memory_projection_checkpoint_store;
+pub mod in_memory_projector_processed_event_store;
+
+pub use in_memory_event_feed_reader::InMemoryEventFeedReader;
+pub use in_memory_projection_checkpoint_store::InMemoryProjectionCheckpointStore;
+pub use in_memory_projector_processed_event_store::InMemoryProjectorProcessedEventStore;
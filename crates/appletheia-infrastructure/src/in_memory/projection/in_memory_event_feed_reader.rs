@@ -0,0 +1,61 @@
+use std::ops::Bound;
+
+use appletheia_application::event::{
+    EventEnvelope, EventFeedBatchSize, EventFeedReader, EventFeedReaderError, EventSelector,
+    EventSequence,
+};
+use appletheia_application::messaging::Subscription;
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+#[derive(Debug)]
+pub struct InMemoryEventFeedReader;
+
+impl InMemoryEventFeedReader {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InMemoryEventFeedReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventFeedReader for InMemoryEventFeedReader {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn read_after(
+        &self,
+        uow: &mut Self::Uow,
+        after: Option<EventSequence>,
+        limit: EventFeedBatchSize,
+        subscription: Subscription<'_, EventSelector>,
+    ) -> Result<Vec<EventEnvelope>, EventFeedReaderError> {
+        if matches!(subscription, Subscription::AnyOf([])) {
+            return Err(EventFeedReaderError::InvalidSubscription);
+        }
+
+        let lower = match after {
+            Some(after) => Bound::Excluded(after),
+            None => Bound::Unbounded,
+        };
+
+        Ok(uow
+            .tables()
+            .events
+            .range((lower, Bound::Unbounded))
+            .map(|(_, event)| event)
+            .filter(|event| match subscription {
+                Subscription::All => true,
+                Subscription::AnyOf(selectors) => {
+                    selectors.iter().any(|selector| selector.matches(event))
+                }
+                Subscription::One(selector) => selector.matches(event),
+            })
+            .take(limit.as_i64() as usize)
+            .cloned()
+            .collect())
+    }
+}
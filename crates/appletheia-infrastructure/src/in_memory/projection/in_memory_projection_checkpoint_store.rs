@@ -0,0 +1,65 @@
+use appletheia_application::event::EventSequence;
+use appletheia_application::projection::{
+    ProjectionCheckpointStore, ProjectionCheckpointStoreError, ProjectorNameOwned,
+};
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+#[derive(Debug)]
+pub struct InMemoryProjectionCheckpointStore;
+
+impl InMemoryProjectionCheckpointStore {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InMemoryProjectionCheckpointStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProjectionCheckpointStore for InMemoryProjectionCheckpointStore {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn load(
+        &self,
+        uow: &mut Self::Uow,
+        projector_name: ProjectorNameOwned,
+    ) -> Result<Option<EventSequence>, ProjectionCheckpointStoreError> {
+        Ok(uow
+            .tables()
+            .projection_checkpoints
+            .get(projector_name.value())
+            .copied())
+    }
+
+    async fn save(
+        &self,
+        uow: &mut Self::Uow,
+        projector_name: ProjectorNameOwned,
+        event_sequence: EventSequence,
+    ) -> Result<(), ProjectionCheckpointStoreError> {
+        let checkpoint = uow
+            .tables_mut()
+            .projection_checkpoints
+            .entry(projector_name.value().to_owned())
+            .or_insert(event_sequence);
+        *checkpoint = (*checkpoint).max(event_sequence);
+
+        Ok(())
+    }
+
+    async fn reset(
+        &self,
+        uow: &mut Self::Uow,
+        projector_name: ProjectorNameOwned,
+    ) -> Result<(), ProjectionCheckpointStoreError> {
+        uow.tables_mut()
+            .projection_checkpoints
+            .remove(projector_name.value());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use appletheia_application::projection::{
+    ProjectorNameOwned, ProjectorProcessedEventStore, ProjectorProcessedEventStoreError,
+};
+use appletheia_domain::EventId;
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+#[derive(Debug)]
+pub struct InMemoryProjectorProcessedEventStore;
+
+impl InMemoryProjectorProcessedEventStore {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InMemoryProjectorProcessedEventStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProjectorProcessedEventStore for InMemoryProjectorProcessedEventStore {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn are_all_processed(
+        &self,
+        uow: &mut Self::Uow,
+        projector_name: ProjectorNameOwned,
+        event_ids: &[EventId],
+    ) -> Result<bool, ProjectorProcessedEventStoreError> {
+        let processed_events = &uow.tables().projector_processed_events;
+        let projector_name = projector_name.value().to_owned();
+
+        Ok(event_ids
+            .iter()
+            .all(|event_id| processed_events.contains(&(projector_name.clone(), *event_id))))
+    }
+
+    async fn is_processed(
+        &self,
+        uow: &mut Self::Uow,
+        projector_name: ProjectorNameOwned,
+        event_id: EventId,
+    ) -> Result<bool, ProjectorProcessedEventStoreError> {
+        Ok(uow
+            .tables()
+            .projector_processed_events
+            .contains(&(projector_name.value().to_owned(), event_id)))
+    }
+
+    async fn mark_processed(
+        &self,
+        uow: &mut Self::Uow,
+        projector_name: ProjectorNameOwned,
+        event_id: EventId,
+    ) -> Result<bool, ProjectorProcessedEventStoreError> {
+        Ok(uow
+            .tables_mut()
+            .projector_processed_events
+            .insert((projector_name.value().to_owned(), event_id)))
+    }
+
+    async fn reset(
+        &self,
+        uow: &mut Self::Uow,
+        projector_name: ProjectorNameOwned,
+    ) -> Result<(), ProjectorProcessedEventStoreError> {
+        uow.tables_mut()
+            .projector_processed_events
+            .retain(|(name, _)| name != projector_name.value());
+
+        Ok(())
+    }
+}
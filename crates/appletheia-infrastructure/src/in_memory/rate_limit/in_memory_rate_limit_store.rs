@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use chrono::Utc;
+
+use appletheia_application::rate_limit::{
+    RateLimitDecision, RateLimitKey, RateLimitPolicy, RateLimitStore, RateLimitStoreError,
+    TokenBucket,
+};
+
+/// Token buckets kept in process memory.
+///
+/// Limits only hold per process; use a shared store when several instances serve the same
+/// actors. Cloning the store yields another handle to the same buckets.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryRateLimitStore {
+    buckets: Arc<Mutex<HashMap<RateLimitKey, TokenBucket>>>,
+}
+
+impl InMemoryRateLimitStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RateLimitStore for InMemoryRateLimitStore {
+    async fn try_acquire(
+        &self,
+        key: &RateLimitKey,
+        policy: &RateLimitPolicy,
+    ) -> Result<RateLimitDecision, RateLimitStoreError> {
+        let now = Utc::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        let bucket = buckets
+            .entry(key.clone())
+            .or_insert_with(|| TokenBucket::full(policy, now));
+
+        Ok(bucket.try_acquire(policy, now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use appletheia_application::command::CommandName;
+    use appletheia_application::rate_limit::RateLimitTarget;
+    use appletheia_application::request_context::ActorRef;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn try_acquire_shares_buckets_between_clones() {
+        let store = InMemoryRateLimitStore::new();
+        let policy = RateLimitPolicy::new(2, Duration::hours(1)).expect("policy should be valid");
+        let key = RateLimitKey::new(
+            ActorRef::Anonymous,
+            RateLimitTarget::Command(CommandName::new("open_account")),
+        );
+
+        let first = store.try_acquire(&key, &policy).await.expect("acquire");
+        let second = store
+            .clone()
+            .try_acquire(&key, &policy)
+            .await
+            .expect("acquire");
+        let third = store.try_acquire(&key, &policy).await.expect("acquire");
+
+        assert_eq!(first, RateLimitDecision::Allowed { remaining: 1 });
+        assert_eq!(second, RateLimitDecision::Allowed { remaining: 0 });
+        assert!(!third.is_allowed());
+    }
+}
@@ -0,0 +1,8 @@
+pub mod in_memory_repository;
+pub mod in_memory_unique_key_reservation_store;
+pub(crate) mod in_memory_unique_reservation_key;
+pub mod in_memory_unique_value_owner_lookup;
+
+pub use in_memory_repository::InMemoryRepository;
+pub use in_memory_unique_key_reservation_store::InMemoryUniqueKeyReservationStore;
+pub use in_memory_unique_value_owner_lookup::InMemoryUniqueValueOwnerLookup;
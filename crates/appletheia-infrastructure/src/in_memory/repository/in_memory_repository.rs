@@ -0,0 +1,19 @@
+use appletheia_application::repository::DefaultRepository;
+
+use crate::in_memory::event::{InMemoryEventReader, InMemoryEventWriter};
+use crate::in_memory::repository::{
+    InMemoryUniqueKeyReservationStore, InMemoryUniqueValueOwnerLookup,
+};
+use crate::in_memory::snapshot::{InMemorySnapshotReader, InMemorySnapshotWriter};
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+pub type InMemoryRepository<A> = DefaultRepository<
+    A,
+    InMemoryEventReader<A>,
+    InMemoryEventWriter<A>,
+    InMemorySnapshotReader<A>,
+    InMemorySnapshotWriter<A>,
+    InMemoryUniqueValueOwnerLookup,
+    InMemoryUniqueKeyReservationStore,
+    InMemoryUnitOfWork,
+>;
@@ -0,0 +1,64 @@
+use appletheia_application::repository::{
+    UniqueKeyReservationStore, UniqueKeyReservationStoreError,
+};
+use appletheia_domain::aggregate::{AggregateId, AggregateType, UniqueEntries};
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+use super::in_memory_unique_reservation_key::InMemoryUniqueReservationKey;
+
+pub struct InMemoryUniqueKeyReservationStore;
+
+impl InMemoryUniqueKeyReservationStore {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InMemoryUniqueKeyReservationStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UniqueKeyReservationStore for InMemoryUniqueKeyReservationStore {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn replace<I>(
+        &self,
+        uow: &mut Self::Uow,
+        aggregate_type: AggregateType,
+        owner_aggregate_id: I,
+        unique_entries: &UniqueEntries,
+    ) -> Result<(), UniqueKeyReservationStoreError>
+    where
+        I: AggregateId,
+    {
+        let owner_aggregate_id = owner_aggregate_id.value();
+        let reservations = &mut uow.tables_mut().unique_key_reservations;
+
+        reservations.retain(|key, owner| {
+            key.aggregate_type != aggregate_type.value() || *owner != owner_aggregate_id
+        });
+
+        for (namespace, values) in unique_entries.iter() {
+            for value in values.iter() {
+                let key = InMemoryUniqueReservationKey {
+                    aggregate_type: aggregate_type.value().to_owned(),
+                    namespace: namespace.value().to_owned(),
+                    normalized_value: value.normalized_key(),
+                };
+                if reservations.contains_key(&key) {
+                    return Err(UniqueKeyReservationStoreError::conflict(
+                        aggregate_type,
+                        *namespace,
+                        value,
+                    ));
+                }
+                reservations.insert(key, owner_aggregate_id);
+            }
+        }
+
+        Ok(())
+    }
+}
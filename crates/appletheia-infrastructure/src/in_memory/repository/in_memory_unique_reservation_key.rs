@@ -0,0 +1,7 @@
+/// Mirrors the `(aggregate_type, namespace, normalized_value)` unique constraint.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub(crate) struct InMemoryUniqueReservationKey {
+    pub aggregate_type: String,
+    pub namespace: String,
+    pub normalized_value: String,
+}
@@ -0,0 +1,50 @@
+use appletheia_application::repository::{UniqueValueOwnerLookup, UniqueValueOwnerLookupError};
+use appletheia_domain::aggregate::{AggregateId, AggregateType, UniqueKey, UniqueValue};
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+use super::in_memory_unique_reservation_key::InMemoryUniqueReservationKey;
+
+/// Looks up aggregate owners from in-memory unique-key reservations.
+pub struct InMemoryUniqueValueOwnerLookup;
+
+impl InMemoryUniqueValueOwnerLookup {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InMemoryUniqueValueOwnerLookup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UniqueValueOwnerLookup for InMemoryUniqueValueOwnerLookup {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn find_owner_id<I>(
+        &self,
+        uow: &mut Self::Uow,
+        aggregate_type: AggregateType,
+        unique_key: UniqueKey,
+        unique_value: &UniqueValue,
+    ) -> Result<Option<I>, UniqueValueOwnerLookupError>
+    where
+        I: AggregateId,
+    {
+        let key = InMemoryUniqueReservationKey {
+            aggregate_type: aggregate_type.value().to_owned(),
+            namespace: unique_key.value().to_owned(),
+            normalized_value: unique_value.normalized_key(),
+        };
+
+        let Some(owner_aggregate_id) = uow.tables().unique_key_reservations.get(&key) else {
+            return Ok(None);
+        };
+
+        I::try_from_uuid(*owner_aggregate_id)
+            .map(Some)
+            .map_err(|error| UniqueValueOwnerLookupError::OwnerAggregateId(Box::new(error)))
+    }
+}
@@ -0,0 +1,6 @@
+pub mod in_memory_saga_processed_event_store;
+pub(crate) mod in_memory_saga_run_row;
+pub mod in_memory_saga_run_store;
+
+pub use in_memory_saga_processed_event_store::InMemorySagaProcessedEventStore;
+pub use in_memory_saga_run_store::InMemorySagaRunStore;
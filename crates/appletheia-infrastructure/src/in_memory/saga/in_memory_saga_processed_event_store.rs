@@ -0,0 +1,37 @@
+use appletheia_application::saga::{
+    SagaNameOwned, SagaProcessedEventStore, SagaProcessedEventStoreError,
+};
+use appletheia_domain::EventId;
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+#[derive(Debug)]
+pub struct InMemorySagaProcessedEventStore;
+
+impl InMemorySagaProcessedEventStore {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InMemorySagaProcessedEventStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SagaProcessedEventStore for InMemorySagaProcessedEventStore {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn mark_processed(
+        &self,
+        uow: &mut Self::Uow,
+        saga_name: SagaNameOwned,
+        event_id: EventId,
+    ) -> Result<bool, SagaProcessedEventStoreError> {
+        Ok(uow
+            .tables_mut()
+            .saga_processed_events
+            .insert((saga_name.value().to_owned(), event_id)))
+    }
+}
@@ -0,0 +1,12 @@
+use appletheia_application::request_context::MessageId;
+use appletheia_application::saga::SagaRunId;
+use appletheia_domain::EventId;
+
+#[derive(Clone, Debug)]
+pub(crate) struct InMemorySagaRunRow {
+    pub id: SagaRunId,
+    pub saga_name: String,
+    pub trigger_event_id: EventId,
+    pub dispatched_command_message_id: Option<MessageId>,
+    pub context: serde_json::Value,
+}
@@ -0,0 +1,112 @@
+use appletheia_application::request_context::MessageId;
+use appletheia_application::saga::{SagaNameOwned, SagaRun, SagaRunStore, SagaRunStoreError};
+use appletheia_domain::EventId;
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::in_memory::InMemoryStorageError;
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+use super::in_memory_saga_run_row::InMemorySagaRunRow;
+
+#[derive(Debug)]
+pub struct InMemorySagaRunStore;
+
+impl InMemorySagaRunStore {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn read<C: Serialize + DeserializeOwned + Send + Sync + 'static>(
+        uow: &InMemoryUnitOfWork,
+        saga_name: SagaNameOwned,
+        predicate: impl Fn(&InMemorySagaRunRow) -> bool,
+    ) -> Result<Option<SagaRun<C>>, SagaRunStoreError> {
+        let Some(row) = uow
+            .tables()
+            .saga_runs
+            .iter()
+            .find(|row| row.saga_name == saga_name.value() && predicate(row))
+        else {
+            return Ok(None);
+        };
+
+        let context = serde_json::from_value(row.context.clone())
+            .map_err(|e| SagaRunStoreError::MappingFailed(Box::new(e)))?;
+
+        Ok(Some(SagaRun {
+            saga_run_id: row.id,
+            saga_name,
+            trigger_event_id: row.trigger_event_id,
+            dispatched_command_message_id: row.dispatched_command_message_id,
+            context,
+        }))
+    }
+}
+
+impl Default for InMemorySagaRunStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SagaRunStore for InMemorySagaRunStore {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn read_by_trigger_event<C: Serialize + DeserializeOwned + Send + Sync + 'static>(
+        &self,
+        uow: &mut Self::Uow,
+        saga_name: SagaNameOwned,
+        trigger_event_id: EventId,
+    ) -> Result<Option<SagaRun<C>>, SagaRunStoreError> {
+        Self::read(uow, saga_name, |row| {
+            row.trigger_event_id == trigger_event_id
+        })
+    }
+
+    async fn read_by_dispatched_command_message<
+        C: Serialize + DeserializeOwned + Send + Sync + 'static,
+    >(
+        &self,
+        uow: &mut Self::Uow,
+        saga_name: SagaNameOwned,
+        dispatched_command_message_id: MessageId,
+    ) -> Result<Option<SagaRun<C>>, SagaRunStoreError> {
+        Self::read(uow, saga_name, |row| {
+            row.dispatched_command_message_id == Some(dispatched_command_message_id)
+        })
+    }
+
+    async fn write<C: Serialize + DeserializeOwned + Send + Sync + 'static>(
+        &self,
+        uow: &mut Self::Uow,
+        run: &SagaRun<C>,
+    ) -> Result<(), SagaRunStoreError> {
+        let context =
+            serde_json::to_value(&run.context).map_err(SagaRunStoreError::ContextSerialize)?;
+
+        let saga_runs = &mut uow.tables_mut().saga_runs;
+        let is_duplicate = saga_runs.iter().any(|row| {
+            row.id == run.saga_run_id
+                || (row.saga_name == run.saga_name.value()
+                    && (row.trigger_event_id == run.trigger_event_id
+                        || (run.dispatched_command_message_id.is_some()
+                            && row.dispatched_command_message_id
+                                == run.dispatched_command_message_id)))
+        });
+        if is_duplicate {
+            return Err(SagaRunStoreError::Persistence(Box::new(
+                InMemoryStorageError::UniqueViolation("saga_runs"),
+            )));
+        }
+
+        saga_runs.push(InMemorySagaRunRow {
+            id: run.saga_run_id,
+            saga_name: run.saga_name.value().to_owned(),
+            trigger_event_id: run.trigger_event_id,
+            dispatched_command_message_id: run.dispatched_command_message_id,
+            context,
+        });
+
+        Ok(())
+    }
+}
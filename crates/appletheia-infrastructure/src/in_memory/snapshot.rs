@@ -0,0 +1,6 @@
+pub mod in_memory_snapshot_reader;
+pub(crate) mod in_memory_snapshot_row;
+pub mod in_memory_snapshot_writer;
+
+pub use in_memory_snapshot_reader::InMemorySnapshotReader;
+pub use in_memory_snapshot_writer::InMemorySnapshotWriter;
@@ -0,0 +1,60 @@
+use std::marker::PhantomData;
+
+use appletheia_application::snapshot::{SnapshotReader, SnapshotReaderError};
+use appletheia_domain::{Aggregate, AggregateId, AggregateState, AggregateVersion, Snapshot};
+
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+pub struct InMemorySnapshotReader<A: Aggregate> {
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Aggregate> InMemorySnapshotReader<A> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Aggregate> Default for InMemorySnapshotReader<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Aggregate> SnapshotReader<A> for InMemorySnapshotReader<A> {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn read_latest_snapshot(
+        &self,
+        uow: &mut Self::Uow,
+        aggregate_id: A::Id,
+        as_of: Option<AggregateVersion>,
+    ) -> Result<Option<Snapshot<A::State>>, SnapshotReaderError> {
+        let Some(row) = uow
+            .tables()
+            .snapshots
+            .iter()
+            .filter(|row| {
+                row.aggregate_type == A::TYPE
+                    && row.aggregate_id == aggregate_id.value()
+                    && as_of.is_none_or(|version| row.aggregate_version <= version)
+            })
+            .max_by_key(|row| row.aggregate_version)
+        else {
+            return Ok(None);
+        };
+
+        let state = A::State::try_from_json_value(row.state.clone())
+            .map_err(|e| SnapshotReaderError::MappingFailed(Box::new(e)))?;
+
+        Ok(Some(Snapshot::from_persisted(
+            row.id,
+            aggregate_id,
+            row.aggregate_version,
+            state,
+            row.materialized_at,
+        )))
+    }
+}
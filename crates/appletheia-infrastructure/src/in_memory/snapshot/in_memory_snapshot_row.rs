@@ -0,0 +1,12 @@
+use appletheia_domain::{AggregateType, AggregateVersion, SnapshotId, SnapshotMaterializedAt};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub(crate) struct InMemorySnapshotRow {
+    pub id: SnapshotId,
+    pub aggregate_type: AggregateType,
+    pub aggregate_id: Uuid,
+    pub aggregate_version: AggregateVersion,
+    pub state: serde_json::Value,
+    pub materialized_at: SnapshotMaterializedAt,
+}
@@ -0,0 +1,62 @@
+use std::marker::PhantomData;
+
+use appletheia_application::snapshot::{SnapshotWriter, SnapshotWriterError};
+use appletheia_domain::{Aggregate, AggregateId, Snapshot};
+
+use crate::in_memory::InMemoryStorageError;
+use crate::in_memory::unit_of_work::InMemoryUnitOfWork;
+
+use super::in_memory_snapshot_row::InMemorySnapshotRow;
+
+pub struct InMemorySnapshotWriter<A: Aggregate> {
+    _aggregate: PhantomData<A>,
+}
+
+impl<A: Aggregate> InMemorySnapshotWriter<A> {
+    pub fn new() -> Self {
+        Self {
+            _aggregate: PhantomData,
+        }
+    }
+}
+
+impl<A: Aggregate> Default for InMemorySnapshotWriter<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Aggregate> SnapshotWriter<A> for InMemorySnapshotWriter<A> {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn write_snapshot(
+        &self,
+        uow: &mut Self::Uow,
+        snapshot: &Snapshot<A::State>,
+    ) -> Result<(), SnapshotWriterError> {
+        let row = InMemorySnapshotRow {
+            id: snapshot.id(),
+            aggregate_type: A::TYPE,
+            aggregate_id: snapshot.aggregate_id().value(),
+            aggregate_version: snapshot.aggregate_version(),
+            state: serde_json::to_value(snapshot.state()).map_err(SnapshotWriterError::Json)?,
+            materialized_at: snapshot.materialized_at(),
+        };
+
+        let snapshots = &mut uow.tables_mut().snapshots;
+        let is_duplicate = snapshots.iter().any(|existing| {
+            existing.id == row.id
+                || (existing.aggregate_type == row.aggregate_type
+                    && existing.aggregate_id == row.aggregate_id
+                    && existing.aggregate_version == row.aggregate_version)
+        });
+        if is_duplicate {
+            return Err(SnapshotWriterError::Persistence(Box::new(
+                InMemoryStorageError::UniqueViolation("snapshots"),
+            )));
+        }
+
+        snapshots.push(row);
+        Ok(())
+    }
+}
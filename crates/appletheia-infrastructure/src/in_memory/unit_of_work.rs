@@ -0,0 +1,10 @@
+pub mod in_memory_database;
+mod in_memory_sequences;
+mod in_memory_table;
+pub(crate) mod in_memory_tables;
+pub mod in_memory_unit_of_work;
+pub mod in_memory_unit_of_work_factory;
+
+pub use in_memory_database::InMemoryDatabase;
+pub use in_memory_unit_of_work::InMemoryUnitOfWork;
+pub use in_memory_unit_of_work_factory::InMemoryUnitOfWorkFactory;
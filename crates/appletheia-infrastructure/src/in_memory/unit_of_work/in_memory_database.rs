@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use super::in_memory_tables::InMemoryTables;
+
+/// Shared state of the in-memory backend.
+///
+/// Cloning the database yields another handle to the same tables. Units of work
+/// begun on the database run one at a time, so every unit of work sees a
+/// serializable view of the data.
+///
+/// A unit of work holds the database until it is committed, rolled back or dropped, and
+/// every other `begin` waits for it, including one from another future on the same task.
+/// Code must therefore finish a unit of work before it begins the next one: a `begin`
+/// awaited while the caller still holds a unit of work never completes. The dispatchers,
+/// runners and relays of the application layer never nest units of work.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryDatabase {
+    tables: Arc<Mutex<InMemoryTables>>,
+}
+
+impl InMemoryDatabase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn tables(&self) -> Arc<Mutex<InMemoryTables>> {
+        Arc::clone(&self.tables)
+    }
+}
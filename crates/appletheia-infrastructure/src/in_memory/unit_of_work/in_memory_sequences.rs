@@ -0,0 +1,23 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// Counters that, like PostgreSQL sequences, are not rolled back with a unit of work.
+#[derive(Debug, Default)]
+pub(crate) struct InMemorySequences {
+    event_sequence: AtomicI64,
+    command_sequence: AtomicI64,
+    relationship_revision: AtomicI64,
+}
+
+impl InMemorySequences {
+    pub(crate) fn next_event_sequence(&self) -> i64 {
+        self.event_sequence.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    pub(crate) fn next_command_sequence(&self) -> i64 {
+        self.command_sequence.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    pub(crate) fn next_relationship_revision(&self) -> i64 {
+        self.relationship_revision.fetch_add(1, Ordering::SeqCst) + 1
+    }
+}
@@ -0,0 +1,41 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+/// A table of `InMemoryTables` that copies of the tables share until one of them writes to it.
+///
+/// Copying the tables, as units of work and savepoints do, therefore only copies the rows of
+/// the tables that are written afterwards.
+#[derive(Debug, Default)]
+pub(crate) struct InMemoryTable<T>(Arc<T>);
+
+impl<T> Clone for InMemoryTable<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Deref for InMemoryTable<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Clone> DerefMut for InMemoryTable<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        Arc::make_mut(&mut self.0)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a InMemoryTable<T>
+where
+    &'a T: IntoIterator,
+{
+    type Item = <&'a T as IntoIterator>::Item;
+    type IntoIter = <&'a T as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.as_ref().into_iter()
+    }
+}
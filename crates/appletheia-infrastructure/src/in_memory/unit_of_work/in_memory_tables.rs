@@ -0,0 +1,41 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+use appletheia_application::authorization::{Relationship, RelationshipRevision};
+use appletheia_application::event::{EventEnvelope, EventSequence};
+use appletheia_application::outbox::command::{CommandOutbox, CommandOutboxId};
+use appletheia_application::outbox::event::{EventOutbox, EventOutboxId};
+use appletheia_application::request_context::MessageId;
+use appletheia_domain::EventId;
+
+use crate::in_memory::command::in_memory_idempotency_row::InMemoryIdempotencyRow;
+use crate::in_memory::repository::in_memory_unique_reservation_key::InMemoryUniqueReservationKey;
+use crate::in_memory::saga::in_memory_saga_run_row::InMemorySagaRunRow;
+use crate::in_memory::snapshot::in_memory_snapshot_row::InMemorySnapshotRow;
+
+use super::in_memory_sequences::InMemorySequences;
+use super::in_memory_table::InMemoryTable;
+
+/// The rows held by an `InMemoryDatabase`.
+///
+/// Each field plays the role of the PostgreSQL table with the same name. Cloning the tables
+/// shares the rows of every table until it is written.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct InMemoryTables {
+    pub(crate) sequences: Arc<InMemorySequences>,
+    pub(crate) events: InMemoryTable<BTreeMap<EventSequence, EventEnvelope>>,
+    pub(crate) snapshots: InMemoryTable<Vec<InMemorySnapshotRow>>,
+    pub(crate) unique_key_reservations:
+        InMemoryTable<HashMap<InMemoryUniqueReservationKey, uuid::Uuid>>,
+    pub(crate) event_outbox: InMemoryTable<BTreeMap<EventOutboxId, EventOutbox>>,
+    pub(crate) event_dead_letters: InMemoryTable<BTreeMap<EventOutboxId, EventOutbox>>,
+    pub(crate) command_outbox: InMemoryTable<BTreeMap<CommandOutboxId, CommandOutbox>>,
+    pub(crate) command_dead_letters: InMemoryTable<BTreeMap<CommandOutboxId, CommandOutbox>>,
+    pub(crate) idempotency: InMemoryTable<HashMap<MessageId, InMemoryIdempotencyRow>>,
+    pub(crate) projection_checkpoints: InMemoryTable<HashMap<String, EventSequence>>,
+    pub(crate) projector_processed_events: InMemoryTable<HashSet<(String, EventId)>>,
+    pub(crate) saga_runs: InMemoryTable<Vec<InMemorySagaRunRow>>,
+    pub(crate) saga_processed_events: InMemoryTable<HashSet<(String, EventId)>>,
+    pub(crate) relationships: InMemoryTable<HashSet<Relationship>>,
+    pub(crate) relationship_revision: RelationshipRevision,
+}
@@ -0,0 +1,169 @@
+use appletheia_application::unit_of_work::{UnitOfWork, UnitOfWorkError};
+use tokio::sync::OwnedMutexGuard;
+
+use super::in_memory_tables::InMemoryTables;
+
+/// Holds exclusive access to an `InMemoryDatabase` for the lifetime of the unit of work.
+///
+/// Writes are applied to the shared tables directly and a copy taken on begin is
+/// restored when the unit of work is rolled back or dropped without committing. Savepoints
+/// keep further copies, one per nesting level. Copies share the rows of each table until it
+/// is written, so a copy costs a clone of every table written after it was taken.
+pub struct InMemoryUnitOfWork {
+    tables: OwnedMutexGuard<InMemoryTables>,
+    rollback_image: Option<InMemoryTables>,
+    savepoint_images: Vec<InMemoryTables>,
+}
+
+impl InMemoryUnitOfWork {
+    pub(super) fn new(tables: OwnedMutexGuard<InMemoryTables>) -> Self {
+        let rollback_image = Some(tables.clone());
+        Self {
+            tables,
+            rollback_image,
+            savepoint_images: Vec::new(),
+        }
+    }
+
+    pub(crate) fn tables(&self) -> &InMemoryTables {
+        &self.tables
+    }
+
+    pub(crate) fn tables_mut(&mut self) -> &mut InMemoryTables {
+        &mut self.tables
+    }
+}
+
+impl Drop for InMemoryUnitOfWork {
+    fn drop(&mut self) {
+        if let Some(rollback_image) = self.rollback_image.take() {
+            *self.tables = rollback_image;
+        }
+    }
+}
+
+impl UnitOfWork for InMemoryUnitOfWork {
+    async fn commit(mut self) -> Result<(), UnitOfWorkError> {
+        self.rollback_image = None;
+        Ok(())
+    }
+
+    async fn rollback(self) -> Result<(), UnitOfWorkError> {
+        Ok(())
+    }
+
+    async fn savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+        self.savepoint_images.push(self.tables.clone());
+        Ok(())
+    }
+
+    async fn release_savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+        self.savepoint_images
+            .pop()
+            .map(|_| ())
+            .ok_or(UnitOfWorkError::NoActiveSavepoint)
+    }
+
+    async fn rollback_to_savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+        let savepoint_image = self
+            .savepoint_images
+            .pop()
+            .ok_or(UnitOfWorkError::NoActiveSavepoint)?;
+        *self.tables = savepoint_image;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use appletheia_application::event::EventSequence;
+    use appletheia_application::unit_of_work::UnitOfWork;
+    use tokio::time::timeout;
+
+    use super::InMemoryUnitOfWork;
+    use crate::in_memory::unit_of_work::InMemoryDatabase;
+
+    async fn begin(database: &InMemoryDatabase) -> InMemoryUnitOfWork {
+        InMemoryUnitOfWork::new(database.tables().lock_owned().await)
+    }
+
+    fn sequence(value: i64) -> EventSequence {
+        EventSequence::try_from(value).expect("sequence should be valid")
+    }
+
+    #[tokio::test]
+    async fn rollback_restores_only_the_written_tables() {
+        let database = InMemoryDatabase::new();
+        let mut uow = begin(&database).await;
+        uow.tables_mut()
+            .projection_checkpoints
+            .insert("kept".to_owned(), sequence(1));
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = begin(&database).await;
+        uow.tables_mut().projection_checkpoints.clear();
+        uow.tables_mut()
+            .projection_checkpoints
+            .insert("discarded".to_owned(), sequence(2));
+        uow.rollback().await.expect("rollback should succeed");
+
+        let uow = begin(&database).await;
+        let checkpoints = &uow.tables().projection_checkpoints;
+        assert_eq!(checkpoints.len(), 1);
+        assert_eq!(checkpoints.get("kept"), Some(&sequence(1)));
+        assert!(uow.tables().events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn other_units_of_work_see_changes_only_after_commit() {
+        let database = InMemoryDatabase::new();
+        let mut writer = begin(&database).await;
+        writer
+            .tables_mut()
+            .projection_checkpoints
+            .insert("projector".to_owned(), sequence(1));
+
+        assert!(
+            timeout(Duration::from_millis(50), begin(&database))
+                .await
+                .is_err()
+        );
+
+        writer.commit().await.expect("commit should succeed");
+        let reader = begin(&database).await;
+        assert!(
+            reader
+                .tables()
+                .projection_checkpoints
+                .contains_key("projector")
+        );
+    }
+
+    #[tokio::test]
+    async fn nested_savepoints_unwind_one_level_at_a_time() {
+        let database = InMemoryDatabase::new();
+        let mut uow = begin(&database).await;
+
+        uow.savepoint().await.expect("savepoint should succeed");
+        uow.tables_mut()
+            .projection_checkpoints
+            .insert("outer".to_owned(), sequence(1));
+        uow.savepoint().await.expect("savepoint should succeed");
+        uow.tables_mut()
+            .projection_checkpoints
+            .insert("inner".to_owned(), sequence(2));
+
+        uow.rollback_to_savepoint()
+            .await
+            .expect("rollback to savepoint should succeed");
+        assert!(uow.tables().projection_checkpoints.contains_key("outer"));
+        assert!(!uow.tables().projection_checkpoints.contains_key("inner"));
+
+        uow.rollback_to_savepoint()
+            .await
+            .expect("rollback to savepoint should succeed");
+        assert!(uow.tables().projection_checkpoints.is_empty());
+    }
+}
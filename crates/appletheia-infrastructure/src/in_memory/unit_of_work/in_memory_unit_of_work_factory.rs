@@ -0,0 +1,131 @@
+use appletheia_application::unit_of_work::{UnitOfWorkFactory, UnitOfWorkFactoryError};
+
+use super::in_memory_database::InMemoryDatabase;
+use super::in_memory_unit_of_work::InMemoryUnitOfWork;
+
+/// Begins units of work on an `InMemoryDatabase`.
+///
+/// `begin` waits until no other unit of work holds the database, so it must not be awaited
+/// while the caller still holds one; see `InMemoryDatabase`.
+pub struct InMemoryUnitOfWorkFactory {
+    database: InMemoryDatabase,
+}
+
+impl InMemoryUnitOfWorkFactory {
+    pub fn new(database: InMemoryDatabase) -> Self {
+        Self { database }
+    }
+}
+
+impl UnitOfWorkFactory for InMemoryUnitOfWorkFactory {
+    type Uow = InMemoryUnitOfWork;
+
+    async fn begin(&self) -> Result<Self::Uow, UnitOfWorkFactoryError> {
+        let tables = self.database.tables().lock_owned().await;
+        Ok(InMemoryUnitOfWork::new(tables))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use appletheia_application::event::EventSequence;
+    use appletheia_application::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+    use tokio::time::{sleep, timeout};
+
+    use super::{InMemoryDatabase, InMemoryUnitOfWorkFactory};
+
+    async fn save_checkpoint(factory: &InMemoryUnitOfWorkFactory) -> super::InMemoryUnitOfWork {
+        let mut uow = factory.begin().await.expect("begin should succeed");
+        uow.tables_mut().projection_checkpoints.insert(
+            "projector".to_owned(),
+            EventSequence::try_from(1).expect("sequence should be valid"),
+        );
+        uow
+    }
+
+    async fn has_checkpoint(factory: &InMemoryUnitOfWorkFactory) -> bool {
+        let uow = factory.begin().await.expect("begin should succeed");
+        uow.tables()
+            .projection_checkpoints
+            .contains_key("projector")
+    }
+
+    #[tokio::test]
+    async fn commit_keeps_changes() {
+        let factory = InMemoryUnitOfWorkFactory::new(InMemoryDatabase::new());
+
+        let uow = save_checkpoint(&factory).await;
+        uow.commit().await.expect("commit should succeed");
+
+        assert!(has_checkpoint(&factory).await);
+    }
+
+    #[tokio::test]
+    async fn rollback_and_drop_discard_changes() {
+        let factory = InMemoryUnitOfWorkFactory::new(InMemoryDatabase::new());
+
+        let uow = save_checkpoint(&factory).await;
+        uow.rollback().await.expect("rollback should succeed");
+        assert!(!has_checkpoint(&factory).await);
+
+        drop(save_checkpoint(&factory).await);
+        assert!(!has_checkpoint(&factory).await);
+    }
+
+    #[tokio::test]
+    async fn sequences_are_not_rolled_back() {
+        let factory = InMemoryUnitOfWorkFactory::new(InMemoryDatabase::new());
+
+        let uow = factory.begin().await.expect("begin should succeed");
+        assert_eq!(uow.tables().sequences.next_relationship_revision(), 1);
+        uow.rollback().await.expect("rollback should succeed");
+
+        let uow = factory.begin().await.expect("begin should succeed");
+        assert_eq!(uow.tables().sequences.next_relationship_revision(), 2);
+    }
+
+    #[tokio::test]
+    async fn rollback_to_savepoint_discards_only_later_changes() {
+        let factory = InMemoryUnitOfWorkFactory::new(InMemoryDatabase::new());
+
+        let mut uow = save_checkpoint(&factory).await;
+        uow.savepoint().await.expect("savepoint should succeed");
+        uow.tables_mut().projection_checkpoints.clear();
+        uow.rollback_to_savepoint()
+            .await
+            .expect("rollback to savepoint should succeed");
+        assert!(
+            uow.tables()
+                .projection_checkpoints
+                .contains_key("projector")
+        );
+        assert!(uow.release_savepoint().await.is_err());
+        uow.commit().await.expect("commit should succeed");
+
+        assert!(has_checkpoint(&factory).await);
+    }
+
+    #[tokio::test]
+    async fn begins_on_the_same_task_wait_for_the_open_unit_of_work() {
+        let factory = InMemoryUnitOfWorkFactory::new(InMemoryDatabase::new());
+
+        let writer = async {
+            let uow = save_checkpoint(&factory).await;
+            sleep(Duration::from_millis(20)).await;
+            uow.commit().await.expect("commit should succeed");
+        };
+        let reader = async {
+            sleep(Duration::from_millis(5)).await;
+            has_checkpoint(&factory).await
+        };
+        let ((), seen) = timeout(Duration::from_secs(1), async {
+            tokio::join!(writer, reader)
+        })
+        .await
+        .expect("the waiting begin should not hang");
+
+        assert!(seen);
+    }
+}
@@ -1,11 +1,20 @@
 pub mod aes_gcm;
+#[cfg(feature = "amqp")]
+pub mod amqp;
 pub mod bridge;
 pub mod core;
 pub mod google_cloud;
 pub mod http;
+pub mod in_memory;
 pub mod jwt;
+#[cfg(feature = "nats")]
+pub mod nats;
 pub mod postgresql;
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
 pub mod sha;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 
 pub use aes_gcm::Aes256GcmAuthTokenExchangeGrantCipher;
 pub use aes_gcm::Aes256GcmAuthTokenExchangeGrantCipherError;
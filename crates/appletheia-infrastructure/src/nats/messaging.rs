@@ -0,0 +1,16 @@
+pub mod nats_command_publisher;
+pub mod nats_command_subscriber;
+pub mod nats_consumer;
+pub mod nats_consumer_options;
+pub mod nats_delivery;
+pub mod nats_event_publisher;
+pub mod nats_event_subscriber;
+mod nats_publish;
+
+pub use nats_command_publisher::NatsCommandPublisher;
+pub use nats_command_subscriber::NatsCommandSubscriber;
+pub use nats_consumer::NatsConsumer;
+pub use nats_consumer_options::NatsConsumerOptions;
+pub use nats_delivery::NatsDelivery;
+pub use nats_event_publisher::NatsEventPublisher;
+pub use nats_event_subscriber::NatsEventSubscriber;
@@ -0,0 +1,64 @@
+use appletheia_application::messaging::{PublishResult, Publisher, PublisherError, TopicId};
+use appletheia_application::outbox::OrderingKey;
+use appletheia_application::outbox::command::CommandEnvelope;
+use async_nats::HeaderMap;
+use async_nats::jetstream::Context;
+
+use super::nats_publish::{NatsMessage, publish_acknowledged};
+
+/// Publishes commands to the JetStream stream of a topic.
+///
+/// The subject is `<topic>.<command_name>`.
+#[derive(Clone)]
+pub struct NatsCommandPublisher {
+    context: Context,
+    topic_id: TopicId,
+}
+
+impl NatsCommandPublisher {
+    pub fn new(context: Context, topic_id: TopicId) -> Self {
+        Self { context, topic_id }
+    }
+
+    pub(crate) fn subject(topic_id: &TopicId, command_name: &str) -> String {
+        format!("{}.{command_name}", topic_id.value())
+    }
+
+    fn build_message(&self, command: &CommandEnvelope) -> Result<NatsMessage, PublisherError> {
+        let mut headers = HeaderMap::new();
+
+        headers.insert("message_id", command.message_id.to_string());
+        headers.insert("command_name", command.command_name.to_string());
+        headers.insert("correlation_id", command.correlation_id.to_string());
+        headers.insert("causation_id", command.causation_id.to_string());
+        headers.insert(
+            "ordering_key",
+            OrderingKey::from(command.correlation_id).to_string(),
+        );
+
+        let data = serde_json::to_vec(command)
+            .map_err(|source| PublisherError::Publish(Box::new(source)))?;
+
+        Ok(NatsMessage {
+            subject: Self::subject(&self.topic_id, command.command_name.value()),
+            message_id: command.message_id.to_string(),
+            headers,
+            data,
+        })
+    }
+}
+
+impl Publisher<CommandEnvelope> for NatsCommandPublisher {
+    async fn publish<'a, I>(&self, messages: I) -> Result<Vec<PublishResult>, PublisherError>
+    where
+        I: IntoIterator<Item = &'a CommandEnvelope>,
+        CommandEnvelope: 'a,
+    {
+        let nats_messages = messages
+            .into_iter()
+            .map(|command| self.build_message(command))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(publish_acknowledged(&self.context, nats_messages).await)
+    }
+}
@@ -0,0 +1,69 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use appletheia_application::{Consumer, ConsumerError, UndecodableMessage};
+use async_nats::jetstream::AckKind;
+use async_nats::jetstream::consumer::pull::Stream as MessageStream;
+use futures_util::StreamExt;
+use serde::de::DeserializeOwned;
+
+use super::nats_delivery::NatsDelivery;
+
+pub struct NatsConsumer<M> {
+    messages: MessageStream,
+    nak_delay: Duration,
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<M> NatsConsumer<M> {
+    pub(crate) fn new(messages: MessageStream, nak_delay: Duration) -> Self {
+        Self {
+            messages,
+            nak_delay,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<M> Consumer<M> for NatsConsumer<M>
+where
+    M: DeserializeOwned + Send + Sync + 'static,
+{
+    type Delivery = NatsDelivery<M>;
+
+    async fn next(&mut self) -> Result<Self::Delivery, ConsumerError> {
+        let message = self
+            .messages
+            .next()
+            .await
+            .transpose()
+            .map_err(|error| ConsumerError::Next(Box::new(error)))?
+            .ok_or_else(|| {
+                ConsumerError::Next(Box::new(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "jetstream message stream ended",
+                )))
+            })?;
+
+        let (message, acker) = message.split();
+
+        // An undecodable message can never succeed, so it is terminated instead of redelivered.
+        let message: M = match serde_json::from_slice(&message.payload) {
+            Ok(message) => message,
+            Err(error) => {
+                acker
+                    .ack_with(AckKind::Term)
+                    .await
+                    .map_err(ConsumerError::Nack)?;
+                return Err(ConsumerError::Undecodable(UndecodableMessage {
+                    transport_message_id: None,
+                    attributes: [("subject".to_string(), message.subject.to_string())].into(),
+                    data: message.payload.to_vec(),
+                    reason: error.to_string(),
+                }));
+            }
+        };
+
+        Ok(NatsDelivery::new(acker, message, self.nak_delay))
+    }
+}
@@ -0,0 +1,110 @@
+use std::num::{NonZeroI64, NonZeroU32};
+use std::time::Duration;
+
+use async_nats::jetstream::Context;
+use async_nats::jetstream::consumer::{AckPolicy, PullConsumer, pull};
+use async_nats::jetstream::stream;
+
+use appletheia_application::messaging::{ConsumerGroup, TopicId};
+
+/// Settings of the durable JetStream consumers created for consumer groups.
+///
+/// A nacked message is redelivered after `nak_delay`, an unsettled one after `ack_wait`.
+/// Once a message has been delivered `max_deliver` times JetStream stops redelivering it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NatsConsumerOptions {
+    ack_wait: Duration,
+    nak_delay: Duration,
+    max_deliver: NonZeroU32,
+    max_ack_pending: NonZeroI64,
+}
+
+impl NatsConsumerOptions {
+    pub fn new() -> Self {
+        Self {
+            ack_wait: Duration::from_secs(30),
+            nak_delay: Duration::from_secs(10),
+            max_deliver: NonZeroU32::new(5).expect("5 should be non-zero"),
+            max_ack_pending: NonZeroI64::new(1).expect("1 should be non-zero"),
+        }
+    }
+
+    pub fn with_ack_wait(mut self, ack_wait: Duration) -> Self {
+        self.ack_wait = ack_wait;
+        self
+    }
+
+    pub fn with_nak_delay(mut self, nak_delay: Duration) -> Self {
+        self.nak_delay = nak_delay;
+        self
+    }
+
+    pub fn with_max_deliver(mut self, max_deliver: NonZeroU32) -> Self {
+        self.max_deliver = max_deliver;
+        self
+    }
+
+    /// How many unacked messages a consumer may hold; `1` keeps deliveries in stream order.
+    pub fn with_max_ack_pending(mut self, max_ack_pending: NonZeroI64) -> Self {
+        self.max_ack_pending = max_ack_pending;
+        self
+    }
+
+    pub fn ack_wait(&self) -> Duration {
+        self.ack_wait
+    }
+
+    pub fn nak_delay(&self) -> Duration {
+        self.nak_delay
+    }
+
+    pub fn max_deliver(&self) -> NonZeroU32 {
+        self.max_deliver
+    }
+
+    pub fn max_ack_pending(&self) -> NonZeroI64 {
+        self.max_ack_pending
+    }
+
+    /// Creates the stream of `topic_id` and the durable consumer of `consumer_group`.
+    ///
+    /// The stream captures `<topic>.>`; both calls keep an existing stream or consumer as is.
+    pub(crate) async fn get_or_create_consumer(
+        &self,
+        context: &Context,
+        topic_id: &TopicId,
+        consumer_group: &ConsumerGroup,
+        filter_subjects: Vec<String>,
+    ) -> Result<PullConsumer, async_nats::Error> {
+        let stream = context
+            .get_or_create_stream(stream::Config {
+                name: topic_id.value().to_string(),
+                subjects: vec![format!("{}.>", topic_id.value())],
+                ..stream::Config::default()
+            })
+            .await?;
+
+        let consumer = stream
+            .get_or_create_consumer(
+                consumer_group.value(),
+                pull::Config {
+                    durable_name: Some(consumer_group.value().to_string()),
+                    ack_policy: AckPolicy::Explicit,
+                    ack_wait: self.ack_wait,
+                    max_deliver: i64::from(self.max_deliver.get()),
+                    max_ack_pending: self.max_ack_pending.get(),
+                    filter_subjects,
+                    ..pull::Config::default()
+                },
+            )
+            .await?;
+
+        Ok(consumer)
+    }
+}
+
+impl Default for NatsConsumerOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use appletheia_application::{ConsumerError, Delivery};
+use async_nats::jetstream::AckKind;
+use async_nats::jetstream::message::Acker;
+
+pub struct NatsDelivery<M> {
+    acker: Option<Acker>,
+    message: M,
+    nak_delay: Duration,
+}
+
+impl<M> NatsDelivery<M> {
+    pub(crate) fn new(acker: Acker, message: M, nak_delay: Duration) -> Self {
+        Self {
+            acker: Some(acker),
+            message,
+            nak_delay,
+        }
+    }
+}
+
+impl<M> Delivery<M> for NatsDelivery<M>
+where
+    M: Send,
+{
+    fn message(&self) -> &M {
+        &self.message
+    }
+
+    async fn ack(&mut self) -> Result<(), ConsumerError> {
+        if let Some(acker) = self.acker.take() {
+            acker.double_ack().await.map_err(ConsumerError::Ack)?;
+        }
+        Ok(())
+    }
+
+    async fn nack(&mut self) -> Result<(), ConsumerError> {
+        if let Some(acker) = self.acker.take() {
+            acker
+                .ack_with(AckKind::Nak(Some(self.nak_delay)))
+                .await
+                .map_err(ConsumerError::Nack)?;
+        }
+        Ok(())
+    }
+}
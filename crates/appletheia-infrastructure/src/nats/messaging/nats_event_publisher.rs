@@ -0,0 +1,73 @@
+use appletheia_application::event::EventEnvelope;
+use appletheia_application::messaging::{PublishResult, Publisher, PublisherError, TopicId};
+use appletheia_application::outbox::OrderingKey;
+use async_nats::HeaderMap;
+use async_nats::jetstream::Context;
+
+use super::nats_publish::{NatsMessage, publish_acknowledged};
+
+/// Publishes events to the JetStream stream of a topic.
+///
+/// The subject is `<topic>.<aggregate_type>.<event_name>`.
+#[derive(Clone)]
+pub struct NatsEventPublisher {
+    context: Context,
+    topic_id: TopicId,
+}
+
+impl NatsEventPublisher {
+    pub fn new(context: Context, topic_id: TopicId) -> Self {
+        Self { context, topic_id }
+    }
+
+    pub(crate) fn subject(topic_id: &TopicId, aggregate_type: &str, event_name: &str) -> String {
+        format!("{}.{aggregate_type}.{event_name}", topic_id.value())
+    }
+
+    fn build_message(&self, event: &EventEnvelope) -> Result<NatsMessage, PublisherError> {
+        let mut headers = HeaderMap::new();
+
+        headers.insert("event_sequence", event.event_sequence.to_string());
+        headers.insert("event_id", event.event_id.to_string());
+        headers.insert("aggregate_type", event.aggregate_type.to_string());
+        headers.insert("aggregate_id", event.aggregate_id.to_string());
+        headers.insert("aggregate_version", event.aggregate_version.to_string());
+        headers.insert("event_name", event.event_name.to_string());
+        headers.insert("occurred_at", event.occurred_at.to_string());
+        headers.insert("correlation_id", event.correlation_id.to_string());
+        headers.insert("causation_id", event.causation_id.to_string());
+        headers.insert(
+            "ordering_key",
+            OrderingKey::from((&event.aggregate_type, &event.aggregate_id)).to_string(),
+        );
+
+        let data = serde_json::to_vec(event)
+            .map_err(|source| PublisherError::Publish(Box::new(source)))?;
+
+        Ok(NatsMessage {
+            subject: Self::subject(
+                &self.topic_id,
+                event.aggregate_type.value(),
+                event.event_name.value(),
+            ),
+            message_id: event.event_id.to_string(),
+            headers,
+            data,
+        })
+    }
+}
+
+impl Publisher<EventEnvelope> for NatsEventPublisher {
+    async fn publish<'a, I>(&self, messages: I) -> Result<Vec<PublishResult>, PublisherError>
+    where
+        I: IntoIterator<Item = &'a EventEnvelope>,
+        EventEnvelope: 'a,
+    {
+        let nats_messages = messages
+            .into_iter()
+            .map(|event| self.build_message(event))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(publish_acknowledged(&self.context, nats_messages).await)
+    }
+}
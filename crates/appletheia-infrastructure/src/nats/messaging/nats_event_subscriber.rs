@@ -0,0 +1,83 @@
+use appletheia_application::ConsumerGroup;
+use appletheia_application::Subscriber;
+use appletheia_application::SubscriberError;
+use appletheia_application::event::{EventEnvelope, EventSelector};
+use appletheia_application::messaging::{Subscription, TopicId};
+use async_nats::jetstream::Context;
+
+use super::NatsConsumerOptions;
+use super::NatsEventPublisher;
+use super::nats_consumer::NatsConsumer;
+
+/// Consumes events through a durable JetStream consumer per consumer group.
+///
+/// Selectors become filter subjects `<topic>.<aggregate_type>.<event_name>` and
+/// `Subscription::All` filters nothing.
+pub struct NatsEventSubscriber {
+    context: Context,
+    consumer_options: NatsConsumerOptions,
+    topic_id: TopicId,
+}
+
+impl NatsEventSubscriber {
+    pub fn new(context: Context, consumer_options: NatsConsumerOptions, topic_id: TopicId) -> Self {
+        Self {
+            context,
+            consumer_options,
+            topic_id,
+        }
+    }
+
+    fn filter_subject_for_selector(topic_id: &TopicId, selector: &EventSelector) -> String {
+        NatsEventPublisher::subject(
+            topic_id,
+            selector.aggregate_type.value(),
+            selector.event_name.value(),
+        )
+    }
+}
+
+impl Subscriber<EventEnvelope> for NatsEventSubscriber {
+    type Consumer = NatsConsumer<EventEnvelope>;
+    type Selector = EventSelector;
+
+    async fn subscribe(
+        &self,
+        consumer_group: &ConsumerGroup,
+        subscription: Subscription<'_, Self::Selector>,
+    ) -> Result<Self::Consumer, SubscriberError> {
+        let filter_subjects = match subscription {
+            Subscription::All => Vec::new(),
+            Subscription::AnyOf([]) => {
+                return Err(SubscriberError::InvalidSubscription);
+            }
+            Subscription::AnyOf(selectors) => selectors
+                .iter()
+                .map(|selector| Self::filter_subject_for_selector(&self.topic_id, selector))
+                .collect(),
+            Subscription::One(selector) => {
+                vec![Self::filter_subject_for_selector(&self.topic_id, selector)]
+            }
+        };
+
+        let consumer = self
+            .consumer_options
+            .get_or_create_consumer(
+                &self.context,
+                &self.topic_id,
+                consumer_group,
+                filter_subjects,
+            )
+            .await
+            .map_err(SubscriberError::Subscribe)?;
+        let messages = consumer
+            .messages()
+            .await
+            .map_err(|error| SubscriberError::Subscribe(Box::new(error)))?;
+
+        Ok(NatsConsumer::new(
+            messages,
+            self.consumer_options.nak_delay(),
+        ))
+    }
+}
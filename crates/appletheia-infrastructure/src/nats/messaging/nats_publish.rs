@@ -0,0 +1,69 @@
+use appletheia_application::messaging::{PublishDispatchError, PublishResult};
+use async_nats::HeaderMap;
+use async_nats::jetstream::Context;
+use async_nats::jetstream::context::{Publish, PublishError, PublishErrorKind};
+
+/// Message prepared for a JetStream subject.
+pub(crate) struct NatsMessage {
+    pub(crate) subject: String,
+    pub(crate) message_id: String,
+    pub(crate) headers: HeaderMap,
+    pub(crate) data: Vec<u8>,
+}
+
+/// Publishes messages one by one and waits for every stream acknowledgement.
+///
+/// The message id is sent as `Nats-Msg-Id`, so a message republished by a relay within the
+/// stream's duplicate window is stored only once.
+pub(crate) async fn publish_acknowledged(
+    context: &Context,
+    messages: Vec<NatsMessage>,
+) -> Vec<PublishResult> {
+    let mut results = Vec::with_capacity(messages.len());
+
+    for (input_index, message) in messages.into_iter().enumerate() {
+        let publish = Publish::build()
+            .payload(message.data.into())
+            .headers(message.headers)
+            .message_id(&message.message_id);
+
+        let acknowledged = match context.send_publish(message.subject, publish).await {
+            Ok(ack_future) => ack_future.await,
+            Err(error) => Err(error),
+        };
+
+        match acknowledged {
+            Ok(ack) => results.push(PublishResult::Success {
+                input_index,
+                transport_message_id: Some(ack.sequence.to_string()),
+            }),
+            Err(error) => results.push(PublishResult::Failed {
+                input_index,
+                cause: dispatch_error(error),
+            }),
+        }
+    }
+
+    results
+}
+
+/// A missing stream is transient: streams are created when the first consumer subscribes.
+fn dispatch_error(error: PublishError) -> PublishDispatchError {
+    let code = match error.kind() {
+        PublishErrorKind::StreamNotFound => "stream_not_found",
+        PublishErrorKind::TimedOut => "timed_out",
+        PublishErrorKind::BrokenPipe => "broken_pipe",
+        PublishErrorKind::WrongLastMessageId => "wrong_last_message_id",
+        PublishErrorKind::WrongLastSequence => "wrong_last_sequence",
+        PublishErrorKind::Other => "publish_error",
+    }
+    .to_string();
+    let message = error.to_string();
+
+    match error.kind() {
+        PublishErrorKind::WrongLastMessageId | PublishErrorKind::WrongLastSequence => {
+            PublishDispatchError::Permanent { code, message }
+        }
+        _ => PublishDispatchError::Transient { code, message },
+    }
+}
@@ -3,8 +3,12 @@ pub mod authorization;
 pub mod command;
 pub mod event;
 pub mod http;
+pub mod leadership;
+pub mod messaging;
 pub mod outbox;
 pub mod projection;
+pub mod rate_limit;
+pub mod retention;
 pub mod saga;
 pub mod snapshot;
 
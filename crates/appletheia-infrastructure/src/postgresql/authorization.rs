@@ -1,5 +1,11 @@
+pub mod pg_authorized_aggregate_filter;
+pub mod pg_identifier;
+pub mod pg_identifier_error;
 pub mod pg_relationship_row;
 pub mod pg_relationship_row_error;
 pub mod pg_relationship_store;
 
+pub use pg_authorized_aggregate_filter::*;
+pub use pg_identifier::*;
+pub use pg_identifier_error::*;
 pub use pg_relationship_store::*;
@@ -0,0 +1,145 @@
+use appletheia_application::authorization::AggregateRef;
+use appletheia_domain::{Aggregate, AggregateId, AggregateType};
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use super::PgIdentifier;
+
+/// Pushes an authorized set of aggregates down into a read-model query.
+///
+/// Build it from the output of `AggregateAuthorizationFilter` and append it to
+/// the query of a PostgreSQL-backed query handler, either as an `= ANY(...)`
+/// predicate or as a join against the unnested identifiers. The read model is
+/// expected to hold rows of a single aggregate type, so only identifiers are
+/// bound and references to any other aggregate type are dropped. Column and
+/// alias names are `PgIdentifier`s because they are pushed into the SQL text.
+/// An empty set matches no rows.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PgAuthorizedAggregateFilter {
+    aggregate_ids: Vec<Uuid>,
+}
+
+impl PgAuthorizedAggregateFilter {
+    pub fn new(aggregate_type: AggregateType, aggregates: &[AggregateRef]) -> Self {
+        Self {
+            aggregate_ids: aggregates
+                .iter()
+                .filter(|aggregate| aggregate.aggregate_type.value() == aggregate_type.value())
+                .map(|aggregate| aggregate.aggregate_id.value())
+                .collect(),
+        }
+    }
+
+    pub fn from_ids<A: Aggregate>(ids: &[A::Id]) -> Self {
+        Self {
+            aggregate_ids: ids.iter().map(|id| id.value()).collect(),
+        }
+    }
+
+    pub fn aggregate_ids(&self) -> &[Uuid] {
+        &self.aggregate_ids
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.aggregate_ids.is_empty()
+    }
+
+    /// Appends `<column> = ANY($n::uuid[])`.
+    pub fn push_in(&self, query: &mut QueryBuilder<'_, Postgres>, column: &PgIdentifier) {
+        query.push(column.value());
+        query.push(" = ANY(");
+        query.push_bind(self.aggregate_ids.clone());
+        query.push("::uuid[])");
+    }
+
+    /// Appends `JOIN UNNEST($n::uuid[]) AS <alias>(aggregate_id) ON <alias>.aggregate_id = <column>`.
+    pub fn push_join(
+        &self,
+        query: &mut QueryBuilder<'_, Postgres>,
+        column: &PgIdentifier,
+        alias: &PgIdentifier,
+    ) {
+        query.push(" JOIN UNNEST(");
+        query.push_bind(self.aggregate_ids.clone());
+        query.push("::uuid[]) AS ");
+        query.push(alias.value());
+        query.push("(aggregate_id) ON ");
+        query.push(alias.value());
+        query.push(".aggregate_id = ");
+        query.push(column.value());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use appletheia_domain::AggregateType;
+    use sqlx::{Postgres, QueryBuilder};
+    use uuid::Uuid;
+
+    use appletheia_application::authorization::AggregateRef;
+    use appletheia_application::event::{AggregateIdValue, AggregateTypeOwned};
+
+    use super::PgAuthorizedAggregateFilter;
+    use crate::postgresql::authorization::PgIdentifier;
+
+    const DOCUMENT: AggregateType = AggregateType::new("document");
+
+    fn aggregate_ref(aggregate_type: &str, id: u128) -> AggregateRef {
+        let aggregate_type: AggregateTypeOwned = aggregate_type
+            .parse()
+            .expect("aggregate type should be valid");
+        AggregateRef::new(aggregate_type, AggregateIdValue::from(Uuid::from_u128(id)))
+    }
+
+    fn filter() -> PgAuthorizedAggregateFilter {
+        PgAuthorizedAggregateFilter::new(DOCUMENT, &[aggregate_ref("document", 1)])
+    }
+
+    fn identifier(value: &str) -> PgIdentifier {
+        value.parse().expect("identifier should be valid")
+    }
+
+    #[test]
+    fn new_keeps_only_aggregates_of_the_given_type() {
+        let filter = PgAuthorizedAggregateFilter::new(
+            DOCUMENT,
+            &[
+                aggregate_ref("document", 1),
+                aggregate_ref("folder", 2),
+                aggregate_ref("document", 3),
+            ],
+        );
+
+        assert_eq!(
+            filter.aggregate_ids(),
+            &[Uuid::from_u128(1), Uuid::from_u128(3)]
+        );
+    }
+
+    #[test]
+    fn push_in_appends_any_predicate() {
+        let mut query = QueryBuilder::<Postgres>::new("SELECT * FROM documents WHERE ");
+        filter().push_in(&mut query, &identifier("document_id"));
+
+        assert_eq!(
+            query.sql(),
+            "SELECT * FROM documents WHERE document_id = ANY($1::uuid[])"
+        );
+    }
+
+    #[test]
+    fn push_join_appends_unnest_join() {
+        let mut query = QueryBuilder::<Postgres>::new("SELECT d.* FROM documents d");
+        filter().push_join(
+            &mut query,
+            &identifier("d.document_id"),
+            &identifier("allowed"),
+        );
+
+        assert_eq!(
+            query.sql(),
+            "SELECT d.* FROM documents d JOIN UNNEST($1::uuid[]) AS allowed(aggregate_id) \
+             ON allowed.aggregate_id = d.document_id"
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use std::{fmt, fmt::Display, str::FromStr};
+
+use super::PgIdentifierError;
+
+/// An unquoted, optionally qualified SQL identifier such as `document_id` or `d.document_id`.
+///
+/// Identifiers are pushed into SQL text verbatim, so every dot-separated part must be a
+/// plain identifier: an ASCII letter or `_` followed by ASCII letters, digits or `_`, and
+/// at most `MAX_PART_LENGTH` bytes long.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PgIdentifier(String);
+
+impl PgIdentifier {
+    pub const MAX_PART_LENGTH: usize = 63;
+
+    pub fn new(value: String) -> Result<Self, PgIdentifierError> {
+        Self::validate(&value)?;
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    fn validate(value: &str) -> Result<(), PgIdentifierError> {
+        if value.is_empty() {
+            return Err(PgIdentifierError::Empty);
+        }
+        for part in value.split('.') {
+            let len = part.len();
+            if len > Self::MAX_PART_LENGTH {
+                return Err(PgIdentifierError::TooLong {
+                    len,
+                    max: Self::MAX_PART_LENGTH,
+                });
+            }
+            let mut bytes = part.bytes();
+            let starts_well = bytes
+                .next()
+                .is_some_and(|b| b.is_ascii_alphabetic() || b == b'_');
+            if !starts_well || !bytes.all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+                return Err(PgIdentifierError::InvalidFormat {
+                    value: value.to_owned(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PgIdentifier {
+    type Err = PgIdentifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::validate(s)?;
+        Ok(Self(s.to_owned()))
+    }
+}
+
+impl TryFrom<&str> for PgIdentifier {
+    type Error = PgIdentifierError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_str(value)
+    }
+}
+
+impl TryFrom<String> for PgIdentifier {
+    type Error = PgIdentifierError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl Display for PgIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PgIdentifier;
+    use crate::postgresql::authorization::PgIdentifierError;
+
+    #[test]
+    fn accepts_plain_and_qualified_identifiers() {
+        for value in [
+            "document_id",
+            "d.document_id",
+            "_Allowed1",
+            "public.documents.id",
+        ] {
+            let identifier: PgIdentifier = value.parse().expect("identifier should be valid");
+            assert_eq!(identifier.value(), value);
+        }
+    }
+
+    #[test]
+    fn rejects_identifiers_that_are_not_plain() {
+        for value in [
+            "document_id; DROP TABLE documents",
+            "\"quoted\"",
+            "1abc",
+            "d.",
+            ".id",
+            "a b",
+        ] {
+            assert!(matches!(
+                value.parse::<PgIdentifier>(),
+                Err(PgIdentifierError::InvalidFormat { .. })
+            ));
+        }
+        assert!(matches!(
+            "".parse::<PgIdentifier>(),
+            Err(PgIdentifierError::Empty)
+        ));
+        assert!(matches!(
+            "a".repeat(64).parse::<PgIdentifier>(),
+            Err(PgIdentifierError::TooLong { len: 64, max: 63 })
+        ));
+    }
+}
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PgIdentifierError {
+    #[error("identifier is empty")]
+    Empty,
+
+    #[error("identifier part is too long: {len} (max {max})")]
+    TooLong { len: usize, max: usize },
+
+    #[error("identifier must be dot-separated [A-Za-z_][A-Za-z0-9_]* parts, got {value}")]
+    InvalidFormat { value: String },
+}
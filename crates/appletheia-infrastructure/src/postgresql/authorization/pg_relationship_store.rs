@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use appletheia_application::authorization::{
     AggregateRef, RelationRefOwned, Relationship, RelationshipChange, RelationshipId,
-    RelationshipStore, RelationshipStoreError, RelationshipSubject,
+    RelationshipRevision, RelationshipStore, RelationshipStoreError, RelationshipSubject,
 };
 use appletheia_application::event::{AggregateIdValue, AggregateTypeOwned};
 use sqlx::{Postgres, QueryBuilder, Row};
@@ -12,6 +12,12 @@ use crate::postgresql::PgUnitOfWork;
 
 use super::pg_relationship_row::PgRelationshipRow;
 
+/// Stores relationships in PostgreSQL.
+///
+/// The committed revision lives in `relationship_revision` and is bumped by a deferred trigger
+/// while a transaction that changed relationships commits, so concurrent writers only contend
+/// for its row lock during commit. Until then the writing unit of work reads a revision of its
+/// own, drawn from `relationship_revision_seq`, which no other unit of work observes.
 pub struct PgRelationshipStore;
 
 impl PgRelationshipStore {
@@ -41,6 +47,7 @@ impl RelationshipStore for PgRelationshipStore {
         const CHUNK_SIZE: usize = 1000;
 
         let transaction = uow.transaction_mut();
+        let mut affected_rows: u64 = 0;
 
         let mut deduped: HashMap<Relationship, bool> = HashMap::new();
         for change in changes {
@@ -127,11 +134,12 @@ impl RelationshipStore for PgRelationshipStore {
                 "#,
             );
 
-            query
+            affected_rows += query
                 .build()
                 .execute(transaction.as_mut())
                 .await
-                .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?;
+                .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?
+                .rows_affected();
         }
 
         for chunk in upserts.chunks(CHUNK_SIZE) {
@@ -189,11 +197,27 @@ impl RelationshipStore for PgRelationshipStore {
 
             query.push(" ON CONFLICT DO NOTHING");
 
-            query
+            affected_rows += query
                 .build()
                 .execute(transaction.as_mut())
                 .await
-                .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?;
+                .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?
+                .rows_affected();
+        }
+
+        if affected_rows > 0 {
+            sqlx::query(
+                r#"
+                SELECT set_config(
+                    'appletheia.relationship_revision',
+                    nextval('relationship_revision_seq')::text,
+                    true
+                )
+                "#,
+            )
+            .execute(transaction.as_mut())
+            .await
+            .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?;
         }
 
         Ok(())
@@ -323,4 +347,104 @@ impl RelationshipStore for PgRelationshipStore {
 
         Ok(out)
     }
+
+    async fn read_subjects_by_aggregates(
+        &self,
+        uow: &mut PgUnitOfWork,
+        aggregates: &[AggregateRef],
+        relation: &RelationRefOwned,
+        subject_aggregate_type: Option<&AggregateTypeOwned>,
+    ) -> Result<HashMap<AggregateRef, Vec<RelationshipSubject>>, RelationshipStoreError> {
+        const CHUNK_SIZE: usize = 1000;
+
+        let mut out: HashMap<AggregateRef, Vec<RelationshipSubject>> = aggregates
+            .iter()
+            .map(|aggregate| (aggregate.clone(), Vec::new()))
+            .collect();
+
+        let transaction = uow.transaction_mut();
+        for chunk in aggregates.chunks(CHUNK_SIZE) {
+            let aggregate_types: Vec<&str> = chunk
+                .iter()
+                .map(|aggregate| aggregate.aggregate_type.value())
+                .collect();
+            let aggregate_ids: Vec<Uuid> = chunk
+                .iter()
+                .map(|aggregate| aggregate.aggregate_id.value())
+                .collect();
+
+            let mut query = QueryBuilder::<Postgres>::new(
+                r#"
+                SELECT
+                    r.id,
+                    r.aggregate_type,
+                    r.aggregate_id,
+                    r.relation,
+                    r.subject_aggregate_type,
+                    r.subject_aggregate_id,
+                    r.subject_relation,
+                    r.subject_is_wildcard
+                FROM relationships r
+                JOIN UNNEST(
+                "#,
+            );
+            query.push_bind(aggregate_types);
+            query.push("::text[], ");
+            query.push_bind(aggregate_ids);
+            query.push(
+                r#"::uuid[]) AS v(aggregate_type, aggregate_id)
+                  ON r.aggregate_type = v.aggregate_type
+                 AND r.aggregate_id = v.aggregate_id
+                WHERE r.relation =
+                "#,
+            );
+            query.push_bind(relation.relation_name.value());
+
+            if let Some(subject_aggregate_type) = subject_aggregate_type {
+                query.push(" AND r.subject_aggregate_type = ");
+                query.push_bind(subject_aggregate_type.value());
+            }
+
+            let rows: Vec<PgRelationshipRow> = query
+                .build_query_as()
+                .fetch_all(transaction.as_mut())
+                .await
+                .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?;
+
+            for row in rows {
+                let relationship = row
+                    .try_into_relationship()
+                    .map_err(|e| RelationshipStoreError::MappingFailed(Box::new(e)))?;
+                out.entry(relationship.aggregate)
+                    .or_default()
+                    .push(relationship.subject);
+            }
+        }
+
+        Ok(out)
+    }
+
+    async fn read_revision(
+        &self,
+        uow: &mut PgUnitOfWork,
+    ) -> Result<RelationshipRevision, RelationshipStoreError> {
+        let transaction = uow.transaction_mut();
+        let revision: Option<i64> = sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(
+                NULLIF(current_setting('appletheia.relationship_revision', true), '')::bigint,
+                (SELECT revision FROM relationship_revision WHERE id = true)
+            )
+            "#,
+        )
+        .fetch_one(transaction.as_mut())
+        .await
+        .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?;
+
+        match revision {
+            Some(revision) => RelationshipRevision::try_from(revision)
+                .map_err(|e| RelationshipStoreError::MappingFailed(Box::new(e))),
+            None => Ok(RelationshipRevision::initial()),
+        }
+    }
 }
@@ -9,6 +9,7 @@ use appletheia_domain::{Aggregate, AggregateId, Event, EventPayload};
 use chrono::{DateTime, Utc};
 use sqlx::{Postgres, QueryBuilder};
 
+use crate::postgresql::outbox::PgOutboxChannel;
 use crate::postgresql::unit_of_work::PgUnitOfWork;
 
 use super::{PgEventRow, PgEventRowError};
@@ -67,7 +68,7 @@ impl<A: Aggregate> EventWriter<A> for PgEventWriter<A> {
             let occurred_at: DateTime<Utc> = event.occurred_at().into();
 
             sep.push("(")
-                .push_bind(id)
+                .push_bind_unseparated(id)
                 .push_bind(A::TYPE.to_string())
                 .push_bind(aggregate_id)
                 .push_bind(version)
@@ -77,7 +78,7 @@ impl<A: Aggregate> EventWriter<A> for PgEventWriter<A> {
                 .push_bind(correlation_id)
                 .push_bind(causation_id)
                 .push_bind(&context_json)
-                .push(")");
+                .push_unseparated(")");
         }
         events_query.push(
             r#"
@@ -121,7 +122,7 @@ impl<A: Aggregate> EventWriter<A> for PgEventWriter<A> {
                 .map_err(|e: PgEventRowError| EventWriterError::Persistence(Box::new(e)))?;
 
             sep.push("(")
-                .push_bind(outbox_id)
+                .push_bind_unseparated(outbox_id)
                 .push_bind(event_envelope.event_sequence.value())
                 .push_bind(event_envelope.event_id.value())
                 .push_bind(event_envelope.aggregate_type.to_string())
@@ -133,7 +134,7 @@ impl<A: Aggregate> EventWriter<A> for PgEventWriter<A> {
                 .push_bind(event_envelope.correlation_id.value())
                 .push_bind(event_envelope.causation_id.value())
                 .push_bind(&context_json)
-                .push(")");
+                .push_unseparated(")");
         }
         outbox_query
             .build()
@@ -141,6 +142,11 @@ impl<A: Aggregate> EventWriter<A> for PgEventWriter<A> {
             .await
             .map_err(|e| EventWriterError::Persistence(Box::new(e)))?;
 
+        PgOutboxChannel::Event
+            .notify(uow)
+            .await
+            .map_err(|e| EventWriterError::Persistence(Box::new(e)))?;
+
         Ok(())
     }
 }
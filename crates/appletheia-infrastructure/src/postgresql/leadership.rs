@@ -0,0 +1,5 @@
+pub mod pg_leader_elector;
+pub mod pg_leadership_fence;
+
+pub use pg_leader_elector::PgLeaderElector;
+pub use pg_leadership_fence::PgLeadershipFence;
@@ -0,0 +1,107 @@
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+
+use appletheia_application::leadership::{
+    FencingToken, LeaderElector, LeaderElectorError, LeadershipKey, LeadershipLease,
+};
+use appletheia_application::outbox::OutboxRelayInstance;
+
+/// Leader election over the `leadership_leases` table.
+///
+/// Each statement runs on its own pooled connection and expiry is judged by the database
+/// clock, so leadership does not depend on a long-lived session or on host clocks agreeing.
+pub struct PgLeaderElector {
+    pool: PgPool,
+}
+
+impl PgLeaderElector {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl LeaderElector for PgLeaderElector {
+    async fn try_acquire(
+        &self,
+        key: &LeadershipKey,
+        holder: &OutboxRelayInstance,
+        lease_duration: Duration,
+    ) -> Result<Option<LeadershipLease>, LeaderElectorError> {
+        let acquired = sqlx::query_as::<_, (i64, DateTime<Utc>)>(
+            r#"
+            INSERT INTO leadership_leases (key, holder, fencing_token, acquired_at, expires_at)
+            VALUES ($1, $2, 1, now(), now() + $3 * interval '1 millisecond')
+            ON CONFLICT (key) DO UPDATE
+            SET holder = EXCLUDED.holder,
+                fencing_token = leadership_leases.fencing_token + 1,
+                acquired_at = EXCLUDED.acquired_at,
+                expires_at = EXCLUDED.expires_at
+            WHERE leadership_leases.expires_at <= now()
+            RETURNING fencing_token, expires_at
+            "#,
+        )
+        .bind(key.value())
+        .bind(holder.to_string())
+        .bind(lease_duration.num_milliseconds())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|error| LeaderElectorError::Persistence(Box::new(error)))?;
+
+        Ok(acquired.map(|(fencing_token, expires_at)| {
+            LeadershipLease::new(
+                key.clone(),
+                holder.clone(),
+                FencingToken::new(fencing_token),
+                expires_at,
+            )
+        }))
+    }
+
+    async fn renew(
+        &self,
+        lease: &LeadershipLease,
+        lease_duration: Duration,
+    ) -> Result<Option<LeadershipLease>, LeaderElectorError> {
+        let expires_at = sqlx::query_scalar::<_, DateTime<Utc>>(
+            r#"
+            UPDATE leadership_leases
+            SET expires_at = now() + $4 * interval '1 millisecond'
+            WHERE key = $1
+              AND holder = $2
+              AND fencing_token = $3
+              AND expires_at > now()
+            RETURNING expires_at
+            "#,
+        )
+        .bind(lease.key().value())
+        .bind(lease.holder().to_string())
+        .bind(lease.fencing_token().value())
+        .bind(lease_duration.num_milliseconds())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|error| LeaderElectorError::Persistence(Box::new(error)))?;
+
+        Ok(expires_at.map(|expires_at| lease.clone().with_expires_at(expires_at)))
+    }
+
+    async fn release(&self, lease: &LeadershipLease) -> Result<(), LeaderElectorError> {
+        sqlx::query(
+            r#"
+            UPDATE leadership_leases
+            SET expires_at = now()
+            WHERE key = $1
+              AND holder = $2
+              AND fencing_token = $3
+              AND expires_at > now()
+            "#,
+        )
+        .bind(lease.key().value())
+        .bind(lease.holder().to_string())
+        .bind(lease.fencing_token().value())
+        .execute(&self.pool)
+        .await
+        .map_err(|error| LeaderElectorError::Persistence(Box::new(error)))?;
+
+        Ok(())
+    }
+}
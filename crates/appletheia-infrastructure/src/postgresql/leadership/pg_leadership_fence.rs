@@ -0,0 +1,60 @@
+use appletheia_application::leadership::{
+    FencingToken, LeaderElectorError, LeadershipFence, LeadershipKey,
+};
+
+use crate::postgresql::unit_of_work::PgUnitOfWork;
+
+/// Fences writes against the `leadership_leases` row of a `PgLeaderElector`.
+///
+/// The check share-locks the lease row, so a standby's takeover waits for the checking
+/// transaction to end and a former leader's writes cannot commit after it.
+pub struct PgLeadershipFence;
+
+impl PgLeadershipFence {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for PgLeadershipFence {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LeadershipFence for PgLeadershipFence {
+    type Uow = PgUnitOfWork;
+
+    async fn check(
+        &self,
+        uow: &mut Self::Uow,
+        key: &LeadershipKey,
+        fencing_token: FencingToken,
+    ) -> Result<(), LeaderElectorError> {
+        let transaction = uow.transaction_mut();
+
+        let held = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT TRUE
+            FROM leadership_leases
+            WHERE key = $1
+              AND fencing_token = $2
+              AND expires_at > now()
+            FOR SHARE
+            "#,
+        )
+        .bind(key.value())
+        .bind(fencing_token.value())
+        .fetch_optional(transaction.as_mut())
+        .await
+        .map_err(|error| LeaderElectorError::Persistence(Box::new(error)))?;
+
+        match held {
+            Some(_) => Ok(()),
+            None => Err(LeaderElectorError::Fenced {
+                key: key.clone(),
+                fencing_token,
+            }),
+        }
+    }
+}
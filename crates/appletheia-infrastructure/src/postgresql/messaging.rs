@@ -0,0 +1,15 @@
+pub mod pg_message_queue_config;
+pub mod pg_message_queue_consumer;
+pub mod pg_message_queue_delivery;
+pub mod pg_message_queue_message;
+pub mod pg_message_queue_publisher;
+pub mod pg_message_queue_selector;
+pub mod pg_message_queue_subscriber;
+
+pub use pg_message_queue_config::PgMessageQueueConfig;
+pub use pg_message_queue_consumer::PgMessageQueueConsumer;
+pub use pg_message_queue_delivery::PgMessageQueueDelivery;
+pub use pg_message_queue_message::PgMessageQueueMessage;
+pub use pg_message_queue_publisher::PgMessageQueuePublisher;
+pub use pg_message_queue_selector::PgMessageQueueSelector;
+pub use pg_message_queue_subscriber::PgMessageQueueSubscriber;
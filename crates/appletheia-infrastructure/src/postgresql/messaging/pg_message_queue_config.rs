@@ -0,0 +1,68 @@
+use std::num::NonZeroU32;
+
+use chrono::Duration;
+
+use appletheia_application::outbox::{
+    OutboxMaxAttempts, OutboxRetryDelay, OutboxRetryOptions, OutboxRetrySchedule,
+};
+
+/// Delivery settings of PostgreSQL message queue consumers.
+///
+/// Nack backoff and dead-lettering reuse the outbox retry vocabulary: a delivery is
+/// dead-lettered once it has been attempted `max_attempts` times.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PgMessageQueueConfig {
+    visibility_timeout: Duration,
+    poll_interval: Duration,
+    retry_options: OutboxRetryOptions,
+}
+
+impl PgMessageQueueConfig {
+    pub fn new() -> Self {
+        Self {
+            visibility_timeout: Duration::seconds(30),
+            poll_interval: Duration::milliseconds(500),
+            retry_options: OutboxRetryOptions {
+                schedule: OutboxRetrySchedule::Fixed(OutboxRetryDelay::new(Duration::seconds(10))),
+                max_attempts: OutboxMaxAttempts::new(
+                    NonZeroU32::new(5).expect("5 should be non-zero"),
+                ),
+            },
+        }
+    }
+
+    /// How long a leased delivery stays invisible before it is redelivered unsettled.
+    pub fn with_visibility_timeout(mut self, visibility_timeout: Duration) -> Self {
+        self.visibility_timeout = visibility_timeout;
+        self
+    }
+
+    /// How long an idle consumer waits before looking for deliveries again.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn with_retry_options(mut self, retry_options: OutboxRetryOptions) -> Self {
+        self.retry_options = retry_options;
+        self
+    }
+
+    pub fn visibility_timeout(&self) -> Duration {
+        self.visibility_timeout
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    pub fn retry_options(&self) -> &OutboxRetryOptions {
+        &self.retry_options
+    }
+}
+
+impl Default for PgMessageQueueConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::PgPool;
+use tokio::time::sleep;
+use uuid::Uuid;
+
+use appletheia_application::messaging::{
+    Consumer, ConsumerError, ConsumerGroup, UndecodableMessage,
+};
+use appletheia_application::outbox::OutboxAttemptCount;
+
+use super::{PgMessageQueueConfig, PgMessageQueueDelivery, PgMessageQueueMessage};
+
+/// Leases deliveries of one consumer group with `FOR UPDATE SKIP LOCKED`.
+///
+/// A delivery is skipped while an earlier delivery with the same ordering key is still
+/// pending, leased or backing off. A lease that is neither acked nor nacked becomes visible
+/// again once the visibility timeout has passed, or is dead-lettered if that was its last
+/// allowed attempt. A payload that does not decode into `M` is dead-lettered right away and
+/// surfaced as `ConsumerError::Undecodable`.
+pub struct PgMessageQueueConsumer<M> {
+    pool: PgPool,
+    consumer_group: ConsumerGroup,
+    config: PgMessageQueueConfig,
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<M: PgMessageQueueMessage> PgMessageQueueConsumer<M> {
+    pub(crate) fn new(
+        pool: PgPool,
+        consumer_group: ConsumerGroup,
+        config: PgMessageQueueConfig,
+    ) -> Self {
+        Self {
+            pool,
+            consumer_group,
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Dead-letters deliveries whose lease expired on their last allowed attempt.
+    async fn dead_letter_exhausted(&self, now: DateTime<Utc>) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            WITH exhausted AS (
+                SELECT consumer_group, message_id
+                FROM message_queue_deliveries
+                WHERE consumer_group = $1
+                  AND visible_after <= $2
+                  AND attempt_count >= $3
+                FOR UPDATE SKIP LOCKED
+            ),
+            removed AS (
+                DELETE FROM message_queue_deliveries d
+                 USING exhausted
+                 WHERE d.consumer_group = exhausted.consumer_group
+                   AND d.message_id = exhausted.message_id
+                RETURNING d.consumer_group, d.message_id, d.ordering_key, d.attempt_count
+            )
+            INSERT INTO message_queue_dead_letters (
+                consumer_group,
+                message_id,
+                ordering_key,
+                attempt_count
+            )
+            SELECT consumer_group, message_id, ordering_key, attempt_count
+            FROM removed
+            ON CONFLICT (consumer_group, message_id) DO UPDATE
+               SET attempt_count = EXCLUDED.attempt_count,
+                   dead_lettered_at = now()
+            "#,
+        )
+        .bind(self.consumer_group.value())
+        .bind(now)
+        .bind(self.max_attempts())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn lease_next(&self) -> Result<Option<PgMessageQueueDelivery<M>>, ConsumerError> {
+        let now = Utc::now();
+        let lease_token = Uuid::now_v7();
+
+        self.dead_letter_exhausted(now)
+            .await
+            .map_err(|error| ConsumerError::Next(Box::new(error)))?;
+
+        let mut transaction = self
+            .pool
+            .begin()
+            .await
+            .map_err(|error| ConsumerError::Next(Box::new(error)))?;
+
+        let leased = sqlx::query_as::<_, (i64, i64, Value, Value)>(
+            r#"
+            WITH next AS (
+                SELECT d.consumer_group, d.message_id
+                FROM message_queue_deliveries d
+                WHERE d.consumer_group = $1
+                  AND d.visible_after <= $2
+                  AND d.attempt_count < $5
+                  AND (
+                    d.ordering_key IS NULL
+                    OR NOT EXISTS (
+                      SELECT 1
+                      FROM message_queue_deliveries d2
+                      WHERE d2.consumer_group = d.consumer_group
+                        AND d2.ordering_key = d.ordering_key
+                        AND d2.message_id < d.message_id
+                    )
+                  )
+                ORDER BY d.message_id ASC
+                LIMIT 1
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE message_queue_deliveries d
+               SET attempt_count = d.attempt_count + 1,
+                   visible_after = $3,
+                   lease_token = $4
+              FROM next, message_queue_messages m
+             WHERE d.consumer_group = next.consumer_group
+               AND d.message_id = next.message_id
+               AND m.id = d.message_id
+            RETURNING d.message_id, d.attempt_count, m.attributes, m.payload
+            "#,
+        )
+        .bind(self.consumer_group.value())
+        .bind(now)
+        .bind(now + self.config.visibility_timeout())
+        .bind(lease_token)
+        .bind(self.max_attempts())
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| ConsumerError::Next(Box::new(error)))?;
+
+        let Some((message_id, attempt_count, attributes, payload)) = leased else {
+            return Ok(None);
+        };
+
+        let attempt_count = OutboxAttemptCount::try_from(attempt_count)
+            .map_err(|error| ConsumerError::Next(Box::new(error)))?;
+        let message = match serde_json::from_value::<M>(payload.clone()) {
+            Ok(message) => message,
+            Err(error) => {
+                // An undecodable payload can never succeed, so it goes straight to the dead
+                // letters instead of holding back its ordering key until its attempts run out.
+                PgMessageQueueDelivery::<M>::dead_letter_lease(
+                    &mut *transaction,
+                    &self.consumer_group,
+                    message_id,
+                    lease_token,
+                )
+                .await
+                .map_err(|error| ConsumerError::Next(Box::new(error)))?;
+                transaction
+                    .commit()
+                    .await
+                    .map_err(|error| ConsumerError::Next(Box::new(error)))?;
+
+                return Err(ConsumerError::Undecodable(UndecodableMessage {
+                    transport_message_id: Some(message_id.to_string()),
+                    attributes: Self::string_attributes(attributes),
+                    data: payload.to_string().into_bytes(),
+                    reason: error.to_string(),
+                }));
+            }
+        };
+
+        transaction
+            .commit()
+            .await
+            .map_err(|error| ConsumerError::Next(Box::new(error)))?;
+
+        Ok(Some(PgMessageQueueDelivery::new(
+            self.pool.clone(),
+            self.consumer_group.clone(),
+            message_id,
+            attempt_count,
+            lease_token,
+            message,
+            self.config.clone(),
+        )))
+    }
+
+    fn max_attempts(&self) -> i64 {
+        i64::from(self.config.retry_options().max_attempts.value().get())
+    }
+
+    fn string_attributes(attributes: Value) -> HashMap<String, String> {
+        let Value::Object(attributes) = attributes else {
+            return HashMap::new();
+        };
+
+        attributes
+            .into_iter()
+            .map(|(name, value)| match value {
+                Value::String(value) => (name, value),
+                value => (name, value.to_string()),
+            })
+            .collect()
+    }
+}
+
+impl<M: PgMessageQueueMessage> Consumer<M> for PgMessageQueueConsumer<M> {
+    type Delivery = PgMessageQueueDelivery<M>;
+
+    async fn next(&mut self) -> Result<Self::Delivery, ConsumerError> {
+        loop {
+            if let Some(delivery) = self.lease_next().await? {
+                return Ok(delivery);
+            }
+
+            let poll_interval = self
+                .config
+                .poll_interval()
+                .to_std()
+                .unwrap_or_else(|_| StdDuration::from_secs(0));
+            sleep(poll_interval).await;
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use chrono::Utc;
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use appletheia_application::messaging::{ConsumerError, ConsumerGroup, Delivery};
+use appletheia_application::outbox::OutboxAttemptCount;
+
+use super::PgMessageQueueConfig;
+
+/// Leased delivery of the PostgreSQL message queue.
+///
+/// Settling is guarded by the lease token, so a delivery whose lease already expired and was
+/// taken over by another consumer is left to that consumer.
+pub struct PgMessageQueueDelivery<M> {
+    pool: PgPool,
+    consumer_group: ConsumerGroup,
+    message_id: i64,
+    attempt_count: OutboxAttemptCount,
+    lease_token: Uuid,
+    message: M,
+    config: PgMessageQueueConfig,
+    settled: bool,
+}
+
+impl<M> PgMessageQueueDelivery<M> {
+    pub(crate) fn new(
+        pool: PgPool,
+        consumer_group: ConsumerGroup,
+        message_id: i64,
+        attempt_count: OutboxAttemptCount,
+        lease_token: Uuid,
+        message: M,
+        config: PgMessageQueueConfig,
+    ) -> Self {
+        Self {
+            pool,
+            consumer_group,
+            message_id,
+            attempt_count,
+            lease_token,
+            message,
+            config,
+            settled: false,
+        }
+    }
+
+    pub fn message_id(&self) -> i64 {
+        self.message_id
+    }
+
+    pub fn attempt_count(&self) -> OutboxAttemptCount {
+        self.attempt_count
+    }
+
+    /// Moves a leased delivery to the dead letters, keeping its message for inspection.
+    pub(super) async fn dead_letter_lease<'e, E: PgExecutor<'e>>(
+        executor: E,
+        consumer_group: &ConsumerGroup,
+        message_id: i64,
+        lease_token: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            WITH removed AS (
+                DELETE FROM message_queue_deliveries
+                 WHERE consumer_group = $1
+                   AND message_id = $2
+                   AND lease_token = $3
+                RETURNING consumer_group, message_id, ordering_key, attempt_count
+            )
+            INSERT INTO message_queue_dead_letters (
+                consumer_group,
+                message_id,
+                ordering_key,
+                attempt_count
+            )
+            SELECT consumer_group, message_id, ordering_key, attempt_count
+            FROM removed
+            ON CONFLICT (consumer_group, message_id) DO UPDATE
+               SET attempt_count = EXCLUDED.attempt_count,
+                   dead_lettered_at = now()
+            "#,
+        )
+        .bind(consumer_group.value())
+        .bind(message_id)
+        .bind(lease_token)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn dead_letter(&self) -> Result<(), sqlx::Error> {
+        Self::dead_letter_lease(
+            &self.pool,
+            &self.consumer_group,
+            self.message_id,
+            self.lease_token,
+        )
+        .await
+    }
+
+    /// Removes the delivery and, once no consumer group still references it, its message.
+    ///
+    /// The message row is locked first so that consumer groups acking the same message
+    /// concurrently see each other's deletes. `FOR NO KEY UPDATE` still lets dead-lettering
+    /// insert rows that reference the message.
+    async fn remove_delivery(&self) -> Result<(), sqlx::Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        sqlx::query("SELECT 1 FROM message_queue_messages WHERE id = $1 FOR NO KEY UPDATE")
+            .bind(self.message_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let removed = sqlx::query(
+            r#"
+            DELETE FROM message_queue_deliveries
+             WHERE consumer_group = $1
+               AND message_id = $2
+               AND lease_token = $3
+            "#,
+        )
+        .bind(self.consumer_group.value())
+        .bind(self.message_id)
+        .bind(self.lease_token)
+        .execute(&mut *transaction)
+        .await?;
+
+        if removed.rows_affected() > 0 {
+            sqlx::query(
+                r#"
+                DELETE FROM message_queue_messages m
+                 WHERE m.id = $1
+                   AND NOT EXISTS (
+                     SELECT 1
+                     FROM message_queue_deliveries d
+                     WHERE d.message_id = m.id
+                   )
+                   AND NOT EXISTS (
+                     SELECT 1
+                     FROM message_queue_dead_letters dl
+                     WHERE dl.message_id = m.id
+                   )
+                "#,
+            )
+            .bind(self.message_id)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await
+    }
+
+    async fn schedule_retry(&self) -> Result<(), sqlx::Error> {
+        let retry_delay = self
+            .config
+            .retry_options()
+            .schedule
+            .delay_for(self.attempt_count);
+
+        sqlx::query(
+            r#"
+            UPDATE message_queue_deliveries
+               SET visible_after = $4,
+                   lease_token = NULL
+             WHERE consumer_group = $1
+               AND message_id = $2
+               AND lease_token = $3
+            "#,
+        )
+        .bind(self.consumer_group.value())
+        .bind(self.message_id)
+        .bind(self.lease_token)
+        .bind(Utc::now() + retry_delay.value())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl<M: Send> Delivery<M> for PgMessageQueueDelivery<M> {
+    fn message(&self) -> &M {
+        &self.message
+    }
+
+    async fn ack(&mut self) -> Result<(), ConsumerError> {
+        if self.settled {
+            return Ok(());
+        }
+
+        self.remove_delivery()
+            .await
+            .map_err(|error| ConsumerError::Ack(Box::new(error)))?;
+
+        self.settled = true;
+        Ok(())
+    }
+
+    async fn nack(&mut self) -> Result<(), ConsumerError> {
+        if self.settled {
+            return Ok(());
+        }
+
+        let max_attempts = self.config.retry_options().max_attempts.value().get() as i64;
+        let result = if self.attempt_count.value() >= max_attempts {
+            self.dead_letter().await
+        } else {
+            self.schedule_retry().await
+        };
+        result.map_err(|error| ConsumerError::Nack(Box::new(error)))?;
+
+        self.settled = true;
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+
+use appletheia_application::event::EventEnvelope;
+use appletheia_application::outbox::OrderingKey;
+use appletheia_application::outbox::command::CommandEnvelope;
+
+/// Message that can be stored in the PostgreSQL message queue.
+///
+/// `attributes` must be a JSON object; subscription filters select messages by attribute
+/// containment, the same attributes the Pub/Sub publishers attach.
+pub trait PgMessageQueueMessage: Serialize + DeserializeOwned + Send + Sync + 'static {
+    fn ordering_key(&self) -> Option<OrderingKey>;
+
+    fn attributes(&self) -> Value;
+}
+
+impl PgMessageQueueMessage for EventEnvelope {
+    fn ordering_key(&self) -> Option<OrderingKey> {
+        Some(OrderingKey::from((
+            &self.aggregate_type,
+            &self.aggregate_id,
+        )))
+    }
+
+    fn attributes(&self) -> Value {
+        json!({
+            "aggregate_type": self.aggregate_type.value(),
+            "event_name": self.event_name.value(),
+        })
+    }
+}
+
+impl PgMessageQueueMessage for CommandEnvelope {
+    fn ordering_key(&self) -> Option<OrderingKey> {
+        Some(OrderingKey::from(self.correlation_id))
+    }
+
+    fn attributes(&self) -> Value {
+        json!({
+            "command_name": self.command_name.value(),
+        })
+    }
+}
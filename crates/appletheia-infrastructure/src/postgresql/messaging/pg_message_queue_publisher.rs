@@ -0,0 +1,110 @@
+use std::marker::PhantomData;
+
+use chrono::Utc;
+use sqlx::PgPool;
+
+use appletheia_application::messaging::{
+    PublishDispatchError, PublishResult, Publisher, PublisherError, TopicId,
+};
+
+use super::PgMessageQueueMessage;
+
+/// Publishes messages into the PostgreSQL message queue.
+///
+/// A message is fanned out to a delivery row for every consumer group of the topic whose
+/// filter accepts it; messages no consumer group receives are not stored.
+pub struct PgMessageQueuePublisher<M> {
+    pool: PgPool,
+    topic: TopicId,
+    _marker: PhantomData<fn(M)>,
+}
+
+impl<M: PgMessageQueueMessage> PgMessageQueuePublisher<M> {
+    pub fn new(pool: PgPool, topic: TopicId) -> Self {
+        Self {
+            pool,
+            topic,
+            _marker: PhantomData,
+        }
+    }
+
+    async fn publish_one(&self, message: &M) -> Result<Option<i64>, PublishDispatchError> {
+        let payload =
+            serde_json::to_value(message).map_err(|error| PublishDispatchError::Permanent {
+                code: "serialization_failed".to_string(),
+                message: error.to_string(),
+            })?;
+
+        let message_ids = sqlx::query_scalar::<_, i64>(
+            r#"
+            WITH targets AS (
+                SELECT s.consumer_group
+                FROM message_queue_subscriptions s
+                WHERE s.topic = $1
+                  AND (
+                    s.filter IS NULL
+                    OR EXISTS (
+                      SELECT 1
+                      FROM jsonb_array_elements(s.filter) f
+                      WHERE $3::jsonb @> f
+                    )
+                  )
+            ),
+            inserted AS (
+                INSERT INTO message_queue_messages (topic, ordering_key, attributes, payload)
+                SELECT $1, $2, $3, $4
+                WHERE EXISTS (SELECT 1 FROM targets)
+                RETURNING id, ordering_key
+            )
+            INSERT INTO message_queue_deliveries (
+                consumer_group,
+                message_id,
+                ordering_key,
+                visible_after
+            )
+            SELECT t.consumer_group, i.id, i.ordering_key, $5
+            FROM targets t
+            CROSS JOIN inserted i
+            RETURNING message_id
+            "#,
+        )
+        .bind(self.topic.value())
+        .bind(message.ordering_key().map(|key| key.to_string()))
+        .bind(message.attributes())
+        .bind(payload)
+        .bind(Utc::now())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|error| PublishDispatchError::Transient {
+            code: error
+                .as_database_error()
+                .and_then(|db_error| db_error.code().map(|code| code.into_owned()))
+                .unwrap_or_else(|| "database_error".to_string()),
+            message: error.to_string(),
+        })?;
+
+        Ok(message_ids.into_iter().next())
+    }
+}
+
+impl<M: PgMessageQueueMessage> Publisher<M> for PgMessageQueuePublisher<M> {
+    async fn publish<'a, I>(&self, messages: I) -> Result<Vec<PublishResult>, PublisherError>
+    where
+        I: IntoIterator<Item = &'a M>,
+        M: 'a,
+    {
+        let mut results = Vec::new();
+
+        for (input_index, message) in messages.into_iter().enumerate() {
+            match self.publish_one(message).await {
+                Ok(message_id) => results.push(PublishResult::Success {
+                    input_index,
+                    transport_message_id: message_id.map(|id| id.to_string()),
+                }),
+                Err(cause) => results.push(PublishResult::Failed { input_index, cause }),
+            }
+        }
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,28 @@
+use serde_json::{Value, json};
+
+use appletheia_application::command::CommandSelector;
+use appletheia_application::event::EventSelector;
+
+/// Selector that can be stored as a PostgreSQL message queue subscription filter.
+///
+/// A message matches when its attributes contain every attribute returned here.
+pub trait PgMessageQueueSelector: Send + Sync {
+    fn attributes(&self) -> Value;
+}
+
+impl PgMessageQueueSelector for EventSelector {
+    fn attributes(&self) -> Value {
+        json!({
+            "aggregate_type": self.aggregate_type.value(),
+            "event_name": self.event_name.value(),
+        })
+    }
+}
+
+impl PgMessageQueueSelector for CommandSelector {
+    fn attributes(&self) -> Value {
+        json!({
+            "command_name": self.command_name.value(),
+        })
+    }
+}
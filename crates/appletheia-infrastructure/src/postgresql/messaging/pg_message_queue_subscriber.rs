@@ -0,0 +1,85 @@
+use std::marker::PhantomData;
+
+use serde_json::Value;
+use sqlx::PgPool;
+
+use appletheia_application::messaging::{
+    ConsumerGroup, Selector, Subscriber, SubscriberError, Subscription, TopicId,
+};
+
+use super::{
+    PgMessageQueueConfig, PgMessageQueueConsumer, PgMessageQueueMessage, PgMessageQueueSelector,
+};
+
+/// Creates consumer groups of the PostgreSQL message queue.
+///
+/// As with Pub/Sub subscriptions, a consumer group keeps the topic and filter it was first
+/// created with and only receives messages published after that.
+pub struct PgMessageQueueSubscriber<M, S> {
+    pool: PgPool,
+    topic: TopicId,
+    config: PgMessageQueueConfig,
+    _marker: PhantomData<fn(M, S)>,
+}
+
+impl<M, S> PgMessageQueueSubscriber<M, S>
+where
+    M: PgMessageQueueMessage,
+    S: PgMessageQueueSelector,
+{
+    pub fn new(pool: PgPool, topic: TopicId, config: PgMessageQueueConfig) -> Self {
+        Self {
+            pool,
+            topic,
+            config,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<M, S> Subscriber<M> for PgMessageQueueSubscriber<M, S>
+where
+    M: PgMessageQueueMessage,
+    S: PgMessageQueueSelector + Selector<M>,
+{
+    type Consumer = PgMessageQueueConsumer<M>;
+    type Selector = S;
+
+    async fn subscribe(
+        &self,
+        consumer_group: &ConsumerGroup,
+        subscription: Subscription<'_, Self::Selector>,
+    ) -> Result<Self::Consumer, SubscriberError> {
+        let filter = match subscription {
+            Subscription::All => None,
+            Subscription::AnyOf([]) => return Err(SubscriberError::InvalidSubscription),
+            Subscription::AnyOf(selectors) => Some(Value::Array(
+                selectors
+                    .iter()
+                    .map(PgMessageQueueSelector::attributes)
+                    .collect(),
+            )),
+            Subscription::One(selector) => Some(Value::Array(vec![selector.attributes()])),
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO message_queue_subscriptions (consumer_group, topic, filter)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (consumer_group) DO NOTHING
+            "#,
+        )
+        .bind(consumer_group.value())
+        .bind(self.topic.value())
+        .bind(filter)
+        .execute(&self.pool)
+        .await
+        .map_err(|error| SubscriberError::Subscribe(Box::new(error)))?;
+
+        Ok(PgMessageQueueConsumer::new(
+            self.pool.clone(),
+            consumer_group.clone(),
+            self.config.clone(),
+        ))
+    }
+}
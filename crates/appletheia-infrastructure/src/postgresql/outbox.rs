@@ -1,2 +1,7 @@
 pub mod command;
 pub mod event;
+pub mod pg_outbox_channel;
+pub mod pg_outbox_wakeup_listener;
+
+pub use pg_outbox_channel::PgOutboxChannel;
+pub use pg_outbox_wakeup_listener::PgOutboxWakeupListener;
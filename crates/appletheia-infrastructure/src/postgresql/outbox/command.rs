@@ -1,11 +1,13 @@
 mod pg_command_outbox_dead_letter_row;
 mod pg_command_outbox_dead_letter_row_error;
+pub mod pg_command_outbox_dead_letter_service;
 pub mod pg_command_outbox_enqueuer;
 pub mod pg_command_outbox_fetcher;
 pub mod pg_command_outbox_row;
 pub mod pg_command_outbox_row_error;
 pub mod pg_command_outbox_writer;
 
+pub use pg_command_outbox_dead_letter_service::PgCommandOutboxDeadLetterService;
 pub use pg_command_outbox_enqueuer::PgCommandOutboxEnqueuer;
 pub use pg_command_outbox_fetcher::PgCommandOutboxFetcher;
 pub use pg_command_outbox_row::PgCommandOutboxRow;
@@ -19,6 +19,7 @@ pub struct PgCommandOutboxDeadLetterRow {
     pub correlation_id: Uuid,
     pub causation_id: Uuid,
     pub options: serde_json::Value,
+    pub trace_context: Option<serde_json::Value>,
     pub published_at: Option<DateTime<Utc>>,
     pub attempt_count: i64,
     pub next_attempt_after: DateTime<Utc>,
@@ -41,6 +42,7 @@ impl PgCommandOutboxDeadLetterRow {
             correlation_id: self.correlation_id,
             causation_id: self.causation_id,
             options: self.options,
+            trace_context: self.trace_context,
             published_at: self.published_at,
             attempt_count: self.attempt_count,
             next_attempt_after: self.next_attempt_after,
@@ -0,0 +1,328 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use appletheia_application::outbox::command::{
+    CommandOutbox, CommandOutboxDeadLetterFilter, CommandOutboxId,
+};
+use appletheia_application::outbox::{
+    OrderingKey, Outbox, OutboxBatchSize, OutboxDeadLetterDiscard, OutboxDeadLetterService,
+    OutboxDeadLetterServiceError, OutboxLifecycle, OutboxWriter, ProcessedOutboxCount,
+};
+
+use crate::postgresql::unit_of_work::PgUnitOfWork;
+
+use super::PgCommandOutboxWriter;
+use super::pg_command_outbox_dead_letter_row::PgCommandOutboxDeadLetterRow;
+
+const SELECT_DEAD_LETTERS: &str = r#"
+    SELECT
+        command_outbox_id,
+        command_sequence,
+        message_id,
+        command_name,
+        payload,
+        correlation_id,
+        causation_id,
+        options,
+        trace_context,
+        published_at,
+        attempt_count,
+        next_attempt_after,
+        lease_owner,
+        lease_until,
+        last_error,
+        dead_lettered_at
+    FROM command_dead_letters
+    WHERE TRUE
+"#;
+
+pub struct PgCommandOutboxDeadLetterService;
+
+impl PgCommandOutboxDeadLetterService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn push_filter(
+        query_builder: &mut QueryBuilder<'_, Postgres>,
+        filter: &CommandOutboxDeadLetterFilter,
+    ) {
+        if let Some(command_name) = &filter.command_name {
+            query_builder
+                .push(" AND command_name = ")
+                .push_bind(command_name.value().to_owned());
+        }
+        if let Some(dead_lettered_from) = filter.dead_lettered_from {
+            query_builder
+                .push(" AND dead_lettered_at >= ")
+                .push_bind(DateTime::<Utc>::from(dead_lettered_from));
+        }
+        if let Some(dead_lettered_until) = filter.dead_lettered_until {
+            query_builder
+                .push(" AND dead_lettered_at <= ")
+                .push_bind(DateTime::<Utc>::from(dead_lettered_until));
+        }
+        if let Some(error_contains) = &filter.error_contains {
+            query_builder
+                .push(" AND strpos(lower(last_error::text), lower(")
+                .push_bind(error_contains.clone())
+                .push(")) > 0");
+        }
+    }
+
+    async fn fetch(
+        uow: &mut PgUnitOfWork,
+        mut query_builder: QueryBuilder<'_, Postgres>,
+    ) -> Result<Vec<CommandOutbox>, OutboxDeadLetterServiceError> {
+        let transaction = uow.transaction_mut();
+
+        let rows = query_builder
+            .build_query_as::<PgCommandOutboxDeadLetterRow>()
+            .fetch_all(transaction.as_mut())
+            .await
+            .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+
+        rows.into_iter()
+            .map(PgCommandOutboxDeadLetterRow::try_into_outbox)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| OutboxDeadLetterServiceError::MappingFailed(Box::new(e)))
+    }
+
+    async fn fetch_for_update_by_ids(
+        uow: &mut PgUnitOfWork,
+        ids: &[CommandOutboxId],
+    ) -> Result<Vec<CommandOutbox>, OutboxDeadLetterServiceError> {
+        let ids = ids
+            .iter()
+            .map(CommandOutboxId::value)
+            .collect::<Vec<Uuid>>();
+
+        let mut query_builder = QueryBuilder::<Postgres>::new(SELECT_DEAD_LETTERS);
+        query_builder
+            .push(" AND command_outbox_id = ANY(")
+            .push_bind(ids)
+            .push(") ORDER BY dead_lettered_at ASC, command_outbox_id ASC FOR UPDATE");
+
+        Self::fetch(uow, query_builder).await
+    }
+
+    async fn redrive_outboxes(
+        uow: &mut PgUnitOfWork,
+        mut outboxes: Vec<CommandOutbox>,
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError> {
+        for outbox in &mut outboxes {
+            outbox.redrive()?;
+        }
+
+        PgCommandOutboxWriter::new()
+            .write_outbox(uow, &outboxes)
+            .await?;
+
+        Ok(ProcessedOutboxCount::from_usize_saturating(outboxes.len()))
+    }
+
+    async fn insert_discards(
+        uow: &mut PgUnitOfWork,
+        outboxes: &[CommandOutbox],
+        discard: &OutboxDeadLetterDiscard,
+    ) -> Result<(), OutboxDeadLetterServiceError> {
+        let discarded_by = serde_json::to_value(&discard.discarded_by)
+            .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+
+        let mut entries = Vec::with_capacity(outboxes.len());
+        for outbox in outboxes {
+            let message = serde_json::to_value(&outbox.command)
+                .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+            let last_error = outbox
+                .last_error
+                .as_ref()
+                .map(serde_json::to_value)
+                .transpose()
+                .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+            let dead_lettered_at = match outbox.lifecycle {
+                OutboxLifecycle::DeadLettered { dead_lettered_at } => {
+                    DateTime::<Utc>::from(dead_lettered_at)
+                }
+                OutboxLifecycle::Active => Utc::now(),
+            };
+            entries.push((outbox, message, last_error, dead_lettered_at));
+        }
+
+        let mut query_builder = QueryBuilder::<Postgres>::new(
+            r#"
+            INSERT INTO outbox_dead_letter_discards (
+                id,
+                outbox_kind,
+                outbox_id,
+                message_name,
+                message,
+                attempt_count,
+                last_error,
+                dead_lettered_at,
+                discarded_by,
+                reason
+            )
+            "#,
+        );
+        query_builder.push_values(
+            entries,
+            |mut builder, (outbox, message, last_error, dead_lettered_at)| {
+                builder
+                    .push_bind(Uuid::now_v7())
+                    .push_bind("command")
+                    .push_bind(outbox.id.value())
+                    .push_bind(outbox.command.command_name.value().to_owned())
+                    .push_bind(message)
+                    .push_bind(outbox.state.attempt_count().value())
+                    .push_bind(last_error)
+                    .push_bind(dead_lettered_at)
+                    .push_bind(discarded_by.clone())
+                    .push_bind(discard.reason.clone());
+            },
+        );
+
+        let transaction = uow.transaction_mut();
+
+        query_builder
+            .build()
+            .execute(transaction.as_mut())
+            .await
+            .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+
+        Ok(())
+    }
+}
+
+impl Default for PgCommandOutboxDeadLetterService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutboxDeadLetterService for PgCommandOutboxDeadLetterService {
+    type Uow = PgUnitOfWork;
+    type Outbox = CommandOutbox;
+    type Filter = CommandOutboxDeadLetterFilter;
+
+    async fn list(
+        &self,
+        uow: &mut Self::Uow,
+        filter: &Self::Filter,
+        limit: OutboxBatchSize,
+    ) -> Result<Vec<CommandOutbox>, OutboxDeadLetterServiceError> {
+        let mut query_builder = QueryBuilder::<Postgres>::new(SELECT_DEAD_LETTERS);
+        Self::push_filter(&mut query_builder, filter);
+        query_builder
+            .push(" ORDER BY dead_lettered_at ASC, command_outbox_id ASC LIMIT ")
+            .push_bind(limit.as_i64());
+
+        Self::fetch(uow, query_builder).await
+    }
+
+    async fn find(
+        &self,
+        uow: &mut Self::Uow,
+        id: CommandOutboxId,
+    ) -> Result<Option<CommandOutbox>, OutboxDeadLetterServiceError> {
+        let mut query_builder = QueryBuilder::<Postgres>::new(SELECT_DEAD_LETTERS);
+        query_builder
+            .push(" AND command_outbox_id = ")
+            .push_bind(id.value());
+
+        Ok(Self::fetch(uow, query_builder).await?.into_iter().next())
+    }
+
+    async fn redrive(
+        &self,
+        uow: &mut Self::Uow,
+        ids: &[CommandOutboxId],
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError> {
+        if ids.is_empty() {
+            return Ok(ProcessedOutboxCount::zero());
+        }
+
+        let outboxes = Self::fetch_for_update_by_ids(uow, ids).await?;
+        Self::redrive_outboxes(uow, outboxes).await
+    }
+
+    async fn redrive_matching(
+        &self,
+        uow: &mut Self::Uow,
+        filter: &Self::Filter,
+        limit: OutboxBatchSize,
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError> {
+        let mut query_builder = QueryBuilder::<Postgres>::new(SELECT_DEAD_LETTERS);
+        Self::push_filter(&mut query_builder, filter);
+        query_builder
+            .push(" ORDER BY dead_lettered_at ASC, command_outbox_id ASC LIMIT ")
+            .push_bind(limit.as_i64())
+            .push(" FOR UPDATE");
+
+        let outboxes = Self::fetch(uow, query_builder).await?;
+        Self::redrive_outboxes(uow, outboxes).await
+    }
+
+    async fn discard(
+        &self,
+        uow: &mut Self::Uow,
+        ids: &[CommandOutboxId],
+        discard: &OutboxDeadLetterDiscard,
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError> {
+        if ids.is_empty() {
+            return Ok(ProcessedOutboxCount::zero());
+        }
+
+        let outboxes = Self::fetch_for_update_by_ids(uow, ids).await?;
+        if outboxes.is_empty() {
+            return Ok(ProcessedOutboxCount::zero());
+        }
+
+        Self::insert_discards(uow, &outboxes, discard).await?;
+
+        let outbox_ids = outboxes
+            .iter()
+            .map(|outbox| outbox.id().value())
+            .collect::<Vec<Uuid>>();
+        let transaction = uow.transaction_mut();
+
+        sqlx::query("DELETE FROM command_dead_letters WHERE command_outbox_id = ANY($1)")
+            .bind(outbox_ids)
+            .execute(transaction.as_mut())
+            .await
+            .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+
+        Ok(ProcessedOutboxCount::from_usize_saturating(outboxes.len()))
+    }
+
+    async fn unblock_ordering_key(
+        &self,
+        uow: &mut Self::Uow,
+        ordering_key: &OrderingKey,
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError> {
+        // Command ordering keys are the correlation id; anything else cannot belong to a command
+        // dead letter.
+        let Ok(correlation_id) = Uuid::parse_str(ordering_key.as_str()) else {
+            return Ok(ProcessedOutboxCount::zero());
+        };
+
+        let transaction = uow.transaction_mut();
+
+        let result = sqlx::query(
+            r#"
+            UPDATE command_dead_letters
+               SET ordering_released_at = now()
+             WHERE ordering_released_at IS NULL
+               AND correlation_id = $1
+            "#,
+        )
+        .bind(correlation_id)
+        .execute(transaction.as_mut())
+        .await
+        .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+
+        Ok(ProcessedOutboxCount::from_usize_saturating(
+            result.rows_affected() as usize,
+        ))
+    }
+}
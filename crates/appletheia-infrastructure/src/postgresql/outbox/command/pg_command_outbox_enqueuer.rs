@@ -1,6 +1,7 @@
 use sqlx::{Postgres, QueryBuilder};
 use uuid::Uuid;
 
+use crate::postgresql::outbox::PgOutboxChannel;
 use crate::postgresql::unit_of_work::PgUnitOfWork;
 use appletheia_application::outbox::command::{
     CommandEnvelope, CommandOutboxEnqueueError, CommandOutboxEnqueuer,
@@ -43,7 +44,8 @@ impl CommandOutboxEnqueuer for PgCommandOutboxEnqueuer {
               payload,
               correlation_id,
               causation_id,
-              options
+              options,
+              trace_context
             ) VALUES
             "#,
         );
@@ -59,17 +61,24 @@ impl CommandOutboxEnqueuer for PgCommandOutboxEnqueuer {
                 let causation_id_value = command.causation_id.value();
                 let options_value = serde_json::to_value(&command.options)
                     .map_err(|source| CommandOutboxEnqueueError::Persistence(Box::new(source)))?;
+                let trace_context_value = command
+                    .trace_context
+                    .as_ref()
+                    .map(serde_json::to_value)
+                    .transpose()
+                    .map_err(|source| CommandOutboxEnqueueError::Persistence(Box::new(source)))?;
 
                 separated
                     .push("(")
-                    .push_bind(id_value)
+                    .push_bind_unseparated(id_value)
                     .push_bind(message_id_value)
                     .push_bind(command_name_value)
                     .push_bind(payload_value)
                     .push_bind(correlation_id_value)
                     .push_bind(causation_id_value)
                     .push_bind(options_value)
-                    .push(")");
+                    .push_bind(trace_context_value)
+                    .push_unseparated(")");
             }
         }
 
@@ -79,6 +88,11 @@ impl CommandOutboxEnqueuer for PgCommandOutboxEnqueuer {
             .await
             .map_err(|source| CommandOutboxEnqueueError::Persistence(Box::new(source)))?;
 
+        PgOutboxChannel::Command
+            .notify(uow)
+            .await
+            .map_err(|source| CommandOutboxEnqueueError::Persistence(Box::new(source)))?;
+
         Ok(())
     }
 }
@@ -1,19 +1,37 @@
 use chrono::Utc;
-use sqlx::Postgres;
+use sqlx::{Postgres, QueryBuilder};
 
 use appletheia_application::outbox::{
-    OutboxBatchSize, OutboxFetcher, OutboxFetcherError, command::CommandOutbox,
+    OutboxBatchSize, OutboxFetcher, OutboxFetcherError, OutboxOrderingMode, command::CommandOutbox,
 };
 
 use crate::postgresql::unit_of_work::PgUnitOfWork;
 
 use super::{PgCommandOutboxRow, PgCommandOutboxRowError};
 
-pub struct PgCommandOutboxFetcher;
+/// Fetches due commands in sequence order.
+///
+/// With `OutboxOrderingMode::Strict`, a command is held back while an earlier command with the
+/// same correlation id is unpublished, or sits in `command_dead_letters` without having its
+/// ordering key unblocked.
+pub struct PgCommandOutboxFetcher {
+    ordering_mode: OutboxOrderingMode,
+}
 
 impl PgCommandOutboxFetcher {
     pub fn new() -> Self {
-        Self
+        Self {
+            ordering_mode: OutboxOrderingMode::default(),
+        }
+    }
+
+    pub fn with_ordering_mode(mut self, ordering_mode: OutboxOrderingMode) -> Self {
+        self.ordering_mode = ordering_mode;
+        self
+    }
+
+    pub fn ordering_mode(&self) -> OutboxOrderingMode {
+        self.ordering_mode
     }
 }
 
@@ -34,9 +52,7 @@ impl OutboxFetcher for PgCommandOutboxFetcher {
     ) -> Result<Vec<CommandOutbox>, OutboxFetcherError> {
         let now = Utc::now();
 
-        let transaction = uow.transaction_mut();
-
-        let outbox_rows = sqlx::query_as::<Postgres, PgCommandOutboxRow>(
+        let mut query_builder = QueryBuilder::<Postgres>::new(
             r#"
             SELECT
                 id,
@@ -47,26 +63,56 @@ impl OutboxFetcher for PgCommandOutboxFetcher {
                 correlation_id,
                 causation_id,
                 options,
+                trace_context,
                 published_at,
                 attempt_count,
                 next_attempt_after,
                 lease_owner,
                 lease_until,
                 last_error
-            FROM command_outbox
+            FROM command_outbox co
             WHERE published_at IS NULL
-              AND next_attempt_after <= $1
-              AND (lease_owner IS NULL OR lease_until <= $1)
-            ORDER BY next_attempt_after ASC, command_sequence ASC
-            LIMIT $2
-            FOR UPDATE SKIP LOCKED
+              AND next_attempt_after <= "#,
+        );
+        query_builder
+            .push_bind(now)
+            .push(" AND (lease_owner IS NULL OR lease_until <= ")
+            .push_bind(now)
+            .push(")");
+
+        if self.ordering_mode == OutboxOrderingMode::Strict {
+            query_builder.push(
+                r#"
+              AND NOT EXISTS (
+                SELECT 1
+                FROM command_outbox co2
+                WHERE co2.published_at IS NULL
+                  AND co2.correlation_id = co.correlation_id
+                  AND co2.command_sequence < co.command_sequence
+              )
+              AND NOT EXISTS (
+                SELECT 1
+                FROM command_dead_letters dl
+                WHERE dl.ordering_released_at IS NULL
+                  AND dl.correlation_id = co.correlation_id
+                  AND dl.command_sequence < co.command_sequence
+              )
             "#,
-        )
-        .bind(now)
-        .bind(limit.as_i64())
-        .fetch_all(transaction.as_mut())
-        .await
-        .map_err(|e| OutboxFetcherError::Persistence(Box::new(e)))?;
+            );
+        }
+
+        query_builder
+            .push(" ORDER BY next_attempt_after ASC, command_sequence ASC LIMIT ")
+            .push_bind(limit.as_i64())
+            .push(" FOR UPDATE SKIP LOCKED");
+
+        let transaction = uow.transaction_mut();
+
+        let outbox_rows = query_builder
+            .build_query_as::<PgCommandOutboxRow>()
+            .fetch_all(transaction.as_mut())
+            .await
+            .map_err(|e| OutboxFetcherError::Persistence(Box::new(e)))?;
 
         if outbox_rows.is_empty() {
             return Ok(Vec::new());
@@ -102,6 +148,7 @@ impl OutboxFetcher for PgCommandOutboxFetcher {
                 correlation_id,
                 causation_id,
                 options,
+                trace_context,
                 published_at,
                 attempt_count,
                 next_attempt_after,
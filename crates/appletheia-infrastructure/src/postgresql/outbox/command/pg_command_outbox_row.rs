@@ -13,6 +13,7 @@ use appletheia_application::outbox::{
     command::{CommandOutbox, CommandOutboxId},
 };
 use appletheia_application::request_context::{CausationId, CorrelationId, MessageId};
+use appletheia_application::telemetry::TraceContext;
 
 use super::PgCommandOutboxRowError;
 
@@ -26,6 +27,7 @@ pub struct PgCommandOutboxRow {
     pub correlation_id: Uuid,
     pub causation_id: Uuid,
     pub options: serde_json::Value,
+    pub trace_context: Option<serde_json::Value>,
     pub published_at: Option<DateTime<Utc>>,
     pub attempt_count: i64,
     pub next_attempt_after: DateTime<Utc>,
@@ -49,6 +51,10 @@ impl PgCommandOutboxRow {
         let message_id = MessageId::from(self.message_id);
         let causation_id = CausationId::from(MessageId::from(self.causation_id));
         let options = serde_json::from_value::<CommandOptions>(self.options)?;
+        let trace_context = match self.trace_context {
+            Some(value) => Some(serde_json::from_value::<TraceContext>(value)?),
+            None => None,
+        };
 
         let command = CommandEnvelope {
             command_name,
@@ -57,6 +63,7 @@ impl PgCommandOutboxRow {
             message_id,
             causation_id,
             options,
+            trace_context,
         };
 
         let attempt_count = OutboxAttemptCount::try_from(self.attempt_count)?;
@@ -3,9 +3,11 @@ use sqlx::{Postgres, QueryBuilder};
 
 use appletheia_application::messaging::PublishDispatchError;
 use appletheia_application::outbox::{
-    OutboxLifecycle, OutboxWriter, OutboxWriterError, command::CommandOutbox,
+    OutboxLifecycle, OutboxNextAttemptAt, OutboxState, OutboxWriter, OutboxWriterError,
+    command::CommandOutbox,
 };
 
+use crate::postgresql::outbox::PgOutboxChannel;
 use crate::postgresql::unit_of_work::PgUnitOfWork;
 
 pub struct PgCommandOutboxWriter;
@@ -33,6 +35,28 @@ impl PgCommandOutboxWriter {
             .map_err(|source| OutboxWriterError::Persistence(Box::new(source)))
     }
 
+    fn serialize_trace_context(
+        outbox: &CommandOutbox,
+    ) -> Result<Option<serde_json::Value>, OutboxWriterError> {
+        outbox
+            .command
+            .trace_context
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|source| OutboxWriterError::Persistence(Box::new(source)))
+    }
+
+    fn has_due_pending(outboxes: &[&CommandOutbox]) -> bool {
+        let now = OutboxNextAttemptAt::now();
+        outboxes.iter().any(|outbox| {
+            matches!(
+                outbox.state,
+                OutboxState::Pending { next_attempt_after, .. } if next_attempt_after <= now
+            )
+        })
+    }
+
     async fn upsert_outbox_rows(
         uow: &mut PgUnitOfWork,
         outboxes: &[&CommandOutbox],
@@ -52,6 +76,7 @@ impl PgCommandOutboxWriter {
                 correlation_id,
                 causation_id,
                 options,
+                trace_context,
                 published_at,
                 attempt_count,
                 next_attempt_after,
@@ -68,6 +93,7 @@ impl PgCommandOutboxWriter {
                 let command = &outbox.command;
                 let last_error_value = Self::serialize_last_error(outbox)?;
                 let options_value = Self::serialize_options(outbox)?;
+                let trace_context_value = Self::serialize_trace_context(outbox)?;
                 let next_attempt_after_value = outbox
                     .state
                     .next_attempt_after()
@@ -76,7 +102,7 @@ impl PgCommandOutboxWriter {
 
                 separated
                     .push("(")
-                    .push_bind(outbox.id.value())
+                    .push_bind_unseparated(outbox.id.value())
                     .push_bind(outbox.sequence)
                     .push_bind(command.message_id.value())
                     .push_bind(command.command_name.value())
@@ -84,13 +110,14 @@ impl PgCommandOutboxWriter {
                     .push_bind(command.correlation_id.value())
                     .push_bind(command.causation_id.value())
                     .push_bind(options_value)
+                    .push_bind(trace_context_value)
                     .push_bind(outbox.state.published_at().map(DateTime::<Utc>::from))
                     .push_bind(outbox.state.attempt_count().value())
                     .push_bind(next_attempt_after_value)
                     .push_bind(outbox.state.lease_owner().map(ToString::to_string))
                     .push_bind(outbox.state.lease_until().map(DateTime::<Utc>::from))
                     .push_bind(last_error_value)
-                    .push(")");
+                    .push_unseparated(")");
             }
         }
 
@@ -132,6 +159,7 @@ impl PgCommandOutboxWriter {
                 correlation_id,
                 causation_id,
                 options,
+                trace_context,
                 published_at,
                 attempt_count,
                 next_attempt_after,
@@ -149,6 +177,7 @@ impl PgCommandOutboxWriter {
                 let command = &outbox.command;
                 let last_error_value = Self::serialize_last_error(outbox)?;
                 let options_value = Self::serialize_options(outbox)?;
+                let trace_context_value = Self::serialize_trace_context(outbox)?;
                 let dead_lettered_at_value = match outbox.lifecycle {
                     OutboxLifecycle::DeadLettered { dead_lettered_at } => {
                         DateTime::<Utc>::from(dead_lettered_at)
@@ -158,7 +187,7 @@ impl PgCommandOutboxWriter {
 
                 separated
                     .push("(")
-                    .push_bind(outbox.id.value())
+                    .push_bind_unseparated(outbox.id.value())
                     .push_bind(outbox.sequence)
                     .push_bind(command.message_id.value())
                     .push_bind(command.command_name.value())
@@ -166,6 +195,7 @@ impl PgCommandOutboxWriter {
                     .push_bind(command.correlation_id.value())
                     .push_bind(command.causation_id.value())
                     .push_bind(options_value)
+                    .push_bind(trace_context_value)
                     .push_bind(outbox.state.published_at().map(DateTime::<Utc>::from))
                     .push_bind(outbox.state.attempt_count().value())
                     .push_bind(outbox.state.next_attempt_after().map(DateTime::<Utc>::from))
@@ -173,7 +203,7 @@ impl PgCommandOutboxWriter {
                     .push_bind(outbox.state.lease_until().map(DateTime::<Utc>::from))
                     .push_bind(last_error_value)
                     .push_bind(dead_lettered_at_value)
-                    .push(")");
+                    .push_unseparated(")");
             }
         }
 
@@ -283,6 +313,13 @@ impl OutboxWriter for PgCommandOutboxWriter {
         Self::upsert_outbox_rows(uow, &active_outboxes).await?;
         Self::delete_dead_letters(uow, &active_outboxes).await?;
 
+        if Self::has_due_pending(&active_outboxes) {
+            PgOutboxChannel::Command
+                .notify(uow)
+                .await
+                .map_err(|source| OutboxWriterError::Persistence(Box::new(source)))?;
+        }
+
         if !dead_lettered_outboxes.is_empty() {
             Self::insert_dead_letters(uow, &dead_lettered_outboxes).await?;
             Self::delete_outboxes(uow, &dead_lettered_outboxes).await?;
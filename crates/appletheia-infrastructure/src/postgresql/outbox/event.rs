@@ -1,10 +1,12 @@
 mod pg_event_outbox_dead_letter_row;
 mod pg_event_outbox_dead_letter_row_error;
+pub mod pg_event_outbox_dead_letter_service;
 pub mod pg_event_outbox_fetcher;
 pub mod pg_event_outbox_row;
 pub mod pg_event_outbox_row_error;
 pub mod pg_event_outbox_writer;
 
+pub use pg_event_outbox_dead_letter_service::PgEventOutboxDeadLetterService;
 pub use pg_event_outbox_fetcher::PgEventOutboxFetcher;
 pub use pg_event_outbox_row::PgEventOutboxRow;
 pub use pg_event_outbox_row_error::PgEventOutboxRowError;
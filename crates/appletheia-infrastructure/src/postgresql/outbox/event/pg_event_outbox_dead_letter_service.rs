@@ -0,0 +1,342 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use appletheia_application::outbox::event::{
+    EventOutbox, EventOutboxDeadLetterFilter, EventOutboxId,
+};
+use appletheia_application::outbox::{
+    OrderingKey, Outbox, OutboxBatchSize, OutboxDeadLetterDiscard, OutboxDeadLetterService,
+    OutboxDeadLetterServiceError, OutboxLifecycle, OutboxWriter, ProcessedOutboxCount,
+};
+
+use crate::postgresql::unit_of_work::PgUnitOfWork;
+
+use super::PgEventOutboxWriter;
+use super::pg_event_outbox_dead_letter_row::PgEventOutboxDeadLetterRow;
+
+const SELECT_DEAD_LETTERS: &str = r#"
+    SELECT
+        event_outbox_id,
+        event_sequence,
+        event_id,
+        aggregate_type,
+        aggregate_id,
+        aggregate_version,
+        event_name,
+        payload,
+        occurred_at,
+        correlation_id,
+        causation_id,
+        context,
+        published_at,
+        attempt_count,
+        next_attempt_after,
+        lease_owner,
+        lease_until,
+        last_error,
+        dead_lettered_at
+    FROM event_dead_letters
+    WHERE TRUE
+"#;
+
+pub struct PgEventOutboxDeadLetterService;
+
+impl PgEventOutboxDeadLetterService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn push_filter(
+        query_builder: &mut QueryBuilder<'_, Postgres>,
+        filter: &EventOutboxDeadLetterFilter,
+    ) {
+        if let Some(aggregate_type) = &filter.aggregate_type {
+            query_builder
+                .push(" AND aggregate_type = ")
+                .push_bind(aggregate_type.value().to_owned());
+        }
+        if let Some(event_name) = &filter.event_name {
+            query_builder
+                .push(" AND event_name = ")
+                .push_bind(event_name.value().to_owned());
+        }
+        if let Some(dead_lettered_from) = filter.dead_lettered_from {
+            query_builder
+                .push(" AND dead_lettered_at >= ")
+                .push_bind(DateTime::<Utc>::from(dead_lettered_from));
+        }
+        if let Some(dead_lettered_until) = filter.dead_lettered_until {
+            query_builder
+                .push(" AND dead_lettered_at <= ")
+                .push_bind(DateTime::<Utc>::from(dead_lettered_until));
+        }
+        if let Some(error_contains) = &filter.error_contains {
+            query_builder
+                .push(" AND strpos(lower(last_error::text), lower(")
+                .push_bind(error_contains.clone())
+                .push(")) > 0");
+        }
+    }
+
+    async fn fetch(
+        uow: &mut PgUnitOfWork,
+        mut query_builder: QueryBuilder<'_, Postgres>,
+    ) -> Result<Vec<EventOutbox>, OutboxDeadLetterServiceError> {
+        let transaction = uow.transaction_mut();
+
+        let rows = query_builder
+            .build_query_as::<PgEventOutboxDeadLetterRow>()
+            .fetch_all(transaction.as_mut())
+            .await
+            .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+
+        rows.into_iter()
+            .map(PgEventOutboxDeadLetterRow::try_into_outbox)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| OutboxDeadLetterServiceError::MappingFailed(Box::new(e)))
+    }
+
+    async fn fetch_for_update_by_ids(
+        uow: &mut PgUnitOfWork,
+        ids: &[EventOutboxId],
+    ) -> Result<Vec<EventOutbox>, OutboxDeadLetterServiceError> {
+        let ids = ids.iter().map(EventOutboxId::value).collect::<Vec<Uuid>>();
+
+        let mut query_builder = QueryBuilder::<Postgres>::new(SELECT_DEAD_LETTERS);
+        query_builder
+            .push(" AND event_outbox_id = ANY(")
+            .push_bind(ids)
+            .push(") ORDER BY dead_lettered_at ASC, event_outbox_id ASC FOR UPDATE");
+
+        Self::fetch(uow, query_builder).await
+    }
+
+    async fn redrive_outboxes(
+        uow: &mut PgUnitOfWork,
+        mut outboxes: Vec<EventOutbox>,
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError> {
+        for outbox in &mut outboxes {
+            outbox.redrive()?;
+        }
+
+        PgEventOutboxWriter::new()
+            .write_outbox(uow, &outboxes)
+            .await?;
+
+        Ok(ProcessedOutboxCount::from_usize_saturating(outboxes.len()))
+    }
+
+    async fn insert_discards(
+        uow: &mut PgUnitOfWork,
+        outboxes: &[EventOutbox],
+        discard: &OutboxDeadLetterDiscard,
+    ) -> Result<(), OutboxDeadLetterServiceError> {
+        let discarded_by = serde_json::to_value(&discard.discarded_by)
+            .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+
+        let mut entries = Vec::with_capacity(outboxes.len());
+        for outbox in outboxes {
+            let message = serde_json::to_value(&outbox.event)
+                .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+            let last_error = outbox
+                .last_error
+                .as_ref()
+                .map(serde_json::to_value)
+                .transpose()
+                .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+            let dead_lettered_at = match outbox.lifecycle {
+                OutboxLifecycle::DeadLettered { dead_lettered_at } => {
+                    DateTime::<Utc>::from(dead_lettered_at)
+                }
+                OutboxLifecycle::Active => Utc::now(),
+            };
+            entries.push((outbox, message, last_error, dead_lettered_at));
+        }
+
+        let mut query_builder = QueryBuilder::<Postgres>::new(
+            r#"
+            INSERT INTO outbox_dead_letter_discards (
+                id,
+                outbox_kind,
+                outbox_id,
+                message_name,
+                message,
+                attempt_count,
+                last_error,
+                dead_lettered_at,
+                discarded_by,
+                reason
+            )
+            "#,
+        );
+        query_builder.push_values(
+            entries,
+            |mut builder, (outbox, message, last_error, dead_lettered_at)| {
+                builder
+                    .push_bind(Uuid::now_v7())
+                    .push_bind("event")
+                    .push_bind(outbox.id.value())
+                    .push_bind(outbox.event.event_name.value().to_owned())
+                    .push_bind(message)
+                    .push_bind(outbox.state.attempt_count().value())
+                    .push_bind(last_error)
+                    .push_bind(dead_lettered_at)
+                    .push_bind(discarded_by.clone())
+                    .push_bind(discard.reason.clone());
+            },
+        );
+
+        let transaction = uow.transaction_mut();
+
+        query_builder
+            .build()
+            .execute(transaction.as_mut())
+            .await
+            .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+
+        Ok(())
+    }
+}
+
+impl Default for PgEventOutboxDeadLetterService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutboxDeadLetterService for PgEventOutboxDeadLetterService {
+    type Uow = PgUnitOfWork;
+    type Outbox = EventOutbox;
+    type Filter = EventOutboxDeadLetterFilter;
+
+    async fn list(
+        &self,
+        uow: &mut Self::Uow,
+        filter: &Self::Filter,
+        limit: OutboxBatchSize,
+    ) -> Result<Vec<EventOutbox>, OutboxDeadLetterServiceError> {
+        let mut query_builder = QueryBuilder::<Postgres>::new(SELECT_DEAD_LETTERS);
+        Self::push_filter(&mut query_builder, filter);
+        query_builder
+            .push(" ORDER BY dead_lettered_at ASC, event_outbox_id ASC LIMIT ")
+            .push_bind(limit.as_i64());
+
+        Self::fetch(uow, query_builder).await
+    }
+
+    async fn find(
+        &self,
+        uow: &mut Self::Uow,
+        id: EventOutboxId,
+    ) -> Result<Option<EventOutbox>, OutboxDeadLetterServiceError> {
+        let mut query_builder = QueryBuilder::<Postgres>::new(SELECT_DEAD_LETTERS);
+        query_builder
+            .push(" AND event_outbox_id = ")
+            .push_bind(id.value());
+
+        Ok(Self::fetch(uow, query_builder).await?.into_iter().next())
+    }
+
+    async fn redrive(
+        &self,
+        uow: &mut Self::Uow,
+        ids: &[EventOutboxId],
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError> {
+        if ids.is_empty() {
+            return Ok(ProcessedOutboxCount::zero());
+        }
+
+        let outboxes = Self::fetch_for_update_by_ids(uow, ids).await?;
+        Self::redrive_outboxes(uow, outboxes).await
+    }
+
+    async fn redrive_matching(
+        &self,
+        uow: &mut Self::Uow,
+        filter: &Self::Filter,
+        limit: OutboxBatchSize,
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError> {
+        let mut query_builder = QueryBuilder::<Postgres>::new(SELECT_DEAD_LETTERS);
+        Self::push_filter(&mut query_builder, filter);
+        query_builder
+            .push(" ORDER BY dead_lettered_at ASC, event_outbox_id ASC LIMIT ")
+            .push_bind(limit.as_i64())
+            .push(" FOR UPDATE");
+
+        let outboxes = Self::fetch(uow, query_builder).await?;
+        Self::redrive_outboxes(uow, outboxes).await
+    }
+
+    async fn discard(
+        &self,
+        uow: &mut Self::Uow,
+        ids: &[EventOutboxId],
+        discard: &OutboxDeadLetterDiscard,
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError> {
+        if ids.is_empty() {
+            return Ok(ProcessedOutboxCount::zero());
+        }
+
+        let outboxes = Self::fetch_for_update_by_ids(uow, ids).await?;
+        if outboxes.is_empty() {
+            return Ok(ProcessedOutboxCount::zero());
+        }
+
+        Self::insert_discards(uow, &outboxes, discard).await?;
+
+        let outbox_ids = outboxes
+            .iter()
+            .map(|outbox| outbox.id().value())
+            .collect::<Vec<Uuid>>();
+        let transaction = uow.transaction_mut();
+
+        sqlx::query("DELETE FROM event_dead_letters WHERE event_outbox_id = ANY($1)")
+            .bind(outbox_ids)
+            .execute(transaction.as_mut())
+            .await
+            .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+
+        Ok(ProcessedOutboxCount::from_usize_saturating(outboxes.len()))
+    }
+
+    async fn unblock_ordering_key(
+        &self,
+        uow: &mut Self::Uow,
+        ordering_key: &OrderingKey,
+    ) -> Result<ProcessedOutboxCount, OutboxDeadLetterServiceError> {
+        // Event ordering keys are `<aggregate_type>:<aggregate_id>`; anything else cannot belong
+        // to an event dead letter.
+        let Some((aggregate_type, aggregate_id)) =
+            ordering_key
+                .as_str()
+                .rsplit_once(':')
+                .and_then(|(aggregate_type, aggregate_id)| {
+                    Some((aggregate_type, Uuid::parse_str(aggregate_id).ok()?))
+                })
+        else {
+            return Ok(ProcessedOutboxCount::zero());
+        };
+
+        let transaction = uow.transaction_mut();
+
+        let result = sqlx::query(
+            r#"
+            UPDATE event_dead_letters
+               SET ordering_released_at = now()
+             WHERE ordering_released_at IS NULL
+               AND aggregate_type = $1
+               AND aggregate_id = $2
+            "#,
+        )
+        .bind(aggregate_type)
+        .bind(aggregate_id)
+        .execute(transaction.as_mut())
+        .await
+        .map_err(|e| OutboxDeadLetterServiceError::Persistence(Box::new(e)))?;
+
+        Ok(ProcessedOutboxCount::from_usize_saturating(
+            result.rows_affected() as usize,
+        ))
+    }
+}
@@ -1,19 +1,36 @@
 use chrono::Utc;
-use sqlx::Postgres;
+use sqlx::{Postgres, QueryBuilder};
 
 use appletheia_application::outbox::{
-    OutboxBatchSize, OutboxFetcher, OutboxFetcherError, event::EventOutbox,
+    OutboxBatchSize, OutboxFetcher, OutboxFetcherError, OutboxOrderingMode, event::EventOutbox,
 };
 
 use crate::postgresql::unit_of_work::PgUnitOfWork;
 
 use super::{PgEventOutboxRow, PgEventOutboxRowError};
 
-pub struct PgEventOutboxFetcher;
+/// Fetches the lowest unpublished version of each aggregate.
+///
+/// With `OutboxOrderingMode::Strict`, an aggregate is also held back while one of its earlier
+/// events sits in `event_dead_letters` without having its ordering key unblocked.
+pub struct PgEventOutboxFetcher {
+    ordering_mode: OutboxOrderingMode,
+}
 
 impl PgEventOutboxFetcher {
     pub fn new() -> Self {
-        Self
+        Self {
+            ordering_mode: OutboxOrderingMode::default(),
+        }
+    }
+
+    pub fn with_ordering_mode(mut self, ordering_mode: OutboxOrderingMode) -> Self {
+        self.ordering_mode = ordering_mode;
+        self
+    }
+
+    pub fn ordering_mode(&self) -> OutboxOrderingMode {
+        self.ordering_mode
     }
 }
 
@@ -34,9 +51,7 @@ impl OutboxFetcher for PgEventOutboxFetcher {
     ) -> Result<Vec<EventOutbox>, OutboxFetcherError> {
         let now = Utc::now();
 
-        let transaction = uow.transaction_mut();
-
-        let outbox_rows = sqlx::query_as::<Postgres, PgEventOutboxRow>(
+        let mut query_builder = QueryBuilder::<Postgres>::new(
             r#"
             SELECT
                 id,
@@ -59,8 +74,14 @@ impl OutboxFetcher for PgEventOutboxFetcher {
                 last_error
             FROM event_outbox eo
             WHERE published_at IS NULL
-              AND next_attempt_after <= $1
-              AND (lease_owner IS NULL OR lease_until <= $1)
+              AND next_attempt_after <= "#,
+        );
+        query_builder
+            .push_bind(now)
+            .push(" AND (lease_owner IS NULL OR lease_until <= ")
+            .push_bind(now)
+            .push(
+                r#")
               AND NOT EXISTS (
                 SELECT 1
                 FROM event_outbox eo2
@@ -69,16 +90,36 @@ impl OutboxFetcher for PgEventOutboxFetcher {
                   AND eo2.aggregate_id = eo.aggregate_id
                   AND eo2.aggregate_version < eo.aggregate_version
               )
-            ORDER BY next_attempt_after ASC, event_sequence ASC
-            LIMIT $2
-            FOR UPDATE SKIP LOCKED
             "#,
-        )
-        .bind(now)
-        .bind(limit.as_i64())
-        .fetch_all(transaction.as_mut())
-        .await
-        .map_err(|e| OutboxFetcherError::Persistence(Box::new(e)))?;
+            );
+
+        if self.ordering_mode == OutboxOrderingMode::Strict {
+            query_builder.push(
+                r#"
+              AND NOT EXISTS (
+                SELECT 1
+                FROM event_dead_letters dl
+                WHERE dl.ordering_released_at IS NULL
+                  AND dl.aggregate_type = eo.aggregate_type
+                  AND dl.aggregate_id = eo.aggregate_id
+                  AND dl.aggregate_version < eo.aggregate_version
+              )
+            "#,
+            );
+        }
+
+        query_builder
+            .push(" ORDER BY next_attempt_after ASC, event_sequence ASC LIMIT ")
+            .push_bind(limit.as_i64())
+            .push(" FOR UPDATE SKIP LOCKED");
+
+        let transaction = uow.transaction_mut();
+
+        let outbox_rows = query_builder
+            .build_query_as::<PgEventOutboxRow>()
+            .fetch_all(transaction.as_mut())
+            .await
+            .map_err(|e| OutboxFetcherError::Persistence(Box::new(e)))?;
 
         if outbox_rows.is_empty() {
             return Ok(Vec::new());
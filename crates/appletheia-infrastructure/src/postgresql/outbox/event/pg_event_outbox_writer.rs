@@ -3,9 +3,11 @@ use sqlx::{Postgres, QueryBuilder};
 
 use appletheia_application::messaging::PublishDispatchError;
 use appletheia_application::outbox::{
-    OutboxLifecycle, OutboxWriter, OutboxWriterError, event::EventOutbox,
+    OutboxLifecycle, OutboxNextAttemptAt, OutboxState, OutboxWriter, OutboxWriterError,
+    event::EventOutbox,
 };
 
+use crate::postgresql::outbox::PgOutboxChannel;
 use crate::postgresql::unit_of_work::PgUnitOfWork;
 
 pub struct PgEventOutboxWriter;
@@ -28,6 +30,16 @@ impl PgEventOutboxWriter {
         }
     }
 
+    fn has_due_pending(outboxes: &[&EventOutbox]) -> bool {
+        let now = OutboxNextAttemptAt::now();
+        outboxes.iter().any(|outbox| {
+            matches!(
+                outbox.state,
+                OutboxState::Pending { next_attempt_after, .. } if next_attempt_after <= now
+            )
+        })
+    }
+
     async fn upsert_outbox_rows(
         uow: &mut PgUnitOfWork,
         outboxes: &[&EventOutbox],
@@ -76,7 +88,7 @@ impl PgEventOutboxWriter {
 
                 separated
                     .push("(")
-                    .push_bind(outbox.id.value())
+                    .push_bind_unseparated(outbox.id.value())
                     .push_bind(event.event_sequence.value())
                     .push_bind(event.event_id.value())
                     .push_bind(event.aggregate_type.value())
@@ -94,7 +106,7 @@ impl PgEventOutboxWriter {
                     .push_bind(outbox.state.lease_owner().map(ToString::to_string))
                     .push_bind(outbox.state.lease_until().map(DateTime::<Utc>::from))
                     .push_bind(last_error_value)
-                    .push(")");
+                    .push_unseparated(")");
             }
         }
 
@@ -168,7 +180,7 @@ impl PgEventOutboxWriter {
 
                 separated
                     .push("(")
-                    .push_bind(outbox_id)
+                    .push_bind_unseparated(outbox_id)
                     .push_bind(event.event_sequence.value())
                     .push_bind(event.event_id.value())
                     .push_bind(event.aggregate_type.value())
@@ -187,7 +199,7 @@ impl PgEventOutboxWriter {
                     .push_bind(outbox.state.lease_until().map(DateTime::<Utc>::from))
                     .push_bind(last_error_value)
                     .push_bind(dead_lettered_at_value)
-                    .push(")");
+                    .push_unseparated(")");
             }
         }
 
@@ -297,6 +309,13 @@ impl OutboxWriter for PgEventOutboxWriter {
         Self::upsert_outbox_rows(uow, &active_outboxes).await?;
         Self::delete_dead_letters(uow, &active_outboxes).await?;
 
+        if Self::has_due_pending(&active_outboxes) {
+            PgOutboxChannel::Event
+                .notify(uow)
+                .await
+                .map_err(|source| OutboxWriterError::Persistence(Box::new(source)))?;
+        }
+
         if !dead_lettered_outboxes.is_empty() {
             Self::insert_dead_letters(uow, &dead_lettered_outboxes).await?;
             Self::delete_outboxes(uow, &dead_lettered_outboxes).await?;
@@ -0,0 +1,39 @@
+use std::{fmt, fmt::Display};
+
+use crate::postgresql::unit_of_work::PgUnitOfWork;
+
+/// `LISTEN`/`NOTIFY` channel signalled whenever due work is written to an outbox table.
+///
+/// Notifications are sent inside the writing transaction, so PostgreSQL only delivers them
+/// once that transaction commits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PgOutboxChannel {
+    Event,
+    Command,
+}
+
+impl PgOutboxChannel {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Event => "appletheia_event_outbox",
+            Self::Command => "appletheia_command_outbox",
+        }
+    }
+
+    pub(crate) async fn notify(&self, uow: &mut PgUnitOfWork) -> Result<(), sqlx::Error> {
+        let transaction = uow.transaction_mut();
+
+        sqlx::query("SELECT pg_notify($1, '')")
+            .bind(self.as_str())
+            .execute(transaction.as_mut())
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Display for PgOutboxChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
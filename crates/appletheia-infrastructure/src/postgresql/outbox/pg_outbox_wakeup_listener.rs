@@ -0,0 +1,75 @@
+use std::time::Duration as StdDuration;
+
+use sqlx::PgPool;
+use sqlx::postgres::PgListener;
+use tokio::sync::Mutex;
+use tokio::time::timeout;
+
+use appletheia_application::outbox::{
+    OutboxPollInterval, OutboxWakeup, OutboxWakeupListener, OutboxWakeupListenerError,
+};
+
+use super::PgOutboxChannel;
+
+/// Wakes an idle relay on `NOTIFY` from the outbox writers.
+///
+/// The listener holds a dedicated connection and reconnects on its own; notifications sent
+/// while it is reconnecting are lost, which the relay's poll interval covers. A failed
+/// reconnect is returned as `OutboxWakeupListenerError::Connection`; the relay logs it, falls
+/// back to polling and the next `wait` tries to reconnect again.
+pub struct PgOutboxWakeupListener {
+    listener: Mutex<PgListener>,
+}
+
+impl PgOutboxWakeupListener {
+    pub async fn connect(
+        pool: &PgPool,
+        channel: PgOutboxChannel,
+    ) -> Result<Self, OutboxWakeupListenerError> {
+        let mut listener = PgListener::connect_with(pool)
+            .await
+            .map_err(|e| OutboxWakeupListenerError::Connection(Box::new(e)))?;
+        listener
+            .listen(channel.as_str())
+            .await
+            .map_err(|e| OutboxWakeupListenerError::Connection(Box::new(e)))?;
+
+        Ok(Self {
+            listener: Mutex::new(listener),
+        })
+    }
+
+    fn drain_buffered(listener: &mut PgListener) -> bool {
+        let mut drained = false;
+        while listener.next_buffered().is_some() {
+            drained = true;
+        }
+        drained
+    }
+}
+
+impl OutboxWakeupListener for PgOutboxWakeupListener {
+    async fn wait(
+        &self,
+        poll_interval: OutboxPollInterval,
+    ) -> Result<OutboxWakeup, OutboxWakeupListenerError> {
+        let wait_for = poll_interval
+            .value()
+            .to_std()
+            .unwrap_or_else(|_| StdDuration::from_secs(0));
+
+        let mut listener = self.listener.lock().await;
+        if Self::drain_buffered(&mut listener) {
+            return Ok(OutboxWakeup::Notified);
+        }
+
+        match timeout(wait_for, listener.recv()).await {
+            Ok(Ok(_)) => {
+                Self::drain_buffered(&mut listener);
+                Ok(OutboxWakeup::Notified)
+            }
+            Ok(Err(e)) => Err(OutboxWakeupListenerError::Connection(Box::new(e))),
+            Err(_) => Ok(OutboxWakeup::TimedOut),
+        }
+    }
+}
@@ -74,10 +74,10 @@ impl EventFeedReader for PgEventFeedReader {
                     for selector in selectors {
                         separated
                             .push("(aggregate_type = ")
-                            .push_bind(selector.aggregate_type.value())
-                            .push(" AND event_name = ")
-                            .push_bind(selector.event_name.value())
-                            .push(")");
+                            .push_bind_unseparated(selector.aggregate_type.value())
+                            .push_unseparated(" AND event_name = ")
+                            .push_bind_unseparated(selector.event_name.value())
+                            .push_unseparated(")");
                     }
                     separated.push_unseparated(")");
                 }
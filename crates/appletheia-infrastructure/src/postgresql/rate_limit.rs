@@ -0,0 +1,3 @@
+pub mod pg_rate_limit_store;
+
+pub use pg_rate_limit_store::PgRateLimitStore;
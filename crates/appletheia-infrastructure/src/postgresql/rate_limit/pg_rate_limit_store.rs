@@ -0,0 +1,91 @@
+use sqlx::PgPool;
+
+use appletheia_application::rate_limit::{
+    RateLimitDecision, RateLimitKey, RateLimitPolicy, RateLimitStore, RateLimitStoreError,
+};
+
+// Refills the bucket by the time elapsed on the database clock, the same way `TokenBucket`
+// does, and takes a token only if a whole one is available. A limited bucket is left untouched
+// and reports how many tokens it still misses.
+const TRY_ACQUIRE: &str = r#"
+    WITH acquired AS (
+        INSERT INTO rate_limit_buckets AS bucket (key, tokens, updated_at)
+        VALUES ($1, $2 - 1, now())
+        ON CONFLICT (key) DO UPDATE
+        SET tokens = LEAST(
+                $2,
+                bucket.tokens
+                    + GREATEST(EXTRACT(EPOCH FROM now() - bucket.updated_at)::double precision, 0)
+                        * $2 / $3
+            ) - 1,
+            updated_at = GREATEST(bucket.updated_at, now())
+        WHERE LEAST(
+                $2,
+                bucket.tokens
+                    + GREATEST(EXTRACT(EPOCH FROM now() - bucket.updated_at)::double precision, 0)
+                        * $2 / $3
+            ) >= 1
+        RETURNING tokens
+    )
+    SELECT tokens AS remaining, NULL::double precision AS missing
+    FROM acquired
+    UNION ALL
+    SELECT
+        NULL,
+        1 - LEAST(
+            $2,
+            tokens
+                + GREATEST(EXTRACT(EPOCH FROM now() - updated_at)::double precision, 0)
+                    * $2 / $3
+        )
+    FROM rate_limit_buckets
+    WHERE key = $1
+      AND NOT EXISTS (SELECT 1 FROM acquired)
+"#;
+
+/// Token buckets in the `rate_limit_buckets` table, shared by every instance on the database.
+///
+/// Each acquisition is a single upsert that refills and takes a token under the row lock and
+/// judges time by the database clock, so concurrent dispatches from different hosts cannot
+/// overdraw a bucket. Idle buckets are removed by a `RetentionTarget::RateLimitBuckets` policy.
+pub struct PgRateLimitStore {
+    pool: PgPool,
+}
+
+impl PgRateLimitStore {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl RateLimitStore for PgRateLimitStore {
+    async fn try_acquire(
+        &self,
+        key: &RateLimitKey,
+        policy: &RateLimitPolicy,
+    ) -> Result<RateLimitDecision, RateLimitStoreError> {
+        let refill_period = policy.refill_period().num_milliseconds() as f64 / 1000.0;
+
+        let row = sqlx::query_as::<_, (Option<f64>, Option<f64>)>(TRY_ACQUIRE)
+            .bind(key.to_string())
+            .bind(f64::from(policy.capacity()))
+            .bind(refill_period)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|error| RateLimitStoreError::Persistence(Box::new(error)))?;
+
+        Ok(match row {
+            Some((Some(remaining), _)) => RateLimitDecision::Allowed {
+                remaining: remaining.floor() as u32,
+            },
+            Some((None, Some(missing))) => RateLimitDecision::Limited {
+                retry_after: policy.refill_time(missing),
+            },
+            // The bucket was created and drained by a concurrent dispatch after this statement
+            // took its snapshot.
+            _ => RateLimitDecision::Limited {
+                retry_after: policy.token_interval(),
+            },
+        })
+    }
+}
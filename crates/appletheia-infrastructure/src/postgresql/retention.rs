@@ -0,0 +1,3 @@
+pub mod pg_retention_store;
+
+pub use pg_retention_store::PgRetentionStore;
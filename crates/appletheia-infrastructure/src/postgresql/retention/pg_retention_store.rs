@@ -0,0 +1,386 @@
+use chrono::{DateTime, Utc};
+
+use appletheia_application::retention::{
+    RemovedRowCount, RetentionAction, RetentionBatchSize, RetentionCutoff, RetentionPolicy,
+    RetentionStore, RetentionStoreError, RetentionTarget,
+};
+
+use crate::postgresql::unit_of_work::PgUnitOfWork;
+
+const PURGE_EVENT_OUTBOX: &str = r#"
+    WITH expired AS (
+        SELECT id
+        FROM event_outbox
+        WHERE published_at IS NOT NULL
+          AND published_at < $1
+        ORDER BY published_at ASC
+        LIMIT $2
+        FOR UPDATE SKIP LOCKED
+    )
+    DELETE FROM event_outbox AS outbox
+    USING expired
+    WHERE outbox.id = expired.id
+"#;
+
+const ARCHIVE_EVENT_OUTBOX: &str = r#"
+    WITH expired AS (
+        SELECT id
+        FROM event_outbox
+        WHERE published_at IS NOT NULL
+          AND published_at < $1
+        ORDER BY published_at ASC
+        LIMIT $2
+        FOR UPDATE SKIP LOCKED
+    ),
+    removed AS (
+        DELETE FROM event_outbox AS outbox
+        USING expired
+        WHERE outbox.id = expired.id
+        RETURNING
+            outbox.id,
+            outbox.event_sequence,
+            outbox.event_id,
+            outbox.aggregate_type,
+            outbox.aggregate_id,
+            outbox.aggregate_version,
+            outbox.event_name,
+            outbox.payload,
+            outbox.occurred_at,
+            outbox.correlation_id,
+            outbox.causation_id,
+            outbox.context,
+            outbox.published_at,
+            outbox.attempt_count
+    )
+    INSERT INTO event_outbox_archive (
+        id,
+        event_sequence,
+        event_id,
+        aggregate_type,
+        aggregate_id,
+        aggregate_version,
+        event_name,
+        payload,
+        occurred_at,
+        correlation_id,
+        causation_id,
+        context,
+        published_at,
+        attempt_count
+    )
+    SELECT
+        id,
+        event_sequence,
+        event_id,
+        aggregate_type,
+        aggregate_id,
+        aggregate_version,
+        event_name,
+        payload,
+        occurred_at,
+        correlation_id,
+        causation_id,
+        context,
+        published_at,
+        attempt_count
+    FROM removed
+"#;
+
+const PURGE_COMMAND_OUTBOX: &str = r#"
+    WITH expired AS (
+        SELECT id
+        FROM command_outbox
+        WHERE published_at IS NOT NULL
+          AND published_at < $1
+        ORDER BY published_at ASC
+        LIMIT $2
+        FOR UPDATE SKIP LOCKED
+    )
+    DELETE FROM command_outbox AS outbox
+    USING expired
+    WHERE outbox.id = expired.id
+"#;
+
+const ARCHIVE_COMMAND_OUTBOX: &str = r#"
+    WITH expired AS (
+        SELECT id
+        FROM command_outbox
+        WHERE published_at IS NOT NULL
+          AND published_at < $1
+        ORDER BY published_at ASC
+        LIMIT $2
+        FOR UPDATE SKIP LOCKED
+    ),
+    removed AS (
+        DELETE FROM command_outbox AS outbox
+        USING expired
+        WHERE outbox.id = expired.id
+        RETURNING
+            outbox.id,
+            outbox.command_sequence,
+            outbox.message_id,
+            outbox.command_name,
+            outbox.payload,
+            outbox.correlation_id,
+            outbox.causation_id,
+            outbox.options,
+            outbox.trace_context,
+            outbox.published_at,
+            outbox.attempt_count
+    )
+    INSERT INTO command_outbox_archive (
+        id,
+        command_sequence,
+        message_id,
+        command_name,
+        payload,
+        correlation_id,
+        causation_id,
+        options,
+        trace_context,
+        published_at,
+        attempt_count
+    )
+    SELECT
+        id,
+        command_sequence,
+        message_id,
+        command_name,
+        payload,
+        correlation_id,
+        causation_id,
+        options,
+        trace_context,
+        published_at,
+        attempt_count
+    FROM removed
+"#;
+
+const PURGE_IDEMPOTENCY: &str = r#"
+    WITH expired AS (
+        SELECT id
+        FROM idempotency
+        WHERE completed_at IS NOT NULL
+          AND completed_at < $1
+        ORDER BY completed_at ASC
+        LIMIT $2
+        FOR UPDATE SKIP LOCKED
+    )
+    DELETE FROM idempotency AS entry
+    USING expired
+    WHERE entry.id = expired.id
+"#;
+
+const ARCHIVE_IDEMPOTENCY: &str = r#"
+    WITH expired AS (
+        SELECT id
+        FROM idempotency
+        WHERE completed_at IS NOT NULL
+          AND completed_at < $1
+        ORDER BY completed_at ASC
+        LIMIT $2
+        FOR UPDATE SKIP LOCKED
+    ),
+    removed AS (
+        DELETE FROM idempotency AS entry
+        USING expired
+        WHERE entry.id = expired.id
+        RETURNING
+            entry.id,
+            entry.message_id,
+            entry.command_name,
+            entry.command_hash,
+            entry.output,
+            entry.error,
+            entry.started_at,
+            entry.completed_at
+    )
+    INSERT INTO idempotency_archive (
+        id,
+        message_id,
+        command_name,
+        command_hash,
+        output,
+        error,
+        started_at,
+        completed_at
+    )
+    SELECT
+        id,
+        message_id,
+        command_name,
+        command_hash,
+        output,
+        error,
+        started_at,
+        completed_at
+    FROM removed
+"#;
+
+// The message is deleted alongside its expired dead letters unless a delivery or a dead letter
+// of another consumer group still references it.
+const PURGE_MESSAGE_QUEUE_DEAD_LETTERS: &str = r#"
+    WITH expired AS (
+        SELECT consumer_group, message_id
+        FROM message_queue_dead_letters
+        WHERE dead_lettered_at < $1
+        ORDER BY dead_lettered_at ASC
+        LIMIT $2
+        FOR UPDATE SKIP LOCKED
+    ),
+    orphaned AS (
+        DELETE FROM message_queue_messages AS message
+        WHERE message.id IN (SELECT message_id FROM expired)
+          AND NOT EXISTS (
+            SELECT 1
+            FROM message_queue_deliveries AS delivery
+            WHERE delivery.message_id = message.id
+          )
+          AND NOT EXISTS (
+            SELECT 1
+            FROM message_queue_dead_letters AS dead_letter
+            WHERE dead_letter.message_id = message.id
+              AND (dead_letter.consumer_group, dead_letter.message_id) NOT IN (
+                SELECT consumer_group, message_id FROM expired
+              )
+          )
+    )
+    DELETE FROM message_queue_dead_letters AS dead_letter
+    USING expired
+    WHERE dead_letter.consumer_group = expired.consumer_group
+      AND dead_letter.message_id = expired.message_id
+"#;
+
+const ARCHIVE_MESSAGE_QUEUE_DEAD_LETTERS: &str = r#"
+    WITH expired AS (
+        SELECT consumer_group, message_id
+        FROM message_queue_dead_letters
+        WHERE dead_lettered_at < $1
+        ORDER BY dead_lettered_at ASC
+        LIMIT $2
+        FOR UPDATE SKIP LOCKED
+    ),
+    removed AS (
+        DELETE FROM message_queue_dead_letters AS dead_letter
+        USING expired
+        WHERE dead_letter.consumer_group = expired.consumer_group
+          AND dead_letter.message_id = expired.message_id
+        RETURNING
+            dead_letter.consumer_group,
+            dead_letter.message_id,
+            dead_letter.ordering_key,
+            dead_letter.attempt_count,
+            dead_letter.dead_lettered_at
+    ),
+    orphaned AS (
+        DELETE FROM message_queue_messages AS message
+        WHERE message.id IN (SELECT message_id FROM expired)
+          AND NOT EXISTS (
+            SELECT 1
+            FROM message_queue_deliveries AS delivery
+            WHERE delivery.message_id = message.id
+          )
+          AND NOT EXISTS (
+            SELECT 1
+            FROM message_queue_dead_letters AS dead_letter
+            WHERE dead_letter.message_id = message.id
+              AND (dead_letter.consumer_group, dead_letter.message_id) NOT IN (
+                SELECT consumer_group, message_id FROM expired
+              )
+          )
+    )
+    INSERT INTO message_queue_dead_letter_archive (
+        consumer_group,
+        message_id,
+        topic,
+        ordering_key,
+        attributes,
+        payload,
+        attempt_count,
+        dead_lettered_at
+    )
+    SELECT
+        removed.consumer_group,
+        removed.message_id,
+        message.topic,
+        removed.ordering_key,
+        message.attributes,
+        message.payload,
+        removed.attempt_count,
+        removed.dead_lettered_at
+    FROM removed
+    JOIN message_queue_messages AS message ON message.id = removed.message_id
+    ON CONFLICT (consumer_group, message_id) DO NOTHING
+"#;
+
+const PURGE_RATE_LIMIT_BUCKETS: &str = r#"
+    WITH expired AS (
+        SELECT key
+        FROM rate_limit_buckets
+        WHERE updated_at < $1
+        ORDER BY updated_at ASC
+        LIMIT $2
+        FOR UPDATE SKIP LOCKED
+    )
+    DELETE FROM rate_limit_buckets AS bucket
+    USING expired
+    WHERE bucket.key = expired.key
+"#;
+
+/// Removes expired rows with `FOR UPDATE SKIP LOCKED`, so rows held by a relay or an
+/// in-flight command are left alone until a later pass.
+#[derive(Debug)]
+pub struct PgRetentionStore;
+
+impl PgRetentionStore {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn statement(policy: &RetentionPolicy) -> &'static str {
+        match (policy.target, policy.action) {
+            (RetentionTarget::EventOutbox, RetentionAction::Purge) => PURGE_EVENT_OUTBOX,
+            (RetentionTarget::EventOutbox, RetentionAction::Archive) => ARCHIVE_EVENT_OUTBOX,
+            (RetentionTarget::CommandOutbox, RetentionAction::Purge) => PURGE_COMMAND_OUTBOX,
+            (RetentionTarget::CommandOutbox, RetentionAction::Archive) => ARCHIVE_COMMAND_OUTBOX,
+            (RetentionTarget::Idempotency, RetentionAction::Purge) => PURGE_IDEMPOTENCY,
+            (RetentionTarget::Idempotency, RetentionAction::Archive) => ARCHIVE_IDEMPOTENCY,
+            (RetentionTarget::MessageQueueDeadLetters, RetentionAction::Purge) => {
+                PURGE_MESSAGE_QUEUE_DEAD_LETTERS
+            }
+            (RetentionTarget::MessageQueueDeadLetters, RetentionAction::Archive) => {
+                ARCHIVE_MESSAGE_QUEUE_DEAD_LETTERS
+            }
+            (RetentionTarget::RateLimitBuckets, _) => PURGE_RATE_LIMIT_BUCKETS,
+        }
+    }
+}
+
+impl Default for PgRetentionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetentionStore for PgRetentionStore {
+    type Uow = PgUnitOfWork;
+
+    async fn remove_expired(
+        &self,
+        uow: &mut Self::Uow,
+        policy: &RetentionPolicy,
+        cutoff: RetentionCutoff,
+        batch_size: RetentionBatchSize,
+    ) -> Result<RemovedRowCount, RetentionStoreError> {
+        let transaction = uow.transaction_mut();
+
+        let result = sqlx::query(Self::statement(policy))
+            .bind(DateTime::<Utc>::from(cutoff))
+            .bind(batch_size.as_i64())
+            .execute(transaction.as_mut())
+            .await
+            .map_err(|e| RetentionStoreError::Persistence(Box::new(e)))?;
+
+        Ok(RemovedRowCount::from_u64_saturating(result.rows_affected()))
+    }
+}
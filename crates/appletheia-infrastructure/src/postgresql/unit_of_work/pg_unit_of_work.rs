@@ -3,16 +3,42 @@ use sqlx::{Postgres, Transaction};
 
 pub struct PgUnitOfWork {
     transaction: Transaction<'static, Postgres>,
+    savepoint_depth: u32,
 }
 
 impl PgUnitOfWork {
     pub(super) fn new(transaction: Transaction<'static, Postgres>) -> Self {
-        Self { transaction }
+        Self {
+            transaction,
+            savepoint_depth: 0,
+        }
     }
 
     pub fn transaction_mut(&mut self) -> &mut Transaction<'static, Postgres> {
         &mut self.transaction
     }
+
+    fn savepoint_name(depth: u32) -> String {
+        format!("appletheia_savepoint_{depth}")
+    }
+
+    async fn execute_savepoint_statement(
+        &mut self,
+        statement: &str,
+    ) -> Result<(), UnitOfWorkError> {
+        sqlx::raw_sql(statement)
+            .execute(&mut *self.transaction)
+            .await
+            .map_err(|e| UnitOfWorkError::SavepointFailed(Box::new(e)))?;
+        Ok(())
+    }
+
+    fn active_savepoint(&self) -> Result<String, UnitOfWorkError> {
+        if self.savepoint_depth == 0 {
+            return Err(UnitOfWorkError::NoActiveSavepoint);
+        }
+        Ok(Self::savepoint_name(self.savepoint_depth))
+    }
 }
 
 impl UnitOfWork for PgUnitOfWork {
@@ -31,4 +57,30 @@ impl UnitOfWork for PgUnitOfWork {
             .map_err(|e| UnitOfWorkError::RollbackFailed(Box::new(e)))?;
         Ok(())
     }
+
+    async fn savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+        let name = Self::savepoint_name(self.savepoint_depth + 1);
+        self.execute_savepoint_statement(&format!("SAVEPOINT {name}"))
+            .await?;
+        self.savepoint_depth += 1;
+        Ok(())
+    }
+
+    async fn release_savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+        let name = self.active_savepoint()?;
+        self.execute_savepoint_statement(&format!("RELEASE SAVEPOINT {name}"))
+            .await?;
+        self.savepoint_depth -= 1;
+        Ok(())
+    }
+
+    async fn rollback_to_savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+        let name = self.active_savepoint()?;
+        self.execute_savepoint_statement(&format!(
+            "ROLLBACK TO SAVEPOINT {name}; RELEASE SAVEPOINT {name}"
+        ))
+        .await?;
+        self.savepoint_depth -= 1;
+        Ok(())
+    }
 }
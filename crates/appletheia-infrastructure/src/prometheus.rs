@@ -0,0 +1,5 @@
+pub mod prometheus_metrics_exporter;
+pub mod prometheus_metrics_exporter_error;
+
+pub use prometheus_metrics_exporter::PrometheusMetricsExporter;
+pub use prometheus_metrics_exporter_error::PrometheusMetricsExporterError;
@@ -0,0 +1,90 @@
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+
+use appletheia_application::telemetry::MetricName;
+
+use super::PrometheusMetricsExporterError;
+
+/// Installs a global Prometheus recorder for the appletheia metrics.
+///
+/// The exporter does not listen on its own; serve [`PrometheusMetricsExporter::render`] from the
+/// application's HTTP server, typically at `/metrics`.
+#[derive(Clone, Debug)]
+pub struct PrometheusMetricsExporter {
+    handle: PrometheusHandle,
+}
+
+impl PrometheusMetricsExporter {
+    /// Histogram buckets, in seconds, for every `*_seconds` histogram.
+    pub const DURATION_BUCKETS: &'static [f64] = &[
+        0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+    ];
+
+    /// Histogram buckets for relationship nodes visited per authorization check.
+    pub const NODE_COUNT_BUCKETS: &'static [f64] = &[
+        1.0, 2.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 1_000.0, 10_000.0,
+    ];
+
+    /// Installs the recorder globally and describes every [`MetricName`].
+    ///
+    /// Fails if another recorder is already installed.
+    pub fn install() -> Result<Self, PrometheusMetricsExporterError> {
+        let handle = Self::builder()?.install_recorder()?;
+        MetricName::describe_all();
+
+        Ok(Self { handle })
+    }
+
+    pub fn handle(&self) -> &PrometheusHandle {
+        &self.handle
+    }
+
+    /// Renders the current metrics in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        self.handle.render()
+    }
+
+    fn builder() -> Result<PrometheusBuilder, PrometheusMetricsExporterError> {
+        Ok(PrometheusBuilder::new()
+            .set_buckets_for_metric(
+                Matcher::Suffix("_seconds".to_string()),
+                Self::DURATION_BUCKETS,
+            )?
+            .set_buckets_for_metric(
+                Matcher::Full(MetricName::AuthorizationEvaluatedNodes.as_str().to_string()),
+                Self::NODE_COUNT_BUCKETS,
+            )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use metrics::{counter, histogram};
+
+    use super::*;
+
+    #[test]
+    fn renders_recorded_metrics_with_buckets() {
+        let recorder = PrometheusMetricsExporter::builder()
+            .expect("builder should be valid")
+            .build_recorder();
+        let exporter = PrometheusMetricsExporter {
+            handle: recorder.handle(),
+        };
+
+        metrics::with_local_recorder(&recorder, || {
+            MetricName::describe_all();
+            counter!(MetricName::OutboxPublished.as_str(), "outbox" => "event").increment(3);
+            histogram!(MetricName::OutboxRelayBatchDuration.as_str(), "outbox" => "event")
+                .record(Duration::from_millis(20));
+        });
+
+        let rendered = exporter.render();
+        assert!(rendered.contains("appletheia_outbox_published_total{outbox=\"event\"} 3"));
+        assert!(rendered.contains(
+            "appletheia_outbox_relay_batch_duration_seconds_bucket{outbox=\"event\",le=\"0.025\"} 1"
+        ));
+        assert!(rendered.contains("# HELP appletheia_outbox_published_total"));
+    }
+}
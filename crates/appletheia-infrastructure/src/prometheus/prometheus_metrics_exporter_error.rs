@@ -0,0 +1,8 @@
+use metrics_exporter_prometheus::BuildError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PrometheusMetricsExporterError {
+    #[error("prometheus recorder build error: {0}")]
+    Build(#[from] BuildError),
+}
@@ -0,0 +1,18 @@
+pub mod authorization;
+pub mod command;
+pub mod event;
+pub mod outbox;
+pub mod projection;
+pub mod saga;
+pub mod snapshot;
+#[cfg(test)]
+mod sqlite_storage_conformance;
+
+pub mod migration;
+pub mod repository;
+pub mod unit_of_work;
+
+pub use authorization::*;
+pub use migration::*;
+pub use repository::*;
+pub use unit_of_work::*;
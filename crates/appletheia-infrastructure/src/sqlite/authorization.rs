@@ -0,0 +1,5 @@
+pub mod sqlite_relationship_row;
+pub mod sqlite_relationship_row_error;
+pub mod sqlite_relationship_store;
+
+pub use sqlite_relationship_store::*;
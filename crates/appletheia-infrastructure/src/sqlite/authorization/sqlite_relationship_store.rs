@@ -0,0 +1,589 @@
+use std::collections::HashMap;
+
+use appletheia_application::authorization::{
+    AggregateRef, RelationRefOwned, Relationship, RelationshipChange, RelationshipId,
+    RelationshipRevision, RelationshipStore, RelationshipStoreError, RelationshipSubject,
+};
+use appletheia_application::event::{AggregateIdValue, AggregateTypeOwned};
+use sqlx::{QueryBuilder, Row, Sqlite};
+use uuid::Uuid;
+
+use crate::sqlite::SqliteUnitOfWork;
+
+use super::sqlite_relationship_row::SqliteRelationshipRow;
+
+pub struct SqliteRelationshipStore;
+
+impl SqliteRelationshipStore {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for SqliteRelationshipStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelationshipStore for SqliteRelationshipStore {
+    type Uow = SqliteUnitOfWork;
+
+    async fn apply_changes(
+        &self,
+        uow: &mut SqliteUnitOfWork,
+        changes: &[RelationshipChange],
+    ) -> Result<(), RelationshipStoreError> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        const CHUNK_SIZE: usize = 1000;
+
+        let transaction = uow.transaction_mut();
+        let mut affected_rows: u64 = 0;
+
+        let mut deduped: HashMap<Relationship, bool> = HashMap::new();
+        for change in changes {
+            let (relationship, is_upsert) = match change {
+                RelationshipChange::Upsert(relationship) => (relationship, true),
+                RelationshipChange::Delete(relationship) => (relationship, false),
+            };
+
+            deduped.insert(relationship.clone(), is_upsert);
+        }
+
+        let mut deletes: Vec<Relationship> = Vec::new();
+        let mut upserts: Vec<Relationship> = Vec::new();
+        for (relationship, is_upsert) in deduped {
+            if is_upsert {
+                upserts.push(relationship);
+            } else {
+                deletes.push(relationship);
+            }
+        }
+
+        for chunk in deletes.chunks(CHUNK_SIZE) {
+            let mut query = QueryBuilder::<Sqlite>::new(
+                r#"
+                DELETE FROM relationships
+                 WHERE EXISTS (
+                    SELECT 1
+                    FROM (
+                "#,
+            );
+
+            query.push_values(chunk, |mut b, item| {
+                let (
+                    subject_aggregate_type,
+                    subject_aggregate_id,
+                    subject_relation,
+                    subject_is_wildcard,
+                ) = match &item.subject {
+                    RelationshipSubject::Aggregate(subject) => (
+                        subject.aggregate_type.value(),
+                        Some(subject.aggregate_id.value()),
+                        None,
+                        false,
+                    ),
+                    RelationshipSubject::Wildcard { aggregate_type } => {
+                        (aggregate_type.value(), None, None, true)
+                    }
+                    RelationshipSubject::AggregateSet {
+                        aggregate,
+                        relation,
+                    } => (
+                        aggregate.aggregate_type.value(),
+                        Some(aggregate.aggregate_id.value()),
+                        Some(relation.relation_name.value()),
+                        false,
+                    ),
+                };
+
+                b.push_bind(item.aggregate.aggregate_type.value())
+                    .push_bind(item.aggregate.aggregate_id.value())
+                    .push_bind(item.relation.relation_name.value())
+                    .push_bind(subject_aggregate_type)
+                    .push_bind(subject_aggregate_id)
+                    .push_bind(subject_relation)
+                    .push_bind(subject_is_wildcard);
+            });
+
+            query.push(
+                r#"
+                    ) AS v
+                    WHERE relationships.aggregate_type = v.column1
+                      AND relationships.aggregate_id = v.column2
+                      AND relationships.relation = v.column3
+                      AND relationships.subject_aggregate_type = v.column4
+                      AND relationships.subject_aggregate_id IS v.column5
+                      AND relationships.subject_relation IS v.column6
+                      AND relationships.subject_is_wildcard = v.column7
+                 )
+                "#,
+            );
+
+            affected_rows += query
+                .build()
+                .execute(transaction.as_mut())
+                .await
+                .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?
+                .rows_affected();
+        }
+
+        for chunk in upserts.chunks(CHUNK_SIZE) {
+            let mut query = QueryBuilder::<Sqlite>::new(
+                r#"
+                INSERT INTO relationships (
+                    id,
+                    aggregate_type,
+                    aggregate_id,
+                    relation,
+                    subject_aggregate_type,
+                    subject_aggregate_id,
+                    subject_relation,
+                    subject_is_wildcard
+                )
+                "#,
+            );
+
+            query.push_values(chunk, |mut b, item| {
+                let (
+                    subject_aggregate_type,
+                    subject_aggregate_id,
+                    subject_relation,
+                    subject_is_wildcard,
+                ) = match &item.subject {
+                    RelationshipSubject::Aggregate(subject) => (
+                        subject.aggregate_type.value(),
+                        Some(subject.aggregate_id.value()),
+                        None,
+                        false,
+                    ),
+                    RelationshipSubject::Wildcard { aggregate_type } => {
+                        (aggregate_type.value(), None, None, true)
+                    }
+                    RelationshipSubject::AggregateSet {
+                        aggregate,
+                        relation,
+                    } => (
+                        aggregate.aggregate_type.value(),
+                        Some(aggregate.aggregate_id.value()),
+                        Some(relation.relation_name.value()),
+                        false,
+                    ),
+                };
+
+                b.push_bind(RelationshipId::new().value())
+                    .push_bind(item.aggregate.aggregate_type.value())
+                    .push_bind(item.aggregate.aggregate_id.value())
+                    .push_bind(item.relation.relation_name.value())
+                    .push_bind(subject_aggregate_type)
+                    .push_bind(subject_aggregate_id)
+                    .push_bind(subject_relation)
+                    .push_bind(subject_is_wildcard);
+            });
+
+            query.push(" ON CONFLICT DO NOTHING");
+
+            affected_rows += query
+                .build()
+                .execute(transaction.as_mut())
+                .await
+                .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?
+                .rows_affected();
+        }
+
+        if affected_rows > 0 {
+            sqlx::query(
+                r#"
+                UPDATE relationship_revision
+                SET revision = revision + 1,
+                    updated_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+                WHERE id = 1
+                "#,
+            )
+            .execute(transaction.as_mut())
+            .await
+            .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?;
+        }
+
+        Ok(())
+    }
+
+    async fn read_aggregates_by_subject(
+        &self,
+        uow: &mut SqliteUnitOfWork,
+        subject: &RelationshipSubject,
+        relation: &RelationRefOwned,
+    ) -> Result<Vec<AggregateRef>, RelationshipStoreError> {
+        let mut query = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT DISTINCT aggregate_type, aggregate_id
+            FROM relationships
+            WHERE relation =
+            "#,
+        );
+        query.push_bind(relation.relation_name.value());
+
+        match subject {
+            RelationshipSubject::Aggregate(subject) => {
+                query.push(" AND subject_is_wildcard = false");
+                query.push(" AND subject_relation IS NULL");
+                query.push(" AND subject_aggregate_type = ");
+                query.push_bind(subject.aggregate_type.value());
+                query.push(" AND subject_aggregate_id = ");
+                query.push_bind(subject.aggregate_id.value());
+            }
+            RelationshipSubject::Wildcard { aggregate_type } => {
+                query.push(" AND subject_is_wildcard = true");
+                query.push(" AND subject_aggregate_type = ");
+                query.push_bind(aggregate_type.value());
+            }
+            RelationshipSubject::AggregateSet {
+                aggregate,
+                relation,
+            } => {
+                query.push(" AND subject_is_wildcard = false");
+                query.push(" AND subject_relation = ");
+                query.push_bind(relation.relation_name.value());
+                query.push(" AND subject_aggregate_type = ");
+                query.push_bind(aggregate.aggregate_type.value());
+                query.push(" AND subject_aggregate_id = ");
+                query.push_bind(aggregate.aggregate_id.value());
+            }
+        }
+
+        query.push(" AND aggregate_type = ");
+        query.push_bind(relation.aggregate_type.value());
+
+        let transaction = uow.transaction_mut();
+        let rows = query
+            .build()
+            .fetch_all(transaction.as_mut())
+            .await
+            .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            let aggregate_type: String = row
+                .try_get("aggregate_type")
+                .map_err(|e| RelationshipStoreError::MappingFailed(Box::new(e)))?;
+            let aggregate_type: AggregateTypeOwned = aggregate_type
+                .parse()
+                .map_err(|e| RelationshipStoreError::MappingFailed(Box::new(e)))?;
+            let aggregate_id: Uuid = row
+                .try_get("aggregate_id")
+                .map_err(|e| RelationshipStoreError::MappingFailed(Box::new(e)))?;
+            out.push(AggregateRef {
+                aggregate_type,
+                aggregate_id: AggregateIdValue::from(aggregate_id),
+            });
+        }
+
+        Ok(out)
+    }
+
+    async fn read_subjects_by_aggregate(
+        &self,
+        uow: &mut SqliteUnitOfWork,
+        aggregate: &AggregateRef,
+        relation: &RelationRefOwned,
+        subject_aggregate_type: Option<&AggregateTypeOwned>,
+    ) -> Result<Vec<RelationshipSubject>, RelationshipStoreError> {
+        let transaction = uow.transaction_mut();
+        let mut query = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT
+                id,
+                aggregate_type,
+                aggregate_id,
+                relation,
+                subject_aggregate_type,
+                subject_aggregate_id,
+                subject_relation,
+                subject_is_wildcard
+            FROM relationships
+            WHERE aggregate_type =
+            "#,
+        );
+        query.push_bind(aggregate.aggregate_type.value());
+        query.push(" AND aggregate_id = ");
+        query.push_bind(aggregate.aggregate_id.value());
+        query.push(" AND relation = ");
+        query.push_bind(relation.relation_name.value());
+
+        if let Some(subject_aggregate_type) = subject_aggregate_type {
+            query.push(" AND subject_aggregate_type = ");
+            query.push_bind(subject_aggregate_type.value());
+        }
+
+        let rows: Vec<SqliteRelationshipRow> = query
+            .build_query_as()
+            .fetch_all(transaction.as_mut())
+            .await
+            .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?;
+
+        let mut out: Vec<RelationshipSubject> = Vec::with_capacity(rows.len());
+
+        for row in rows {
+            let relationship = row
+                .try_into_relationship()
+                .map_err(|e| RelationshipStoreError::MappingFailed(Box::new(e)))?;
+            out.push(relationship.subject);
+        }
+
+        Ok(out)
+    }
+
+    async fn read_subjects_by_aggregates(
+        &self,
+        uow: &mut SqliteUnitOfWork,
+        aggregates: &[AggregateRef],
+        relation: &RelationRefOwned,
+        subject_aggregate_type: Option<&AggregateTypeOwned>,
+    ) -> Result<HashMap<AggregateRef, Vec<RelationshipSubject>>, RelationshipStoreError> {
+        const CHUNK_SIZE: usize = 1000;
+
+        let mut out: HashMap<AggregateRef, Vec<RelationshipSubject>> = aggregates
+            .iter()
+            .map(|aggregate| (aggregate.clone(), Vec::new()))
+            .collect();
+
+        let transaction = uow.transaction_mut();
+        for chunk in aggregates.chunks(CHUNK_SIZE) {
+            let mut query = QueryBuilder::<Sqlite>::new(
+                r#"
+                SELECT
+                    r.id,
+                    r.aggregate_type,
+                    r.aggregate_id,
+                    r.relation,
+                    r.subject_aggregate_type,
+                    r.subject_aggregate_id,
+                    r.subject_relation,
+                    r.subject_is_wildcard
+                FROM relationships r
+                JOIN (
+                "#,
+            );
+            query.push_values(chunk, |mut b, aggregate| {
+                b.push_bind(aggregate.aggregate_type.value())
+                    .push_bind(aggregate.aggregate_id.value());
+            });
+            query.push(
+                r#"
+                ) AS v
+                  ON r.aggregate_type = v.column1
+                 AND r.aggregate_id = v.column2
+                WHERE r.relation =
+                "#,
+            );
+            query.push_bind(relation.relation_name.value());
+
+            if let Some(subject_aggregate_type) = subject_aggregate_type {
+                query.push(" AND r.subject_aggregate_type = ");
+                query.push_bind(subject_aggregate_type.value());
+            }
+
+            let rows: Vec<SqliteRelationshipRow> = query
+                .build_query_as()
+                .fetch_all(transaction.as_mut())
+                .await
+                .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?;
+
+            for row in rows {
+                let relationship = row
+                    .try_into_relationship()
+                    .map_err(|e| RelationshipStoreError::MappingFailed(Box::new(e)))?;
+                out.entry(relationship.aggregate)
+                    .or_default()
+                    .push(relationship.subject);
+            }
+        }
+
+        Ok(out)
+    }
+
+    async fn read_revision(
+        &self,
+        uow: &mut SqliteUnitOfWork,
+    ) -> Result<RelationshipRevision, RelationshipStoreError> {
+        let transaction = uow.transaction_mut();
+        let revision: Option<i64> = sqlx::query_scalar(
+            r#"
+            SELECT revision
+            FROM relationship_revision
+            WHERE id = 1
+            "#,
+        )
+        .fetch_optional(transaction.as_mut())
+        .await
+        .map_err(|e| RelationshipStoreError::Persistence(Box::new(e)))?;
+
+        match revision {
+            Some(revision) => RelationshipRevision::try_from(revision)
+                .map_err(|e| RelationshipStoreError::MappingFailed(Box::new(e))),
+            None => Ok(RelationshipRevision::initial()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use appletheia_application::authorization::{
+        AggregateRef, RelationNameOwned, RelationRefOwned, Relationship, RelationshipChange,
+        RelationshipRevision, RelationshipStore, RelationshipSubject,
+    };
+    use appletheia_application::event::{AggregateIdValue, AggregateTypeOwned};
+    use appletheia_application::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+    use sqlx::sqlite::SqlitePoolOptions;
+    use uuid::Uuid;
+
+    use crate::core::migration::EventStoreMigrator;
+    use crate::sqlite::{SqliteEventStoreMigrator, SqliteUnitOfWorkFactory};
+
+    use super::SqliteRelationshipStore;
+
+    async fn factory() -> SqliteUnitOfWorkFactory {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("pool should connect");
+        SqliteEventStoreMigrator::new(pool.clone())
+            .run()
+            .await
+            .expect("migrations should run");
+        SqliteUnitOfWorkFactory::new(pool)
+    }
+
+    fn aggregate(aggregate_type: &str) -> AggregateRef {
+        AggregateRef::new(
+            AggregateTypeOwned::try_from(aggregate_type).expect("aggregate type should be valid"),
+            AggregateIdValue::from(Uuid::now_v7()),
+        )
+    }
+
+    fn relation(relation_name: &str) -> RelationRefOwned {
+        RelationRefOwned::new(
+            AggregateTypeOwned::try_from("document").expect("aggregate type should be valid"),
+            RelationNameOwned::try_from(relation_name).expect("relation name should be valid"),
+        )
+    }
+
+    fn relationship(
+        aggregate: &AggregateRef,
+        relation_name: &str,
+        subject: RelationshipSubject,
+    ) -> Relationship {
+        Relationship {
+            aggregate: aggregate.clone(),
+            relation: relation(relation_name),
+            subject,
+        }
+    }
+
+    #[tokio::test]
+    async fn apply_changes_persists_relationships_and_bumps_revision() {
+        let factory = factory().await;
+        let store = SqliteRelationshipStore::new();
+        let document = aggregate("document");
+        let user = RelationshipSubject::Aggregate(aggregate("user"));
+        let everyone = RelationshipSubject::Wildcard {
+            aggregate_type: AggregateTypeOwned::try_from("user")
+                .expect("aggregate type should be valid"),
+        };
+
+        let mut uow = factory.begin().await.expect("begin should succeed");
+        store
+            .apply_changes(
+                &mut uow,
+                &[
+                    RelationshipChange::Upsert(relationship(&document, "viewer", user.clone())),
+                    RelationshipChange::Upsert(relationship(&document, "viewer", everyone.clone())),
+                ],
+            )
+            .await
+            .expect("apply should succeed");
+        uow.commit().await.expect("commit should succeed");
+
+        let mut uow = factory.begin().await.expect("begin should succeed");
+        let mut subjects = store
+            .read_subjects_by_aggregates(
+                &mut uow,
+                std::slice::from_ref(&document),
+                &relation("viewer"),
+                None,
+            )
+            .await
+            .expect("read should succeed")
+            .remove(&document)
+            .expect("requested aggregate should be present");
+        subjects.sort_by_key(|subject| matches!(subject, RelationshipSubject::Wildcard { .. }));
+        assert_eq!(subjects, vec![user.clone(), everyone.clone()]);
+        assert_eq!(
+            store
+                .read_aggregates_by_subject(&mut uow, &everyone, &relation("viewer"))
+                .await
+                .expect("read should succeed"),
+            vec![document.clone()]
+        );
+        let revision = store
+            .read_revision(&mut uow)
+            .await
+            .expect("revision should be readable");
+        assert!(revision > RelationshipRevision::initial());
+
+        store
+            .apply_changes(
+                &mut uow,
+                &[RelationshipChange::Delete(relationship(
+                    &document, "viewer", everyone,
+                ))],
+            )
+            .await
+            .expect("apply should succeed");
+        assert_eq!(
+            store
+                .read_subjects_by_aggregate(&mut uow, &document, &relation("viewer"), None)
+                .await
+                .expect("read should succeed"),
+            vec![user]
+        );
+        assert!(
+            store
+                .read_revision(&mut uow)
+                .await
+                .expect("revision should be readable")
+                > revision
+        );
+    }
+
+    #[tokio::test]
+    async fn apply_changes_keeps_revision_when_nothing_changes() {
+        let factory = factory().await;
+        let store = SqliteRelationshipStore::new();
+        let document = aggregate("document");
+        let user = RelationshipSubject::Aggregate(aggregate("user"));
+
+        let mut uow = factory.begin().await.expect("begin should succeed");
+        store
+            .apply_changes(
+                &mut uow,
+                &[RelationshipChange::Delete(relationship(
+                    &document, "viewer", user,
+                ))],
+            )
+            .await
+            .expect("apply should succeed");
+
+        assert_eq!(
+            store
+                .read_revision(&mut uow)
+                .await
+                .expect("revision should be readable"),
+            RelationshipRevision::initial()
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod sqlite_idempotency_row;
+pub mod sqlite_idempotency_service;
+
+pub use sqlite_idempotency_service::SqliteIdempotencyService;
@@ -0,0 +1,204 @@
+use appletheia_application::command::CommandFailureReport;
+use appletheia_application::command::{
+    CommandHash, CommandName, IdempotencyBeginResult, IdempotencyId, IdempotencyOutput,
+    IdempotencyService, IdempotencyServiceError, IdempotencyState,
+};
+use appletheia_application::request_context::MessageId;
+
+use crate::sqlite::unit_of_work::SqliteUnitOfWork;
+
+use super::sqlite_idempotency_row::IdempotencyRow;
+
+#[derive(Debug)]
+pub struct SqliteIdempotencyService;
+
+impl SqliteIdempotencyService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn is_in_progress_lock_error(source: &sqlx::Error) -> bool {
+        let Some(db_error) = source.as_database_error() else {
+            return false;
+        };
+
+        // SQLite reports extended result codes; the low byte is the primary code.
+        // - 5: SQLITE_BUSY (the busy timeout elapsed while another connection held the lock)
+        // - 6: SQLITE_LOCKED (a conflicting lock within the same shared cache)
+        // For our use case, treat these as "someone else is already processing it".
+        let Some(code) = db_error.code().and_then(|code| code.parse::<i32>().ok()) else {
+            return false;
+        };
+        matches!(code & 0xff, 5 | 6)
+    }
+}
+
+impl Default for SqliteIdempotencyService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdempotencyService for SqliteIdempotencyService {
+    type Uow = SqliteUnitOfWork;
+
+    async fn begin(
+        &self,
+        uow: &mut Self::Uow,
+        message_id: MessageId,
+        command_name: CommandName,
+        command_hash: &CommandHash,
+    ) -> Result<IdempotencyBeginResult, IdempotencyServiceError> {
+        let transaction = uow.transaction_mut();
+
+        let message_id_value = message_id.value();
+        let command_name_value = command_name.to_string();
+        let command_hash_value = command_hash.as_str();
+        let id_value = IdempotencyId::new().value();
+
+        let insert_result = sqlx::query(
+            r#"
+            INSERT INTO idempotency (
+              id,
+              message_id,
+              command_name,
+              command_hash
+            ) VALUES (
+              $1,
+              $2,
+              $3,
+              $4
+            )
+            ON CONFLICT (message_id) DO NOTHING
+            "#,
+        )
+        .bind(id_value)
+        .bind(message_id_value)
+        .bind(&command_name_value)
+        .bind(command_hash_value)
+        .execute(transaction.as_mut())
+        .await;
+
+        match insert_result {
+            Ok(done) if done.rows_affected() == 1 => return Ok(IdempotencyBeginResult::New),
+            Ok(_) => {}
+            Err(source) if Self::is_in_progress_lock_error(&source) => {
+                return Ok(IdempotencyBeginResult::InProgress);
+            }
+            Err(source) => return Err(IdempotencyServiceError::Persistence(Box::new(source))),
+        }
+
+        let row: IdempotencyRow = sqlx::query_as(
+            r#"
+            SELECT
+              id,
+              command_name,
+              command_hash,
+              completed_at,
+              output,
+              error
+            FROM idempotency
+            WHERE message_id = $1
+            "#,
+        )
+        .bind(message_id_value)
+        .fetch_one(transaction.as_mut())
+        .await
+        .map_err(|source| IdempotencyServiceError::Persistence(Box::new(source)))?;
+
+        let _idempotency_id = IdempotencyId::try_from(row.id)
+            .map_err(|source| IdempotencyServiceError::Persistence(Box::new(source)))?;
+
+        if row.command_name != command_name_value || row.command_hash != command_hash_value {
+            return Err(IdempotencyServiceError::Conflict { message_id });
+        }
+
+        match row.completed_at {
+            None => Ok(IdempotencyBeginResult::InProgress),
+            Some(_) => match (row.output, row.error) {
+                (Some(output), None) => Ok(IdempotencyBeginResult::Existing {
+                    state: IdempotencyState::Succeeded {
+                        output: IdempotencyOutput::from(output),
+                    },
+                }),
+                (None, Some(error)) => {
+                    let error = serde_json::from_value(error)
+                        .map_err(|source| IdempotencyServiceError::Persistence(Box::new(source)))?;
+                    Ok(IdempotencyBeginResult::Existing {
+                        state: IdempotencyState::Failed { error },
+                    })
+                }
+                _ => Err(IdempotencyServiceError::InvalidStateTransition),
+            },
+        }
+    }
+
+    async fn complete_success(
+        &self,
+        uow: &mut Self::Uow,
+        message_id: MessageId,
+        output: IdempotencyOutput,
+    ) -> Result<(), IdempotencyServiceError> {
+        let transaction = uow.transaction_mut();
+
+        let message_id_value = message_id.value();
+
+        let updated = sqlx::query(
+            r#"
+            UPDATE idempotency
+               SET completed_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'),
+                   output = $2,
+                   error = NULL
+             WHERE message_id = $1
+               AND completed_at IS NULL
+            "#,
+        )
+        .bind(message_id_value)
+        .bind(serde_json::Value::from(output))
+        .execute(transaction.as_mut())
+        .await
+        .map_err(|source| IdempotencyServiceError::Persistence(Box::new(source)))?;
+
+        if updated.rows_affected() != 1 {
+            return Err(IdempotencyServiceError::InvalidStateTransition);
+        }
+
+        Ok(())
+    }
+
+    async fn complete_failure(
+        &self,
+        uow: &mut Self::Uow,
+        message_id: MessageId,
+        error: CommandFailureReport,
+    ) -> Result<(), IdempotencyServiceError> {
+        let transaction = uow.transaction_mut();
+
+        let message_id_value = message_id.value();
+
+        let error_json = serde_json::to_value(error)
+            .map_err(|source| IdempotencyServiceError::Persistence(Box::new(source)))?;
+
+        let updated = sqlx::query(
+            r#"
+            UPDATE idempotency
+               SET completed_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'),
+                   output = NULL,
+                   error = $2
+             WHERE message_id = $1
+               AND completed_at IS NULL
+            "#,
+        )
+        .bind(message_id_value)
+        .bind(error_json)
+        .execute(transaction.as_mut())
+        .await
+        .map_err(|source| IdempotencyServiceError::Persistence(Box::new(source)))?;
+
+        if updated.rows_affected() != 1 {
+            return Err(IdempotencyServiceError::InvalidStateTransition);
+        }
+
+        Ok(())
+    }
+}
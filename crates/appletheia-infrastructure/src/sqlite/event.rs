@@ -0,0 +1,11 @@
+pub mod sqlite_event_lookup;
+pub mod sqlite_event_reader;
+pub mod sqlite_event_row;
+pub mod sqlite_event_row_error;
+pub mod sqlite_event_writer;
+
+pub use sqlite_event_lookup::SqliteEventLookup;
+pub use sqlite_event_reader::SqliteEventReader;
+pub use sqlite_event_row::SqliteEventRow;
+pub use sqlite_event_row_error::SqliteEventRowError;
+pub use sqlite_event_writer::SqliteEventWriter;
@@ -0,0 +1,5 @@
+pub mod sqlite_event_store_migrator;
+pub mod sqlite_event_store_migrator_error;
+
+pub use sqlite_event_store_migrator::*;
+pub use sqlite_event_store_migrator_error::*;
@@ -0,0 +1,25 @@
+use crate::core::migration::EventStoreMigrator;
+use sqlx::SqlitePool;
+
+use super::sqlite_event_store_migrator_error::SqliteEventStoreMigratorError;
+
+pub struct SqliteEventStoreMigrator {
+    pool: SqlitePool,
+}
+
+impl SqliteEventStoreMigrator {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+impl EventStoreMigrator for SqliteEventStoreMigrator {
+    type Error = SqliteEventStoreMigratorError;
+
+    async fn run(&self) -> Result<(), Self::Error> {
+        sqlx::migrate!("./migrations/sqlite")
+            .run(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+use sqlx::migrate::MigrateError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SqliteEventStoreMigratorError {
+    #[error("migrate error: {0}")]
+    Migrate(#[from] MigrateError),
+}
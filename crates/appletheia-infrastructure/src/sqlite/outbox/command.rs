@@ -0,0 +1,13 @@
+mod sqlite_command_outbox_dead_letter_row;
+mod sqlite_command_outbox_dead_letter_row_error;
+pub mod sqlite_command_outbox_enqueuer;
+pub mod sqlite_command_outbox_fetcher;
+pub mod sqlite_command_outbox_row;
+pub mod sqlite_command_outbox_row_error;
+pub mod sqlite_command_outbox_writer;
+
+pub use sqlite_command_outbox_enqueuer::SqliteCommandOutboxEnqueuer;
+pub use sqlite_command_outbox_fetcher::SqliteCommandOutboxFetcher;
+pub use sqlite_command_outbox_row::SqliteCommandOutboxRow;
+pub use sqlite_command_outbox_row_error::SqliteCommandOutboxRowError;
+pub use sqlite_command_outbox_writer::SqliteCommandOutboxWriter;
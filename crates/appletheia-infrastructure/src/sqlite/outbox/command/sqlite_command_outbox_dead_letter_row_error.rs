@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+use super::SqliteCommandOutboxRowError;
+
+#[derive(Debug, Error)]
+pub enum SqliteCommandOutboxDeadLetterRowError {
+    #[error("command outbox row error: {0}")]
+    Outbox(#[from] SqliteCommandOutboxRowError),
+}
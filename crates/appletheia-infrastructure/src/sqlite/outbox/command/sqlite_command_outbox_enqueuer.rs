@@ -0,0 +1,92 @@
+use sqlx::{QueryBuilder, Sqlite};
+use uuid::Uuid;
+
+use crate::sqlite::unit_of_work::SqliteUnitOfWork;
+use appletheia_application::outbox::command::{
+    CommandEnvelope, CommandOutboxEnqueueError, CommandOutboxEnqueuer,
+};
+
+pub struct SqliteCommandOutboxEnqueuer;
+
+impl SqliteCommandOutboxEnqueuer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for SqliteCommandOutboxEnqueuer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandOutboxEnqueuer for SqliteCommandOutboxEnqueuer {
+    type Uow = SqliteUnitOfWork;
+
+    async fn enqueue_commands(
+        &self,
+        uow: &mut Self::Uow,
+        commands: &[CommandEnvelope],
+    ) -> Result<(), CommandOutboxEnqueueError> {
+        if commands.is_empty() {
+            return Ok(());
+        }
+
+        let transaction = uow.transaction_mut();
+
+        let mut query_builder = QueryBuilder::<Sqlite>::new(
+            r#"
+            INSERT INTO command_outbox (
+              id,
+              message_id,
+              command_name,
+              payload,
+              correlation_id,
+              causation_id,
+              options,
+              trace_context
+            ) VALUES
+            "#,
+        );
+
+        {
+            let mut separated = query_builder.separated(", ");
+            for command in commands {
+                let id_value = Uuid::now_v7();
+                let message_id_value = command.message_id.value();
+                let command_name_value = command.command_name.value();
+                let payload_value = command.command.value().clone();
+                let correlation_id_value = command.correlation_id.value();
+                let causation_id_value = command.causation_id.value();
+                let options_value = serde_json::to_value(&command.options)
+                    .map_err(|source| CommandOutboxEnqueueError::Persistence(Box::new(source)))?;
+                let trace_context_value = command
+                    .trace_context
+                    .as_ref()
+                    .map(serde_json::to_value)
+                    .transpose()
+                    .map_err(|source| CommandOutboxEnqueueError::Persistence(Box::new(source)))?;
+
+                separated
+                    .push("(")
+                    .push_bind_unseparated(id_value)
+                    .push_bind(message_id_value)
+                    .push_bind(command_name_value)
+                    .push_bind(payload_value)
+                    .push_bind(correlation_id_value)
+                    .push_bind(causation_id_value)
+                    .push_bind(options_value)
+                    .push_bind(trace_context_value)
+                    .push_unseparated(")");
+            }
+        }
+
+        query_builder
+            .build()
+            .execute(transaction.as_mut())
+            .await
+            .map_err(|source| CommandOutboxEnqueueError::Persistence(Box::new(source)))?;
+
+        Ok(())
+    }
+}
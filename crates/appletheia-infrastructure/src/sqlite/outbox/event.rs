@@ -0,0 +1,11 @@
+mod sqlite_event_outbox_dead_letter_row;
+mod sqlite_event_outbox_dead_letter_row_error;
+pub mod sqlite_event_outbox_fetcher;
+pub mod sqlite_event_outbox_row;
+pub mod sqlite_event_outbox_row_error;
+pub mod sqlite_event_outbox_writer;
+
+pub use sqlite_event_outbox_fetcher::SqliteEventOutboxFetcher;
+pub use sqlite_event_outbox_row::SqliteEventOutboxRow;
+pub use sqlite_event_outbox_row_error::SqliteEventOutboxRowError;
+pub use sqlite_event_outbox_writer::SqliteEventOutboxWriter;
@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+use super::SqliteEventOutboxRowError;
+
+#[derive(Debug, Error)]
+pub enum SqliteEventOutboxDeadLetterRowError {
+    #[error("event outbox row error: {0}")]
+    Outbox(#[from] SqliteEventOutboxRowError),
+}
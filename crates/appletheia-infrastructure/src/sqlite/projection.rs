@@ -0,0 +1,9 @@
+pub mod sqlite_event_feed_reader;
+pub mod sqlite_projection_checkpoint_row;
+pub mod sqlite_projection_checkpoint_store;
+pub mod sqlite_projector_processed_event_row;
+pub mod sqlite_projector_processed_event_store;
+
+pub use sqlite_event_feed_reader::SqliteEventFeedReader;
+pub use sqlite_projection_checkpoint_store::SqliteProjectionCheckpointStore;
+pub use sqlite_projector_processed_event_store::SqliteProjectorProcessedEventStore;
@@ -0,0 +1,294 @@
+use sqlx::{QueryBuilder, Sqlite};
+
+use appletheia_application::event::{
+    EventEnvelope, EventFeedBatchSize, EventFeedReader, EventFeedReaderError, EventSelector,
+    EventSequence,
+};
+use appletheia_application::messaging::Subscription;
+
+use crate::sqlite::event::{SqliteEventRow, SqliteEventRowError};
+use crate::sqlite::unit_of_work::SqliteUnitOfWork;
+
+#[derive(Debug)]
+pub struct SqliteEventFeedReader;
+
+impl SqliteEventFeedReader {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for SqliteEventFeedReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventFeedReader for SqliteEventFeedReader {
+    type Uow = SqliteUnitOfWork;
+
+    async fn read_after(
+        &self,
+        uow: &mut Self::Uow,
+        after: Option<EventSequence>,
+        limit: EventFeedBatchSize,
+        subscription: Subscription<'_, EventSelector>,
+    ) -> Result<Vec<EventEnvelope>, EventFeedReaderError> {
+        enum Selectors<'a> {
+            AnyOf(&'a [EventSelector]),
+            One(&'a EventSelector),
+        }
+
+        let selectors = match subscription {
+            Subscription::All => None,
+            Subscription::AnyOf([]) => {
+                return Err(EventFeedReaderError::InvalidSubscription);
+            }
+            Subscription::AnyOf(selectors) => Some(Selectors::AnyOf(selectors)),
+            Subscription::One(selector) => Some(Selectors::One(selector)),
+        };
+
+        let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(
+            r#"
+            SELECT
+                event_sequence, id, aggregate_type, aggregate_id, aggregate_version,
+                event_name, payload, occurred_at, correlation_id, causation_id, context
+            FROM events
+            "#,
+        );
+
+        let mut has_where = false;
+
+        if let Some(after) = after {
+            query
+                .push(" WHERE event_sequence > ")
+                .push_bind(after.value());
+            has_where = true;
+        }
+
+        if let Some(selectors) = selectors {
+            query.push(if has_where { " AND (" } else { " WHERE (" });
+            match selectors {
+                Selectors::AnyOf(selectors) => {
+                    let mut separated = query.separated(" OR ");
+                    for selector in selectors {
+                        separated
+                            .push("(aggregate_type = ")
+                            .push_bind_unseparated(selector.aggregate_type.value())
+                            .push_unseparated(" AND event_name = ")
+                            .push_bind_unseparated(selector.event_name.value())
+                            .push_unseparated(")");
+                    }
+                    separated.push_unseparated(")");
+                }
+                Selectors::One(selector) => {
+                    query
+                        .push("(aggregate_type = ")
+                        .push_bind(selector.aggregate_type.value())
+                        .push(" AND event_name = ")
+                        .push_bind(selector.event_name.value())
+                        .push("))");
+                }
+            }
+        }
+
+        query
+            .push(" ORDER BY event_sequence ASC LIMIT ")
+            .push_bind(limit.as_i64());
+
+        let transaction = uow.transaction_mut();
+
+        let event_rows = query
+            .build_query_as::<SqliteEventRow>()
+            .fetch_all(transaction.as_mut())
+            .await
+            .map_err(|e| EventFeedReaderError::Persistence(Box::new(e)))?;
+
+        let envelopes = event_rows
+            .into_iter()
+            .map(|row| row.try_into_event_envelope())
+            .collect::<Result<Vec<_>, SqliteEventRowError>>()
+            .map_err(|e| EventFeedReaderError::Persistence(Box::new(e)))?;
+
+        Ok(envelopes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use appletheia_application::event::{
+        EventEnvelope, EventFeedBatchSize, EventFeedReader, EventSelector, EventSequence,
+    };
+    use appletheia_application::messaging::Subscription;
+    use appletheia_application::request_context::{
+        CorrelationId, MessageId, Principal, RequestContext,
+    };
+    use appletheia_application::unit_of_work::UnitOfWorkFactory;
+    use appletheia_domain::{AggregateType, EventName};
+    use chrono::Utc;
+    use sqlx::SqlitePool;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use uuid::Uuid;
+
+    use crate::core::migration::EventStoreMigrator;
+    use crate::sqlite::{SqliteEventStoreMigrator, SqliteUnitOfWorkFactory};
+
+    use super::SqliteEventFeedReader;
+
+    const ORDER_PLACED: EventSelector =
+        EventSelector::new(AggregateType::new("order"), EventName::new("placed"));
+    const USER_RENAMED: EventSelector =
+        EventSelector::new(AggregateType::new("user"), EventName::new("renamed"));
+
+    async fn pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("pool should connect");
+        SqliteEventStoreMigrator::new(pool.clone())
+            .run()
+            .await
+            .expect("migrations should run");
+        pool
+    }
+
+    async fn insert_event(
+        pool: &SqlitePool,
+        aggregate_type: &str,
+        aggregate_id: Uuid,
+        version: i64,
+        event_name: &str,
+    ) {
+        let correlation_id = Uuid::now_v7();
+        let message_id = MessageId::new();
+        let context = RequestContext::new(
+            CorrelationId::from(correlation_id),
+            message_id,
+            Principal::System,
+        )
+        .expect("request context should be valid");
+
+        sqlx::query(
+            r#"
+            INSERT INTO events (
+                id, aggregate_type, aggregate_id, aggregate_version,
+                event_name, payload, occurred_at, correlation_id, causation_id, context
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+        )
+        .bind(Uuid::now_v7())
+        .bind(aggregate_type)
+        .bind(aggregate_id)
+        .bind(version)
+        .bind(event_name)
+        .bind(serde_json::json!({ "version": version }))
+        .bind(Utc::now())
+        .bind(correlation_id)
+        .bind(message_id.value())
+        .bind(serde_json::to_value(&context).expect("context should serialize"))
+        .execute(pool)
+        .await
+        .expect("insert should succeed");
+    }
+
+    async fn read_after(
+        factory: &SqliteUnitOfWorkFactory,
+        after: Option<EventSequence>,
+        limit: u32,
+        subscription: Subscription<'_, EventSelector>,
+    ) -> Vec<EventEnvelope> {
+        let mut uow = factory.begin().await.expect("begin should succeed");
+        SqliteEventFeedReader::new()
+            .read_after(
+                &mut uow,
+                after,
+                EventFeedBatchSize::new(NonZeroU32::new(limit).expect("limit should be non-zero")),
+                subscription,
+            )
+            .await
+            .expect("read should succeed")
+    }
+
+    fn names(envelopes: &[EventEnvelope]) -> Vec<(String, i64)> {
+        envelopes
+            .iter()
+            .map(|envelope| {
+                (
+                    format!("{}.{}", envelope.aggregate_type, envelope.event_name),
+                    envelope.aggregate_version.value(),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn read_after_pages_through_events_of_several_aggregates() {
+        let pool = pool().await;
+        let order_id = Uuid::now_v7();
+        let user_id = Uuid::now_v7();
+        insert_event(&pool, "order", order_id, 1, "placed").await;
+        insert_event(&pool, "user", user_id, 1, "renamed").await;
+        insert_event(&pool, "order", order_id, 2, "shipped").await;
+        insert_event(&pool, "user", user_id, 2, "renamed").await;
+        let factory = SqliteUnitOfWorkFactory::new(pool);
+
+        let first_page = read_after(&factory, None, 3, Subscription::All).await;
+        let cursor = first_page.last().map(|envelope| envelope.event_sequence);
+        let second_page = read_after(&factory, cursor, 3, Subscription::All).await;
+
+        assert_eq!(
+            names(&first_page),
+            [
+                ("order.placed".to_owned(), 1),
+                ("user.renamed".to_owned(), 1),
+                ("order.shipped".to_owned(), 2),
+            ]
+        );
+        assert_eq!(names(&second_page), [("user.renamed".to_owned(), 2)]);
+    }
+
+    #[tokio::test]
+    async fn read_after_combines_cursor_with_any_of_selectors() {
+        let pool = pool().await;
+        let order_id = Uuid::now_v7();
+        let user_id = Uuid::now_v7();
+        insert_event(&pool, "order", order_id, 1, "placed").await;
+        insert_event(&pool, "user", user_id, 1, "renamed").await;
+        insert_event(&pool, "order", order_id, 2, "shipped").await;
+        insert_event(&pool, "user", user_id, 2, "renamed").await;
+        insert_event(&pool, "order", Uuid::now_v7(), 1, "placed").await;
+        let factory = SqliteUnitOfWorkFactory::new(pool);
+        let selectors = [ORDER_PLACED, USER_RENAMED];
+
+        let all = read_after(&factory, None, 10, Subscription::AnyOf(&selectors)).await;
+        let after_first = read_after(
+            &factory,
+            Some(all[0].event_sequence),
+            10,
+            Subscription::AnyOf(&selectors),
+        )
+        .await;
+        let one = read_after(
+            &factory,
+            Some(all[0].event_sequence),
+            10,
+            Subscription::One(&ORDER_PLACED),
+        )
+        .await;
+
+        assert_eq!(
+            names(&all),
+            [
+                ("order.placed".to_owned(), 1),
+                ("user.renamed".to_owned(), 1),
+                ("user.renamed".to_owned(), 2),
+                ("order.placed".to_owned(), 1),
+            ]
+        );
+        assert_eq!(names(&after_first), names(&all[1..]));
+        assert_eq!(names(&one), [("order.placed".to_owned(), 1)]);
+    }
+}
@@ -0,0 +1,150 @@
+use appletheia_application::projection::{
+    ProjectorNameOwned, ProjectorProcessedEventId, ProjectorProcessedEventStore,
+    ProjectorProcessedEventStoreError,
+};
+use appletheia_domain::EventId;
+use sqlx::{QueryBuilder, Sqlite};
+
+use crate::sqlite::unit_of_work::SqliteUnitOfWork;
+
+use super::sqlite_projector_processed_event_row::SqliteProjectorProcessedEventRow;
+
+#[derive(Debug)]
+pub struct SqliteProjectorProcessedEventStore;
+
+impl SqliteProjectorProcessedEventStore {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for SqliteProjectorProcessedEventStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProjectorProcessedEventStore for SqliteProjectorProcessedEventStore {
+    type Uow = SqliteUnitOfWork;
+
+    async fn are_all_processed(
+        &self,
+        uow: &mut Self::Uow,
+        projector_name: ProjectorNameOwned,
+        event_ids: &[EventId],
+    ) -> Result<bool, ProjectorProcessedEventStoreError> {
+        if event_ids.is_empty() {
+            return Ok(true);
+        }
+
+        let transaction = uow.transaction_mut();
+        let projector_name_value = projector_name.value();
+        let mut query = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT count(DISTINCT event_id)
+              FROM projector_processed_events
+             WHERE projector_name =
+            "#,
+        );
+        query.push_bind(projector_name_value);
+        query.push(" AND event_id IN (");
+        {
+            let mut separated = query.separated(", ");
+            for event_id in event_ids {
+                separated.push_bind(event_id.value());
+            }
+        }
+        query.push(")");
+
+        let processed_count: i64 = query
+            .build_query_scalar()
+            .fetch_one(transaction.as_mut())
+            .await
+            .map_err(|source| ProjectorProcessedEventStoreError::Persistence(Box::new(source)))?;
+        let all_processed = processed_count == event_ids.len() as i64;
+
+        Ok(all_processed)
+    }
+
+    async fn is_processed(
+        &self,
+        uow: &mut Self::Uow,
+        projector_name: ProjectorNameOwned,
+        event_id: EventId,
+    ) -> Result<bool, ProjectorProcessedEventStoreError> {
+        let transaction = uow.transaction_mut();
+
+        let projector_name_value = projector_name.value();
+        let event_id_value = event_id.value();
+
+        let row: Option<SqliteProjectorProcessedEventRow> = sqlx::query_as(
+            r#"
+            SELECT
+              id,
+              projector_name,
+              event_id,
+              processed_at
+              FROM projector_processed_events
+             WHERE projector_name = $1
+               AND event_id = $2
+            "#,
+        )
+        .bind(projector_name_value)
+        .bind(event_id_value)
+        .fetch_optional(transaction.as_mut())
+        .await
+        .map_err(|source| ProjectorProcessedEventStoreError::Persistence(Box::new(source)))?;
+
+        Ok(row.is_some())
+    }
+
+    async fn mark_processed(
+        &self,
+        uow: &mut Self::Uow,
+        projector_name: ProjectorNameOwned,
+        event_id: EventId,
+    ) -> Result<bool, ProjectorProcessedEventStoreError> {
+        let transaction = uow.transaction_mut();
+
+        let projector_name_value = projector_name.value();
+        let event_id_value = event_id.value();
+        let id_value = ProjectorProcessedEventId::new().value();
+
+        let done = sqlx::query(
+            r#"
+            INSERT INTO projector_processed_events (id, projector_name, event_id)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (projector_name, event_id) DO NOTHING
+            "#,
+        )
+        .bind(id_value)
+        .bind(projector_name_value)
+        .bind(event_id_value)
+        .execute(transaction.as_mut())
+        .await
+        .map_err(|source| ProjectorProcessedEventStoreError::Persistence(Box::new(source)))?;
+
+        Ok(done.rows_affected() == 1)
+    }
+
+    async fn reset(
+        &self,
+        uow: &mut Self::Uow,
+        projector_name: ProjectorNameOwned,
+    ) -> Result<(), ProjectorProcessedEventStoreError> {
+        let transaction = uow.transaction_mut();
+
+        sqlx::query(
+            r#"
+            DELETE FROM projector_processed_events
+             WHERE projector_name = $1
+            "#,
+        )
+        .bind(projector_name.value())
+        .execute(transaction.as_mut())
+        .await
+        .map_err(|source| ProjectorProcessedEventStoreError::Persistence(Box::new(source)))?;
+
+        Ok(())
+    }
+}
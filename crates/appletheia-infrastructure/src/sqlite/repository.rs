@@ -0,0 +1,9 @@
+pub mod sqlite_repository;
+pub mod sqlite_unique_key_reservation_store;
+pub mod sqlite_unique_reservation_row;
+pub mod sqlite_unique_value_owner_lookup;
+
+pub use sqlite_repository::SqliteRepository;
+pub use sqlite_unique_key_reservation_store::SqliteUniqueKeyReservationStore;
+pub use sqlite_unique_reservation_row::SqliteUniqueReservationRow;
+pub use sqlite_unique_value_owner_lookup::SqliteUniqueValueOwnerLookup;
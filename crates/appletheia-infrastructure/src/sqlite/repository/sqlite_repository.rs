@@ -0,0 +1,17 @@
+use appletheia_application::repository::DefaultRepository;
+
+use crate::sqlite::event::{SqliteEventReader, SqliteEventWriter};
+use crate::sqlite::repository::{SqliteUniqueKeyReservationStore, SqliteUniqueValueOwnerLookup};
+use crate::sqlite::snapshot::{SqliteSnapshotReader, SqliteSnapshotWriter};
+use crate::sqlite::unit_of_work::SqliteUnitOfWork;
+
+pub type SqliteRepository<A> = DefaultRepository<
+    A,
+    SqliteEventReader<A>,
+    SqliteEventWriter<A>,
+    SqliteSnapshotReader<A>,
+    SqliteSnapshotWriter<A>,
+    SqliteUniqueValueOwnerLookup,
+    SqliteUniqueKeyReservationStore,
+    SqliteUnitOfWork,
+>;
@@ -0,0 +1,11 @@
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash, FromRow)]
+pub struct SqliteUniqueReservationRow {
+    pub id: Uuid,
+    pub aggregate_type: String,
+    pub owner_aggregate_id: Uuid,
+    pub namespace: String,
+    pub normalized_value: String,
+}
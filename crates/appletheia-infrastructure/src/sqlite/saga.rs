@@ -0,0 +1,7 @@
+pub mod sqlite_saga_processed_event_row;
+pub mod sqlite_saga_processed_event_store;
+mod sqlite_saga_run_row;
+pub mod sqlite_saga_run_store;
+
+pub use sqlite_saga_processed_event_store::SqliteSagaProcessedEventStore;
+pub use sqlite_saga_run_store::SqliteSagaRunStore;
@@ -0,0 +1,68 @@
+use appletheia_application::saga::{
+    SagaNameOwned, SagaProcessedEventId, SagaProcessedEventStore, SagaProcessedEventStoreError,
+};
+use appletheia_domain::EventId;
+
+use crate::sqlite::unit_of_work::SqliteUnitOfWork;
+
+use super::sqlite_saga_processed_event_row::SqliteSagaProcessedEventRow;
+
+#[derive(Debug)]
+pub struct SqliteSagaProcessedEventStore;
+
+impl SqliteSagaProcessedEventStore {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for SqliteSagaProcessedEventStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SagaProcessedEventStore for SqliteSagaProcessedEventStore {
+    type Uow = SqliteUnitOfWork;
+
+    async fn mark_processed(
+        &self,
+        uow: &mut Self::Uow,
+        saga_name: SagaNameOwned,
+        event_id: EventId,
+    ) -> Result<bool, SagaProcessedEventStoreError> {
+        let transaction = uow.transaction_mut();
+
+        let saga_name_value = saga_name.value();
+        let event_id_value = event_id.value();
+        let id_value = SagaProcessedEventId::new().value();
+
+        let row: Option<SqliteSagaProcessedEventRow> = sqlx::query_as(
+            r#"
+            INSERT INTO saga_processed_events (
+              id,
+              saga_name,
+              event_id
+            ) VALUES (
+              $1,
+              $2,
+              $3
+            )
+            ON CONFLICT (saga_name, event_id) DO NOTHING
+            RETURNING
+              id,
+              saga_name,
+              event_id,
+              processed_at
+            "#,
+        )
+        .bind(id_value)
+        .bind(saga_name_value)
+        .bind(event_id_value)
+        .fetch_optional(transaction.as_mut())
+        .await
+        .map_err(|source| SagaProcessedEventStoreError::Persistence(Box::new(source)))?;
+
+        Ok(row.is_some())
+    }
+}
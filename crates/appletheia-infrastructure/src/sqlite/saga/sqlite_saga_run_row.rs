@@ -0,0 +1,38 @@
+use std::error::Error;
+
+use serde::{Serialize, de::DeserializeOwned};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use appletheia_application::request_context::MessageId;
+use appletheia_application::saga::{SagaNameOwned, SagaRun, SagaRunId};
+use appletheia_domain::EventId;
+
+#[derive(Debug, FromRow)]
+pub struct SqliteSagaRunRow {
+    pub id: Uuid,
+    pub trigger_event_id: Uuid,
+    pub dispatched_command_message_id: Option<Uuid>,
+    pub context: serde_json::Value,
+}
+
+impl SqliteSagaRunRow {
+    pub fn try_into_run<C: Serialize + DeserializeOwned + Send + Sync + 'static>(
+        self,
+        saga_name: SagaNameOwned,
+    ) -> Result<SagaRun<C>, Box<dyn Error + Send + Sync>> {
+        let saga_run_id = SagaRunId::try_from(self.id)?;
+        let trigger_event_id = EventId::try_from(self.trigger_event_id)?;
+        let dispatched_command_message_id = self.dispatched_command_message_id.map(MessageId::from);
+
+        let context = serde_json::from_value(self.context)?;
+
+        Ok(SagaRun {
+            saga_run_id,
+            saga_name,
+            trigger_event_id,
+            dispatched_command_message_id,
+            context,
+        })
+    }
+}
@@ -0,0 +1,8 @@
+pub(crate) mod sqlite_snapshot_error;
+pub mod sqlite_snapshot_reader;
+pub(crate) mod sqlite_snapshot_row;
+pub(crate) mod sqlite_snapshot_writer;
+
+pub use sqlite_snapshot_reader::SqliteSnapshotReader;
+pub(crate) use sqlite_snapshot_row::SqliteSnapshotRow;
+pub(crate) use sqlite_snapshot_writer::SqliteSnapshotWriter;
@@ -0,0 +1,105 @@
+use appletheia_application::conformance::{
+    ConformanceAggregate, StorageConformanceHarness, StorageConformanceSuite,
+};
+
+use sqlx::sqlite::SqlitePoolOptions;
+
+use crate::core::migration::EventStoreMigrator;
+use crate::sqlite::command::SqliteIdempotencyService;
+use crate::sqlite::event::{SqliteEventReader, SqliteEventWriter};
+use crate::sqlite::outbox::event::{SqliteEventOutboxFetcher, SqliteEventOutboxWriter};
+use crate::sqlite::projection::SqliteEventFeedReader;
+use crate::sqlite::{
+    SqliteEventStoreMigrator, SqliteRelationshipStore, SqliteUniqueKeyReservationStore,
+    SqliteUnitOfWork, SqliteUnitOfWorkFactory,
+};
+
+struct SqliteConformanceHarness {
+    unit_of_work_factory: SqliteUnitOfWorkFactory,
+    event_writer: SqliteEventWriter<ConformanceAggregate>,
+    event_reader: SqliteEventReader<ConformanceAggregate>,
+    event_feed_reader: SqliteEventFeedReader,
+    event_outbox_fetcher: SqliteEventOutboxFetcher,
+    event_outbox_writer: SqliteEventOutboxWriter,
+    idempotency_service: SqliteIdempotencyService,
+    unique_key_reservation_store: SqliteUniqueKeyReservationStore,
+    relationship_store: SqliteRelationshipStore,
+}
+
+impl StorageConformanceHarness for SqliteConformanceHarness {
+    type Uow = SqliteUnitOfWork;
+    type UnitOfWorkFactory = SqliteUnitOfWorkFactory;
+    type EventWriter = SqliteEventWriter<ConformanceAggregate>;
+    type EventReader = SqliteEventReader<ConformanceAggregate>;
+    type EventFeedReader = SqliteEventFeedReader;
+    type EventOutboxFetcher = SqliteEventOutboxFetcher;
+    type EventOutboxWriter = SqliteEventOutboxWriter;
+    type IdempotencyService = SqliteIdempotencyService;
+    type UniqueKeyReservationStore = SqliteUniqueKeyReservationStore;
+    type RelationshipStore = SqliteRelationshipStore;
+
+    async fn setup() -> Self {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("pool should connect");
+        SqliteEventStoreMigrator::new(pool.clone())
+            .run()
+            .await
+            .expect("migrations should run");
+
+        Self {
+            unit_of_work_factory: SqliteUnitOfWorkFactory::new(pool),
+            event_writer: SqliteEventWriter::new(),
+            event_reader: SqliteEventReader::new(),
+            event_feed_reader: SqliteEventFeedReader::new(),
+            event_outbox_fetcher: SqliteEventOutboxFetcher::new(),
+            event_outbox_writer: SqliteEventOutboxWriter::new(),
+            idempotency_service: SqliteIdempotencyService::new(),
+            unique_key_reservation_store: SqliteUniqueKeyReservationStore::new(),
+            relationship_store: SqliteRelationshipStore::new(),
+        }
+    }
+
+    fn unit_of_work_factory(&self) -> &Self::UnitOfWorkFactory {
+        &self.unit_of_work_factory
+    }
+
+    fn event_writer(&self) -> &Self::EventWriter {
+        &self.event_writer
+    }
+
+    fn event_reader(&self) -> &Self::EventReader {
+        &self.event_reader
+    }
+
+    fn event_feed_reader(&self) -> &Self::EventFeedReader {
+        &self.event_feed_reader
+    }
+
+    fn event_outbox_fetcher(&self) -> &Self::EventOutboxFetcher {
+        &self.event_outbox_fetcher
+    }
+
+    fn event_outbox_writer(&self) -> &Self::EventOutboxWriter {
+        &self.event_outbox_writer
+    }
+
+    fn idempotency_service(&self) -> &Self::IdempotencyService {
+        &self.idempotency_service
+    }
+
+    fn unique_key_reservation_store(&self) -> &Self::UniqueKeyReservationStore {
+        &self.unique_key_reservation_store
+    }
+
+    fn relationship_store(&self) -> &Self::RelationshipStore {
+        &self.relationship_store
+    }
+}
+
+#[tokio::test]
+async fn sqlite_backend_passes_storage_conformance_suite() {
+    StorageConformanceSuite::<SqliteConformanceHarness>::run_all().await;
+}
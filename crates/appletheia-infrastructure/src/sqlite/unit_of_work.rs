@@ -0,0 +1,5 @@
+pub mod sqlite_unit_of_work;
+pub mod sqlite_unit_of_work_factory;
+
+pub use sqlite_unit_of_work::SqliteUnitOfWork;
+pub use sqlite_unit_of_work_factory::SqliteUnitOfWorkFactory;
@@ -0,0 +1,141 @@
+use appletheia_application::unit_of_work::{UnitOfWork, UnitOfWorkError};
+use sqlx::{Sqlite, Transaction};
+
+pub struct SqliteUnitOfWork {
+    transaction: Transaction<'static, Sqlite>,
+    savepoint_depth: u32,
+}
+
+impl SqliteUnitOfWork {
+    pub(super) fn new(transaction: Transaction<'static, Sqlite>) -> Self {
+        Self {
+            transaction,
+            savepoint_depth: 0,
+        }
+    }
+
+    pub fn transaction_mut(&mut self) -> &mut Transaction<'static, Sqlite> {
+        &mut self.transaction
+    }
+
+    fn savepoint_name(depth: u32) -> String {
+        format!("appletheia_savepoint_{depth}")
+    }
+
+    async fn execute_savepoint_statement(
+        &mut self,
+        statement: &str,
+    ) -> Result<(), UnitOfWorkError> {
+        sqlx::raw_sql(statement)
+            .execute(&mut *self.transaction)
+            .await
+            .map_err(|e| UnitOfWorkError::SavepointFailed(Box::new(e)))?;
+        Ok(())
+    }
+
+    fn active_savepoint(&self) -> Result<String, UnitOfWorkError> {
+        if self.savepoint_depth == 0 {
+            return Err(UnitOfWorkError::NoActiveSavepoint);
+        }
+        Ok(Self::savepoint_name(self.savepoint_depth))
+    }
+}
+
+impl UnitOfWork for SqliteUnitOfWork {
+    async fn commit(self) -> Result<(), UnitOfWorkError> {
+        self.transaction
+            .commit()
+            .await
+            .map_err(|e| UnitOfWorkError::CommitFailed(Box::new(e)))?;
+        Ok(())
+    }
+
+    async fn rollback(self) -> Result<(), UnitOfWorkError> {
+        self.transaction
+            .rollback()
+            .await
+            .map_err(|e| UnitOfWorkError::RollbackFailed(Box::new(e)))?;
+        Ok(())
+    }
+
+    async fn savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+        let name = Self::savepoint_name(self.savepoint_depth + 1);
+        self.execute_savepoint_statement(&format!("SAVEPOINT {name}"))
+            .await?;
+        self.savepoint_depth += 1;
+        Ok(())
+    }
+
+    async fn release_savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+        let name = self.active_savepoint()?;
+        self.execute_savepoint_statement(&format!("RELEASE SAVEPOINT {name}"))
+            .await?;
+        self.savepoint_depth -= 1;
+        Ok(())
+    }
+
+    async fn rollback_to_savepoint(&mut self) -> Result<(), UnitOfWorkError> {
+        let name = self.active_savepoint()?;
+        self.execute_savepoint_statement(&format!(
+            "ROLLBACK TO SAVEPOINT {name}; RELEASE SAVEPOINT {name}"
+        ))
+        .await?;
+        self.savepoint_depth -= 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use appletheia_application::unit_of_work::{UnitOfWork, UnitOfWorkError, UnitOfWorkFactory};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use crate::sqlite::SqliteUnitOfWorkFactory;
+
+    #[tokio::test]
+    async fn savepoints_roll_back_nested_changes_only() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("pool should connect");
+        let factory = SqliteUnitOfWorkFactory::new(pool);
+        let mut uow = factory.begin().await.expect("begin should succeed");
+
+        sqlx::raw_sql(
+            "CREATE TABLE entries (value INTEGER NOT NULL); INSERT INTO entries VALUES (1)",
+        )
+        .execute(&mut **uow.transaction_mut())
+        .await
+        .expect("setup should succeed");
+
+        uow.savepoint().await.expect("savepoint should succeed");
+        sqlx::query("INSERT INTO entries VALUES (2)")
+            .execute(&mut **uow.transaction_mut())
+            .await
+            .expect("insert should succeed");
+        uow.savepoint()
+            .await
+            .expect("nested savepoint should succeed");
+        sqlx::query("INSERT INTO entries VALUES (3)")
+            .execute(&mut **uow.transaction_mut())
+            .await
+            .expect("insert should succeed");
+        uow.rollback_to_savepoint()
+            .await
+            .expect("rollback to savepoint should succeed");
+        uow.release_savepoint()
+            .await
+            .expect("release should succeed");
+
+        let values: Vec<i64> = sqlx::query_scalar("SELECT value FROM entries ORDER BY value")
+            .fetch_all(&mut **uow.transaction_mut())
+            .await
+            .expect("select should succeed");
+        assert_eq!(values, vec![1, 2]);
+        assert!(matches!(
+            uow.rollback_to_savepoint().await,
+            Err(UnitOfWorkError::NoActiveSavepoint)
+        ));
+    }
+}
@@ -0,0 +1,31 @@
+use appletheia_application::unit_of_work::{UnitOfWorkFactory, UnitOfWorkFactoryError};
+use sqlx::SqlitePool;
+
+use super::sqlite_unit_of_work::SqliteUnitOfWork;
+
+/// Begins units of work as `BEGIN IMMEDIATE` transactions.
+///
+/// SQLite allows a single writer at a time; taking the write lock up front keeps concurrent
+/// units of work from failing when a read transaction is later upgraded to a write.
+pub struct SqliteUnitOfWorkFactory {
+    pool: SqlitePool,
+}
+
+impl SqliteUnitOfWorkFactory {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+impl UnitOfWorkFactory for SqliteUnitOfWorkFactory {
+    type Uow = SqliteUnitOfWork;
+
+    async fn begin(&self) -> Result<Self::Uow, UnitOfWorkFactoryError> {
+        let transaction = self
+            .pool
+            .begin_with("BEGIN IMMEDIATE")
+            .await
+            .map_err(|e| UnitOfWorkFactoryError::BeginFailed(Box::new(e)))?;
+        Ok(SqliteUnitOfWork::new(transaction))
+    }
+}
@@ -0,0 +1,207 @@
+//! Runs against a local RabbitMQ broker, e.g. `docker run -p 5672:5672 rabbitmq`.
+//!
+//! `cargo test -p appletheia-infrastructure --features amqp -- --ignored`; set `AMQP_URL` to
+//! use a broker other than `amqp://127.0.0.1:5672`.
+#![cfg(feature = "amqp")]
+
+use std::time::Duration;
+
+use appletheia_application::command::{CommandName, CommandOptions, CommandSelector};
+use appletheia_application::event::{
+    AggregateIdValue, AggregateTypeOwned, EventEnvelope, EventNameOwned, EventSelector,
+    EventSequence, SerializedEventPayload,
+};
+use appletheia_application::messaging::{
+    Consumer, ConsumerGroup, Delivery, PublishResult, Publisher, Subscriber, Subscription, TopicId,
+};
+use appletheia_application::outbox::command::{CommandEnvelope, SerializedCommand};
+use appletheia_application::request_context::{
+    CausationId, CorrelationId, MessageId, Principal, RequestContext,
+};
+use appletheia_domain::{AggregateType, AggregateVersion, EventId, EventName, EventOccurredAt};
+use appletheia_infrastructure::amqp::messaging::{
+    AmqpCommandPublisher, AmqpCommandSubscriber, AmqpEventPublisher, AmqpEventSubscriber,
+    AmqpQueueOptions,
+};
+use chrono::Utc;
+use lapin::options::ConfirmSelectOptions;
+use lapin::{Channel, Connection, ConnectionProperties};
+use tokio::time::timeout;
+use uuid::Uuid;
+
+const ACCOUNT: AggregateType = AggregateType::new("account");
+const OPENED: EventName = EventName::new("opened");
+const CLOSED: EventName = EventName::new("closed");
+const OPEN_ACCOUNT: CommandName = CommandName::new("open_account");
+const CLOSE_ACCOUNT: CommandName = CommandName::new("close_account");
+
+async fn connection() -> Connection {
+    let url = std::env::var("AMQP_URL").unwrap_or_else(|_| "amqp://127.0.0.1:5672".to_owned());
+    Connection::connect(&url, ConnectionProperties::default())
+        .await
+        .expect("AMQP broker should be reachable")
+}
+
+async fn confirmed_channel(connection: &Connection) -> Channel {
+    let channel = connection
+        .create_channel()
+        .await
+        .expect("channel should open");
+    channel
+        .confirm_select(ConfirmSelectOptions::default())
+        .await
+        .expect("publisher confirms should be enabled");
+    channel
+}
+
+fn unique_name(prefix: &str) -> String {
+    format!("{prefix}_{}", Uuid::now_v7().simple())
+}
+
+fn event(event_name: EventName) -> EventEnvelope {
+    let context = RequestContext::new(
+        CorrelationId::from(Uuid::now_v7()),
+        MessageId::new(),
+        Principal::System,
+    )
+    .expect("system request context should be valid");
+    EventEnvelope {
+        event_sequence: EventSequence::try_from(1).expect("event sequence should be valid"),
+        event_id: EventId::new(),
+        aggregate_type: AggregateTypeOwned::from(ACCOUNT),
+        aggregate_id: AggregateIdValue::from(Uuid::now_v7()),
+        aggregate_version: AggregateVersion::new().next(),
+        event_name: EventNameOwned::from(event_name),
+        payload: SerializedEventPayload::try_from(serde_json::json!({ "amount": 1 }))
+            .expect("event payload should be valid"),
+        occurred_at: EventOccurredAt::from(Utc::now()),
+        correlation_id: context.correlation_id,
+        causation_id: CausationId::from(context.message_id),
+        context,
+    }
+}
+
+fn command(command_name: CommandName) -> CommandEnvelope {
+    let message_id = MessageId::new();
+    CommandEnvelope {
+        command_name: command_name.into(),
+        command: SerializedCommand::new(serde_json::json!({ "amount": 1 }))
+            .expect("command should be valid"),
+        correlation_id: CorrelationId::from(Uuid::now_v7()),
+        message_id,
+        causation_id: CausationId::from(message_id),
+        options: CommandOptions::default(),
+        trace_context: None,
+    }
+}
+
+async fn next_message<M: Clone>(consumer: &mut impl Consumer<M>) -> M {
+    let mut delivery = timeout(Duration::from_secs(5), consumer.next())
+        .await
+        .expect("a message should be delivered")
+        .expect("delivery should succeed");
+    let message = delivery.message().clone();
+    delivery.ack().await.expect("ack should succeed");
+    message
+}
+
+fn assert_published(results: &[PublishResult]) {
+    assert!(
+        results
+            .iter()
+            .all(|result| matches!(result, PublishResult::Success { .. }))
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires a local AMQP broker"]
+async fn delivers_selected_events_to_consumer_group() {
+    let topic_id = TopicId::new(unique_name("events")).expect("topic should be valid");
+    let consumer_group =
+        ConsumerGroup::new(unique_name("ledger")).expect("consumer group should be valid");
+    let subscriber = AmqpEventSubscriber::new(
+        connection().await,
+        AmqpQueueOptions::new(),
+        topic_id.clone(),
+    );
+    let mut consumer = subscriber
+        .subscribe(
+            &consumer_group,
+            Subscription::One(&EventSelector::new(ACCOUNT, OPENED)),
+        )
+        .await
+        .expect("subscribe should succeed");
+
+    let opened = event(OPENED);
+    let publisher_connection = connection().await;
+    let results = AmqpEventPublisher::new(confirmed_channel(&publisher_connection).await, topic_id)
+        .publish(&[event(CLOSED), opened.clone()])
+        .await
+        .expect("publish should succeed");
+
+    assert_published(&results);
+    assert_eq!(next_message(&mut consumer).await, opened);
+}
+
+#[tokio::test]
+#[ignore = "requires a local AMQP broker"]
+async fn delivers_selected_commands_to_consumer_group() {
+    let topic_id = TopicId::new(unique_name("commands")).expect("topic should be valid");
+    let consumer_group =
+        ConsumerGroup::new(unique_name("ledger")).expect("consumer group should be valid");
+    let subscriber = AmqpCommandSubscriber::new(
+        connection().await,
+        AmqpQueueOptions::new(),
+        topic_id.clone(),
+    );
+    let mut consumer = subscriber
+        .subscribe(
+            &consumer_group,
+            Subscription::One(&CommandSelector::new(OPEN_ACCOUNT)),
+        )
+        .await
+        .expect("subscribe should succeed");
+
+    let open = command(OPEN_ACCOUNT);
+    let publisher_connection = connection().await;
+    let results =
+        AmqpCommandPublisher::new(confirmed_channel(&publisher_connection).await, topic_id)
+            .publish(&[command(CLOSE_ACCOUNT), open.clone()])
+            .await
+            .expect("publish should succeed");
+
+    assert_published(&results);
+    assert_eq!(next_message(&mut consumer).await, open);
+}
+
+#[tokio::test]
+#[ignore = "requires a local AMQP broker"]
+async fn redelivers_nacked_command() {
+    let topic_id = TopicId::new(unique_name("commands")).expect("topic should be valid");
+    let consumer_group =
+        ConsumerGroup::new(unique_name("ledger")).expect("consumer group should be valid");
+    let subscriber = AmqpCommandSubscriber::new(
+        connection().await,
+        AmqpQueueOptions::new(),
+        topic_id.clone(),
+    );
+    let mut consumer = subscriber
+        .subscribe(&consumer_group, Subscription::All)
+        .await
+        .expect("subscribe should succeed");
+
+    let open = command(OPEN_ACCOUNT);
+    let publisher_connection = connection().await;
+    AmqpCommandPublisher::new(confirmed_channel(&publisher_connection).await, topic_id)
+        .publish(std::slice::from_ref(&open))
+        .await
+        .expect("publish should succeed");
+
+    let mut delivery = timeout(Duration::from_secs(5), consumer.next())
+        .await
+        .expect("a message should be delivered")
+        .expect("delivery should succeed");
+    delivery.nack().await.expect("nack should succeed");
+
+    assert_eq!(next_message(&mut consumer).await, open);
+}
@@ -0,0 +1,478 @@
+//! Runs a command through its events, a projection and a saga on the in-memory backend.
+//!
+//! Every relay and worker runs under one `WorkerSupervisor`, so they share a single task and
+//! their units of work wait for each other on the same `InMemoryDatabase`.
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+use std::ops::Bound;
+use std::sync::{Arc, Mutex};
+use std::time::Duration as StdDuration;
+
+use appletheia_application::authorization::{
+    DefaultAuthorizer, DefaultRelationshipResolver, InMemoryAuthorizationModel,
+    RelationshipResolverConfig,
+};
+use appletheia_application::command::{
+    Command, CommandDispatcher, CommandHandled, CommandHandler, CommandName, CommandOptions,
+    CommandRequest, DefaultCommandDispatcher, DefaultCommandWorker,
+};
+use appletheia_application::conformance::{
+    ConformanceAggregate, ConformanceAggregateId, ConformanceEventPayload,
+};
+use appletheia_application::event::{EventEnvelope, EventReader, EventSelector};
+use appletheia_application::messaging::{ConsumerGroup, Subscription};
+use appletheia_application::outbox::{
+    OutboxBatchSize, OutboxLeaseDuration, OutboxMaxAttempts, OutboxPollBackoffMultiplier,
+    OutboxPollInterval, OutboxPollJitterRatio, OutboxPollingOptions, OutboxRelayConfig,
+    OutboxRelayInstance, OutboxRelayInstanceId, OutboxRelayProcessId, OutboxRetryDelay,
+    OutboxRetryOptions, OutboxRetrySchedule,
+};
+use appletheia_application::projection::{
+    DefaultProjectorRunner, DefaultProjectorWorker, DefaultReadYourWritesWaiter, Projector,
+    ProjectorDescriptor, ProjectorName, ProjectorSpec,
+};
+use appletheia_application::repository::{Repository, RepositoryConfig, RepositoryError};
+use appletheia_application::request_context::{
+    ActorRef, CorrelationId, MessageId, Principal, RequestContext,
+};
+use appletheia_application::saga::{
+    DefaultSagaRunner, DefaultSagaWorker, Saga, SagaDescriptor, SagaName, SagaPredecessor,
+    SagaSpec, SagaTransition,
+};
+use appletheia_application::snapshot::SnapshotPolicy;
+use appletheia_application::supervision::{WorkerName, WorkerSupervisor, WorkerSupervisorConfig};
+use appletheia_application::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+use appletheia_domain::{Aggregate, AggregateVersionRange, Event};
+use appletheia_infrastructure::Sha256CommandHasher;
+use appletheia_infrastructure::in_memory::{
+    InMemoryBroker, InMemoryCommandOutboxEnqueuer, InMemoryCommandOutboxFetcher,
+    InMemoryCommandOutboxRelay, InMemoryCommandOutboxWriter, InMemoryCommandSubscriber,
+    InMemoryDatabase, InMemoryEventLookup, InMemoryEventOutboxFetcher, InMemoryEventOutboxRelay,
+    InMemoryEventOutboxWriter, InMemoryEventReader, InMemoryEventSubscriber, InMemoryEventWriter,
+    InMemoryIdempotencyService, InMemoryProjectorProcessedEventStore, InMemoryPublisher,
+    InMemoryRelationshipStore, InMemoryRepository, InMemorySagaProcessedEventStore,
+    InMemorySagaRunStore, InMemorySnapshotReader, InMemorySnapshotWriter,
+    InMemoryUniqueKeyReservationStore, InMemoryUniqueValueOwnerLookup, InMemoryUnitOfWork,
+    InMemoryUnitOfWorkFactory,
+};
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::time::{sleep, timeout};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+struct CreateDocumentCommand {
+    id: ConformanceAggregateId,
+    name: String,
+}
+
+impl Command for CreateDocumentCommand {
+    const NAME: CommandName = CommandName::new("create_document");
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+struct RenameDocumentCommand {
+    id: ConformanceAggregateId,
+    name: String,
+}
+
+impl Command for RenameDocumentCommand {
+    const NAME: CommandName = CommandName::new("rename_document");
+}
+
+#[derive(Debug, Error)]
+enum DocumentCommandHandlerError {
+    #[error(transparent)]
+    Repository(#[from] RepositoryError<ConformanceAggregate>),
+
+    #[error("document not found")]
+    NotFound,
+}
+
+fn repository() -> InMemoryRepository<ConformanceAggregate> {
+    InMemoryRepository::new(
+        RepositoryConfig {
+            snapshot_policy: SnapshotPolicy::Disabled,
+        },
+        InMemoryEventReader::new(),
+        InMemorySnapshotReader::new(),
+        InMemoryEventWriter::new(),
+        InMemorySnapshotWriter::new(),
+        InMemoryUniqueValueOwnerLookup::new(),
+        InMemoryUniqueKeyReservationStore::new(),
+    )
+}
+
+struct CreateDocumentCommandHandler {
+    repository: InMemoryRepository<ConformanceAggregate>,
+}
+
+impl CommandHandler for CreateDocumentCommandHandler {
+    type Command = CreateDocumentCommand;
+    type Output = ();
+    type ReplayOutput = ();
+    type Error = DocumentCommandHandlerError;
+    type Uow = InMemoryUnitOfWork;
+
+    async fn handle(
+        &self,
+        uow: &mut Self::Uow,
+        request_context: &RequestContext,
+        command: &Self::Command,
+    ) -> Result<CommandHandled<Self::Output, Self::ReplayOutput>, Self::Error> {
+        let mut document = ConformanceAggregate::default();
+        document
+            .append_event(ConformanceEventPayload::Created {
+                id: command.id,
+                name: command.name.clone(),
+            })
+            .map_err(RepositoryError::Aggregate)?;
+        self.repository
+            .save(uow, request_context, &mut document)
+            .await?;
+        Ok(CommandHandled::same(()))
+    }
+}
+
+struct RenameDocumentCommandHandler {
+    repository: InMemoryRepository<ConformanceAggregate>,
+}
+
+impl CommandHandler for RenameDocumentCommandHandler {
+    type Command = RenameDocumentCommand;
+    type Output = ();
+    type ReplayOutput = ();
+    type Error = DocumentCommandHandlerError;
+    type Uow = InMemoryUnitOfWork;
+
+    async fn handle(
+        &self,
+        uow: &mut Self::Uow,
+        request_context: &RequestContext,
+        command: &Self::Command,
+    ) -> Result<CommandHandled<Self::Output, Self::ReplayOutput>, Self::Error> {
+        let Some(mut document) = self.repository.find(uow, command.id).await? else {
+            return Err(DocumentCommandHandlerError::NotFound);
+        };
+        document
+            .append_event(ConformanceEventPayload::Renamed {
+                name: command.name.clone(),
+            })
+            .map_err(RepositoryError::Aggregate)?;
+        self.repository
+            .save(uow, request_context, &mut document)
+            .await?;
+        Ok(CommandHandled::same(()))
+    }
+}
+
+/// Keeps the latest name of every document.
+#[derive(Clone, Default)]
+struct DocumentNames(Arc<Mutex<HashMap<ConformanceAggregateId, String>>>);
+
+impl DocumentNames {
+    fn get(&self, id: ConformanceAggregateId) -> Option<String> {
+        self.0
+            .lock()
+            .expect("lock should succeed")
+            .get(&id)
+            .cloned()
+    }
+}
+
+struct DocumentNameProjectorSpec;
+
+impl ProjectorSpec for DocumentNameProjectorSpec {
+    const DESCRIPTOR: ProjectorDescriptor =
+        ProjectorDescriptor::new(ProjectorName::new("document_name"), Subscription::All);
+}
+
+#[derive(Debug, Error)]
+#[error("event payload could not be read")]
+struct DocumentNameProjectorError;
+
+struct DocumentNameProjector {
+    names: DocumentNames,
+}
+
+impl Projector for DocumentNameProjector {
+    type Spec = DocumentNameProjectorSpec;
+    type Uow = InMemoryUnitOfWork;
+    type Error = DocumentNameProjectorError;
+
+    async fn project(
+        &self,
+        _uow: &mut Self::Uow,
+        event: &EventEnvelope,
+    ) -> Result<(), Self::Error> {
+        let event = event
+            .try_into_domain_event::<ConformanceAggregate>()
+            .map_err(|_| DocumentNameProjectorError)?;
+        let name = match event.payload() {
+            ConformanceEventPayload::Created { name, .. }
+            | ConformanceEventPayload::Renamed { name } => name.clone(),
+        };
+        self.names
+            .0
+            .lock()
+            .expect("lock should succeed")
+            .insert(event.aggregate_id(), name);
+        Ok(())
+    }
+}
+
+struct RenameCreatedDocumentSagaSpec;
+
+impl SagaSpec for RenameCreatedDocumentSagaSpec {
+    const DESCRIPTOR: SagaDescriptor = SagaDescriptor::new(
+        SagaName::new("rename_created_document"),
+        EventSelector::new(ConformanceAggregate::TYPE, ConformanceEventPayload::CREATED),
+        SagaPredecessor::None,
+    );
+}
+
+#[derive(Debug, Error)]
+#[error("unexpected event")]
+struct RenameCreatedDocumentSagaError;
+
+struct RenameCreatedDocumentSaga;
+
+impl Saga for RenameCreatedDocumentSaga {
+    type Spec = RenameCreatedDocumentSagaSpec;
+    type Context = ();
+    type EventAggregate = ConformanceAggregate;
+    type Command = RenameDocumentCommand;
+    type Error = RenameCreatedDocumentSagaError;
+
+    fn on_event(
+        &self,
+        _context: Option<Self::Context>,
+        event: &Event<
+            <Self::EventAggregate as Aggregate>::Id,
+            <Self::EventAggregate as Aggregate>::EventPayload,
+        >,
+    ) -> Result<SagaTransition<Self::Context, Self::Command>, Self::Error> {
+        let ConformanceEventPayload::Created { id, name } = event.payload() else {
+            return Err(RenameCreatedDocumentSagaError);
+        };
+        Ok(SagaTransition::new(
+            (),
+            CommandRequest::new(RenameDocumentCommand {
+                id: *id,
+                name: format!("{name} (reviewed)"),
+            }),
+        ))
+    }
+}
+
+type InMemoryCommandDispatcher = DefaultCommandDispatcher<
+    Sha256CommandHasher,
+    InMemoryIdempotencyService,
+    DefaultReadYourWritesWaiter<
+        InMemoryUnitOfWorkFactory,
+        InMemoryEventLookup,
+        InMemoryProjectorProcessedEventStore,
+    >,
+    InMemoryUnitOfWorkFactory,
+    DefaultAuthorizer<
+        InMemoryUnitOfWorkFactory,
+        DefaultRelationshipResolver<InMemoryRelationshipStore, InMemoryAuthorizationModel>,
+    >,
+    InMemoryCommandOutboxEnqueuer,
+>;
+
+fn command_dispatcher(database: &InMemoryDatabase) -> InMemoryCommandDispatcher {
+    DefaultCommandDispatcher::new(
+        Sha256CommandHasher::new(),
+        InMemoryIdempotencyService::new(),
+        DefaultReadYourWritesWaiter::new(
+            InMemoryUnitOfWorkFactory::new(database.clone()),
+            InMemoryEventLookup::new(),
+            InMemoryProjectorProcessedEventStore::new(),
+        ),
+        InMemoryUnitOfWorkFactory::new(database.clone()),
+        DefaultAuthorizer::new(
+            InMemoryUnitOfWorkFactory::new(database.clone()),
+            DefaultRelationshipResolver::new(
+                InMemoryRelationshipStore::new(),
+                InMemoryAuthorizationModel::new(),
+                RelationshipResolverConfig::default(),
+            ),
+        ),
+        InMemoryCommandOutboxEnqueuer::new(),
+    )
+}
+
+fn relay_config(instance_id: &str) -> OutboxRelayConfig {
+    let poll_interval = OutboxPollInterval::new(Duration::milliseconds(5));
+    OutboxRelayConfig {
+        instance: OutboxRelayInstance::new(
+            OutboxRelayInstanceId::new(instance_id.to_owned())
+                .expect("instance id should be valid"),
+            OutboxRelayProcessId::new(1),
+        ),
+        batch_size: OutboxBatchSize::new(
+            NonZeroU32::new(10).expect("batch size should be non-zero"),
+        ),
+        lease_duration: OutboxLeaseDuration::new(Duration::seconds(30)),
+        retry_options: OutboxRetryOptions {
+            schedule: OutboxRetrySchedule::Fixed(OutboxRetryDelay::new(Duration::zero())),
+            max_attempts: OutboxMaxAttempts::new(
+                NonZeroU32::new(3).expect("max attempts should be non-zero"),
+            ),
+        },
+        polling_options: OutboxPollingOptions::new(
+            poll_interval,
+            poll_interval,
+            OutboxPollBackoffMultiplier::new(),
+            OutboxPollJitterRatio::new(),
+        )
+        .expect("polling options should be valid"),
+    }
+}
+
+fn worker_name(value: &str) -> WorkerName {
+    WorkerName::new(value.to_owned()).expect("worker name should be valid")
+}
+
+fn request_context() -> RequestContext {
+    RequestContext {
+        correlation_id: CorrelationId::from(uuid::Uuid::now_v7()),
+        message_id: MessageId::new(),
+        actor: ActorRef::System,
+        principal: Principal::System,
+        trace_context: None,
+    }
+}
+
+#[tokio::test]
+async fn command_flows_through_events_projection_and_saga() {
+    let database = InMemoryDatabase::new();
+    let event_broker = InMemoryBroker::<EventEnvelope>::new();
+    let command_broker = InMemoryBroker::new();
+    let names = DocumentNames::default();
+    let document_id = ConformanceAggregateId::new();
+
+    let supervisor = WorkerSupervisor::new(WorkerSupervisorConfig {
+        restart_delay_base: Duration::milliseconds(10),
+        restart_delay_max: Duration::milliseconds(10),
+        drain_deadline: Duration::seconds(1),
+    })
+    .with_worker(
+        worker_name("event_outbox_relay"),
+        InMemoryEventOutboxRelay::new(
+            relay_config("event-relay"),
+            InMemoryPublisher::new(event_broker.clone()),
+            InMemoryEventOutboxFetcher::new(),
+            InMemoryEventOutboxWriter::new(),
+            InMemoryUnitOfWorkFactory::new(database.clone()),
+        ),
+    )
+    .with_worker(
+        worker_name("command_outbox_relay"),
+        InMemoryCommandOutboxRelay::new(
+            relay_config("command-relay"),
+            InMemoryPublisher::new(command_broker.clone()),
+            InMemoryCommandOutboxFetcher::new(),
+            InMemoryCommandOutboxWriter::new(),
+            InMemoryUnitOfWorkFactory::new(database.clone()),
+        ),
+    )
+    .with_worker(
+        worker_name("document_name_projector"),
+        DefaultProjectorWorker::new(
+            DefaultProjectorRunner::new(
+                InMemoryProjectorProcessedEventStore::new(),
+                InMemoryUnitOfWorkFactory::new(database.clone()),
+            ),
+            InMemoryEventSubscriber::new(event_broker.clone()),
+            DocumentNameProjector {
+                names: names.clone(),
+            },
+        ),
+    )
+    .with_worker(
+        worker_name("rename_created_document_saga"),
+        DefaultSagaWorker::new(
+            DefaultSagaRunner::new(
+                InMemorySagaRunStore::new(),
+                InMemorySagaProcessedEventStore::new(),
+                InMemoryCommandOutboxEnqueuer::new(),
+                InMemoryUnitOfWorkFactory::new(database.clone()),
+            ),
+            InMemoryEventSubscriber::new(event_broker.clone()),
+            RenameCreatedDocumentSaga,
+        ),
+    )
+    .with_worker(
+        worker_name("rename_document_command"),
+        DefaultCommandWorker::new(
+            command_dispatcher(&database),
+            RenameDocumentCommandHandler {
+                repository: repository(),
+            },
+            InMemoryCommandSubscriber::new(command_broker),
+            ConsumerGroup::new("rename_document".to_owned())
+                .expect("consumer group should be valid"),
+        ),
+    );
+
+    let dispatcher = command_dispatcher(&database);
+    let flow = async {
+        // Let every worker subscribe before anything is published.
+        sleep(StdDuration::from_millis(20)).await;
+
+        dispatcher
+            .dispatch(
+                &CreateDocumentCommandHandler {
+                    repository: repository(),
+                },
+                &request_context(),
+                CreateDocumentCommand {
+                    id: document_id,
+                    name: "draft".to_owned(),
+                },
+                CommandOptions::default(),
+            )
+            .await
+            .expect("create should be dispatched");
+
+        while names.get(document_id).as_deref() != Some("draft (reviewed)") {
+            sleep(StdDuration::from_millis(5)).await;
+        }
+    };
+
+    timeout(StdDuration::from_secs(5), supervisor.run_until(flow))
+        .await
+        .expect("flow should complete")
+        .expect("workers should drain");
+
+    let factory = InMemoryUnitOfWorkFactory::new(database);
+    let mut uow = factory.begin().await.expect("begin should succeed");
+    let events = InMemoryEventReader::<ConformanceAggregate>::new()
+        .read_events(
+            &mut uow,
+            document_id,
+            AggregateVersionRange::new(Bound::Unbounded, Bound::Unbounded),
+        )
+        .await
+        .expect("events should be readable");
+    uow.commit().await.expect("commit should succeed");
+
+    let payloads = events
+        .iter()
+        .map(|event| event.payload().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        payloads,
+        vec![
+            ConformanceEventPayload::Created {
+                id: document_id,
+                name: "draft".to_owned(),
+            },
+            ConformanceEventPayload::Renamed {
+                name: "draft (reviewed)".to_owned(),
+            },
+        ]
+    );
+}
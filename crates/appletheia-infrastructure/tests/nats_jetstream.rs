@@ -0,0 +1,125 @@
+//! Runs against a local JetStream-enabled server, e.g. `nats-server -js`.
+//!
+//! `cargo test -p appletheia-infrastructure --features nats -- --ignored`; set `NATS_URL` to
+//! use a server other than `nats://127.0.0.1:4222`.
+#![cfg(feature = "nats")]
+
+use std::time::Duration;
+
+use appletheia_application::command::{CommandName, CommandOptions, CommandSelector};
+use appletheia_application::messaging::{
+    Consumer, ConsumerGroup, Delivery, PublishResult, Publisher, Subscriber, Subscription, TopicId,
+};
+use appletheia_application::outbox::command::{CommandEnvelope, SerializedCommand};
+use appletheia_application::request_context::{CausationId, CorrelationId, MessageId};
+use appletheia_infrastructure::nats::messaging::{
+    NatsCommandPublisher, NatsCommandSubscriber, NatsConsumerOptions,
+};
+use async_nats::jetstream::{self, Context};
+use tokio::time::timeout;
+use uuid::Uuid;
+
+const OPEN_ACCOUNT: CommandName = CommandName::new("open_account");
+const CLOSE_ACCOUNT: CommandName = CommandName::new("close_account");
+
+async fn context() -> Context {
+    let url = std::env::var("NATS_URL").unwrap_or_else(|_| "nats://127.0.0.1:4222".to_owned());
+    let client = async_nats::connect(url)
+        .await
+        .expect("nats-server should be reachable");
+    jetstream::new(client)
+}
+
+fn unique_name(prefix: &str) -> String {
+    format!("{prefix}_{}", Uuid::now_v7().simple())
+}
+
+fn command(command_name: CommandName) -> CommandEnvelope {
+    let message_id = MessageId::new();
+    CommandEnvelope {
+        command_name: command_name.into(),
+        command: SerializedCommand::new(serde_json::json!({ "amount": 1 }))
+            .expect("command should be valid"),
+        correlation_id: CorrelationId::from(Uuid::now_v7()),
+        message_id,
+        causation_id: CausationId::from(message_id),
+        options: CommandOptions::default(),
+        trace_context: None,
+    }
+}
+
+async fn next_command(consumer: &mut impl Consumer<CommandEnvelope>) -> CommandEnvelope {
+    let mut delivery = timeout(Duration::from_secs(5), consumer.next())
+        .await
+        .expect("a message should be delivered")
+        .expect("delivery should succeed");
+    let command = delivery.message().clone();
+    delivery.ack().await.expect("ack should succeed");
+    command
+}
+
+#[tokio::test]
+#[ignore = "requires a local nats-server with JetStream enabled"]
+async fn delivers_selected_commands_to_durable_consumer() {
+    let context = context().await;
+    let topic_id = TopicId::new(unique_name("commands")).expect("topic should be valid");
+    let consumer_group =
+        ConsumerGroup::new(unique_name("ledger")).expect("consumer group should be valid");
+    let subscriber = NatsCommandSubscriber::new(
+        context.clone(),
+        NatsConsumerOptions::new(),
+        topic_id.clone(),
+    );
+    let mut consumer = subscriber
+        .subscribe(
+            &consumer_group,
+            Subscription::One(&CommandSelector::new(OPEN_ACCOUNT)),
+        )
+        .await
+        .expect("subscribe should succeed");
+
+    let open = command(OPEN_ACCOUNT);
+    let results = NatsCommandPublisher::new(context, topic_id)
+        .publish(&[command(CLOSE_ACCOUNT), open.clone()])
+        .await
+        .expect("publish should succeed");
+
+    assert!(
+        results
+            .iter()
+            .all(|result| matches!(result, PublishResult::Success { .. }))
+    );
+    assert_eq!(next_command(&mut consumer).await, open);
+}
+
+#[tokio::test]
+#[ignore = "requires a local nats-server with JetStream enabled"]
+async fn redelivers_nacked_command_after_nak_delay() {
+    let context = context().await;
+    let topic_id = TopicId::new(unique_name("commands")).expect("topic should be valid");
+    let consumer_group =
+        ConsumerGroup::new(unique_name("ledger")).expect("consumer group should be valid");
+    let subscriber = NatsCommandSubscriber::new(
+        context.clone(),
+        NatsConsumerOptions::new().with_nak_delay(Duration::from_millis(200)),
+        topic_id.clone(),
+    );
+    let mut consumer = subscriber
+        .subscribe(&consumer_group, Subscription::All)
+        .await
+        .expect("subscribe should succeed");
+
+    let open = command(OPEN_ACCOUNT);
+    NatsCommandPublisher::new(context, topic_id)
+        .publish(std::slice::from_ref(&open))
+        .await
+        .expect("publish should succeed");
+
+    let mut delivery = timeout(Duration::from_secs(5), consumer.next())
+        .await
+        .expect("a message should be delivered")
+        .expect("delivery should succeed");
+    delivery.nack().await.expect("nack should succeed");
+
+    assert_eq!(next_command(&mut consumer).await, open);
+}
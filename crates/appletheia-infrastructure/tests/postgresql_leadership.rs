@@ -0,0 +1,76 @@
+//! Runs against a local PostgreSQL server.
+//!
+//! `cargo test -p appletheia-infrastructure --test postgresql_leadership -- --ignored`;
+//! see `support` for the connection settings.
+mod support;
+
+use appletheia_application::leadership::{
+    FencingToken, LeaderElector, LeaderElectorError, LeadershipFence, LeadershipKey,
+    LeadershipLease,
+};
+use appletheia_application::outbox::{
+    OutboxRelayInstance, OutboxRelayInstanceId, OutboxRelayProcessId,
+};
+use appletheia_application::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+use appletheia_infrastructure::postgresql::PgUnitOfWorkFactory;
+use appletheia_infrastructure::postgresql::leadership::{PgLeaderElector, PgLeadershipFence};
+use chrono::Duration;
+
+fn key() -> LeadershipKey {
+    LeadershipKey::new("projector".to_owned()).expect("leadership key should be valid")
+}
+
+fn holder(process: u32) -> OutboxRelayInstance {
+    OutboxRelayInstance::new(
+        OutboxRelayInstanceId::new("instance".to_owned()).expect("instance id should be valid"),
+        OutboxRelayProcessId::new(process),
+    )
+}
+
+async fn acquire(elector: &PgLeaderElector, process: u32) -> LeadershipLease {
+    elector
+        .try_acquire(&key(), &holder(process), Duration::seconds(30))
+        .await
+        .expect("acquire should succeed")
+        .expect("the lease should be free")
+}
+
+async fn check(
+    factory: &PgUnitOfWorkFactory,
+    fencing_token: FencingToken,
+) -> Result<(), LeaderElectorError> {
+    let mut uow = factory.begin().await.expect("unit of work should begin");
+    let checked = PgLeadershipFence::new()
+        .check(&mut uow, &key(), fencing_token)
+        .await;
+    uow.commit().await.expect("commit should succeed");
+    checked
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn fence_rejects_the_token_of_a_former_leader() {
+    let pool = support::pg_pool().await;
+    let factory = PgUnitOfWorkFactory::new(pool.clone());
+    let elector = PgLeaderElector::new(pool);
+
+    let former = acquire(&elector, 1).await;
+    check(&factory, former.fencing_token())
+        .await
+        .expect("the current leader should pass the fence");
+
+    elector
+        .release(&former)
+        .await
+        .expect("release should succeed");
+    let current = acquire(&elector, 2).await;
+
+    assert!(matches!(
+        check(&factory, former.fencing_token()).await,
+        Err(LeaderElectorError::Fenced { fencing_token, .. })
+            if fencing_token == former.fencing_token()
+    ));
+    check(&factory, current.fencing_token())
+        .await
+        .expect("the new leader should pass the fence");
+}
@@ -0,0 +1,253 @@
+//! Runs against a local PostgreSQL server.
+//!
+//! `cargo test -p appletheia-infrastructure --test postgresql_message_queue -- --ignored`;
+//! see `support` for the connection settings.
+mod support;
+
+use std::num::NonZeroU32;
+use std::time::Duration as StdDuration;
+
+use appletheia_application::messaging::{
+    Consumer, ConsumerError, ConsumerGroup, Delivery, Publisher, Selector, Subscriber,
+    Subscription, TopicId,
+};
+use appletheia_application::outbox::{
+    OrderingKey, OutboxMaxAttempts, OutboxRetryDelay, OutboxRetryOptions, OutboxRetrySchedule,
+};
+use appletheia_application::retention::{
+    RetentionBatchSize, RetentionCutoff, RetentionPeriod, RetentionPolicy, RetentionStore,
+    RetentionTarget,
+};
+use appletheia_application::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+use appletheia_infrastructure::postgresql::PgUnitOfWorkFactory;
+use appletheia_infrastructure::postgresql::messaging::{
+    PgMessageQueueConfig, PgMessageQueueConsumer, PgMessageQueueDelivery, PgMessageQueueMessage,
+    PgMessageQueuePublisher, PgMessageQueueSelector, PgMessageQueueSubscriber,
+};
+use appletheia_infrastructure::postgresql::retention::PgRetentionStore;
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use sqlx::PgPool;
+use tokio::time::{sleep, timeout};
+
+const TOPIC: &str = "messages";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct TestMessage {
+    key: String,
+    value: u32,
+}
+
+impl PgMessageQueueMessage for TestMessage {
+    fn ordering_key(&self) -> Option<OrderingKey> {
+        Some(OrderingKey::new(self.key.clone()).expect("ordering key should be valid"))
+    }
+
+    fn attributes(&self) -> Value {
+        json!({ "key": self.key })
+    }
+}
+
+/// Shares the ordering key of `TestMessage` but cannot be decoded as one.
+#[derive(Serialize, Deserialize)]
+struct MalformedMessage {
+    key: String,
+    value: String,
+}
+
+impl PgMessageQueueMessage for MalformedMessage {
+    fn ordering_key(&self) -> Option<OrderingKey> {
+        Some(OrderingKey::new(self.key.clone()).expect("ordering key should be valid"))
+    }
+
+    fn attributes(&self) -> Value {
+        json!({ "key": self.key })
+    }
+}
+
+struct AnyMessage;
+
+impl PgMessageQueueSelector for AnyMessage {
+    fn attributes(&self) -> Value {
+        json!({})
+    }
+}
+
+impl Selector<TestMessage> for AnyMessage {
+    fn matches(&self, _message: &TestMessage) -> bool {
+        true
+    }
+}
+
+fn topic() -> TopicId {
+    TopicId::new(TOPIC.to_owned()).expect("topic should be valid")
+}
+
+fn consumer_group(name: &str) -> ConsumerGroup {
+    ConsumerGroup::new(name.to_owned()).expect("consumer group should be valid")
+}
+
+fn config(max_attempts: u32) -> PgMessageQueueConfig {
+    PgMessageQueueConfig::new()
+        .with_poll_interval(Duration::milliseconds(10))
+        .with_visibility_timeout(Duration::milliseconds(50))
+        .with_retry_options(OutboxRetryOptions {
+            schedule: OutboxRetrySchedule::Fixed(OutboxRetryDelay::new(Duration::zero())),
+            max_attempts: OutboxMaxAttempts::new(
+                NonZeroU32::new(max_attempts).expect("max attempts should be non-zero"),
+            ),
+        })
+}
+
+async fn subscribe(
+    pool: &PgPool,
+    name: &str,
+    config: PgMessageQueueConfig,
+) -> PgMessageQueueConsumer<TestMessage> {
+    PgMessageQueueSubscriber::<TestMessage, AnyMessage>::new(pool.clone(), topic(), config)
+        .subscribe(&consumer_group(name), Subscription::All)
+        .await
+        .expect("consumer group should be created")
+}
+
+async fn publish<M: PgMessageQueueMessage>(pool: &PgPool, message: M) {
+    PgMessageQueuePublisher::<M>::new(pool.clone(), topic())
+        .publish([&message])
+        .await
+        .expect("message should be published");
+}
+
+fn test_message(value: u32) -> TestMessage {
+    TestMessage {
+        key: "key".to_owned(),
+        value,
+    }
+}
+
+async fn next(
+    consumer: &mut PgMessageQueueConsumer<TestMessage>,
+) -> Result<PgMessageQueueDelivery<TestMessage>, ConsumerError> {
+    timeout(StdDuration::from_secs(5), consumer.next())
+        .await
+        .expect("a delivery should be leased")
+}
+
+async fn count(pool: &PgPool, table: &str) -> i64 {
+    sqlx::query_scalar(&format!("SELECT count(*) FROM {table}"))
+        .fetch_one(pool)
+        .await
+        .expect("rows should be counted")
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn undecodable_payload_is_dead_lettered_and_releases_its_ordering_key() {
+    let pool = support::pg_pool().await;
+    let mut consumer = subscribe(&pool, "group", config(5)).await;
+    publish(
+        &pool,
+        MalformedMessage {
+            key: "key".to_owned(),
+            value: "not a number".to_owned(),
+        },
+    )
+    .await;
+    publish(&pool, test_message(2)).await;
+
+    let error = next(&mut consumer)
+        .await
+        .err()
+        .expect("the malformed message should not decode");
+    assert!(matches!(error, ConsumerError::Undecodable(_)));
+    assert_eq!(count(&pool, "message_queue_dead_letters").await, 1);
+
+    let mut delivery = next(&mut consumer)
+        .await
+        .expect("the next message should be delivered");
+    assert_eq!(delivery.message(), &test_message(2));
+    delivery.ack().await.expect("ack should succeed");
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn expired_lease_on_the_last_attempt_is_dead_lettered() {
+    let pool = support::pg_pool().await;
+    let mut consumer = subscribe(&pool, "group", config(1)).await;
+    publish(&pool, test_message(1)).await;
+    publish(&pool, test_message(2)).await;
+
+    let abandoned = next(&mut consumer)
+        .await
+        .expect("the first message should be delivered");
+    assert_eq!(abandoned.message(), &test_message(1));
+    drop(abandoned);
+    sleep(StdDuration::from_millis(100)).await;
+
+    let mut delivery = next(&mut consumer)
+        .await
+        .expect("the next message should be delivered");
+    assert_eq!(delivery.message(), &test_message(2));
+    delivery.ack().await.expect("ack should succeed");
+
+    let attempt_count: i64 =
+        sqlx::query_scalar("SELECT attempt_count FROM message_queue_dead_letters")
+            .fetch_one(&pool)
+            .await
+            .expect("the abandoned delivery should be dead-lettered");
+    assert_eq!(attempt_count, 1);
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn message_is_removed_once_every_consumer_group_acked_it() {
+    let pool = support::pg_pool().await;
+    let mut first = subscribe(&pool, "first", config(5)).await;
+    let mut second = subscribe(&pool, "second", config(5)).await;
+    publish(&pool, test_message(1)).await;
+
+    let mut first_delivery = next(&mut first).await.expect("first group should lease");
+    let mut second_delivery = next(&mut second).await.expect("second group should lease");
+    let (first_ack, second_ack) = tokio::join!(first_delivery.ack(), second_delivery.ack());
+    first_ack.expect("first ack should succeed");
+    second_ack.expect("second ack should succeed");
+
+    assert_eq!(count(&pool, "message_queue_deliveries").await, 0);
+    assert_eq!(count(&pool, "message_queue_messages").await, 0);
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn retention_removes_expired_dead_letters_with_their_message() {
+    let pool = support::pg_pool().await;
+    let mut consumer = subscribe(&pool, "group", config(1)).await;
+    publish(&pool, test_message(1)).await;
+    let mut delivery = next(&mut consumer).await.expect("message should be leased");
+    delivery.nack().await.expect("nack should succeed");
+    assert_eq!(count(&pool, "message_queue_dead_letters").await, 1);
+
+    for action in [RetentionPolicy::archive, RetentionPolicy::purge] {
+        let factory = PgUnitOfWorkFactory::new(pool.clone());
+        let mut uow = factory.begin().await.expect("unit of work should begin");
+        let policy = action(
+            RetentionTarget::MessageQueueDeadLetters,
+            RetentionPeriod::new(Duration::zero()).expect("retention period should be valid"),
+        );
+        PgRetentionStore::new()
+            .remove_expired(
+                &mut uow,
+                &policy,
+                RetentionCutoff::before_now(policy.period),
+                RetentionBatchSize::new(
+                    NonZeroU32::new(10).expect("batch size should be non-zero"),
+                ),
+            )
+            .await
+            .expect("expired dead letters should be removed");
+        uow.commit().await.expect("commit should succeed");
+    }
+
+    assert_eq!(count(&pool, "message_queue_dead_letters").await, 0);
+    assert_eq!(count(&pool, "message_queue_messages").await, 0);
+    assert_eq!(count(&pool, "message_queue_dead_letter_archive").await, 1);
+}
@@ -0,0 +1,231 @@
+//! Runs against a local PostgreSQL server.
+//!
+//! `cargo test -p appletheia-infrastructure --test postgresql_outbox_dead_letters -- --ignored`;
+//! see `support` for the connection settings.
+mod support;
+
+use std::num::NonZeroU32;
+
+use appletheia_application::conformance::{
+    ConformanceAggregate, ConformanceAggregateId, ConformanceEventPayload,
+};
+use appletheia_application::event::{AggregateIdValue, AggregateTypeOwned, EventWriter};
+use appletheia_application::messaging::PublishDispatchError;
+use appletheia_application::outbox::event::{EventOutbox, EventOutboxDeadLetterFilter};
+use appletheia_application::outbox::{
+    OrderingKey, Outbox, OutboxBatchSize, OutboxDeadLetterService, OutboxFetcher,
+    OutboxMaxAttempts, OutboxRetryDelay, OutboxRetryOptions, OutboxRetrySchedule, OutboxWriter,
+};
+use appletheia_application::request_context::{
+    CorrelationId, MessageId, Principal, RequestContext,
+};
+use appletheia_application::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+use appletheia_domain::{Aggregate, AggregateId, AggregateVersion, Event};
+use appletheia_infrastructure::postgresql::event::PgEventWriter;
+use appletheia_infrastructure::postgresql::outbox::command::PgCommandOutboxDeadLetterService;
+use appletheia_infrastructure::postgresql::outbox::event::{
+    PgEventOutboxDeadLetterService, PgEventOutboxFetcher, PgEventOutboxWriter,
+};
+use appletheia_infrastructure::postgresql::{PgUnitOfWork, PgUnitOfWorkFactory};
+use chrono::Duration;
+use uuid::Uuid;
+
+fn batch_size(value: u32) -> OutboxBatchSize {
+    OutboxBatchSize::new(NonZeroU32::new(value).expect("batch size should be non-zero"))
+}
+
+async fn begin(factory: &PgUnitOfWorkFactory) -> PgUnitOfWork {
+    factory.begin().await.expect("unit of work should begin")
+}
+
+/// Writes one event for each of `count` new aggregates and moves their outbox entries to the
+/// dead letters.
+async fn dead_letter_events(
+    factory: &PgUnitOfWorkFactory,
+    count: usize,
+) -> Vec<ConformanceAggregateId> {
+    let aggregate_ids = (0..count)
+        .map(|_| ConformanceAggregateId::new())
+        .collect::<Vec<_>>();
+    let events = aggregate_ids
+        .iter()
+        .map(|&aggregate_id| {
+            Event::new(
+                aggregate_id,
+                AggregateVersion::new().next(),
+                ConformanceEventPayload::Created {
+                    id: aggregate_id,
+                    name: "created".to_owned(),
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    let request_context = RequestContext::new(
+        CorrelationId::from(Uuid::now_v7()),
+        MessageId::new(),
+        Principal::System,
+    )
+    .expect("system request context should be valid");
+
+    let mut uow = begin(factory).await;
+    PgEventWriter::<ConformanceAggregate>::new()
+        .write_events_and_outbox(&mut uow, &request_context, &events)
+        .await
+        .expect("events should be written");
+    uow.commit().await.expect("commit should succeed");
+
+    let permanent = PublishDispatchError::Permanent {
+        code: "rejected".to_owned(),
+        message: "message rejected".to_owned(),
+    };
+    let retry_options = OutboxRetryOptions {
+        schedule: OutboxRetrySchedule::Fixed(OutboxRetryDelay::new(Duration::zero())),
+        max_attempts: OutboxMaxAttempts::new(
+            NonZeroU32::new(10).expect("max attempts should be non-zero"),
+        ),
+    };
+
+    let mut uow = begin(factory).await;
+    let mut pending: Vec<EventOutbox> = PgEventOutboxFetcher::new()
+        .fetch_pending(&mut uow, batch_size(100))
+        .await
+        .expect("pending outbox entries should be fetched");
+    assert_eq!(pending.len(), count);
+    for outbox in &mut pending {
+        outbox
+            .nack(&permanent, &retry_options)
+            .expect("nack should succeed");
+    }
+    PgEventOutboxWriter::new()
+        .write_outbox(&mut uow, &pending)
+        .await
+        .expect("outbox entries should be written");
+    uow.commit().await.expect("commit should succeed");
+
+    aggregate_ids
+}
+
+async fn redrive_matching(factory: &PgUnitOfWorkFactory, limit: u32) -> usize {
+    let mut uow = begin(factory).await;
+    let redriven = PgEventOutboxDeadLetterService::new()
+        .redrive_matching(
+            &mut uow,
+            &EventOutboxDeadLetterFilter::default(),
+            batch_size(limit),
+        )
+        .await
+        .expect("dead letters should be redriven");
+    uow.commit().await.expect("commit should succeed");
+    redriven.value() as usize
+}
+
+fn ordering_key(aggregate_id: ConformanceAggregateId) -> OrderingKey {
+    OrderingKey::from((
+        &AggregateTypeOwned::from(ConformanceAggregate::TYPE),
+        &AggregateIdValue::from(aggregate_id.value()),
+    ))
+}
+
+async fn unblock_event_ordering_key(
+    factory: &PgUnitOfWorkFactory,
+    ordering_key: &OrderingKey,
+) -> usize {
+    let mut uow = begin(factory).await;
+    let released = PgEventOutboxDeadLetterService::new()
+        .unblock_ordering_key(&mut uow, ordering_key)
+        .await
+        .expect("ordering key should be unblocked");
+    uow.commit().await.expect("commit should succeed");
+    released.value() as usize
+}
+
+async fn dead_letter_count(factory: &PgUnitOfWorkFactory) -> usize {
+    let mut uow = begin(factory).await;
+    let dead_letters = PgEventOutboxDeadLetterService::new()
+        .list(
+            &mut uow,
+            &EventOutboxDeadLetterFilter::default(),
+            batch_size(100),
+        )
+        .await
+        .expect("dead letters should be listed");
+    uow.commit().await.expect("commit should succeed");
+    dead_letters.len()
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn redrive_matching_moves_at_most_limit_dead_letters() {
+    let factory = PgUnitOfWorkFactory::new(support::pg_pool().await);
+    dead_letter_events(&factory, 3).await;
+
+    assert_eq!(redrive_matching(&factory, 2).await, 2);
+    assert_eq!(dead_letter_count(&factory).await, 1);
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn redrive_matching_drains_dead_letters_across_calls() {
+    let factory = PgUnitOfWorkFactory::new(support::pg_pool().await);
+    dead_letter_events(&factory, 5).await;
+
+    let mut batches = Vec::new();
+    loop {
+        let redriven = redrive_matching(&factory, 2).await;
+        if redriven == 0 {
+            break;
+        }
+        batches.push(redriven);
+    }
+
+    assert_eq!(batches, vec![2, 2, 1]);
+    assert_eq!(dead_letter_count(&factory).await, 0);
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn unblock_ordering_key_releases_only_the_given_aggregate() {
+    let factory = PgUnitOfWorkFactory::new(support::pg_pool().await);
+    let aggregate_ids = dead_letter_events(&factory, 2).await;
+
+    assert_eq!(
+        unblock_event_ordering_key(&factory, &ordering_key(aggregate_ids[0])).await,
+        1
+    );
+    assert_eq!(
+        unblock_event_ordering_key(&factory, &ordering_key(aggregate_ids[0])).await,
+        0
+    );
+    assert_eq!(
+        unblock_event_ordering_key(&factory, &ordering_key(aggregate_ids[1])).await,
+        1
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn unblock_ordering_key_ignores_keys_of_another_shape() {
+    let factory = PgUnitOfWorkFactory::new(support::pg_pool().await);
+    let aggregate_ids = dead_letter_events(&factory, 1).await;
+    let correlation_key = OrderingKey::new(aggregate_ids[0].value().to_string())
+        .expect("ordering key should be valid");
+    let malformed_key =
+        OrderingKey::new("not-an-ordering-key".to_owned()).expect("ordering key should be valid");
+
+    assert_eq!(
+        unblock_event_ordering_key(&factory, &correlation_key).await,
+        0
+    );
+    assert_eq!(
+        unblock_event_ordering_key(&factory, &malformed_key).await,
+        0
+    );
+
+    let mut uow = begin(&factory).await;
+    let released = PgCommandOutboxDeadLetterService::new()
+        .unblock_ordering_key(&mut uow, &malformed_key)
+        .await
+        .expect("malformed ordering key should be ignored");
+    uow.commit().await.expect("commit should succeed");
+    assert_eq!(released.value(), 0);
+}
@@ -0,0 +1,109 @@
+//! Runs against a local PostgreSQL server.
+//!
+//! `cargo test -p appletheia-infrastructure --test postgresql_rate_limit -- --ignored`;
+//! see `support` for the connection settings.
+mod support;
+
+use std::num::NonZeroU32;
+
+use appletheia_application::command::CommandName;
+use appletheia_application::rate_limit::{
+    RateLimitDecision, RateLimitKey, RateLimitPolicy, RateLimitStore, RateLimitTarget,
+};
+use appletheia_application::request_context::ActorRef;
+use appletheia_application::retention::{
+    RetentionBatchSize, RetentionCutoff, RetentionPeriod, RetentionPolicy, RetentionStore,
+    RetentionTarget,
+};
+use appletheia_application::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+use appletheia_infrastructure::postgresql::PgUnitOfWorkFactory;
+use appletheia_infrastructure::postgresql::rate_limit::PgRateLimitStore;
+use appletheia_infrastructure::postgresql::retention::PgRetentionStore;
+use chrono::Duration;
+use sqlx::PgPool;
+
+fn key() -> RateLimitKey {
+    RateLimitKey::new(
+        ActorRef::Anonymous,
+        RateLimitTarget::Command(CommandName::new("open_account")),
+    )
+}
+
+async fn bucket_count(pool: &PgPool) -> i64 {
+    sqlx::query_scalar("SELECT count(*) FROM rate_limit_buckets")
+        .fetch_one(pool)
+        .await
+        .expect("buckets should be counted")
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn try_acquire_limits_once_the_bucket_is_empty() {
+    let store = PgRateLimitStore::new(support::pg_pool().await);
+    let policy = RateLimitPolicy::new(2, Duration::hours(1)).expect("policy should be valid");
+
+    assert_eq!(
+        store.try_acquire(&key(), &policy).await.expect("acquire"),
+        RateLimitDecision::Allowed { remaining: 1 }
+    );
+    assert_eq!(
+        store.try_acquire(&key(), &policy).await.expect("acquire"),
+        RateLimitDecision::Allowed { remaining: 0 }
+    );
+    let RateLimitDecision::Limited { retry_after } =
+        store.try_acquire(&key(), &policy).await.expect("acquire")
+    else {
+        panic!("the empty bucket should be limited");
+    };
+    assert!(retry_after > Duration::minutes(29) && retry_after <= Duration::minutes(30));
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn concurrent_acquisitions_cannot_overdraw_the_bucket() {
+    let store = PgRateLimitStore::new(support::pg_pool().await);
+    let policy = RateLimitPolicy::new(5, Duration::hours(1)).expect("policy should be valid");
+
+    let decisions = futures_util::future::join_all(
+        (0..20).map(|_| async { store.try_acquire(&key(), &policy).await }),
+    )
+    .await;
+
+    let allowed = decisions
+        .into_iter()
+        .map(|decision| decision.expect("acquire should succeed"))
+        .filter(RateLimitDecision::is_allowed)
+        .count();
+    assert_eq!(allowed, 5);
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn retention_removes_idle_buckets() {
+    let pool = support::pg_pool().await;
+    let policy = RateLimitPolicy::per_second(1).expect("policy should be valid");
+    PgRateLimitStore::new(pool.clone())
+        .try_acquire(&key(), &policy)
+        .await
+        .expect("acquire should succeed");
+    assert_eq!(bucket_count(&pool).await, 1);
+
+    let factory = PgUnitOfWorkFactory::new(pool.clone());
+    let mut uow = factory.begin().await.expect("unit of work should begin");
+    let retention = RetentionPolicy::purge(
+        RetentionTarget::RateLimitBuckets,
+        RetentionPeriod::new(Duration::zero()).expect("retention period should be valid"),
+    );
+    PgRetentionStore::new()
+        .remove_expired(
+            &mut uow,
+            &retention,
+            RetentionCutoff::before_now(retention.period),
+            RetentionBatchSize::new(NonZeroU32::new(10).expect("batch size should be non-zero")),
+        )
+        .await
+        .expect("idle buckets should be removed");
+    uow.commit().await.expect("commit should succeed");
+
+    assert_eq!(bucket_count(&pool).await, 0);
+}
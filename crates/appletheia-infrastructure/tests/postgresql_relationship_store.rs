@@ -0,0 +1,126 @@
+//! Runs against a local PostgreSQL server.
+//!
+//! `cargo test -p appletheia-infrastructure --test postgresql_relationship_store -- --ignored`;
+//! see `support` for the connection settings.
+mod support;
+
+use std::time::Duration;
+
+use appletheia_application::authorization::{
+    AggregateRef, RelationNameOwned, RelationRefOwned, Relationship, RelationshipChange,
+    RelationshipRevision, RelationshipStore, RelationshipSubject,
+};
+use appletheia_application::event::{AggregateIdValue, AggregateTypeOwned};
+use appletheia_application::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+use appletheia_infrastructure::postgresql::{
+    PgRelationshipStore, PgUnitOfWork, PgUnitOfWorkFactory,
+};
+use tokio::time::timeout;
+use uuid::Uuid;
+
+fn aggregate(aggregate_type: &str) -> AggregateRef {
+    AggregateRef::new(
+        AggregateTypeOwned::try_from(aggregate_type).expect("aggregate type should be valid"),
+        AggregateIdValue::from(Uuid::now_v7()),
+    )
+}
+
+fn viewer_change() -> RelationshipChange {
+    RelationshipChange::Upsert(Relationship {
+        aggregate: aggregate("document"),
+        relation: RelationRefOwned::new(
+            AggregateTypeOwned::try_from("document").expect("aggregate type should be valid"),
+            RelationNameOwned::try_from("viewer").expect("relation name should be valid"),
+        ),
+        subject: RelationshipSubject::Aggregate(aggregate("user")),
+    })
+}
+
+async fn revision(store: &PgRelationshipStore, uow: &mut PgUnitOfWork) -> RelationshipRevision {
+    store
+        .read_revision(uow)
+        .await
+        .expect("revision should be readable")
+}
+
+async fn committed_revision(
+    store: &PgRelationshipStore,
+    factory: &PgUnitOfWorkFactory,
+) -> RelationshipRevision {
+    let mut uow = factory.begin().await.expect("begin should succeed");
+    let revision = revision(store, &mut uow).await;
+    uow.rollback().await.expect("rollback should succeed");
+    revision
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn revision_is_private_to_the_writer_until_commit() {
+    let factory = PgUnitOfWorkFactory::new(support::pg_pool().await);
+    let store = PgRelationshipStore::new();
+    let before = committed_revision(&store, &factory).await;
+
+    let mut writer = factory.begin().await.expect("begin should succeed");
+    store
+        .apply_changes(&mut writer, &[viewer_change()])
+        .await
+        .expect("apply should succeed");
+    let pending = revision(&store, &mut writer).await;
+
+    assert_ne!(pending, before);
+    assert_eq!(committed_revision(&store, &factory).await, before);
+
+    writer.commit().await.expect("commit should succeed");
+    let after = committed_revision(&store, &factory).await;
+
+    assert!(after > before);
+    assert_ne!(after, pending);
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn rolled_back_changes_keep_the_revision() {
+    let factory = PgUnitOfWorkFactory::new(support::pg_pool().await);
+    let store = PgRelationshipStore::new();
+    let before = committed_revision(&store, &factory).await;
+
+    let mut writer = factory.begin().await.expect("begin should succeed");
+    store
+        .apply_changes(&mut writer, &[viewer_change()])
+        .await
+        .expect("apply should succeed");
+    writer.rollback().await.expect("rollback should succeed");
+
+    assert_eq!(committed_revision(&store, &factory).await, before);
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn concurrent_writers_do_not_wait_for_each_other() {
+    let factory = PgUnitOfWorkFactory::new(support::pg_pool().await);
+    let store = PgRelationshipStore::new();
+    let before = committed_revision(&store, &factory).await;
+
+    let mut first = factory.begin().await.expect("begin should succeed");
+    store
+        .apply_changes(&mut first, &[viewer_change()])
+        .await
+        .expect("apply should succeed");
+
+    let mut second = factory.begin().await.expect("begin should succeed");
+    timeout(
+        Duration::from_secs(2),
+        store.apply_changes(&mut second, &[viewer_change()]),
+    )
+    .await
+    .expect("second writer should not wait for the first")
+    .expect("apply should succeed");
+    second.commit().await.expect("commit should succeed");
+    let after_second = committed_revision(&store, &factory).await;
+
+    first.commit().await.expect("commit should succeed");
+    let after_first = committed_revision(&store, &factory).await;
+
+    assert!(after_second > before);
+    assert!(after_first > after_second);
+}
@@ -0,0 +1,99 @@
+//! Runs the storage conformance suite against a local PostgreSQL server.
+//!
+//! `cargo test -p appletheia-infrastructure --test postgresql_storage_conformance -- --ignored`;
+//! see `support` for the connection settings.
+mod support;
+
+use appletheia_application::conformance::{
+    ConformanceAggregate, StorageConformanceHarness, StorageConformanceSuite,
+};
+use appletheia_infrastructure::postgresql::command::PgIdempotencyService;
+use appletheia_infrastructure::postgresql::event::{PgEventReader, PgEventWriter};
+use appletheia_infrastructure::postgresql::outbox::event::{
+    PgEventOutboxFetcher, PgEventOutboxWriter,
+};
+use appletheia_infrastructure::postgresql::projection::PgEventFeedReader;
+use appletheia_infrastructure::postgresql::{
+    PgRelationshipStore, PgUniqueKeyReservationStore, PgUnitOfWork, PgUnitOfWorkFactory,
+};
+
+struct PgConformanceHarness {
+    unit_of_work_factory: PgUnitOfWorkFactory,
+    event_writer: PgEventWriter<ConformanceAggregate>,
+    event_reader: PgEventReader<ConformanceAggregate>,
+    event_feed_reader: PgEventFeedReader,
+    event_outbox_fetcher: PgEventOutboxFetcher,
+    event_outbox_writer: PgEventOutboxWriter,
+    idempotency_service: PgIdempotencyService,
+    unique_key_reservation_store: PgUniqueKeyReservationStore,
+    relationship_store: PgRelationshipStore,
+}
+
+impl StorageConformanceHarness for PgConformanceHarness {
+    type Uow = PgUnitOfWork;
+    type UnitOfWorkFactory = PgUnitOfWorkFactory;
+    type EventWriter = PgEventWriter<ConformanceAggregate>;
+    type EventReader = PgEventReader<ConformanceAggregate>;
+    type EventFeedReader = PgEventFeedReader;
+    type EventOutboxFetcher = PgEventOutboxFetcher;
+    type EventOutboxWriter = PgEventOutboxWriter;
+    type IdempotencyService = PgIdempotencyService;
+    type UniqueKeyReservationStore = PgUniqueKeyReservationStore;
+    type RelationshipStore = PgRelationshipStore;
+
+    async fn setup() -> Self {
+        Self {
+            unit_of_work_factory: PgUnitOfWorkFactory::new(support::pg_pool().await),
+            event_writer: PgEventWriter::new(),
+            event_reader: PgEventReader::new(),
+            event_feed_reader: PgEventFeedReader::new(),
+            event_outbox_fetcher: PgEventOutboxFetcher::new(),
+            event_outbox_writer: PgEventOutboxWriter::new(),
+            idempotency_service: PgIdempotencyService::new(),
+            unique_key_reservation_store: PgUniqueKeyReservationStore::new(),
+            relationship_store: PgRelationshipStore::new(),
+        }
+    }
+
+    fn unit_of_work_factory(&self) -> &Self::UnitOfWorkFactory {
+        &self.unit_of_work_factory
+    }
+
+    fn event_writer(&self) -> &Self::EventWriter {
+        &self.event_writer
+    }
+
+    fn event_reader(&self) -> &Self::EventReader {
+        &self.event_reader
+    }
+
+    fn event_feed_reader(&self) -> &Self::EventFeedReader {
+        &self.event_feed_reader
+    }
+
+    fn event_outbox_fetcher(&self) -> &Self::EventOutboxFetcher {
+        &self.event_outbox_fetcher
+    }
+
+    fn event_outbox_writer(&self) -> &Self::EventOutboxWriter {
+        &self.event_outbox_writer
+    }
+
+    fn idempotency_service(&self) -> &Self::IdempotencyService {
+        &self.idempotency_service
+    }
+
+    fn unique_key_reservation_store(&self) -> &Self::UniqueKeyReservationStore {
+        &self.unique_key_reservation_store
+    }
+
+    fn relationship_store(&self) -> &Self::RelationshipStore {
+        &self.relationship_store
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires a PostgreSQL server reachable at DATABASE_URL"]
+async fn postgresql_backend_passes_storage_conformance_suite() {
+    StorageConformanceSuite::<PgConformanceHarness>::run_all().await;
+}
@@ -0,0 +1,44 @@
+//! Shared setup for the PostgreSQL integration tests.
+//!
+//! Each test gets a fresh, migrated database. Set `DATABASE_URL` to a server where the user
+//! may create databases; it defaults to `postgres://postgres@127.0.0.1:5432/postgres`.
+#![allow(dead_code)]
+
+use appletheia_infrastructure::core::migration::EventStoreMigrator;
+use appletheia_infrastructure::postgresql::PgEventStoreMigrator;
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
+use sqlx::{Connection, PgConnection, PgPool};
+use uuid::Uuid;
+
+fn connect_options() -> PgConnectOptions {
+    let url = std::env::var("DATABASE_URL")
+        .unwrap_or_else(|_| "postgres://postgres@127.0.0.1:5432/postgres".to_owned());
+    url.parse()
+        .expect("DATABASE_URL should be a valid PostgreSQL URL")
+}
+
+/// Creates a new database, runs the migrations and returns a pool connected to it.
+pub async fn pg_pool() -> PgPool {
+    let options = connect_options();
+    let database = format!("appletheia_test_{}", Uuid::now_v7().simple());
+
+    let mut connection = PgConnection::connect_with(&options)
+        .await
+        .expect("PostgreSQL should be reachable");
+    sqlx::query(&format!("CREATE DATABASE {database}"))
+        .execute(&mut connection)
+        .await
+        .expect("test database should be created");
+    connection.close().await.expect("connection should close");
+
+    let pool = PgPoolOptions::new()
+        .max_connections(8)
+        .connect_with(options.database(&database))
+        .await
+        .expect("test database should be reachable");
+    PgEventStoreMigrator::new(pool.clone())
+        .run()
+        .await
+        .expect("migrations should run");
+    pool
+}
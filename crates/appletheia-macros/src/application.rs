@@ -14,3 +14,7 @@ pub(crate) fn command_derive(input: DeriveInput) -> Result<TokenStream> {
     let args = command::command_derive_args::CommandDeriveArgs::from_attrs(&input.attrs)?;
     command::command_derive_expand::expand_command_derive(input, args)
 }
+
+pub(crate) fn command_validator_derive(input: DeriveInput) -> Result<TokenStream> {
+    command::command_validator_derive_expand::expand_command_validator_derive(input)
+}
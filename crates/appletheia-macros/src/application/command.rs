@@ -1,3 +1,6 @@
 pub(crate) mod command_attribute_expand;
 pub(crate) mod command_derive_args;
 pub(crate) mod command_derive_expand;
+pub(crate) mod command_validator_derive_expand;
+pub(crate) mod command_validator_field_args;
+pub(crate) mod command_validator_rename_rule;
@@ -47,6 +47,11 @@ pub(crate) fn expand_command_attribute(
     item.attrs
         .push(syn::parse_quote!(#[command_derive(#attr_tokens)]));
 
+    if existing_derive_keys.contains("CommandValidator") {
+        item.attrs
+            .push(syn::parse_quote!(#[command_derive(validator)]));
+    }
+
     Ok(quote!(#item))
 }
 
@@ -4,11 +4,13 @@ use syn::{Attribute, LitStr, Result};
 #[derive(Debug)]
 pub(crate) struct CommandDeriveArgs {
     pub(crate) name: LitStr,
+    pub(crate) validator: bool,
 }
 
 impl CommandDeriveArgs {
     pub(crate) fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
         let mut name: Option<LitStr> = None;
+        let mut validator = false;
 
         for attr in attrs {
             if !attr.path().is_ident("command_derive") {
@@ -25,9 +27,14 @@ impl CommandDeriveArgs {
                     return Ok(());
                 }
 
+                if meta.path.is_ident("validator") {
+                    validator = true;
+                    return Ok(());
+                }
+
                 Err(syn::Error::new(
                     meta.path.span(),
-                    "unsupported key (expected `name`)",
+                    "unsupported key (expected `name` or `validator`)",
                 ))
             })?;
         }
@@ -39,6 +46,6 @@ impl CommandDeriveArgs {
             )
         })?;
 
-        Ok(Self { name })
+        Ok(Self { name, validator })
     }
 }
@@ -25,12 +25,21 @@ pub(crate) fn expand_command_derive(
     };
 
     let command_name = args.name;
+    let validator = args.validator.then(|| {
+        quote! {
+            fn validator(&self) -> ::core::option::Option<&dyn #application::command::CommandValidator> {
+                ::core::option::Option::Some(self)
+            }
+        }
+    });
 
     Ok(quote! {
         #[automatically_derived]
         impl #impl_generics #application::command::Command for #name #ty_generics #where_clause {
             const NAME: #application::command::CommandName =
                 #application::command::CommandName::new(#command_name);
+
+            #validator
         }
     })
 }
@@ -0,0 +1,195 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, GenericArgument, Path, PathArguments, Result, Type};
+
+use super::command_validator_field_args::{
+    CommandValidatorBounds, CommandValidatorFieldArgs, serde_rename, serde_rename_all,
+};
+use crate::utils::crate_path::resolve_application_path;
+
+pub(crate) fn expand_command_validator_derive(input: DeriveInput) -> Result<TokenStream> {
+    let application = resolve_application_path()?;
+    let input_span = input.span();
+    let rename_all = serde_rename_all(&input.attrs)?;
+
+    let name = input.ident;
+    let generics = input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let Data::Struct(data) = input.data else {
+        return Err(syn::Error::new(
+            input_span,
+            "`CommandValidator` can only be derived for structs",
+        ));
+    };
+
+    let fields = match data.fields {
+        Fields::Named(fields) => fields.named.into_iter().collect(),
+        Fields::Unit => Vec::new(),
+        Fields::Unnamed(fields) => {
+            return Err(syn::Error::new(
+                fields.span(),
+                "`CommandValidator` can only be derived for structs with named fields",
+            ));
+        }
+    };
+
+    let mut field_checks = Vec::new();
+    for field in fields {
+        let args = CommandValidatorFieldArgs::from_attrs(&field.attrs)?;
+        if args.is_empty() {
+            continue;
+        }
+
+        let ident = field.ident.expect("named fields have identifiers");
+        let path = match serde_rename(&field.attrs)? {
+            Some(rename) => rename.value(),
+            None => {
+                let field_name = ident.unraw().to_string();
+                match rename_all {
+                    Some(rule) => rule.apply_to_field(&field_name),
+                    None => field_name,
+                }
+            }
+        };
+
+        let checks = expand_checks(&application, &path, &args);
+        let checks = unwrap_value(&application, &field.ty, checks);
+        field_checks.push(quote! {
+            {
+                let value = &self.#ident;
+                #checks
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #application::command::CommandValidator for #name #ty_generics #where_clause {
+            fn validate(&self) -> ::core::result::Result<(), #application::command::CommandValidationError> {
+                #[allow(unused_mut)]
+                let mut validation_error = #application::command::CommandValidationError::new();
+                #(#field_checks)*
+                validation_error.into_result()
+            }
+        }
+    })
+}
+
+/// Wraps `checks` so they only run on the innermost value of `Option` and `FieldPatch` fields.
+fn unwrap_value(application: &Path, ty: &Type, checks: TokenStream) -> TokenStream {
+    let Some((wrapper, inner)) = wrapper_type(ty) else {
+        return checks;
+    };
+
+    let checks = unwrap_value(application, inner, checks);
+    match wrapper.as_str() {
+        "Option" => quote! {
+            if let ::core::option::Option::Some(value) = value {
+                #checks
+            }
+        },
+        _ => quote! {
+            if let #application::command::FieldPatch::Set(value) = value {
+                #checks
+            }
+        },
+    }
+}
+
+fn wrapper_type(ty: &Type) -> Option<(String, &Type)> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let wrapper = segment.ident.to_string();
+    if wrapper != "Option" && wrapper != "FieldPatch" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) if arguments.args.len() == 1 => Some((wrapper, inner)),
+        _ => None,
+    }
+}
+
+fn expand_checks(application: &Path, path: &str, args: &CommandValidatorFieldArgs) -> TokenStream {
+    let push = |code: &str, message: TokenStream| {
+        quote! {
+            validation_error.push(#application::command::CommandFieldError::new(
+                #path,
+                #code,
+                #message,
+            ));
+        }
+    };
+    let field_length = quote!(#application::command::CommandFieldLength::field_length(value));
+
+    let mut checks = Vec::new();
+
+    if args.non_empty {
+        let push = push("non_empty", quote!("must not be empty"));
+        checks.push(quote! {
+            if #field_length == 0 {
+                #push
+            }
+        });
+    }
+
+    if let Some(CommandValidatorBounds { min, max }) = &args.length {
+        if let Some(min) = min {
+            let push = push(
+                "length_min",
+                quote!(::std::format!("length must be at least {}", #min)),
+            );
+            checks.push(quote! {
+                if #field_length < #min {
+                    #push
+                }
+            });
+        }
+        if let Some(max) = max {
+            let push = push(
+                "length_max",
+                quote!(::std::format!("length must be at most {}", #max)),
+            );
+            checks.push(quote! {
+                if #field_length > #max {
+                    #push
+                }
+            });
+        }
+    }
+
+    if let Some(CommandValidatorBounds { min, max }) = &args.range {
+        if let Some(min) = min {
+            let push = push(
+                "range_min",
+                quote!(::std::format!("must be at least {}", #min)),
+            );
+            checks.push(quote! {
+                if *value < #min {
+                    #push
+                }
+            });
+        }
+        if let Some(max) = max {
+            let push = push(
+                "range_max",
+                quote!(::std::format!("must be at most {}", #max)),
+            );
+            checks.push(quote! {
+                if *value > #max {
+                    #push
+                }
+            });
+        }
+    }
+
+    quote!(#(#checks)*)
+}
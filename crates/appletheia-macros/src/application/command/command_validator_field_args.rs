@@ -0,0 +1,245 @@
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Attribute, Expr, ExprLit, Lit, LitStr, Meta, Result, Token, token};
+
+use super::command_validator_rename_rule::CommandValidatorRenameRule;
+
+#[derive(Debug, Default)]
+pub(crate) struct CommandValidatorBounds {
+    pub(crate) min: Option<Expr>,
+    pub(crate) max: Option<Expr>,
+}
+
+impl CommandValidatorBounds {
+    fn parse(meta: &ParseNestedMeta<'_>, rule: &str) -> Result<Self> {
+        let mut bounds = Self::default();
+        if !meta.input.peek(token::Paren) {
+            return Err(syn::Error::new(
+                meta.path.span(),
+                format!("`{rule}` needs `min`, `max` or both"),
+            ));
+        }
+
+        meta.parse_nested_meta(|bound| {
+            let slot = if bound.path.is_ident("min") {
+                &mut bounds.min
+            } else if bound.path.is_ident("max") {
+                &mut bounds.max
+            } else {
+                return Err(syn::Error::new(
+                    bound.path.span(),
+                    format!("unsupported `{rule}` key (expected `min` or `max`)"),
+                ));
+            };
+
+            if slot.is_some() {
+                return Err(syn::Error::new(bound.path.span(), "duplicate bound"));
+            }
+            *slot = Some(bound.value()?.parse()?);
+            Ok(())
+        })?;
+
+        if bounds.min.is_none() && bounds.max.is_none() {
+            return Err(syn::Error::new(
+                meta.path.span(),
+                format!("`{rule}` needs `min`, `max` or both"),
+            ));
+        }
+
+        Ok(bounds)
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct CommandValidatorFieldArgs {
+    pub(crate) non_empty: bool,
+    pub(crate) length: Option<CommandValidatorBounds>,
+    pub(crate) range: Option<CommandValidatorBounds>,
+}
+
+impl CommandValidatorFieldArgs {
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut args = Self::default();
+
+        for attr in attrs {
+            if !attr.path().is_ident("validate") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("non_empty") {
+                    if args.non_empty {
+                        return Err(syn::Error::new(meta.path.span(), "duplicate `non_empty`"));
+                    }
+                    args.non_empty = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("length") {
+                    if args.length.is_some() {
+                        return Err(syn::Error::new(meta.path.span(), "duplicate `length`"));
+                    }
+                    args.length = Some(CommandValidatorBounds::parse(&meta, "length")?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("range") {
+                    if args.range.is_some() {
+                        return Err(syn::Error::new(meta.path.span(), "duplicate `range`"));
+                    }
+                    args.range = Some(CommandValidatorBounds::parse(&meta, "range")?);
+                    return Ok(());
+                }
+
+                Err(syn::Error::new(
+                    meta.path.span(),
+                    "unsupported key (expected `non_empty`, `length` or `range`)",
+                ))
+            })?;
+        }
+
+        Ok(args)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        !self.non_empty && self.length.is_none() && self.range.is_none()
+    }
+}
+
+/// Returns the name given by `#[serde(rename = "...")]`, if any.
+pub(crate) fn serde_rename(attrs: &[Attribute]) -> Result<Option<LitStr>> {
+    serde_value(attrs, "rename")
+}
+
+/// Returns the rule given by a container's `#[serde(rename_all = "...")]`, if any.
+pub(crate) fn serde_rename_all(attrs: &[Attribute]) -> Result<Option<CommandValidatorRenameRule>> {
+    serde_value(attrs, "rename_all")?
+        .map(|rule| CommandValidatorRenameRule::from_lit(&rule))
+        .transpose()
+}
+
+/// Reads `key = "..."` from `#[serde(...)]`, taking the `deserialize` side of
+/// `key(serialize = "...", deserialize = "...")` since commands are validated after deserialization.
+fn serde_value(attrs: &[Attribute], key: &str) -> Result<Option<LitStr>> {
+    for attr in attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in metas {
+            if !meta.path().is_ident(key) {
+                continue;
+            }
+            match meta {
+                Meta::NameValue(name_value) => {
+                    if let Some(value) = lit_str(&name_value.value) {
+                        return Ok(Some(value));
+                    }
+                }
+                Meta::List(list) => {
+                    let sides =
+                        list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+                    for side in sides {
+                        let Meta::NameValue(name_value) = side else {
+                            continue;
+                        };
+                        if !name_value.path.is_ident("deserialize") {
+                            continue;
+                        }
+                        if let Some(value) = lit_str(&name_value.value) {
+                            return Ok(Some(value));
+                        }
+                    }
+                }
+                Meta::Path(_) => {}
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn lit_str(expr: &Expr) -> Option<LitStr> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(value),
+            ..
+        }) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{Attribute, parse_quote};
+
+    use super::{CommandValidatorFieldArgs, serde_rename, serde_rename_all};
+    use crate::application::command::command_validator_rename_rule::CommandValidatorRenameRule;
+
+    #[test]
+    fn from_attrs_collects_rules_across_attributes() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[validate(non_empty, length(max = 64))]),
+            parse_quote!(#[validate(range(min = 1))]),
+        ];
+
+        let args = CommandValidatorFieldArgs::from_attrs(&attrs).expect("args should parse");
+
+        assert!(args.non_empty);
+        assert!(
+            args.length
+                .as_ref()
+                .is_some_and(|length| length.min.is_none())
+        );
+        assert!(args.range.as_ref().is_some_and(|range| range.max.is_none()));
+    }
+
+    #[test]
+    fn from_attrs_rejects_bounds_without_limits() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[validate(length)])];
+
+        let error = CommandValidatorFieldArgs::from_attrs(&attrs).expect_err("args should fail");
+
+        assert!(error.to_string().contains("needs `min`, `max` or both"));
+    }
+
+    #[test]
+    fn serde_rename_reads_rename_value() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[serde(default, rename = "displayName")])];
+
+        let rename = serde_rename(&attrs).expect("attrs should parse");
+
+        assert_eq!(
+            rename.map(|rename| rename.value()).as_deref(),
+            Some("displayName")
+        );
+    }
+
+    #[test]
+    fn serde_rename_reads_deserialize_side() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(
+            #[serde(rename(serialize = "name", deserialize = "displayName"))]
+        )];
+
+        let rename = serde_rename(&attrs).expect("attrs should parse");
+
+        assert_eq!(
+            rename.map(|rename| rename.value()).as_deref(),
+            Some("displayName")
+        );
+    }
+
+    #[test]
+    fn serde_rename_all_reads_container_rule() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[serde(deny_unknown_fields)]),
+            parse_quote!(#[serde(rename_all = "camelCase")]),
+        ];
+
+        let rule = serde_rename_all(&attrs).expect("attrs should parse");
+
+        assert_eq!(rule, Some(CommandValidatorRenameRule::Camel));
+    }
+}
@@ -0,0 +1,106 @@
+use syn::{LitStr, Result};
+
+/// A `#[serde(rename_all = "...")]` rule, applied to field names the way serde applies it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CommandValidatorRenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl CommandValidatorRenameRule {
+    pub(crate) fn from_lit(lit: &LitStr) -> Result<Self> {
+        match lit.value().as_str() {
+            "lowercase" => Ok(Self::Lower),
+            "UPPERCASE" => Ok(Self::Upper),
+            "PascalCase" => Ok(Self::Pascal),
+            "camelCase" => Ok(Self::Camel),
+            "snake_case" => Ok(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnake),
+            "kebab-case" => Ok(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Ok(Self::ScreamingKebab),
+            other => Err(syn::Error::new(
+                lit.span(),
+                format!("unknown `rename_all` rule `{other}`"),
+            )),
+        }
+    }
+
+    /// Renames a `snake_case` field name.
+    pub(crate) fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_owned(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => {
+                let mut pascal = String::with_capacity(field.len());
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            Self::Camel => {
+                let pascal = Self::Pascal.apply_to_field(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => pascal,
+                }
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::LitStr;
+
+    use super::CommandValidatorRenameRule;
+
+    #[test]
+    fn apply_to_field_matches_serde_rules() {
+        let cases = [
+            ("lowercase", "display_name"),
+            ("UPPERCASE", "DISPLAY_NAME"),
+            ("PascalCase", "DisplayName"),
+            ("camelCase", "displayName"),
+            ("snake_case", "display_name"),
+            ("SCREAMING_SNAKE_CASE", "DISPLAY_NAME"),
+            ("kebab-case", "display-name"),
+            ("SCREAMING-KEBAB-CASE", "DISPLAY-NAME"),
+        ];
+
+        for (rule, expected) in cases {
+            let rule = CommandValidatorRenameRule::from_lit(&LitStr::new(
+                rule,
+                proc_macro2::Span::call_site(),
+            ))
+            .expect("rule should parse");
+
+            assert_eq!(rule.apply_to_field("display_name"), expected);
+        }
+    }
+
+    #[test]
+    fn from_lit_rejects_unknown_rules() {
+        let lit = LitStr::new("Title Case", proc_macro2::Span::call_site());
+
+        let error = CommandValidatorRenameRule::from_lit(&lit).expect_err("rule should fail");
+
+        assert!(error.to_string().contains("unknown `rename_all` rule"));
+    }
+}
@@ -16,6 +16,14 @@ pub fn command_derive(input: TokenStream) -> TokenStream {
         .into()
 }
 
+#[proc_macro_derive(CommandValidator, attributes(validate))]
+pub fn command_validator_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    application::command_validator_derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 #[proc_macro_attribute]
 pub fn command(attr: TokenStream, item: TokenStream) -> TokenStream {
     application::command_attribute(attr, item)
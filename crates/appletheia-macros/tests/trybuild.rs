@@ -2,6 +2,7 @@
 fn ui_pass() {
     let t = trybuild::TestCases::new();
     t.pass("tests/ui/command_pass.rs");
+    t.pass("tests/ui/command_validator_pass.rs");
     t.pass("tests/ui/aggregate_pass_default_core.rs");
     t.pass("tests/ui/aggregate_pass_core_ident.rs");
     t.pass("tests/ui/aggregate_pass_core_string.rs");
@@ -0,0 +1,76 @@
+#![allow(dead_code, unused_imports)]
+
+use appletheia_application::command::{Command, CommandValidator, FieldPatch};
+use appletheia_macros::{CommandValidator, command};
+use serde::{Deserialize, Serialize};
+
+#[command(name = "rename_account")]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, CommandValidator)]
+struct RenameAccountCommand {
+    #[validate(non_empty, length(max = 8))]
+    #[serde(rename = "displayName")]
+    display_name: String,
+    #[validate(range(min = 1, max = 10))]
+    priority: Option<u8>,
+    #[validate(length(min = 1))]
+    tags: FieldPatch<Vec<String>>,
+    note: String,
+}
+
+#[command(name = "update_profile")]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, CommandValidator)]
+#[serde(rename_all = "camelCase")]
+struct UpdateProfileCommand {
+    #[validate(non_empty)]
+    display_name: String,
+    #[validate(non_empty)]
+    #[serde(rename = "bio")]
+    biography_text: String,
+    #[validate(range(max = 3))]
+    r#type: u8,
+}
+
+fn main() {
+    let valid = RenameAccountCommand {
+        display_name: "Alice".to_owned(),
+        priority: None,
+        tags: FieldPatch::Unchanged,
+        note: String::new(),
+    };
+    assert!(valid.validator().is_some());
+    assert!(valid.validate().is_ok());
+
+    let invalid = RenameAccountCommand {
+        display_name: String::new(),
+        priority: Some(11),
+        tags: FieldPatch::Set(Vec::new()),
+        note: String::new(),
+    };
+    let error = invalid.validate().expect_err("command should be invalid");
+    let codes: Vec<(&str, &str)> = error
+        .field_errors()
+        .iter()
+        .map(|field_error| (field_error.path.as_str(), field_error.code.as_str()))
+        .collect();
+    assert_eq!(
+        codes,
+        [
+            ("displayName", "non_empty"),
+            ("priority", "range_max"),
+            ("tags", "length_min"),
+        ]
+    );
+
+    let invalid = UpdateProfileCommand {
+        display_name: String::new(),
+        biography_text: String::new(),
+        r#type: 4,
+    };
+    let error = invalid.validate().expect_err("command should be invalid");
+    let paths: Vec<&str> = error
+        .field_errors()
+        .iter()
+        .map(|field_error| field_error.path.as_str())
+        .collect();
+    assert_eq!(paths, ["displayName", "bio", "type"]);
+}
@@ -117,8 +117,8 @@ mod tests {
     use std::sync::{Arc, Mutex};
 
     use appletheia::application::authorization::{
-        AggregateRef, Relation, RelationRefOwned, RelationshipChange, RelationshipStore,
-        RelationshipStoreError, RelationshipSubject,
+        AggregateRef, Relation, RelationRefOwned, RelationshipChange, RelationshipRevision,
+        RelationshipStore, RelationshipStoreError, RelationshipSubject,
     };
     use appletheia::application::event::{EventEnvelope, EventSequence, SerializedEventPayload};
     use appletheia::application::projection::Projector;
@@ -203,6 +203,13 @@ mod tests {
                 .expect("lock should succeed")
                 .clone())
         }
+
+        async fn read_revision(
+            &self,
+            _uow: &mut Self::Uow,
+        ) -> Result<RelationshipRevision, RelationshipStoreError> {
+            Ok(RelationshipRevision::initial())
+        }
     }
 
     fn created_event_envelope(owner: OrganizationOwner) -> EventEnvelope {
@@ -62,8 +62,8 @@ mod tests {
     use std::sync::{Arc, Mutex};
 
     use appletheia::application::authorization::{
-        AggregateRef, Relation, RelationRefOwned, RelationshipChange, RelationshipStore,
-        RelationshipStoreError, RelationshipSubject,
+        AggregateRef, Relation, RelationRefOwned, RelationshipChange, RelationshipRevision,
+        RelationshipStore, RelationshipStoreError, RelationshipSubject,
     };
     use appletheia::application::event::{EventEnvelope, EventSequence, SerializedEventPayload};
     use appletheia::application::projection::Projector;
@@ -136,6 +136,13 @@ mod tests {
         ) -> Result<Vec<RelationshipSubject>, RelationshipStoreError> {
             Ok(Vec::new())
         }
+
+        async fn read_revision(
+            &self,
+            _uow: &mut Self::Uow,
+        ) -> Result<RelationshipRevision, RelationshipStoreError> {
+            Ok(RelationshipRevision::initial())
+        }
     }
 
     fn identity() -> UserIdentity {
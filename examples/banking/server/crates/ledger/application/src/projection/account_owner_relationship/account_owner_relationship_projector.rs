@@ -110,8 +110,8 @@ mod tests {
     use std::sync::{Arc, Mutex};
 
     use appletheia::application::authorization::{
-        AggregateRef, Relation, RelationRefOwned, RelationshipChange, RelationshipStore,
-        RelationshipStoreError, RelationshipSubject,
+        AggregateRef, Relation, RelationRefOwned, RelationshipChange, RelationshipRevision,
+        RelationshipStore, RelationshipStoreError, RelationshipSubject,
     };
     use appletheia::application::event::{EventEnvelope, EventSequence, SerializedEventPayload};
     use appletheia::application::projection::Projector;
@@ -200,6 +200,13 @@ mod tests {
                 .expect("lock should succeed")
                 .clone())
         }
+
+        async fn read_revision(
+            &self,
+            _uow: &mut Self::Uow,
+        ) -> Result<RelationshipRevision, RelationshipStoreError> {
+            Ok(RelationshipRevision::initial())
+        }
     }
 
     fn ownership_transferred_event_envelope() -> (EventEnvelope, OrganizationId, UserId) {